@@ -1,5 +1,3 @@
-mod root_name;
-
 use anyhow::{format_err, Context, Result};
 use clap::{crate_version, load_yaml, App};
 use jtd::{Schema, SerdeSchema};
@@ -26,9 +24,10 @@ fn main() -> Result<()> {
     // Determine the desired root name to pass to jtd_codegen. If the user has
     // supplied root-name, we'll use that. Otherwise, we'll infer a desired root
     // name from the name of the input file.
-    let root_name =
-        root_name::root_name_from_input_name(matches.value_of("root-name").unwrap_or(input))
-            .to_owned();
+    let root_name = jtd_codegen::root_name::root_name_from_input_name(
+        matches.value_of("root-name").unwrap_or(input),
+    )
+    .to_owned();
 
     // Open, parse, and validate the input schema.
     let input_reader: Box<dyn Read> = match input {
@@ -68,7 +67,21 @@ fn main() -> Result<()> {
 
         let package = matches.value_of("go-package").unwrap().to_owned();
 
-        let target = jtd_codegen_target_go::Target::new(package);
+        let tags = matches
+            .values_of("go-tags")
+            .map(|tags| tags.map(str::to_owned).collect())
+            .unwrap_or_default();
+
+        let mut target = jtd_codegen_target_go::Target::new(package)
+            .with_sealed_discriminators(matches.is_present("go-sealed-discriminators"))
+            .with_validation(matches.is_present("go-validation"))
+            .with_file_per_type(matches.is_present("go-file-per-type"))
+            .with_optionals(matches.is_present("go-optionals"))
+            .with_tags(tags);
+
+        if let Some(json_package) = matches.value_of("go-json-package") {
+            target = target.with_json_package(json_package.to_owned());
+        }
 
         let codegen_info =
             jtd_codegen::codegen(&target, root_name.clone(), &schema, &Path::new(out_dir))
@@ -77,12 +90,37 @@ fn main() -> Result<()> {
         log.finish("Go", &codegen_info);
     }
 
+    if let Some(out_dir) = matches.value_of("java-gson-out") {
+        log.start("Java + Gson", out_dir);
+
+        let package = matches.value_of("java-gson-package").unwrap().to_owned();
+        let target = jtd_codegen_target_java_gson::Target::new(package);
+
+        let codegen_info =
+            jtd_codegen::codegen(&target, root_name.clone(), &schema, Path::new(out_dir))
+                .with_context(|| "Failed to generate Java + Gson code")?;
+
+        log.finish("Java + Gson", &codegen_info);
+    }
+
     if let Some(out_dir) = matches.value_of("java-jackson-out") {
         log.start("Java + Jackson", out_dir);
 
         let package = matches.value_of("java-jackson-package").unwrap().to_owned();
 
-        let target = jtd_codegen_target_java_jackson::Target::new(package);
+        let nullness_annotations = matches
+            .value_of("java-jackson-nullness")
+            .map(|annotations| match annotations {
+                "jspecify" => jtd_codegen_target_java_jackson::NullnessAnnotations::JSpecify,
+                "jetbrains" => jtd_codegen_target_java_jackson::NullnessAnnotations::JetBrains,
+                _ => unreachable!(),
+            });
+
+        let target = jtd_codegen_target_java_jackson::Target::new(package)
+            .with_records(matches.is_present("java-jackson-records"))
+            .with_builders(matches.is_present("java-jackson-builders"))
+            .with_optionals(matches.is_present("java-jackson-optionals"))
+            .with_nullness_annotations(nullness_annotations);
 
         let codegen_info =
             jtd_codegen::codegen(&target, root_name.clone(), &schema, &Path::new(out_dir))
@@ -94,7 +132,23 @@ fn main() -> Result<()> {
     if let Some(out_dir) = matches.value_of("python-out") {
         log.start("Python", out_dir);
 
-        let target = jtd_codegen_target_python::Target::new();
+        let mut target = jtd_codegen_target_python::Target::new()
+            .with_pydantic(matches.is_present("python-pydantic"))
+            .with_typed_dict(matches.is_present("python-typed-dict"))
+            .with_validation(matches.is_present("python-validation"))
+            .with_frozen(matches.is_present("python-frozen"));
+
+        if let Some(version) = matches.value_of("python-version") {
+            let parsed = version
+                .split_once('.')
+                .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)));
+
+            let python_version = parsed
+                .ok_or_else(|| format_err!("{:?} is not a version like 3.10", version))
+                .with_context(|| "Failed to parse Python version")?;
+
+            target = target.with_python_version(python_version);
+        }
 
         let codegen_info =
             jtd_codegen::codegen(&target, root_name.clone(), &schema, &Path::new(out_dir))
@@ -134,7 +188,11 @@ fn main() -> Result<()> {
     if let Some(out_dir) = matches.value_of("rust-out") {
         log.start("Rust", out_dir);
 
-        let target = jtd_codegen_target_rust::Target::new();
+        let target = jtd_codegen_target_rust::Target::new()
+            .with_borrowed(matches.is_present("rust-borrowed"))
+            .with_newtypes(matches.is_present("rust-newtypes"))
+            .with_no_std(matches.is_present("rust-no-std"))
+            .with_native_json(matches.is_present("rust-native-json"));
 
         let codegen_info =
             jtd_codegen::codegen(&target, root_name.clone(), &schema, &Path::new(out_dir))
@@ -146,7 +204,14 @@ fn main() -> Result<()> {
     if let Some(out_dir) = matches.value_of("typescript-out") {
         log.start("TypeScript", out_dir);
 
-        let target = jtd_codegen_target_typescript::Target::new();
+        let target = jtd_codegen_target_typescript::Target::new()
+            .with_validators(matches.is_present("typescript-validators"))
+            .with_union_enums(matches.is_present("typescript-union-enums"))
+            .with_dates(matches.is_present("typescript-dates"))
+            .with_readonly(matches.is_present("typescript-readonly"))
+            .with_brands(matches.is_present("typescript-brands"))
+            .with_file_per_type(matches.is_present("typescript-file-per-type"))
+            .with_js_extensions(matches.is_present("typescript-js-extensions"));
 
         let codegen_info =
             jtd_codegen::codegen(&target, root_name.clone(), &schema, &Path::new(out_dir))
@@ -155,6 +220,18 @@ fn main() -> Result<()> {
         log.finish("TypeScript", &codegen_info);
     }
 
+    if let Some(out_dir) = matches.value_of("typescript-zod-out") {
+        log.start("TypeScript + Zod", out_dir);
+
+        let target = jtd_codegen_target_typescript_zod::Target::new();
+
+        let codegen_info =
+            jtd_codegen::codegen(&target, root_name.clone(), &schema, Path::new(out_dir))
+                .with_context(|| "Failed to generate TypeScript + Zod code")?;
+
+        log.finish("TypeScript + Zod", &codegen_info);
+    }
+
     log.flush();
     Ok(())
 }
@@ -1,14 +1,15 @@
 use crate::target::metadata::Metadata;
-use crate::target::{NameableKind, OptionalPropertyHandlingStrategy, Target};
+use crate::target::{Expr, NameableKind, OptionalPropertyHandlingStrategy, Target};
 use jtd::form::TypeValue;
 use jtd::{Form, Schema};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use teeter_inflector::string::singularize::to_singular;
 
 #[derive(Debug)]
 pub struct SchemaAst {
     pub root: Ast,
     pub definitions: BTreeMap<String, Ast>,
+    pub marked_definitions: BTreeSet<String>,
 }
 
 impl SchemaAst {
@@ -25,7 +26,36 @@ impl SchemaAst {
             })
             .collect();
 
-        Self { root, definitions }
+        let marked_definitions = Self::marked_definitions(target, &definitions);
+
+        Self {
+            root,
+            definitions,
+            marked_definitions,
+        }
+    }
+
+    fn marked_definitions<T: Target>(
+        target: &T,
+        definitions: &BTreeMap<String, Ast>,
+    ) -> BTreeSet<String> {
+        // Definitions can refer to one another, possibly cyclically. So we
+        // start by assuming no definition is marked, and keep marking
+        // definitions until we reach a fixed point.
+        let mut marked = BTreeSet::new();
+        loop {
+            let mut changed = false;
+            for (name, ast) in definitions {
+                if !marked.contains(name) && ast.marked(target, &marked) {
+                    marked.insert(name.clone());
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                return marked;
+            }
+        }
     }
 }
 
@@ -143,12 +173,21 @@ pub struct DiscriminatorVariant {
     pub fields: Vec<Field>,
 }
 
+impl DiscriminatorVariant {
+    pub fn marked<T: Target>(&self, target: &T, marked_definitions: &BTreeSet<String>) -> bool {
+        self.fields
+            .iter()
+            .any(|field| field.type_.marked(target, marked_definitions))
+    }
+}
+
 #[derive(Debug)]
 pub struct Field {
     pub metadata: Metadata,
     pub name: String,
     pub json_name: String,
     pub optional: bool,
+    pub nullable: bool,
     pub type_: Ast,
 }
 
@@ -277,6 +316,7 @@ impl Ast {
                         name: ast_name,
                         json_name: json_name.into(),
                         optional: false,
+                        nullable: is_nullable(sub_schema),
                         type_: ast,
                     });
                 }
@@ -306,6 +346,7 @@ impl Ast {
                         name: ast_name,
                         json_name: json_name.into(),
                         optional: true,
+                        nullable: is_nullable(sub_schema),
                         type_: ast,
                     });
                 }
@@ -394,6 +435,37 @@ impl Ast {
         }
     }
 
+    pub fn marked<T: Target>(&self, target: &T, marked_definitions: &BTreeSet<String>) -> bool {
+        let leaf = |metadata: &Metadata, expr: Expr| target.marks(metadata, &expr);
+
+        match self {
+            Ast::Ref { definition, .. } => marked_definitions.contains(definition),
+            Ast::Empty { metadata } => leaf(metadata, Expr::Empty),
+            Ast::Boolean { metadata } => leaf(metadata, Expr::Boolean),
+            Ast::Int8 { metadata } => leaf(metadata, Expr::Int8),
+            Ast::Uint8 { metadata } => leaf(metadata, Expr::Uint8),
+            Ast::Int16 { metadata } => leaf(metadata, Expr::Int16),
+            Ast::Uint16 { metadata } => leaf(metadata, Expr::Uint16),
+            Ast::Int32 { metadata } => leaf(metadata, Expr::Int32),
+            Ast::Uint32 { metadata } => leaf(metadata, Expr::Uint32),
+            Ast::Float32 { metadata } => leaf(metadata, Expr::Float32),
+            Ast::Float64 { metadata } => leaf(metadata, Expr::Float64),
+            Ast::String { metadata } => leaf(metadata, Expr::String),
+            Ast::Timestamp { metadata } => leaf(metadata, Expr::Timestamp),
+            Ast::ArrayOf { type_, .. }
+            | Ast::DictOf { type_, .. }
+            | Ast::NullableOf { type_, .. }
+            | Ast::Alias { type_, .. } => type_.marked(target, marked_definitions),
+            Ast::Enum { .. } => false,
+            Ast::Struct { fields, .. } => fields
+                .iter()
+                .any(|field| field.type_.marked(target, marked_definitions)),
+            Ast::Discriminator { variants, .. } => variants
+                .iter()
+                .any(|variant| variant.marked(target, marked_definitions)),
+        }
+    }
+
     fn into_nullable<T: Target>(self, target: &T, want_nullable: bool, metadata: Metadata) -> Self {
         let strategy = target.strategy();
         let already_nullable = match self {
@@ -429,3 +501,18 @@ impl Ast {
         }
     }
 }
+
+// Whether a schema accepts null. This is what the schema says, whatever the
+// target's strategy does with it.
+fn is_nullable(schema: &Schema) -> bool {
+    match schema.form {
+        Form::Empty => true,
+        Form::Ref(ref ref_) => ref_.nullable,
+        Form::Type(ref type_) => type_.nullable,
+        Form::Enum(ref enum_) => enum_.nullable,
+        Form::Elements(ref elements) => elements.nullable,
+        Form::Properties(ref properties) => properties.nullable,
+        Form::Values(ref values) => values.nullable,
+        Form::Discriminator(ref discriminator) => discriminator.nullable,
+    }
+}
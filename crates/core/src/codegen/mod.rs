@@ -9,10 +9,11 @@ use crate::target::{
 use ast::{Ast, SchemaAst};
 use jtd::Schema;
 use namespace::Namespace;
-use std::collections::BTreeMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub struct CodegenInfo {
     pub root_name: String,
@@ -36,6 +37,11 @@ struct CodeGenerator<'a, T> {
     out_dir: &'a Path,
     strategy: Strategy,
     definition_names: BTreeMap<String, String>,
+    marked_definitions: BTreeSet<String>,
+
+    // The files written so far, relative to out_dir. Files are written from
+    // deep within codegen_ast, which only borrows self.
+    files: RefCell<Vec<PathBuf>>,
 }
 
 struct FileData<T> {
@@ -50,6 +56,8 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
             out_dir,
             strategy: target.strategy(),
             definition_names: BTreeMap::new(),
+            marked_definitions: BTreeSet::new(),
+            files: RefCell::new(Vec::new()),
         }
     }
 
@@ -68,6 +76,8 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
             self.definition_names.insert(name.clone(), ast_name);
         }
 
+        self.marked_definitions = schema_ast.marked_definitions;
+
         // If the target is using FilePerType partitioning, then this state
         // won't actually be used at all. If it's using SingleFile partitioning,
         // then this is the only file state that will be used.
@@ -100,6 +110,7 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
             &mut T::FileState::default(),
             Item::Auxiliary {
                 out_dir: self.out_dir.to_owned(),
+                files: self.files.take(),
             },
         )?;
 
@@ -116,80 +127,100 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
         ast_name: String,
         ast: Ast,
     ) -> Result<String> {
+        // The name the target will declare an item under, and that the rest of
+        // codegen will refer to it by. This only differs from ast_name if the
+        // target marks the item.
+        let type_name = match ast {
+            Ast::Alias { .. }
+            | Ast::Enum { .. }
+            | Ast::Struct { .. }
+            | Ast::Discriminator { .. } => self.type_name(
+                ast_name.clone(),
+                ast.marked(self.target, &self.marked_definitions),
+            ),
+            _ => ast_name.clone(),
+        };
+
         Ok(match ast {
             // Ref nodes are a special sort of "expr-like" node, where we
             // already know what the name of the expression is; it's the name of
             // the definition.
-            Ast::Ref { definition, .. } => self.definition_names[&definition].clone(),
+            Ast::Ref { definition, .. } => self.type_name(
+                self.definition_names[&definition].clone(),
+                self.marked_definitions.contains(&definition),
+            ),
 
             // The remaining "expr-like" node types just build up strings and
             // possibly alter the per-file state (usually in order to add
             // "imports" to the file).
             Ast::Empty { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Empty)
+                    .expr(&mut file_data.state, metadata, Expr::Empty)?
             }
             Ast::Boolean { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Boolean)
+                    .expr(&mut file_data.state, metadata, Expr::Boolean)?
+            }
+            Ast::Int8 { metadata } => {
+                self.target
+                    .expr(&mut file_data.state, metadata, Expr::Int8)?
             }
-            Ast::Int8 { metadata } => self.target.expr(&mut file_data.state, metadata, Expr::Int8),
             Ast::Uint8 { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Uint8)
+                    .expr(&mut file_data.state, metadata, Expr::Uint8)?
             }
             Ast::Int16 { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Int16)
+                    .expr(&mut file_data.state, metadata, Expr::Int16)?
             }
             Ast::Uint16 { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Uint16)
+                    .expr(&mut file_data.state, metadata, Expr::Uint16)?
             }
             Ast::Int32 { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Int32)
+                    .expr(&mut file_data.state, metadata, Expr::Int32)?
             }
             Ast::Uint32 { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Uint32)
+                    .expr(&mut file_data.state, metadata, Expr::Uint32)?
             }
             Ast::Float32 { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Float32)
+                    .expr(&mut file_data.state, metadata, Expr::Float32)?
             }
             Ast::Float64 { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Float64)
+                    .expr(&mut file_data.state, metadata, Expr::Float64)?
             }
             Ast::String { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::String)
+                    .expr(&mut file_data.state, metadata, Expr::String)?
             }
             Ast::Timestamp { metadata } => {
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::Timestamp)
+                    .expr(&mut file_data.state, metadata, Expr::Timestamp)?
             }
             Ast::ArrayOf { metadata, type_ } => {
                 let sub_name = self.ast_name(global_namespace, &type_);
                 let sub_expr = self.codegen_ast(global_namespace, file_data, sub_name, *type_)?;
 
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::ArrayOf(sub_expr))
+                    .expr(&mut file_data.state, metadata, Expr::ArrayOf(sub_expr))?
             }
             Ast::DictOf { metadata, type_ } => {
                 let sub_name = self.ast_name(global_namespace, &type_);
                 let sub_expr = self.codegen_ast(global_namespace, file_data, sub_name, *type_)?;
 
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::DictOf(sub_expr))
+                    .expr(&mut file_data.state, metadata, Expr::DictOf(sub_expr))?
             }
             Ast::NullableOf { metadata, type_ } => {
                 let sub_name = self.ast_name(global_namespace, &type_);
                 let sub_expr = self.codegen_ast(global_namespace, file_data, sub_name, *type_)?;
 
                 self.target
-                    .expr(&mut file_data.state, metadata, Expr::NullableOf(sub_expr))
+                    .expr(&mut file_data.state, metadata, Expr::NullableOf(sub_expr))?
             }
 
             // Now the "item-like" node types. For these, the target is given a
@@ -211,7 +242,7 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
             // Most of the logic for handling this is done through with_subfile.
             Ast::Alias {
                 metadata, type_, ..
-            } => self.with_subfile(ast_name.clone(), file_data, |file_data| {
+            } => self.with_subfile(ast_name, type_name.clone(), file_data, |file_data| {
                 let sub_name = self.ast_name(global_namespace, &type_);
                 let sub_type = self.codegen_ast(global_namespace, file_data, sub_name, *type_)?;
 
@@ -220,7 +251,7 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
                     &mut file_data.state,
                     Item::Alias {
                         metadata,
-                        name: ast_name,
+                        name: type_name,
                         type_: sub_type,
                     },
                 )
@@ -228,7 +259,7 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
 
             Ast::Enum {
                 metadata, members, ..
-            } => self.with_subfile(ast_name.clone(), file_data, |file_data| {
+            } => self.with_subfile(ast_name, type_name.clone(), file_data, |file_data| {
                 // A namespace for member names. Unused if the target has
                 // unmodularized enum member names.
                 let mut member_names = Namespace::new();
@@ -249,7 +280,7 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
                     &mut file_data.state,
                     Item::Enum {
                         metadata,
-                        name: ast_name,
+                        name: type_name,
                         members: enum_members,
                     },
                 )
@@ -261,7 +292,7 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
                 fields,
                 ..
             } => {
-                self.with_subfile(ast_name.clone(), file_data, |file_data| {
+                self.with_subfile(ast_name, type_name.clone(), file_data, |file_data| {
                     let mut field_names = Namespace::new();
                     let mut struct_fields = Vec::new(); // fields to pass to target
                     for field in fields {
@@ -276,6 +307,7 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
                             name: field_name,
                             json_name: field.json_name,
                             optional: field.optional,
+                            nullable: field.nullable,
                             type_: sub_ast,
                         });
                     }
@@ -285,7 +317,7 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
                         &mut file_data.state,
                         Item::Struct {
                             metadata,
-                            name: ast_name,
+                            name: type_name,
                             has_additional,
                             fields: struct_fields,
                         },
@@ -299,11 +331,11 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
                 tag_json_name,
                 variants,
                 ..
-            } => self.with_subfile(ast_name.clone(), file_data, |file_data| {
-                // Reassigning this, because `ast_name` is a bit ambiguous when
+            } => self.with_subfile(ast_name, type_name.clone(), file_data, |file_data| {
+                // Reassigning this, because `type_name` is a bit ambiguous when
                 // we're generating both a discriminator and a set of
                 // discriminator variants.
-                let discriminator_name = ast_name.clone();
+                let discriminator_name = type_name;
 
                 // A namespace for the discriminator tag, as well as each
                 // variant, within the context of the discriminator itself.
@@ -319,11 +351,17 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
                 // subclasses of the discriminator superclass, but you can't
                 // define subclass before the superclass.
                 let mut variant_names = Vec::new();
+                let mut variant_type_names = Vec::new();
                 let mut variant_infos = Vec::new();
                 for variant in &variants {
-                    let type_name = global_namespace.get(variant.type_name.clone());
-
-                    variant_names.push(type_name.clone());
+                    let variant_name = global_namespace.get(variant.type_name.clone());
+                    let type_name = self.type_name(
+                        variant_name.clone(),
+                        variant.marked(self.target, &self.marked_definitions),
+                    );
+
+                    variant_names.push(variant_name);
+                    variant_type_names.push(type_name.clone());
                     variant_infos.push(DiscriminatorVariantInfo {
                         type_name,
                         field_name: discriminator_field_names.get(variant.field_name.clone()),
@@ -353,54 +391,60 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
 
                 // Now generate each of the variants.
                 for (i, variant) in variants.into_iter().enumerate() {
-                    self.with_subfile(variant_names[i].clone(), file_data, |file_data| {
-                        // A for the discriminator tag and the fields of the
-                        // variant.
-                        let mut variant_field_names = Namespace::new();
-
-                        // This value will always be the same as
-                        // `discriminator_tag_field_name`, but we make sure to `get`
-                        // it from the namespace here so we don't give the same name
-                        // to any of the other fields in the variant.
-                        let variant_tag_field_name =
-                            variant_field_names.get(tag_field_name.clone());
-
-                        let mut variant_fields = Vec::new();
-                        for field in variant.fields {
-                            let field_name = variant_field_names.get(field.name);
-
-                            let sub_name = self.ast_name(global_namespace, &field.type_);
-                            let sub_ast = self.codegen_ast(
-                                global_namespace,
-                                file_data,
-                                sub_name,
-                                field.type_,
-                            )?;
-
-                            variant_fields.push(Field {
-                                metadata: field.metadata,
-                                name: field_name,
-                                json_name: field.json_name,
-                                optional: field.optional,
-                                type_: sub_ast,
-                            });
-                        }
-
-                        self.target.item(
-                            &mut file_data.buf,
-                            &mut file_data.state,
-                            Item::DiscriminatorVariant {
-                                metadata: variant.metadata,
-                                name: variant_names[i].clone(),
-                                parent_name: discriminator_name_for_variants.clone(),
-                                tag_field_name: variant_tag_field_name,
-                                tag_json_name: tag_json_name.clone(),
-                                tag_value: variant.tag_value,
-                                has_additional: variant.has_additional,
-                                fields: variant_fields,
-                            },
-                        )
-                    })?;
+                    self.with_subfile(
+                        variant_names[i].clone(),
+                        variant_type_names[i].clone(),
+                        file_data,
+                        |file_data| {
+                            // A for the discriminator tag and the fields of the
+                            // variant.
+                            let mut variant_field_names = Namespace::new();
+
+                            // This value will always be the same as
+                            // `discriminator_tag_field_name`, but we make sure to `get`
+                            // it from the namespace here so we don't give the same name
+                            // to any of the other fields in the variant.
+                            let variant_tag_field_name =
+                                variant_field_names.get(tag_field_name.clone());
+
+                            let mut variant_fields = Vec::new();
+                            for field in variant.fields {
+                                let field_name = variant_field_names.get(field.name);
+
+                                let sub_name = self.ast_name(global_namespace, &field.type_);
+                                let sub_ast = self.codegen_ast(
+                                    global_namespace,
+                                    file_data,
+                                    sub_name,
+                                    field.type_,
+                                )?;
+
+                                variant_fields.push(Field {
+                                    metadata: field.metadata,
+                                    name: field_name,
+                                    json_name: field.json_name,
+                                    optional: field.optional,
+                                    nullable: field.nullable,
+                                    type_: sub_ast,
+                                });
+                            }
+
+                            self.target.item(
+                                &mut file_data.buf,
+                                &mut file_data.state,
+                                Item::DiscriminatorVariant {
+                                    metadata: variant.metadata,
+                                    name: variant_type_names[i].clone(),
+                                    parent_name: discriminator_name_for_variants.clone(),
+                                    tag_field_name: variant_tag_field_name,
+                                    tag_json_name: tag_json_name.clone(),
+                                    tag_value: variant.tag_value,
+                                    has_additional: variant.has_additional,
+                                    fields: variant_fields,
+                                },
+                            )
+                        },
+                    )?;
                 }
 
                 Ok(returned_discriminator_name)
@@ -418,9 +462,18 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
         }
     }
 
+    fn type_name(&self, ast_name: String, marked: bool) -> String {
+        if marked {
+            self.target.marked_name(ast_name)
+        } else {
+            ast_name
+        }
+    }
+
     fn with_subfile<F>(
         &self,
         sub_name: String,
+        type_name: String,
         file_data: &mut FileData<T::FileState>,
         f: F,
     ) -> Result<String>
@@ -445,12 +498,12 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
             // the contents of what the target generated.
             (&FilePartitioningStrategy::FilePerType(_), None) => {
                 self.write_file(&mut sub_file_data, &sub_name)?;
-                Ok(sub_name)
+                Ok(type_name)
             }
 
             // If instead we're in single-file mode (but again with no prefab
             // name), then we don't need to write out a file.
-            (&FilePartitioningStrategy::SingleFile(_), None) => Ok(sub_name),
+            (&FilePartitioningStrategy::SingleFile(_), None) => Ok(type_name),
 
             // If a prefab name was returned, then in no circumstance do we
             // write out a file, and we will have the rest of codegen use the
@@ -462,20 +515,22 @@ impl<'a, T: Target> CodeGenerator<'a, T> {
     fn write_file(&self, file_data: &mut FileData<T::FileState>, type_name: &str) -> Result<()> {
         let file_name = match self.strategy.file_partitioning {
             FilePartitioningStrategy::FilePerType(ref extension) => {
-                Path::new(type_name).with_extension(extension)
+                Path::new(&self.target.file_name(type_name)).with_extension(extension)
             }
             FilePartitioningStrategy::SingleFile(ref file_name) => {
                 Path::new(file_name).to_path_buf()
             }
         };
 
-        let mut file = File::create(self.out_dir.join(file_name))?;
+        let mut file = File::create(self.out_dir.join(&file_name))?;
         self.target
             .item(&mut file, &mut file_data.state, Item::Preamble)?;
         file.write_all(&file_data.buf)?;
         self.target
             .item(&mut file, &mut file_data.state, Item::Postamble)?;
 
+        self.files.borrow_mut().push(file_name);
+
         Ok(())
     }
 }
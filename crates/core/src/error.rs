@@ -9,6 +9,9 @@ pub enum Error {
     #[error("unsupported feature: {0}")]
     UnsupportedFeature(String),
 
+    #[error("invalid metadata: {0}")]
+    InvalidMetadata(String),
+
     #[error("i/o error: {0}")]
     Io(#[from] io::Error),
 }
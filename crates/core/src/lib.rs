@@ -1,5 +1,6 @@
 pub mod codegen;
 pub mod error;
+pub mod root_name;
 pub mod target;
 
 pub use codegen::codegen;
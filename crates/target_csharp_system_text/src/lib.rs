@@ -78,15 +78,15 @@ impl jtd_codegen::target::Target for Target {
         state: &mut FileState,
         metadata: metadata::Metadata,
         expr: target::Expr,
-    ) -> String {
+    ) -> Result<String> {
         if let Some(s) = metadata
             .get("csharpSystemTextType")
             .and_then(|v| v.as_str())
         {
-            return s.into();
+            return Ok(s.into());
         }
 
-        match expr {
+        Ok(match expr {
             target::Expr::Empty => "object".into(),
             target::Expr::Boolean => "bool".into(),
             target::Expr::Int8 => "sbyte".into(),
@@ -107,7 +107,7 @@ impl jtd_codegen::target::Target for Target {
                     .get("csharpSystemTextContainer")
                     .and_then(|v| v.as_str())
                 {
-                    return format!("{}<{}>", s, sub_expr);
+                    return Ok(format!("{}<{}>", s, sub_expr));
                 }
 
                 state.imports.insert("System.Collections.Generic".into());
@@ -118,14 +118,14 @@ impl jtd_codegen::target::Target for Target {
                     .get("csharpSystemTextContainer")
                     .and_then(|v| v.as_str())
                 {
-                    return format!("{}<string, {}>", s, sub_expr);
+                    return Ok(format!("{}<string, {}>", s, sub_expr));
                 }
 
                 state.imports.insert("System.Collections.Generic".into());
                 format!("IDictionary<string, {}>", sub_expr)
             }
             target::Expr::NullableOf(sub_expr) => format!("{}?", sub_expr),
-        }
+        })
     }
 
     fn item(
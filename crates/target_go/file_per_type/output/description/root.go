@@ -0,0 +1,27 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+type Root struct {
+	// A description for discriminator
+	DiscriminatorWithDescription RootDiscriminatorWithDescription `json:"discriminator_with_description"`
+
+	// A description for enum
+	EnumWithDescription RootEnumWithDescription `json:"enum_with_description"`
+
+	// Whereas disregard and contempt for human rights have resulted in barbarous
+	// acts which have outraged the conscience of mankind, and the advent of a
+	// world in which human beings shall enjoy freedom of speech and belief and
+	// freedom from fear and want has been proclaimed as the highest aspiration of
+	// the common people,
+	LongDescription string `json:"long_description"`
+
+	// A description for properties
+	PropertiesWithDescription RootPropertiesWithDescription `json:"properties_with_description"`
+
+	// A description for ref
+	RefWithDescription Baz `json:"ref_with_description"`
+
+	// A description for string
+	StringWithDescription string `json:"string_with_description"`
+}
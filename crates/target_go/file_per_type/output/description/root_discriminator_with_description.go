@@ -0,0 +1,46 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+// A description for discriminator
+type RootDiscriminatorWithDescription struct {
+	Foo string
+
+	Bar RootDiscriminatorWithDescriptionBar
+}
+
+func (v RootDiscriminatorWithDescription) MarshalJSON() ([]byte, error) {
+	switch v.Foo {
+	case "bar":
+		return json.Marshal(struct { T string `json:"foo"`; RootDiscriminatorWithDescriptionBar }{ v.Foo, v.Bar })
+	}
+
+	return nil, fmt.Errorf("bad Foo value: %s", v.Foo)
+}
+
+func (v *RootDiscriminatorWithDescription) UnmarshalJSON(b []byte) error {
+	var t struct { T string `json:"foo"` }
+	if err := json.Unmarshal(b, &t); err != nil {
+		return err
+	}
+
+	var err error
+	switch t.T {
+	case "bar":
+		err = json.Unmarshal(b, &v.Bar)
+	default:
+		err = fmt.Errorf("bad Foo value: %s", t.T)
+	}
+
+	if err != nil {
+		return err
+	}
+
+	v.Foo = t.T
+	return nil
+}
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+// A description for discriminator variant
+type RootDiscriminatorWithDescriptionBar struct {
+}
@@ -0,0 +1,17 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+// A description for enum
+type RootEnumWithDescription string
+
+const (
+// A description for X
+	RootEnumWithDescriptionX RootEnumWithDescription = "X"
+
+// A description for Y
+	RootEnumWithDescriptionY RootEnumWithDescription = "Y"
+
+// A description for Z
+	RootEnumWithDescriptionZ RootEnumWithDescription = "Z"
+)
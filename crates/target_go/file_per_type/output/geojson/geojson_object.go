@@ -0,0 +1,106 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+// A Geometry object represents points, curves, and surfaces in coordinate
+// space.  Every Geometry object is a GeoJSON object no matter where it
+// occurs in a GeoJSON text.
+// 
+// o  The value of a Geometry object's "type" member MUST be one of the
+//     seven geometry types (see Section 1.4).
+// 
+// o  A GeoJSON Geometry object of any type other than
+//     "GeometryCollection" has a member with the name "coordinates". The
+//     value of the "coordinates" member is an array.  The structure of the
+//     elements in this array is determined by the type of geometry.
+//     GeoJSON processors MAY interpret Geometry objects with empty
+//     "coordinates" arrays as null objects.
+type GeojsonObject struct {
+	Type string
+
+	Feature GeojsonObjectFeature
+
+	FeatureCollection GeojsonObjectFeatureCollection
+
+	GeometryCollection GeojsonObjectGeometryCollection
+
+	LineString GeojsonObjectLineString
+
+	MultiLineString GeojsonObjectMultiLineString
+
+	MultiPoint GeojsonObjectMultiPoint
+
+	MultiPolygon GeojsonObjectMultiPolygon
+
+	Point GeojsonObjectPoint
+
+	Polygon GeojsonObjectPolygon
+}
+
+func (v GeojsonObject) MarshalJSON() ([]byte, error) {
+	switch v.Type {
+	case "Feature":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectFeature }{ v.Type, v.Feature })
+	case "FeatureCollection":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectFeatureCollection }{ v.Type, v.FeatureCollection })
+	case "GeometryCollection":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectGeometryCollection }{ v.Type, v.GeometryCollection })
+	case "LineString":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectLineString }{ v.Type, v.LineString })
+	case "MultiLineString":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectMultiLineString }{ v.Type, v.MultiLineString })
+	case "MultiPoint":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectMultiPoint }{ v.Type, v.MultiPoint })
+	case "MultiPolygon":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectMultiPolygon }{ v.Type, v.MultiPolygon })
+	case "Point":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectPoint }{ v.Type, v.Point })
+	case "Polygon":
+		return json.Marshal(struct { T string `json:"type"`; GeojsonObjectPolygon }{ v.Type, v.Polygon })
+	}
+
+	return nil, fmt.Errorf("bad Type value: %s", v.Type)
+}
+
+func (v *GeojsonObject) UnmarshalJSON(b []byte) error {
+	var t struct { T string `json:"type"` }
+	if err := json.Unmarshal(b, &t); err != nil {
+		return err
+	}
+
+	var err error
+	switch t.T {
+	case "Feature":
+		err = json.Unmarshal(b, &v.Feature)
+	case "FeatureCollection":
+		err = json.Unmarshal(b, &v.FeatureCollection)
+	case "GeometryCollection":
+		err = json.Unmarshal(b, &v.GeometryCollection)
+	case "LineString":
+		err = json.Unmarshal(b, &v.LineString)
+	case "MultiLineString":
+		err = json.Unmarshal(b, &v.MultiLineString)
+	case "MultiPoint":
+		err = json.Unmarshal(b, &v.MultiPoint)
+	case "MultiPolygon":
+		err = json.Unmarshal(b, &v.MultiPolygon)
+	case "Point":
+		err = json.Unmarshal(b, &v.Point)
+	case "Polygon":
+		err = json.Unmarshal(b, &v.Polygon)
+	default:
+		err = fmt.Errorf("bad Type value: %s", t.T)
+	}
+
+	if err != nil {
+		return err
+	}
+
+	v.Type = t.T
+	return nil
+}
@@ -0,0 +1,32 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+// A Feature object represents a spatially bounded thing.  Every
+// Feature object is a GeoJSON object no matter where it occurs in a
+// GeoJSON text.
+// 
+// o  A Feature object has a "type" member with the value "Feature".
+// 
+// o  A Feature object has a member with the name "geometry".  The
+//     value of the geometry member SHALL be either a Geometry object
+//     as defined above or, in the case that the Feature is unlocated,
+//     a JSON null value.
+// 
+// o  A Feature object has a member with the name "properties".  The
+//     value of the properties member is an object (any JSON object or
+//     a JSON null value).
+type GeojsonObjectFeature struct {
+	// The GeoJSON specification requires that these elements be
+	// GeoJSON geometry objects, but such a constraint can't be
+	// expressed in JSON Type Definition.
+	// 
+	// It is semantically invalid at the GeoJSON level for this
+	// member to be any GeoJSON object type other than one of the
+	// geometry types.
+	Geometry *GeojsonObject `json:"geometry"`
+
+	Properties map[string]interface{} `json:"properties"`
+
+	ID interface{} `json:"id,omitempty"`
+}
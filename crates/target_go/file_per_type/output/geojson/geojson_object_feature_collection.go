@@ -0,0 +1,12 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+// A GeoJSON object with the type "FeatureCollection" is a
+// FeatureCollection object.  A FeatureCollection object has a member
+// with the name "features".  The value of "features" is a JSON array.
+// Each element of the array is a Feature object as defined above.  It
+// is possible for this array to be empty.
+type GeojsonObjectFeatureCollection struct {
+	Features []GeojsonObject `json:"features"`
+}
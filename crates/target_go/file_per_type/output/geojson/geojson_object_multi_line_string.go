@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+// For type "MultiLineString", the "coordinates" member is an array of
+// LineString coordinate arrays.
+type GeojsonObjectMultiLineString struct {
+	Coordinates []Position `json:"coordinates"`
+
+	Bbox *BoundingBox `json:"bbox,omitempty"`
+}
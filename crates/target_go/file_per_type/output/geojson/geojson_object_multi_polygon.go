@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+// For type "MultiPolygon", the "coordinates" member is an array of
+// Polygon coordinate arrays.
+type GeojsonObjectMultiPolygon struct {
+	Coordinates []LinearRing `json:"coordinates"`
+
+	Bbox *BoundingBox `json:"bbox,omitempty"`
+}
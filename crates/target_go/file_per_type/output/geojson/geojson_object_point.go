@@ -0,0 +1,10 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+// For type "Point", the "coordinates" member is a single position.
+type GeojsonObjectPoint struct {
+	Coordinates Position `json:"coordinates"`
+
+	Bbox *BoundingBox `json:"bbox,omitempty"`
+}
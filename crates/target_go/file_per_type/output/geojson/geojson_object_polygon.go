@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+// For type "Polygon", the "coordinates" member MUST be an array of
+// linear ring coordinate arrays.
+type GeojsonObjectPolygon struct {
+	Coordinates []LinearRing `json:"coordinates"`
+
+	Bbox *BoundingBox `json:"bbox,omitempty"`
+}
@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+type Root struct {
+	For For `json:"for"`
+
+	Object Object `json:"object"`
+}
@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+type Root struct {
+	Foo RootFoo `json:"foo"`
+
+	FooBar RootFooBar0 `json:"foo_bar"`
+}
@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+type Root struct {
+	Bar Optional[[]string] `json:"bar,omitzero"`
+
+	Baz Optional[bool] `json:"baz,omitzero"`
+
+	Foo Optional[string] `json:"foo,omitzero"`
+}
@@ -0,0 +1,33 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import "encoding/json"
+
+// Optional is the type of optional properties. It tells a property that's
+// absent apart from one that's null, empty, or otherwise the zero value.
+//
+// Properties of this type are tagged with omitzero, so that they're left out
+// of JSON when they're not Present. That needs Go 1.24 or later.
+type Optional[T any] struct {
+	Value   T
+	Present bool
+}
+
+// IsZero reports whether o is absent.
+func (o Optional[T]) IsZero() bool {
+	return !o.Present
+}
+
+func (o Optional[T]) MarshalJSON() ([]byte, error) {
+	if !o.Present {
+		return []byte("null"), nil
+	}
+
+	return json.Marshal(o.Value)
+}
+
+func (o *Optional[T]) UnmarshalJSON(b []byte) error {
+	o.Present = true
+	return json.Unmarshal(b, &o.Value)
+}
@@ -6,7 +6,7 @@ type RootOverrideTypeDiscriminatorBaz struct {
 }
 
 type Root struct {
-	OverrideElementsContainer []string `json:"override_elements_container"`
+	OverrideElementsContainer []string `json:"override_elements_container" db:"override_elements_container"`
 
 	OverrideTypeDiscriminator interface{} `json:"override_type_discriminator"`
 
@@ -0,0 +1,68 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+type Root struct {
+	Value RootVariant
+}
+
+// RootVariant is implemented by the variants of Root.
+type RootVariant interface {
+	isRoot()
+}
+
+func (v Root) MarshalJSON() ([]byte, error) {
+	switch x := v.Value.(type) {
+	case RootBarBaz:
+		return json.Marshal(struct { T string `json:"foo"`; RootBarBaz }{ "BAR_BAZ", x })
+	case RootQuux:
+		return json.Marshal(struct { T string `json:"foo"`; RootQuux }{ "QUUX", x })
+	}
+
+	return nil, fmt.Errorf("bad Root value: %T", v.Value)
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var t struct { T string `json:"foo"` }
+	if err := json.Unmarshal(b, &t); err != nil {
+		return err
+	}
+
+	switch t.T {
+	case "BAR_BAZ":
+		var x RootBarBaz
+		if err := json.Unmarshal(b, &x); err != nil {
+			return err
+		}
+
+		v.Value = x
+		return nil
+	case "QUUX":
+		var x RootQuux
+		if err := json.Unmarshal(b, &x); err != nil {
+			return err
+		}
+
+		v.Value = x
+		return nil
+	}
+
+	return fmt.Errorf("bad Foo value: %s", t.T)
+}
+
+type RootBarBaz struct {
+	Baz string `json:"baz"`
+}
+
+func (RootBarBaz) isRoot() {}
+
+type RootQuux struct {
+	Quuz string `json:"quuz"`
+}
+
+func (RootQuux) isRoot() {}
@@ -0,0 +1,54 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+type Root struct {
+	Value RootVariant
+}
+
+// RootVariant is implemented by the variants of Root.
+type RootVariant interface {
+	isRoot()
+}
+
+func (v Root) MarshalJSON() ([]byte, error) {
+	switch x := v.Value.(type) {
+	case RootBar:
+		return json.Marshal(struct { T string `json:"foo"`; RootBar }{ "bar", x })
+	}
+
+	return nil, fmt.Errorf("bad Root value: %T", v.Value)
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var t struct { T string `json:"foo"` }
+	if err := json.Unmarshal(b, &t); err != nil {
+		return err
+	}
+
+	switch t.T {
+	case "bar":
+		var x RootBar
+		if err := json.Unmarshal(b, &x); err != nil {
+			return err
+		}
+
+		v.Value = x
+		return nil
+	}
+
+	return fmt.Errorf("bad Foo value: %s", t.T)
+}
+
+type RootBar struct {
+	Baz []string `json:"baz,omitempty"`
+
+	Quux *bool `json:"quux,omitempty"`
+}
+
+func (RootBar) isRoot() {}
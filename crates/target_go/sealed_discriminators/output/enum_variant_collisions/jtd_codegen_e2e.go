@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+type Root string
+
+const (
+	RootFoo Root = "FOO"
+
+	RootFoo0 Root = "Foo"
+
+	RootFoo1 Root = "foo"
+)
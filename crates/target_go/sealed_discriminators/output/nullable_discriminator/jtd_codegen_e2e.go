@@ -0,0 +1,70 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"encoding/json"
+	"fmt"
+)
+
+type Root0 struct {
+	Value Root0Variant
+}
+
+// Root0Variant is implemented by the variants of Root0.
+type Root0Variant interface {
+	isRoot0()
+}
+
+func (v Root0) MarshalJSON() ([]byte, error) {
+	switch x := v.Value.(type) {
+	case RootBar:
+		return json.Marshal(struct { T string `json:"foo"`; RootBar }{ "bar", x })
+	case RootQuux:
+		return json.Marshal(struct { T string `json:"foo"`; RootQuux }{ "quux", x })
+	}
+
+	return nil, fmt.Errorf("bad Root0 value: %T", v.Value)
+}
+
+func (v *Root0) UnmarshalJSON(b []byte) error {
+	var t struct { T string `json:"foo"` }
+	if err := json.Unmarshal(b, &t); err != nil {
+		return err
+	}
+
+	switch t.T {
+	case "bar":
+		var x RootBar
+		if err := json.Unmarshal(b, &x); err != nil {
+			return err
+		}
+
+		v.Value = x
+		return nil
+	case "quux":
+		var x RootQuux
+		if err := json.Unmarshal(b, &x); err != nil {
+			return err
+		}
+
+		v.Value = x
+		return nil
+	}
+
+	return fmt.Errorf("bad Foo value: %s", t.T)
+}
+
+type RootBar struct {
+	Baz string `json:"baz"`
+}
+
+func (RootBar) isRoot0() {}
+
+type RootQuux struct {
+	Quuz string `json:"quuz"`
+}
+
+func (RootQuux) isRoot0() {}
+
+type Root = *Root0
@@ -0,0 +1,15 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+type Root0 string
+
+const (
+	RootBar Root0 = "Bar"
+
+	RootBaz Root0 = "Baz"
+
+	RootFoo Root0 = "Foo"
+)
+
+type Root = *Root0
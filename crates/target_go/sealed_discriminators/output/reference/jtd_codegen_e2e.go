@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+type Root = Foo
+
+type Bar = Baz
+
+type Baz = string
+
+type Foo = Bar
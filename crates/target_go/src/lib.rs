@@ -1,10 +1,14 @@
+mod validation;
+
 use jtd_codegen::target::{self, inflect, metadata};
-use jtd_codegen::Result;
+use jtd_codegen::{Error, Result};
 use lazy_static::lazy_static;
 use serde_json::Value;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::fs::File;
 use std::io::Write;
+use validation::{Check, Decl};
 
 lazy_static! {
     static ref KEYWORDS: BTreeSet<String> = include_str!("keywords")
@@ -36,13 +40,261 @@ lazy_static! {
         ));
 }
 
+// Marks a type that an item's goType overrode, as opposed to the name of a
+// generated type.
+const OVERRIDE: char = '\u{1}';
+
+// Packages in the mold of encoding/json/v2, which generated code can't use in
+// place of encoding/json.
+const JSON_V2_PACKAGES: [&str; 2] = ["encoding/json/v2", "github.com/go-json-experiment/json"];
+
+// The imports of validation's helpers, besides the JSON package.
+const VALIDATION_IMPORTS: [&str; 3] = ["errors", "fmt", "strings"];
+
 pub struct Target {
     package: String,
+    sealed_discriminators: bool,
+    validation: bool,
+    file_per_type: bool,
+    optionals: bool,
+    tags: Vec<String>,
+    json_package: String,
 }
 
 impl Target {
     pub fn new(package: String) -> Self {
-        Self { package }
+        Self {
+            package,
+            sealed_discriminators: false,
+            validation: false,
+            file_per_type: false,
+            optionals: false,
+            tags: Vec::new(),
+            json_package: "encoding/json".into(),
+        }
+    }
+
+    /// Whether to represent a discriminator `Foo` as a struct holding a
+    /// `FooVariant`, which is an interface that only Foo's variants
+    /// implement, instead of as a struct with a field for each variant.
+    ///
+    /// This lets a type switch on a `FooVariant` tell which variant it holds,
+    /// and doesn't leave room for more than one variant at a time.
+    pub fn with_sealed_discriminators(mut self, sealed_discriminators: bool) -> Self {
+        self.sealed_discriminators = sealed_discriminators;
+        self
+    }
+
+    /// Whether to generate `UnmarshalJSON` methods that check that JSON
+    /// satisfies the schema, which `encoding/json` doesn't do on its own.
+    ///
+    /// Structs check that their required properties are present, and that
+    /// nothing non-nullable is null. Enums check that they're one of their
+    /// values, and get an `IsValid` method that does the same. Errors are
+    /// `*ValidationError`s, which say where in the JSON the problem is.
+    pub fn with_validation(mut self, validation: bool) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Whether to write each type to a file of its own, named after it in snake
+    /// case, rather than writing every type to a single file named after the
+    /// package. For example, `GeojsonObjectFeature` goes in
+    /// `geojson_object_feature.go`.
+    pub fn with_file_per_type(mut self, file_per_type: bool) -> Self {
+        self.file_per_type = file_per_type;
+        self
+    }
+
+    /// Whether to represent optional properties as an `Optional[T]`, rather
+    /// than as a pointer with `omitempty`.
+    ///
+    /// `omitempty` leaves out empty arrays and dictionaries, so they don't
+    /// survive a round trip. An `Optional[T]` tells an absent property apart
+    /// from an empty or null one. It relies on `omitzero`, which needs Go 1.24
+    /// or later.
+    pub fn with_optionals(mut self, optionals: bool) -> Self {
+        self.optionals = optionals;
+        self
+    }
+
+    /// Extra struct tag keys, such as `yaml` or `bson`, to give each field
+    /// alongside `json`. They mirror the field's JSON name, with `omitempty`
+    /// for optional properties.
+    ///
+    /// Only JSON knows how to marshal an `Optional[T]`, so these don't go
+    /// together with `with_optionals`.
+    ///
+    /// Tags that differ from field to field can go in the `goTags` metadata
+    /// of a property instead.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// The package that generated code marshals and unmarshals JSON with,
+    /// rather than `encoding/json`. It's imported as `json`, and must be a
+    /// drop-in replacement for `encoding/json`, such as
+    /// `github.com/goccy/go-json`.
+    ///
+    /// Generated code uses `json.Marshal`, `json.Unmarshal` and
+    /// `json.RawMessage`. `encoding/json/v2` and its precursor,
+    /// `github.com/go-json-experiment/json`, have no `RawMessage`, so
+    /// generating code for them fails with `Error::UnsupportedFeature`.
+    pub fn with_json_package(mut self, json_package: String) -> Self {
+        self.json_package = json_package;
+        self
+    }
+
+    fn reserves(&self, name: &str) -> bool {
+        // Validation and optionals declare these alongside generated types.
+        let validation_names = ["ValidationError"];
+        let optional_names = ["Optional"];
+
+        // In file-per-type mode, types can't share a file name with the
+        // auxiliary files.
+        let file_names = ["Validation"];
+
+        (self.validation && validation_names.contains(&name))
+            || (self.optionals && optional_names.contains(&name))
+            || (self.validation && self.file_per_type && file_names.contains(&name))
+    }
+
+    fn write_preamble(&self, out: &mut dyn Write, imports: &BTreeSet<String>) -> Result<()> {
+        writeln!(
+            out,
+            "// Code generated by jtd-codegen for Go v{}. DO NOT EDIT.",
+            env!("CARGO_PKG_VERSION")
+        )?;
+        writeln!(out)?;
+
+        writeln!(out, "package {}", self.package)?;
+
+        if !imports.is_empty() {
+            writeln!(out)?;
+
+            let imports: Vec<String> = imports.iter().cloned().collect();
+            if imports.len() == 1 {
+                writeln!(out, "import {}", self.import(&imports[0]))?;
+            } else {
+                writeln!(out, "import (")?;
+                for import in imports {
+                    writeln!(out, "\t{}", self.import(&import))?;
+                }
+                writeln!(out, ")")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // An import spec for `import`. The JSON package gets imported as `json`,
+    // whatever its path ends in.
+    fn import(&self, import: &str) -> String {
+        if import == self.json_package && import.rsplit('/').next() != Some("json") {
+            format!("json {:?}", import)
+        } else {
+            format!("{:?}", import)
+        }
+    }
+
+    // The tag of a field, which starts with `json`, then any extra tags and
+    // the field's goTags.
+    fn tag(&self, metadata: &metadata::Metadata, field: &validation::Field) -> String {
+        let json = if field.optional && self.optionals {
+            format!("{},omitzero", field.json_name)
+        } else if field.optional {
+            format!("{},omitempty", field.json_name)
+        } else {
+            field.json_name.clone()
+        };
+
+        // Other packages don't know omitzero, so extra tags only mirror the
+        // JSON name, and leave out optional fields with omitempty.
+        let other = if field.optional {
+            format!("{},omitempty", field.json_name)
+        } else {
+            field.json_name.clone()
+        };
+
+        let mut tag = format!("json:{:?}", json);
+        for key in &self.tags {
+            tag.push_str(&format!(" {}:{:?}", key, other));
+        }
+
+        if let Some(s) = metadata.get("goTags").and_then(|v| v.as_str()) {
+            tag.push(' ');
+            tag.push_str(s);
+        }
+
+        tag
+    }
+
+    fn write_fields(
+        &self,
+        out: &mut dyn Write,
+        fields: &[(metadata::Metadata, validation::Field)],
+    ) -> Result<()> {
+        for (index, (metadata, field)) in fields.iter().enumerate() {
+            if index != 0 {
+                writeln!(out)?;
+            }
+
+            write!(out, "{}", description(metadata, 1))?;
+
+            let tag = self.tag(metadata, field);
+            if field.optional && self.optionals {
+                writeln!(
+                    out,
+                    "\t{} Optional[{}] `{}`",
+                    field.name, field.value.type_, tag
+                )?;
+            } else {
+                writeln!(out, "\t{} {} `{}`", field.name, field.value.type_, tag)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Whether the discriminator `name` is written out as a sealed interface.
+    // The variants of overridden discriminators know them by their override.
+    fn sealed(&self, name: &str) -> bool {
+        self.sealed_discriminators && !name.starts_with(OVERRIDE)
+    }
+
+    // Keeps track of a generated type, if it needs validating.
+    fn declare(&self, state: &mut FileState, decl: Decl) {
+        if !self.validation {
+            return;
+        }
+
+        match decl {
+            Decl::Alias { .. } => {}
+            Decl::Enum { .. } => self.validates(state, "fmt"),
+            Decl::Struct { .. } => self.validates(state, &self.json_package),
+        }
+
+        state.decls.push(decl);
+    }
+
+    // Notes that validation generates methods in this file, which need
+    // `import`. In single-file mode, the file also gets the helpers they use.
+    fn validation_imports(&self) -> BTreeSet<String> {
+        VALIDATION_IMPORTS
+            .iter()
+            .map(|s| s.to_string())
+            .chain(std::iter::once(self.json_package.clone()))
+            .collect()
+    }
+
+    fn validates(&self, state: &mut FileState, import: &str) {
+        state.imports.insert(import.into());
+
+        if !self.file_per_type {
+            state.helpers = true;
+            state.imports.extend(self.validation_imports());
+        }
     }
 }
 
@@ -51,12 +303,20 @@ impl jtd_codegen::target::Target for Target {
 
     fn strategy(&self) -> target::Strategy {
         target::Strategy {
-            file_partitioning: target::FilePartitioningStrategy::SingleFile(format!(
-                "{}.go",
-                PACKAGE_NAMING_CONVENTION.inflect(&[self.package.clone()]),
-            )),
+            file_partitioning: if self.file_per_type {
+                target::FilePartitioningStrategy::FilePerType("go".into())
+            } else {
+                target::FilePartitioningStrategy::SingleFile(format!(
+                    "{}.go",
+                    PACKAGE_NAMING_CONVENTION.inflect(&[self.package.clone()]),
+                ))
+            },
             enum_member_naming: target::EnumMemberNamingStrategy::Unmodularized,
-            optional_property_handling: target::OptionalPropertyHandlingStrategy::WrapWithNullable,
+            optional_property_handling: if self.optionals {
+                target::OptionalPropertyHandlingStrategy::NativeSupport
+            } else {
+                target::OptionalPropertyHandlingStrategy::WrapWithNullable
+            },
             booleans_are_nullable: false,
             int8s_are_nullable: false,
             uint8s_are_nullable: false,
@@ -68,8 +328,11 @@ impl jtd_codegen::target::Target for Target {
             float64s_are_nullable: false,
             strings_are_nullable: false,
             timestamps_are_nullable: false,
-            arrays_are_nullable: true,
-            dicts_are_nullable: true,
+            // Arrays and dicts can be nil, so they're represented the same way
+            // whether they're nullable or not. But validation needs to know
+            // which they are.
+            arrays_are_nullable: false,
+            dicts_are_nullable: false,
             aliases_are_nullable: false,
             enums_are_nullable: false,
             structs_are_nullable: false,
@@ -78,10 +341,16 @@ impl jtd_codegen::target::Target for Target {
     }
 
     fn name(&self, kind: target::NameableKind, parts: &[String]) -> String {
-        match kind {
+        let name = match kind {
             target::NameableKind::Type => ITEM_NAMING_CONVENTION.inflect(parts),
-            target::NameableKind::Field => FIELD_NAMING_CONVENTION.inflect(parts),
+            target::NameableKind::Field => return FIELD_NAMING_CONVENTION.inflect(parts),
             target::NameableKind::EnumMember => ITEM_NAMING_CONVENTION.inflect(parts),
+        };
+
+        if self.reserves(&name) {
+            format!("{}_", name)
+        } else {
+            name
         }
     }
 
@@ -90,31 +359,51 @@ impl jtd_codegen::target::Target for Target {
         state: &mut FileState,
         metadata: metadata::Metadata,
         expr: target::Expr,
-    ) -> String {
+    ) -> Result<String> {
         if let Some(s) = metadata.get("goType").and_then(|v| v.as_str()) {
-            return s.into();
+            return Ok(state.register(s.into(), Check::Any));
         }
 
-        match expr {
-            target::Expr::Empty => "interface{}".into(),
-            target::Expr::Boolean => "bool".into(),
-            target::Expr::Int8 => "int8".into(),
-            target::Expr::Uint8 => "uint8".into(),
-            target::Expr::Int16 => "int16".into(),
-            target::Expr::Uint16 => "uint16".into(),
-            target::Expr::Int32 => "int32".into(),
-            target::Expr::Uint32 => "uint32".into(),
-            target::Expr::Float32 => "float32".into(),
-            target::Expr::Float64 => "float64".into(),
-            target::Expr::String => "string".into(),
+        let (type_, check) = match expr {
+            target::Expr::Empty => ("interface{}".into(), Check::Any),
+            target::Expr::Boolean => ("bool".into(), Check::NonNull),
+            target::Expr::Int8 => ("int8".into(), Check::NonNull),
+            target::Expr::Uint8 => ("uint8".into(), Check::NonNull),
+            target::Expr::Int16 => ("int16".into(), Check::NonNull),
+            target::Expr::Uint16 => ("uint16".into(), Check::NonNull),
+            target::Expr::Int32 => ("int32".into(), Check::NonNull),
+            target::Expr::Uint32 => ("uint32".into(), Check::NonNull),
+            target::Expr::Float32 => ("float32".into(), Check::NonNull),
+            target::Expr::Float64 => ("float64".into(), Check::NonNull),
+            target::Expr::String => ("string".into(), Check::NonNull),
             target::Expr::Timestamp => {
                 state.imports.insert("time".into());
-                "time.Time".into()
+                ("time.Time".into(), Check::NonNull)
             }
-            target::Expr::ArrayOf(sub_expr) => format!("[]{}", sub_expr),
-            target::Expr::DictOf(sub_expr) => format!("map[string]{}", sub_expr),
-            target::Expr::NullableOf(sub_expr) => format!("*{}", sub_expr),
-        }
+            target::Expr::ArrayOf(sub_expr) => {
+                let value = state.value(&sub_expr);
+                (format!("[]{}", value.type_), Check::Array(Box::new(value)))
+            }
+            target::Expr::DictOf(sub_expr) => {
+                let value = state.value(&sub_expr);
+                (
+                    format!("map[string]{}", value.type_),
+                    Check::Dict(Box::new(value)),
+                )
+            }
+            target::Expr::NullableOf(sub_expr) => {
+                let value = state.value(&sub_expr);
+                let type_ = if value.type_.starts_with("[]") || value.type_.starts_with("map[") {
+                    value.type_.clone()
+                } else {
+                    format!("*{}", value.type_)
+                };
+
+                (type_, Check::Nullable(Box::new(value)))
+            }
+        };
+
+        Ok(state.register(type_, check))
     }
 
     fn item(
@@ -124,49 +413,59 @@ impl jtd_codegen::target::Target for Target {
         item: target::Item,
     ) -> Result<Option<String>> {
         Ok(match item {
-            target::Item::Auxiliary { .. } => {
-                // No auxiliary files needed.
+            target::Item::Auxiliary { out_dir, .. } => {
+                if self.optionals {
+                    let mut out = File::create(out_dir.join("optional.go"))?;
+                    let imports = std::iter::once(self.json_package.clone()).collect();
+                    self.write_preamble(&mut out, &imports)?;
+                    write_optional(&mut out)?;
+                }
+
+                // In file-per-type mode, validation's helpers get a file of
+                // their own, rather than being repeated in every file.
+                if self.validation && self.file_per_type {
+                    let mut out = File::create(out_dir.join("validation.go"))?;
+                    self.write_preamble(&mut out, &self.validation_imports())?;
+                    validation::write_helpers(&mut out)?;
+                }
+
                 None
             }
 
             target::Item::Preamble => {
-                writeln!(
-                    out,
-                    "// Code generated by jtd-codegen for Go v{}. DO NOT EDIT.",
-                    env!("CARGO_PKG_VERSION")
-                )?;
-                writeln!(out)?;
+                if JSON_V2_PACKAGES.contains(&self.json_package.as_str()) {
+                    return Err(Error::UnsupportedFeature(format!(
+                        "{} is not a drop-in replacement for encoding/json",
+                        self.json_package
+                    )));
+                }
 
-                writeln!(out, "package {}", self.package)?;
+                self.write_preamble(out, &state.imports)?;
 
-                if !state.imports.is_empty() {
-                    writeln!(out)?;
+                None
+            }
 
-                    let imports: Vec<String> = state.imports.iter().cloned().collect();
-                    if imports.len() == 1 {
-                        writeln!(out, "import {:?}", imports[0])?;
-                    } else {
-                        writeln!(out, "import (")?;
-                        for import in imports {
-                            writeln!(out, "\t{:?}", import)?;
-                        }
-                        writeln!(out, ")")?;
-                    }
+            target::Item::Postamble => {
+                validation::write(out, &state.decls, self.optionals)?;
+                if state.helpers {
+                    validation::write_helpers(out)?;
                 }
 
                 None
             }
 
-            target::Item::Postamble => None,
-
             target::Item::Alias {
                 metadata,
                 name,
                 type_,
             } => {
+                let value = state.value(&type_);
+
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
-                writeln!(out, "type {} = {}", name, type_)?;
+                writeln!(out, "type {} = {}", name, value.type_)?;
+
+                self.declare(state, Decl::Alias { name, value });
 
                 None
             }
@@ -177,7 +476,7 @@ impl jtd_codegen::target::Target for Target {
                 members,
             } => {
                 if let Some(s) = metadata.get("goType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
                 }
 
                 writeln!(out)?;
@@ -186,7 +485,7 @@ impl jtd_codegen::target::Target for Target {
 
                 writeln!(out)?;
                 writeln!(out, "const (")?;
-                for (index, member) in members.into_iter().enumerate() {
+                for (index, member) in members.iter().enumerate() {
                     if index != 0 {
                         writeln!(out)?;
                     }
@@ -200,6 +499,9 @@ impl jtd_codegen::target::Target for Target {
                 }
                 writeln!(out, ")")?;
 
+                let members = members.into_iter().map(|member| member.name).collect();
+                self.declare(state, Decl::Enum { name, members });
+
                 None
             }
 
@@ -210,35 +512,19 @@ impl jtd_codegen::target::Target for Target {
                 fields,
             } => {
                 if let Some(s) = metadata.get("goType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
                 }
 
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
                 writeln!(out, "type {} struct {{", name)?;
-                for (index, field) in fields.into_iter().enumerate() {
-                    if index != 0 {
-                        writeln!(out)?;
-                    }
-
-                    write!(out, "{}", description(&field.metadata, 1))?;
-
-                    if field.optional {
-                        writeln!(
-                            out,
-                            "\t{} {} `json:\"{},omitempty\"`",
-                            field.name, field.type_, field.json_name
-                        )?;
-                    } else {
-                        writeln!(
-                            out,
-                            "\t{} {} `json:\"{}\"`",
-                            field.name, field.type_, field.json_name
-                        )?;
-                    }
-                }
+                let fields = state.fields(fields);
+                self.write_fields(out, &fields)?;
                 writeln!(out, "}}")?;
 
+                let fields = fields.into_iter().map(|(_, field)| field).collect();
+                self.declare(state, Decl::Struct { name, fields });
+
                 None
             }
 
@@ -250,11 +536,28 @@ impl jtd_codegen::target::Target for Target {
                 variants,
             } => {
                 if let Some(s) = metadata.get("goType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
                 }
 
-                state.imports.insert("encoding/json".into());
+                state.imports.insert(self.json_package.clone());
                 state.imports.insert("fmt".into());
+                if self.validation {
+                    self.validates(state, "fmt");
+                }
+
+                if self.sealed_discriminators {
+                    write_sealed_discriminator(
+                        out,
+                        &metadata,
+                        &name,
+                        &tag_field_name,
+                        &tag_json_name,
+                        &variants,
+                        self.validation,
+                    )?;
+
+                    return Ok(None);
+                }
 
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
@@ -290,7 +593,7 @@ impl jtd_codegen::target::Target for Target {
                     tag_json_name
                 )?;
                 writeln!(out, "\tif err := json.Unmarshal(b, &t); err != nil {{")?;
-                writeln!(out, "\t\treturn err")?;
+                writeln!(out, "\t\treturn {}", unmarshal_error(self.validation))?;
                 writeln!(out, "\t}}")?;
                 writeln!(out)?;
                 writeln!(out, "\tvar err error")?;
@@ -306,8 +609,8 @@ impl jtd_codegen::target::Target for Target {
                 writeln!(out, "\tdefault:")?;
                 writeln!(
                     out,
-                    "\t\terr = fmt.Errorf(\"bad {} value: %s\", t.T)",
-                    tag_field_name
+                    "\t\terr = {}",
+                    tag_error(self.validation, &tag_field_name, &tag_json_name)
                 )?;
                 writeln!(out, "\t}}")?;
                 writeln!(out)?;
@@ -325,48 +628,358 @@ impl jtd_codegen::target::Target for Target {
             target::Item::DiscriminatorVariant {
                 metadata,
                 name,
+                parent_name,
                 fields,
                 ..
             } => {
                 if let Some(s) = metadata.get("goType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                    // The marker method of a sealed discriminator is
+                    // unexported, so types from other packages can't implement
+                    // it.
+                    if self.sealed(&parent_name) {
+                        return Err(Error::InvalidMetadata(format!(
+                            "goType can't override {}, a variant of a sealed discriminator",
+                            name
+                        )));
+                    }
+
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
                 }
 
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
                 writeln!(out, "type {} struct {{", name)?;
-                for (index, field) in fields.into_iter().enumerate() {
-                    if index != 0 {
-                        writeln!(out)?;
-                    }
+                let fields = state.fields(fields);
+                self.write_fields(out, &fields)?;
+                writeln!(out, "}}")?;
 
-                    write!(out, "{}", description(&field.metadata, 1))?;
-
-                    if field.optional {
-                        writeln!(
-                            out,
-                            "\t{} {} `json:\"{},omitempty\"`",
-                            field.name, field.type_, field.json_name
-                        )?;
-                    } else {
-                        writeln!(
-                            out,
-                            "\t{} {} `json:\"{}\"`",
-                            field.name, field.type_, field.json_name
-                        )?;
-                    }
+                if self.sealed(&parent_name) {
+                    writeln!(out)?;
+                    writeln!(out, "func ({}) is{}() {{}}", name, parent_name)?;
                 }
-                writeln!(out, "}}")?;
+
+                let fields = fields.into_iter().map(|(_, field)| field).collect();
+                self.declare(state, Decl::Struct { name, fields });
 
                 None
             }
         })
     }
+
+    fn file_name(&self, type_name: &str) -> String {
+        file_name(type_name)
+    }
 }
 
 #[derive(Default)]
 pub struct FileState {
     imports: BTreeSet<String>,
+
+    // Whether validation's helpers need writing out in this file.
+    helpers: bool,
+
+    // The types of expressions, and what to check them against. Expressions
+    // are placeholders for an entry in this list.
+    exprs: Vec<validation::Value>,
+
+    // The types that validation generates UnmarshalJSON methods for, or needs
+    // to know about to do so.
+    decls: Vec<Decl>,
+}
+
+impl FileState {
+    fn register(&mut self, type_: String, check: Check) -> String {
+        self.exprs.push(validation::Value { type_, check });
+        format!("\0{}", self.exprs.len() - 1)
+    }
+
+    // The type for an expression or the name of a type.
+    fn value(&self, s: &str) -> validation::Value {
+        if let Some(value) = s
+            .strip_prefix('\0')
+            .and_then(|index| index.parse::<usize>().ok())
+            .and_then(|index| self.exprs.get(index))
+        {
+            return value.clone();
+        }
+
+        if let Some(type_) = s.strip_prefix(OVERRIDE) {
+            return validation::Value {
+                type_: type_.to_owned(),
+                check: Check::Any,
+            };
+        }
+
+        validation::Value {
+            type_: s.to_owned(),
+            check: Check::Named(s.to_owned()),
+        }
+    }
+
+    fn fields(&self, fields: Vec<target::Field>) -> Vec<(metadata::Metadata, validation::Field)> {
+        fields
+            .into_iter()
+            .map(|field| {
+                let value = self.value(&field.type_);
+                let metadata = field.metadata;
+                let field = validation::Field {
+                    name: field.name,
+                    json_name: field.json_name,
+                    optional: field.optional,
+                    value,
+                };
+
+                (metadata, field)
+            })
+            .collect()
+    }
+}
+
+// Writes out a discriminator as a struct holding one of its variants, through
+// an interface that only its variants implement.
+fn write_sealed_discriminator(
+    out: &mut dyn Write,
+    metadata: &metadata::Metadata,
+    name: &str,
+    tag_field_name: &str,
+    tag_json_name: &str,
+    variants: &[target::DiscriminatorVariantInfo],
+    validation: bool,
+) -> Result<()> {
+    let interface = format!("{}Variant", name);
+    let marker = format!("is{}", name);
+
+    writeln!(out)?;
+    write!(out, "{}", description(metadata, 0))?;
+    writeln!(out, "type {} struct {{", name)?;
+    writeln!(out, "\tValue {}", interface)?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "// {} is implemented by the variants of {}.",
+        interface, name
+    )?;
+    writeln!(out, "type {} interface {{", interface)?;
+    writeln!(out, "\t{}()", marker)?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "func (v {}) MarshalJSON() ([]byte, error) {{", name)?;
+    if variants.is_empty() {
+        writeln!(out, "\tswitch v.Value.(type) {{")?;
+    } else {
+        writeln!(out, "\tswitch x := v.Value.(type) {{")?;
+    }
+    for variant in variants {
+        writeln!(out, "\tcase {}:", variant.type_name)?;
+        writeln!(
+            out,
+            "\t\treturn json.Marshal(struct {{ T string `json:\"{}\"`; {} }}{{ {:?}, x }})",
+            tag_json_name, variant.type_name, variant.tag_value
+        )?;
+    }
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "\treturn nil, fmt.Errorf(\"bad {} value: %T\", v.Value)",
+        name
+    )?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "func (v *{}) UnmarshalJSON(b []byte) error {{", name)?;
+    writeln!(
+        out,
+        "\tvar t struct {{ T string `json:\"{}\"` }}",
+        tag_json_name
+    )?;
+    writeln!(out, "\tif err := json.Unmarshal(b, &t); err != nil {{")?;
+    writeln!(out, "\t\treturn {}", unmarshal_error(validation))?;
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(out, "\tswitch t.T {{")?;
+    for variant in variants {
+        writeln!(out, "\tcase {:?}:", variant.tag_value)?;
+        writeln!(out, "\t\tvar x {}", variant.type_name)?;
+        writeln!(out, "\t\tif err := json.Unmarshal(b, &x); err != nil {{")?;
+        writeln!(out, "\t\t\treturn err")?;
+        writeln!(out, "\t\t}}")?;
+        writeln!(out)?;
+        writeln!(out, "\t\tv.Value = x")?;
+        writeln!(out, "\t\treturn nil")?;
+    }
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "\treturn {}",
+        tag_error(validation, tag_field_name, tag_json_name)
+    )?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+// The error a discriminator returns when it fails to unmarshal its tag.
+fn unmarshal_error(validation: bool) -> &'static str {
+    if validation {
+        "validationErrorAt(err)"
+    } else {
+        "err"
+    }
+}
+
+// The error a discriminator returns when its tag isn't one it knows.
+fn tag_error(validation: bool, tag_field_name: &str, tag_json_name: &str) -> String {
+    let message = format!("\"bad {} value: %s\", t.T", tag_field_name);
+    if validation {
+        let path = format!("/{}", tag_json_name.replace('~', "~0").replace('/', "~1"));
+
+        format!(
+            "&ValidationError{{Path: {:?}, Message: fmt.Sprintf({})}}",
+            path, message
+        )
+    } else {
+        format!("fmt.Errorf({})", message)
+    }
+}
+
+// The name of a type's file in file-per-type mode, which is its name in snake
+// case, like "user_id" for "UserID".
+//
+// The go command only builds a file whose name ends in "_test", or in the name
+// of an operating system or architecture, in those circumstances. Such names
+// get an underscore on the end.
+fn file_name(type_name: &str) -> String {
+    const SUFFIXES: [&str; 43] = [
+        "test",
+        "aix",
+        "android",
+        "darwin",
+        "dragonfly",
+        "freebsd",
+        "hurd",
+        "illumos",
+        "ios",
+        "js",
+        "linux",
+        "nacl",
+        "netbsd",
+        "openbsd",
+        "plan9",
+        "solaris",
+        "wasip1",
+        "windows",
+        "zos",
+        "386",
+        "amd64",
+        "amd64p32",
+        "arm",
+        "armbe",
+        "arm64",
+        "arm64be",
+        "loong64",
+        "mips",
+        "mipsle",
+        "mips64",
+        "mips64le",
+        "mips64p32",
+        "mips64p32le",
+        "ppc",
+        "ppc64",
+        "ppc64le",
+        "riscv",
+        "riscv64",
+        "s390",
+        "s390x",
+        "sparc",
+        "sparc64",
+        "wasm",
+    ];
+
+    let chars: Vec<char> = type_name.chars().collect();
+    let mut file_name = String::new();
+    for (index, &c) in chars.iter().enumerate() {
+        // A word starts at a capital after a lowercase letter or digit, or at
+        // the last capital of an initialism that's followed by another word.
+        if index != 0 && c.is_ascii_uppercase() {
+            let prev = chars[index - 1];
+            let next = chars.get(index + 1);
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next.is_some_and(char::is_ascii_lowercase))
+            {
+                file_name.push('_');
+            }
+        }
+
+        file_name.push(c.to_ascii_lowercase());
+    }
+
+    if file_name
+        .rsplit('_')
+        .next()
+        .is_some_and(|suffix| file_name.contains('_') && SUFFIXES.contains(&suffix))
+    {
+        file_name.push('_');
+    }
+
+    file_name
+}
+
+// Writes out the Optional type that Target::with_optionals enables.
+fn write_optional(out: &mut dyn Write) -> Result<()> {
+    writeln!(out)?;
+    writeln!(
+        out,
+        "// Optional is the type of optional properties. It tells a property that's"
+    )?;
+    writeln!(
+        out,
+        "// absent apart from one that's null, empty, or otherwise the zero value."
+    )?;
+    writeln!(out, "//")?;
+    writeln!(
+        out,
+        "// Properties of this type are tagged with omitzero, so that they're left out"
+    )?;
+    writeln!(
+        out,
+        "// of JSON when they're not Present. That needs Go 1.24 or later."
+    )?;
+    writeln!(out, "type Optional[T any] struct {{")?;
+    writeln!(out, "\tValue   T")?;
+    writeln!(out, "\tPresent bool")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "// IsZero reports whether o is absent.")?;
+    writeln!(out, "func (o Optional[T]) IsZero() bool {{")?;
+    writeln!(out, "\treturn !o.Present")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "func (o Optional[T]) MarshalJSON() ([]byte, error) {{")?;
+    writeln!(out, "\tif !o.Present {{")?;
+    writeln!(out, "\t\treturn []byte(\"null\"), nil")?;
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(out, "\treturn json.Marshal(o.Value)")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "func (o *Optional[T]) UnmarshalJSON(b []byte) error {{"
+    )?;
+    writeln!(out, "\to.Present = true")?;
+    writeln!(out, "\treturn json.Unmarshal(b, &o.Value)")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
 }
 
 fn description(metadata: &BTreeMap<String, Value>, indent: usize) -> String {
@@ -395,6 +1008,102 @@ mod tests {
         jtd_codegen_test::std_test_cases!(&crate::Target::new("jtd_codegen_e2e".into()));
     }
 
+    mod sealed_discriminators_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/sealed_discriminators"),
+            &crate::Target::new("jtd_codegen_e2e".into()).with_sealed_discriminators(true)
+        );
+    }
+
+    mod validation_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/validation"),
+            &crate::Target::new("jtd_codegen_e2e".into()).with_validation(true)
+        );
+    }
+
+    mod file_per_type_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/file_per_type"),
+            &crate::Target::new("jtd_codegen_e2e".into()).with_file_per_type(true)
+        );
+    }
+
+    mod optionals_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/optionals"),
+            &crate::Target::new("jtd_codegen_e2e".into()).with_optionals(true)
+        );
+    }
+
+    mod tags_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tags"),
+            &crate::Target::new("jtd_codegen_e2e".into())
+                .with_tags(vec!["yaml".into(), "bson".into()])
+                .with_json_package("github.com/goccy/go-json".into())
+                .with_validation(true)
+        );
+    }
+
+    #[test]
+    fn test_tags_yaml() {
+        let schema = serde_json::json!({
+            "properties": { "name": { "type": "string" }},
+            "optionalProperties": {
+                "nickname": { "type": "string" },
+                "scores": { "elements": { "type": "int32" }}
+            }
+        });
+
+        let output = jtd_codegen_test::docker_output(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tags/yaml"),
+            &crate::Target::new("jtd_codegen_e2e".into()).with_tags(vec!["yaml".into()]),
+            schema,
+            "{\"name\":\"a\"}\n{\"name\":\"b\",\"nickname\":\"c\",\"scores\":[1]}\n",
+        );
+
+        let expected = "name: a\nname: b\nnickname: c\nscores:\n    - 1\n";
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_file_names() {
+        assert_eq!("root", crate::file_name("Root"));
+        assert_eq!(
+            "geojson_object_feature",
+            crate::file_name("GeojsonObjectFeature")
+        );
+        assert_eq!("user_id", crate::file_name("UserID"));
+        assert_eq!("http_server", crate::file_name("HTTPServer"));
+        assert_eq!("validation_error_", crate::file_name("ValidationError_"));
+        assert_eq!("test", crate::file_name("Test"));
+        assert_eq!("root_test_", crate::file_name("RootTest"));
+        assert_eq!("root_linux_", crate::file_name("RootLinux"));
+        assert_eq!("root_linux_amd64_", crate::file_name("RootLinuxAmd64"));
+    }
+
+    #[test]
+    fn test_json_v2_packages() {
+        let schema: jtd::SerdeSchema =
+            serde_json::from_value(serde_json::json!({ "type": "string" })).unwrap();
+        let schema = std::convert::TryInto::try_into(schema).unwrap();
+
+        for package in crate::JSON_V2_PACKAGES {
+            let out_dir = tempfile::tempdir().unwrap();
+            let err = jtd_codegen::codegen(
+                &crate::Target::new("jtd_codegen_e2e".into()).with_json_package(package.into()),
+                "root".into(),
+                &schema,
+                out_dir.path(),
+            )
+            .err()
+            .unwrap();
+
+            assert!(matches!(err, jtd_codegen::Error::UnsupportedFeature(_)));
+        }
+    }
+
     mod optional_std_tests {
         jtd_codegen_test::strict_std_test_case!(
             &crate::Target::new("jtd_codegen_e2e".into()),
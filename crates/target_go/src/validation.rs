@@ -0,0 +1,432 @@
+// Generates the UnmarshalJSON methods that Target::with_validation enables.
+//
+// encoding/json doesn't check for required properties, and silently ignores
+// nulls where a value can't be null. So in this mode, structs unmarshal
+// themselves property by property, checking each as they go. Enums check that
+// they're one of their values.
+//
+// Every error these methods return is a *ValidationError, with a JSON Pointer
+// to the offending value. Arrays and dictionaries are unmarshaled element by
+// element, so that the pointer can say which element was at fault.
+
+use jtd_codegen::Result;
+use std::collections::BTreeMap;
+use std::io::Write;
+
+/// A Go type, and what its JSON representation needs to be checked against.
+#[derive(Clone)]
+pub struct Value {
+    pub type_: String,
+    pub check: Check,
+}
+
+#[derive(Clone)]
+pub enum Check {
+    /// Anything encoding/json accepts is acceptable. This is also used for
+    /// overridden types, which this target doesn't know how to check.
+    Any,
+
+    /// Anything encoding/json accepts, except null. encoding/json already
+    /// checks the types and ranges of primitives.
+    NonNull,
+
+    Array(Box<Value>),
+    Dict(Box<Value>),
+
+    /// Null, or the given value. Whether the Go type is a pointer depends on
+    /// whether its type differs from the given value's.
+    Nullable(Box<Value>),
+
+    /// A generated type. Aliases can't have methods, so they're checked as
+    /// what they alias, if they're declared in the same file. Other types
+    /// check themselves, including that they aren't null.
+    Named(String),
+}
+
+pub struct Field {
+    pub name: String,
+    pub json_name: String,
+    pub optional: bool,
+    pub value: Value,
+}
+
+/// A generated type, as far as validation is concerned.
+pub enum Decl {
+    Alias {
+        name: String,
+        value: Value,
+    },
+
+    /// An enum, and the names of its members.
+    Enum {
+        name: String,
+        members: Vec<String>,
+    },
+
+    Struct {
+        name: String,
+        fields: Vec<Field>,
+    },
+}
+
+/// Writes out the UnmarshalJSON methods of `decls`. If `optionals` is set,
+/// optional fields are Optionals.
+pub fn write(out: &mut dyn Write, decls: &[Decl], optionals: bool) -> Result<()> {
+    let aliases: BTreeMap<&str, &Value> = decls
+        .iter()
+        .filter_map(|decl| match decl {
+            Decl::Alias { name, value } => Some((name.as_str(), value)),
+            _ => None,
+        })
+        .collect();
+
+    for decl in decls {
+        match decl {
+            Decl::Alias { .. } => {}
+            Decl::Enum { name, members } => write_enum(out, name, members)?,
+            Decl::Struct { name, fields } => write_struct(out, &aliases, name, fields, optionals)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_enum(out: &mut dyn Write, name: &str, members: &[String]) -> Result<()> {
+    writeln!(out)?;
+    writeln!(
+        out,
+        "// IsValid reports whether v is one of the values of {}.",
+        name
+    )?;
+    writeln!(out, "func (v {}) IsValid() bool {{", name)?;
+    writeln!(out, "\tswitch v {{")?;
+    writeln!(out, "\tcase {}:", members.join(", "))?;
+    writeln!(out, "\t\treturn true")?;
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(out, "\treturn false")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "func (v *{}) UnmarshalJSON(b []byte) error {{", name)?;
+    writeln!(out, "\tvar s string")?;
+    writeln!(out, "\tif err := unmarshalNonNull(b, &s); err != nil {{")?;
+    writeln!(out, "\t\treturn validationErrorAt(err)")?;
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(out, "\tif !{}(s).IsValid() {{", name)?;
+    writeln!(
+        out,
+        "\t\treturn &ValidationError{{Message: fmt.Sprintf(\"bad {} value: %q\", s)}}",
+        name
+    )?;
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(out, "\t*v = {}(s)", name)?;
+    writeln!(out, "\treturn nil")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+fn write_struct(
+    out: &mut dyn Write,
+    aliases: &BTreeMap<&str, &Value>,
+    name: &str,
+    fields: &[Field],
+    optionals: bool,
+) -> Result<()> {
+    writeln!(out)?;
+    writeln!(out, "func (v *{}) UnmarshalJSON(b []byte) error {{", name)?;
+    writeln!(out, "\tvar raw map[string]json.RawMessage")?;
+    writeln!(out, "\tif err := unmarshalNonNull(b, &raw); err != nil {{")?;
+    writeln!(out, "\t\treturn validationErrorAt(err)")?;
+    writeln!(out, "\t}}")?;
+
+    for field in fields {
+        writeln!(out)?;
+        writeln!(out, "\tif r, ok := raw[{:?}]; ok {{", field.json_name)?;
+
+        let mut dst = format!("v.{}", field.name);
+        if field.optional && optionals {
+            writeln!(out, "\t\t{}.Present = true", dst)?;
+            dst.push_str(".Value");
+        }
+
+        write_value(
+            out,
+            aliases,
+            &field.value,
+            "r",
+            &dst,
+            &[format!("{:?}", field.json_name)],
+            2,
+            1,
+        )?;
+
+        if field.optional {
+            writeln!(out, "\t}}")?;
+        } else {
+            let message = format!("missing required property {:?}", field.json_name);
+            writeln!(out, "\t}} else {{")?;
+            writeln!(out, "\t\treturn &ValidationError{{Message: {:?}}}", message)?;
+            writeln!(out, "\t}}")?;
+        }
+    }
+
+    writeln!(out)?;
+    writeln!(out, "\treturn nil")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+// Writes out statements that unmarshal the JSON in `r` into `dst`, returning
+// any error from the function they're in. `tokens` are expressions for the
+// JSON Pointer reference tokens that lead to `r`.
+//
+// Variables introduced by the statements are suffixed with `depth`, so that
+// they don't shadow those that `r` and `dst` refer to.
+#[allow(clippy::too_many_arguments)]
+fn write_value(
+    out: &mut dyn Write,
+    aliases: &BTreeMap<&str, &Value>,
+    value: &Value,
+    r: &str,
+    dst: &str,
+    tokens: &[String],
+    indent: usize,
+    depth: usize,
+) -> Result<()> {
+    let tab = "\t".repeat(indent);
+    let fail = format!(
+        "return validationErrorAt(err{})",
+        tokens
+            .iter()
+            .map(|token| format!(", {}", token))
+            .collect::<String>()
+    );
+
+    match &value.check {
+        Check::Named(name) if aliases.contains_key(name.as_str()) => {
+            // Go doesn't allow aliases to refer to themselves, but schemas
+            // can. Those aren't expanded more than once.
+            let mut aliases = aliases.clone();
+            let aliased = aliases.remove(name.as_str()).unwrap();
+            write_value(out, &aliases, aliased, r, dst, tokens, indent, depth)?;
+        }
+
+        Check::Any | Check::Named(_) => {
+            writeln!(
+                out,
+                "{}if err := json.Unmarshal({}, {}); err != nil {{",
+                tab,
+                r,
+                address(dst)
+            )?;
+            writeln!(out, "{}\t{}", tab, fail)?;
+            writeln!(out, "{}}}", tab)?;
+        }
+
+        Check::NonNull => {
+            writeln!(
+                out,
+                "{}if err := unmarshalNonNull({}, {}); err != nil {{",
+                tab,
+                r,
+                address(dst)
+            )?;
+            writeln!(out, "{}\t{}", tab, fail)?;
+            writeln!(out, "{}}}", tab)?;
+        }
+
+        Check::Array(element) => {
+            let (rs, i, ri) = (
+                format!("rs{}", depth),
+                format!("i{}", depth),
+                format!("r{}", depth),
+            );
+
+            writeln!(out, "{}var {} []json.RawMessage", tab, rs)?;
+            writeln!(
+                out,
+                "{}if err := unmarshalNonNull({}, &{}); err != nil {{",
+                tab, r, rs
+            )?;
+            writeln!(out, "{}\t{}", tab, fail)?;
+            writeln!(out, "{}}}", tab)?;
+            writeln!(out)?;
+            writeln!(out, "{}{} = make({}, len({}))", tab, dst, value.type_, rs)?;
+            writeln!(out, "{}for {}, {} := range {} {{", tab, i, ri, rs)?;
+
+            let mut tokens = tokens.to_vec();
+            tokens.push(i.clone());
+            write_value(
+                out,
+                aliases,
+                element,
+                &ri,
+                &format!("{}[{}]", dst, i),
+                &tokens,
+                indent + 1,
+                depth + 1,
+            )?;
+
+            writeln!(out, "{}}}", tab)?;
+        }
+
+        Check::Dict(element) => {
+            let (rs, k, ri, x) = (
+                format!("rs{}", depth),
+                format!("k{}", depth),
+                format!("r{}", depth),
+                format!("x{}", depth),
+            );
+
+            writeln!(out, "{}var {} map[string]json.RawMessage", tab, rs)?;
+            writeln!(
+                out,
+                "{}if err := unmarshalNonNull({}, &{}); err != nil {{",
+                tab, r, rs
+            )?;
+            writeln!(out, "{}\t{}", tab, fail)?;
+            writeln!(out, "{}}}", tab)?;
+            writeln!(out)?;
+            writeln!(out, "{}{} = make({}, len({}))", tab, dst, value.type_, rs)?;
+            writeln!(out, "{}for {}, {} := range {} {{", tab, k, ri, rs)?;
+            writeln!(out, "{}\tvar {} {}", tab, x, element.type_)?;
+
+            let mut tokens = tokens.to_vec();
+            tokens.push(k.clone());
+            write_value(
+                out,
+                aliases,
+                element,
+                &ri,
+                &x,
+                &tokens,
+                indent + 1,
+                depth + 1,
+            )?;
+
+            writeln!(out)?;
+            writeln!(out, "{}\t{}[{}] = {}", tab, dst, k, x)?;
+            writeln!(out, "{}}}", tab)?;
+        }
+
+        Check::Nullable(inner) => {
+            writeln!(out, "{}if string({}) == \"null\" {{", tab, r)?;
+            writeln!(out, "{}\t{} = nil", tab, dst)?;
+            writeln!(out, "{}}} else {{", tab)?;
+
+            // Nullable arrays and dictionaries are represented as nil, rather
+            // than with a pointer.
+            if inner.type_ == value.type_ {
+                write_value(out, aliases, inner, r, dst, tokens, indent + 1, depth)?;
+            } else {
+                writeln!(out, "{}\t{} = new({})", tab, dst, inner.type_)?;
+                write_value(
+                    out,
+                    aliases,
+                    inner,
+                    r,
+                    &format!("(*{})", dst),
+                    tokens,
+                    indent + 1,
+                    depth,
+                )?;
+            }
+
+            writeln!(out, "{}}}", tab)?;
+        }
+    }
+
+    Ok(())
+}
+
+// An expression for the address of `dst`.
+fn address(dst: &str) -> String {
+    match dst.strip_prefix("(*").and_then(|dst| dst.strip_suffix(')')) {
+        Some(pointer) => pointer.to_owned(),
+        None => format!("&{}", dst),
+    }
+}
+
+/// Writes out the helpers that UnmarshalJSON methods use.
+pub fn write_helpers(out: &mut dyn Write) -> Result<()> {
+    writeln!(out)?;
+    writeln!(
+        out,
+        "// ValidationError is returned when unmarshaling JSON that doesn't satisfy"
+    )?;
+    writeln!(out, "// the schema.")?;
+    writeln!(out, "type ValidationError struct {{")?;
+    writeln!(out, "\t// Path is a JSON Pointer to the offending value.")?;
+    writeln!(out, "\tPath string")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "\t// Message says what's wrong with the offending value."
+    )?;
+    writeln!(out, "\tMessage string")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "func (e *ValidationError) Error() string {{")?;
+    writeln!(out, "\treturn fmt.Sprintf(\"%q: %s\", e.Path, e.Message)")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "// validationErrorAt converts err into a ValidationError, if it isn't one"
+    )?;
+    writeln!(
+        out,
+        "// already, and prefixes its path with the given reference tokens. Tokens"
+    )?;
+    writeln!(out, "// are either property names or array indices.")?;
+    writeln!(
+        out,
+        "func validationErrorAt(err error, tokens ...interface{{}}) error {{"
+    )?;
+    writeln!(out, "\tvar e *ValidationError")?;
+    writeln!(out, "\tif !errors.As(err, &e) {{")?;
+    writeln!(out, "\t\te = &ValidationError{{Message: err.Error()}}")?;
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(out, "\tpath := \"\"")?;
+    writeln!(out, "\tfor _, token := range tokens {{")?;
+    writeln!(
+        out,
+        "\t\tpath += \"/\" + strings.NewReplacer(\"~\", \"~0\", \"/\", \"~1\").Replace(fmt.Sprint(token))"
+    )?;
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "\treturn &ValidationError{{Path: path + e.Path, Message: e.Message}}"
+    )?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "// unmarshalNonNull is like json.Unmarshal, except that it rejects null."
+    )?;
+    writeln!(
+        out,
+        "func unmarshalNonNull(b []byte, v interface{{}}) error {{"
+    )?;
+    writeln!(out, "\tif string(b) == \"null\" {{")?;
+    writeln!(
+        out,
+        "\t\treturn &ValidationError{{Message: \"must not be null\"}}"
+    )?;
+    writeln!(out, "\t}}")?;
+    writeln!(out)?;
+    writeln!(out, "\treturn json.Unmarshal(b, v)")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"errors"
+	"fmt"
+	json "github.com/goccy/go-json"
+	"strings"
+)
+
+// A description for discriminator
+type RootDiscriminatorWithDescription struct {
+	Foo string
+
+	Bar RootDiscriminatorWithDescriptionBar
+}
+
+func (v RootDiscriminatorWithDescription) MarshalJSON() ([]byte, error) {
+	switch v.Foo {
+	case "bar":
+		return json.Marshal(struct { T string `json:"foo"`; RootDiscriminatorWithDescriptionBar }{ v.Foo, v.Bar })
+	}
+
+	return nil, fmt.Errorf("bad Foo value: %s", v.Foo)
+}
+
+func (v *RootDiscriminatorWithDescription) UnmarshalJSON(b []byte) error {
+	var t struct { T string `json:"foo"` }
+	if err := json.Unmarshal(b, &t); err != nil {
+		return validationErrorAt(err)
+	}
+
+	var err error
+	switch t.T {
+	case "bar":
+		err = json.Unmarshal(b, &v.Bar)
+	default:
+		err = &ValidationError{Path: "/foo", Message: fmt.Sprintf("bad Foo value: %s", t.T)}
+	}
+
+	if err != nil {
+		return err
+	}
+
+	v.Foo = t.T
+	return nil
+}
+
+// A description for discriminator variant
+type RootDiscriminatorWithDescriptionBar struct {
+}
+
+// A description for enum
+type RootEnumWithDescription string
+
+const (
+// A description for X
+	RootEnumWithDescriptionX RootEnumWithDescription = "X"
+
+// A description for Y
+	RootEnumWithDescriptionY RootEnumWithDescription = "Y"
+
+// A description for Z
+	RootEnumWithDescriptionZ RootEnumWithDescription = "Z"
+)
+
+// A description for properties
+type RootPropertiesWithDescription struct {
+}
+
+type Root struct {
+	// A description for discriminator
+	DiscriminatorWithDescription RootDiscriminatorWithDescription `json:"discriminator_with_description" yaml:"discriminator_with_description" bson:"discriminator_with_description"`
+
+	// A description for enum
+	EnumWithDescription RootEnumWithDescription `json:"enum_with_description" yaml:"enum_with_description" bson:"enum_with_description"`
+
+	// Whereas disregard and contempt for human rights have resulted in barbarous
+	// acts which have outraged the conscience of mankind, and the advent of a
+	// world in which human beings shall enjoy freedom of speech and belief and
+	// freedom from fear and want has been proclaimed as the highest aspiration of
+	// the common people,
+	LongDescription string `json:"long_description" yaml:"long_description" bson:"long_description"`
+
+	// A description for properties
+	PropertiesWithDescription RootPropertiesWithDescription `json:"properties_with_description" yaml:"properties_with_description" bson:"properties_with_description"`
+
+	// A description for ref
+	RefWithDescription Baz `json:"ref_with_description" yaml:"ref_with_description" bson:"ref_with_description"`
+
+	// A description for string
+	StringWithDescription string `json:"string_with_description" yaml:"string_with_description" bson:"string_with_description"`
+}
+
+// A description for a definition
+type Baz = string
+
+func (v *RootDiscriminatorWithDescriptionBar) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	return nil
+}
+
+// IsValid reports whether v is one of the values of RootEnumWithDescription.
+func (v RootEnumWithDescription) IsValid() bool {
+	switch v {
+	case RootEnumWithDescriptionX, RootEnumWithDescriptionY, RootEnumWithDescriptionZ:
+		return true
+	}
+
+	return false
+}
+
+func (v *RootEnumWithDescription) UnmarshalJSON(b []byte) error {
+	var s string
+	if err := unmarshalNonNull(b, &s); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if !RootEnumWithDescription(s).IsValid() {
+		return &ValidationError{Message: fmt.Sprintf("bad RootEnumWithDescription value: %q", s)}
+	}
+
+	*v = RootEnumWithDescription(s)
+	return nil
+}
+
+func (v *RootPropertiesWithDescription) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	return nil
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["discriminator_with_description"]; ok {
+		if err := json.Unmarshal(r, &v.DiscriminatorWithDescription); err != nil {
+			return validationErrorAt(err, "discriminator_with_description")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"discriminator_with_description\""}
+	}
+
+	if r, ok := raw["enum_with_description"]; ok {
+		if err := json.Unmarshal(r, &v.EnumWithDescription); err != nil {
+			return validationErrorAt(err, "enum_with_description")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"enum_with_description\""}
+	}
+
+	if r, ok := raw["long_description"]; ok {
+		if err := unmarshalNonNull(r, &v.LongDescription); err != nil {
+			return validationErrorAt(err, "long_description")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"long_description\""}
+	}
+
+	if r, ok := raw["properties_with_description"]; ok {
+		if err := json.Unmarshal(r, &v.PropertiesWithDescription); err != nil {
+			return validationErrorAt(err, "properties_with_description")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"properties_with_description\""}
+	}
+
+	if r, ok := raw["ref_with_description"]; ok {
+		if err := unmarshalNonNull(r, &v.RefWithDescription); err != nil {
+			return validationErrorAt(err, "ref_with_description")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"ref_with_description\""}
+	}
+
+	if r, ok := raw["string_with_description"]; ok {
+		if err := unmarshalNonNull(r, &v.StringWithDescription); err != nil {
+			return validationErrorAt(err, "string_with_description")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"string_with_description\""}
+	}
+
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
@@ -0,0 +1,131 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"errors"
+	"fmt"
+	json "github.com/goccy/go-json"
+	"strings"
+)
+
+type Root struct {
+	Foo string
+
+	Bar RootBar
+}
+
+func (v Root) MarshalJSON() ([]byte, error) {
+	switch v.Foo {
+	case "bar":
+		return json.Marshal(struct { T string `json:"foo"`; RootBar }{ v.Foo, v.Bar })
+	}
+
+	return nil, fmt.Errorf("bad Foo value: %s", v.Foo)
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var t struct { T string `json:"foo"` }
+	if err := json.Unmarshal(b, &t); err != nil {
+		return validationErrorAt(err)
+	}
+
+	var err error
+	switch t.T {
+	case "bar":
+		err = json.Unmarshal(b, &v.Bar)
+	default:
+		err = &ValidationError{Path: "/foo", Message: fmt.Sprintf("bad Foo value: %s", t.T)}
+	}
+
+	if err != nil {
+		return err
+	}
+
+	v.Foo = t.T
+	return nil
+}
+
+type RootBar struct {
+	Baz []string `json:"baz,omitempty" yaml:"baz,omitempty" bson:"baz,omitempty"`
+
+	Quux *bool `json:"quux,omitempty" yaml:"quux,omitempty" bson:"quux,omitempty"`
+}
+
+func (v *RootBar) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["baz"]; ok {
+		if string(r) == "null" {
+			v.Baz = nil
+		} else {
+			var rs1 []json.RawMessage
+			if err := unmarshalNonNull(r, &rs1); err != nil {
+				return validationErrorAt(err, "baz")
+			}
+
+			v.Baz = make([]string, len(rs1))
+			for i1, r1 := range rs1 {
+				if err := unmarshalNonNull(r1, &v.Baz[i1]); err != nil {
+					return validationErrorAt(err, "baz", i1)
+				}
+			}
+		}
+	}
+
+	if r, ok := raw["quux"]; ok {
+		if string(r) == "null" {
+			v.Quux = nil
+		} else {
+			v.Quux = new(bool)
+			if err := unmarshalNonNull(r, v.Quux); err != nil {
+				return validationErrorAt(err, "quux")
+			}
+		}
+	}
+
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
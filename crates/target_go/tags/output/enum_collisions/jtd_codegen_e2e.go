@@ -0,0 +1,166 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"errors"
+	"fmt"
+	json "github.com/goccy/go-json"
+	"strings"
+)
+
+type RootFooBar string
+
+const (
+	RootFooBarX RootFooBar = "x"
+
+	RootFooBarY RootFooBar = "y"
+)
+
+type RootFoo struct {
+	Bar RootFooBar `json:"bar" yaml:"bar" bson:"bar"`
+}
+
+type RootFooBar0 string
+
+const (
+	RootFooBarX0 RootFooBar0 = "x"
+
+	RootFooBarY0 RootFooBar0 = "y"
+)
+
+type Root struct {
+	Foo RootFoo `json:"foo" yaml:"foo" bson:"foo"`
+
+	FooBar RootFooBar0 `json:"foo_bar" yaml:"foo_bar" bson:"foo_bar"`
+}
+
+// IsValid reports whether v is one of the values of RootFooBar.
+func (v RootFooBar) IsValid() bool {
+	switch v {
+	case RootFooBarX, RootFooBarY:
+		return true
+	}
+
+	return false
+}
+
+func (v *RootFooBar) UnmarshalJSON(b []byte) error {
+	var s string
+	if err := unmarshalNonNull(b, &s); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if !RootFooBar(s).IsValid() {
+		return &ValidationError{Message: fmt.Sprintf("bad RootFooBar value: %q", s)}
+	}
+
+	*v = RootFooBar(s)
+	return nil
+}
+
+func (v *RootFoo) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["bar"]; ok {
+		if err := json.Unmarshal(r, &v.Bar); err != nil {
+			return validationErrorAt(err, "bar")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"bar\""}
+	}
+
+	return nil
+}
+
+// IsValid reports whether v is one of the values of RootFooBar0.
+func (v RootFooBar0) IsValid() bool {
+	switch v {
+	case RootFooBarX0, RootFooBarY0:
+		return true
+	}
+
+	return false
+}
+
+func (v *RootFooBar0) UnmarshalJSON(b []byte) error {
+	var s string
+	if err := unmarshalNonNull(b, &s); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if !RootFooBar0(s).IsValid() {
+		return &ValidationError{Message: fmt.Sprintf("bad RootFooBar0 value: %q", s)}
+	}
+
+	*v = RootFooBar0(s)
+	return nil
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["foo"]; ok {
+		if err := json.Unmarshal(r, &v.Foo); err != nil {
+			return validationErrorAt(err, "foo")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"foo\""}
+	}
+
+	if r, ok := raw["foo_bar"]; ok {
+		if err := json.Unmarshal(r, &v.FooBar); err != nil {
+			return validationErrorAt(err, "foo_bar")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"foo_bar\""}
+	}
+
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
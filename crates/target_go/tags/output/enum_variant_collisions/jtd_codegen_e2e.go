@@ -0,0 +1,84 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"errors"
+	"fmt"
+	json "github.com/goccy/go-json"
+	"strings"
+)
+
+type Root string
+
+const (
+	RootFoo Root = "FOO"
+
+	RootFoo0 Root = "Foo"
+
+	RootFoo1 Root = "foo"
+)
+
+// IsValid reports whether v is one of the values of Root.
+func (v Root) IsValid() bool {
+	switch v {
+	case RootFoo, RootFoo0, RootFoo1:
+		return true
+	}
+
+	return false
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var s string
+	if err := unmarshalNonNull(b, &s); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if !Root(s).IsValid() {
+		return &ValidationError{Message: fmt.Sprintf("bad Root value: %q", s)}
+	}
+
+	*v = Root(s)
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
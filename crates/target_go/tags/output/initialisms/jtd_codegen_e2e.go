@@ -0,0 +1,152 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"errors"
+	"fmt"
+	json "github.com/goccy/go-json"
+	"strings"
+)
+
+type RootNestedIDInitialism struct {
+	JSON string `json:"json" yaml:"json" bson:"json"`
+
+	Normalword string `json:"normalword" yaml:"normalword" bson:"normalword"`
+}
+
+type Root struct {
+	HTTP string `json:"http" yaml:"http" bson:"http"`
+
+	ID string `json:"id" yaml:"id" bson:"id"`
+
+	NestedIDInitialism RootNestedIDInitialism `json:"nested_id_initialism" yaml:"nested_id_initialism" bson:"nested_id_initialism"`
+
+	UTF8 string `json:"utf8" yaml:"utf8" bson:"utf8"`
+
+	WordWithEmbeddedIDInitialism string `json:"word_with_embedded_id_initialism" yaml:"word_with_embedded_id_initialism" bson:"word_with_embedded_id_initialism"`
+
+	WordWithTrailingInitialismID string `json:"word_with_trailing_initialism_id" yaml:"word_with_trailing_initialism_id" bson:"word_with_trailing_initialism_id"`
+}
+
+func (v *RootNestedIDInitialism) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["json"]; ok {
+		if err := unmarshalNonNull(r, &v.JSON); err != nil {
+			return validationErrorAt(err, "json")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"json\""}
+	}
+
+	if r, ok := raw["normalword"]; ok {
+		if err := unmarshalNonNull(r, &v.Normalword); err != nil {
+			return validationErrorAt(err, "normalword")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"normalword\""}
+	}
+
+	return nil
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["http"]; ok {
+		if err := unmarshalNonNull(r, &v.HTTP); err != nil {
+			return validationErrorAt(err, "http")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"http\""}
+	}
+
+	if r, ok := raw["id"]; ok {
+		if err := unmarshalNonNull(r, &v.ID); err != nil {
+			return validationErrorAt(err, "id")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"id\""}
+	}
+
+	if r, ok := raw["nested_id_initialism"]; ok {
+		if err := json.Unmarshal(r, &v.NestedIDInitialism); err != nil {
+			return validationErrorAt(err, "nested_id_initialism")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"nested_id_initialism\""}
+	}
+
+	if r, ok := raw["utf8"]; ok {
+		if err := unmarshalNonNull(r, &v.UTF8); err != nil {
+			return validationErrorAt(err, "utf8")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"utf8\""}
+	}
+
+	if r, ok := raw["word_with_embedded_id_initialism"]; ok {
+		if err := unmarshalNonNull(r, &v.WordWithEmbeddedIDInitialism); err != nil {
+			return validationErrorAt(err, "word_with_embedded_id_initialism")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"word_with_embedded_id_initialism\""}
+	}
+
+	if r, ok := raw["word_with_trailing_initialism_id"]; ok {
+		if err := unmarshalNonNull(r, &v.WordWithTrailingInitialismID); err != nil {
+			return validationErrorAt(err, "word_with_trailing_initialism_id")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"word_with_trailing_initialism_id\""}
+	}
+
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
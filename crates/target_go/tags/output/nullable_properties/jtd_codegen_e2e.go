@@ -0,0 +1,119 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"errors"
+	"fmt"
+	json "github.com/goccy/go-json"
+	"strings"
+)
+
+type Root0 struct {
+	Bar string `json:"bar" yaml:"bar" bson:"bar"`
+
+	Baz []bool `json:"baz" yaml:"baz" bson:"baz"`
+
+	Foo bool `json:"foo" yaml:"foo" bson:"foo"`
+
+	Quux []bool `json:"quux" yaml:"quux" bson:"quux"`
+}
+
+type Root = *Root0
+
+func (v *Root0) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["bar"]; ok {
+		if err := unmarshalNonNull(r, &v.Bar); err != nil {
+			return validationErrorAt(err, "bar")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"bar\""}
+	}
+
+	if r, ok := raw["baz"]; ok {
+		var rs1 []json.RawMessage
+		if err := unmarshalNonNull(r, &rs1); err != nil {
+			return validationErrorAt(err, "baz")
+		}
+
+		v.Baz = make([]bool, len(rs1))
+		for i1, r1 := range rs1 {
+			if err := unmarshalNonNull(r1, &v.Baz[i1]); err != nil {
+				return validationErrorAt(err, "baz", i1)
+			}
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"baz\""}
+	}
+
+	if r, ok := raw["foo"]; ok {
+		if err := unmarshalNonNull(r, &v.Foo); err != nil {
+			return validationErrorAt(err, "foo")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"foo\""}
+	}
+
+	if r, ok := raw["quux"]; ok {
+		var rs1 []json.RawMessage
+		if err := unmarshalNonNull(r, &rs1); err != nil {
+			return validationErrorAt(err, "quux")
+		}
+
+		v.Quux = make([]bool, len(rs1))
+		for i1, r1 := range rs1 {
+			if err := unmarshalNonNull(r1, &v.Quux[i1]); err != nil {
+				return validationErrorAt(err, "quux", i1)
+			}
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"quux\""}
+	}
+
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
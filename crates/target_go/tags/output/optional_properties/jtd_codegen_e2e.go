@@ -0,0 +1,107 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"errors"
+	"fmt"
+	json "github.com/goccy/go-json"
+	"strings"
+)
+
+type Root struct {
+	Bar []string `json:"bar,omitempty" yaml:"bar,omitempty" bson:"bar,omitempty"`
+
+	Baz *bool `json:"baz,omitempty" yaml:"baz,omitempty" bson:"baz,omitempty"`
+
+	Foo *string `json:"foo,omitempty" yaml:"foo,omitempty" bson:"foo,omitempty"`
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["bar"]; ok {
+		if string(r) == "null" {
+			v.Bar = nil
+		} else {
+			var rs1 []json.RawMessage
+			if err := unmarshalNonNull(r, &rs1); err != nil {
+				return validationErrorAt(err, "bar")
+			}
+
+			v.Bar = make([]string, len(rs1))
+			for i1, r1 := range rs1 {
+				if err := unmarshalNonNull(r1, &v.Bar[i1]); err != nil {
+					return validationErrorAt(err, "bar", i1)
+				}
+			}
+		}
+	}
+
+	if r, ok := raw["baz"]; ok {
+		if string(r) == "null" {
+			v.Baz = nil
+		} else {
+			v.Baz = new(bool)
+			if err := unmarshalNonNull(r, v.Baz); err != nil {
+				return validationErrorAt(err, "baz")
+			}
+		}
+	}
+
+	if r, ok := raw["foo"]; ok {
+		if string(r) == "null" {
+			v.Foo = nil
+		} else {
+			v.Foo = new(string)
+			if err := unmarshalNonNull(r, v.Foo); err != nil {
+				return validationErrorAt(err, "foo")
+			}
+		}
+	}
+
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
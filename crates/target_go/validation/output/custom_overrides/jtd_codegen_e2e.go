@@ -0,0 +1,152 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strings"
+)
+
+type RootOverrideTypeDiscriminatorBaz struct {
+}
+
+type Root struct {
+	OverrideElementsContainer []string `json:"override_elements_container" db:"override_elements_container"`
+
+	OverrideTypeDiscriminator interface{} `json:"override_type_discriminator"`
+
+	OverrideTypeEnum interface{} `json:"override_type_enum"`
+
+	OverrideTypeExpr interface{} `json:"override_type_expr"`
+
+	OverrideTypeProperties interface{} `json:"override_type_properties"`
+
+	OverrideValuesContainer map[string]string `json:"override_values_container"`
+}
+
+func (v *RootOverrideTypeDiscriminatorBaz) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	return nil
+}
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["override_elements_container"]; ok {
+		var rs1 []json.RawMessage
+		if err := unmarshalNonNull(r, &rs1); err != nil {
+			return validationErrorAt(err, "override_elements_container")
+		}
+
+		v.OverrideElementsContainer = make([]string, len(rs1))
+		for i1, r1 := range rs1 {
+			if err := unmarshalNonNull(r1, &v.OverrideElementsContainer[i1]); err != nil {
+				return validationErrorAt(err, "override_elements_container", i1)
+			}
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"override_elements_container\""}
+	}
+
+	if r, ok := raw["override_type_discriminator"]; ok {
+		if err := json.Unmarshal(r, &v.OverrideTypeDiscriminator); err != nil {
+			return validationErrorAt(err, "override_type_discriminator")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"override_type_discriminator\""}
+	}
+
+	if r, ok := raw["override_type_enum"]; ok {
+		if err := json.Unmarshal(r, &v.OverrideTypeEnum); err != nil {
+			return validationErrorAt(err, "override_type_enum")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"override_type_enum\""}
+	}
+
+	if r, ok := raw["override_type_expr"]; ok {
+		if err := json.Unmarshal(r, &v.OverrideTypeExpr); err != nil {
+			return validationErrorAt(err, "override_type_expr")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"override_type_expr\""}
+	}
+
+	if r, ok := raw["override_type_properties"]; ok {
+		if err := json.Unmarshal(r, &v.OverrideTypeProperties); err != nil {
+			return validationErrorAt(err, "override_type_properties")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"override_type_properties\""}
+	}
+
+	if r, ok := raw["override_values_container"]; ok {
+		var rs1 map[string]json.RawMessage
+		if err := unmarshalNonNull(r, &rs1); err != nil {
+			return validationErrorAt(err, "override_values_container")
+		}
+
+		v.OverrideValuesContainer = make(map[string]string, len(rs1))
+		for k1, r1 := range rs1 {
+			var x1 string
+			if err := unmarshalNonNull(r1, &x1); err != nil {
+				return validationErrorAt(err, "override_values_container", k1)
+			}
+
+			v.OverrideValuesContainer[k1] = x1
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"override_values_container\""}
+	}
+
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+type Root = Foo0
+
+type Bar = Bar0
+
+type Foo = Bar
+
+type Bar0 = string
+
+type Foo0 = Foo
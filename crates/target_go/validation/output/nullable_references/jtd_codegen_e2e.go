@@ -0,0 +1,158 @@
+// Code generated by jtd-codegen for Go v0.2.1. DO NOT EDIT.
+
+package jtd_codegen_e2e
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strings"
+)
+
+type Root struct {
+	NotnullRefNotnullString NotnullRefNotnullString `json:"notnull_ref_notnull_string"`
+
+	NotnullRefNullString NotnullRefNullString `json:"notnull_ref_null_string"`
+
+	NotnullString NotnullString `json:"notnull_string"`
+
+	NullRefNotnullString NullRefNotnullString `json:"null_ref_notnull_string"`
+
+	NullRefNullString NullRefNullString `json:"null_ref_null_string"`
+
+	NullString NullString `json:"null_string"`
+}
+
+type NotnullRefNotnullString = NotnullString
+
+type NotnullRefNullString = NullString
+
+type NotnullString = string
+
+type NullRefNotnullString = *NotnullString
+
+type NullRefNullString = *NullString
+
+type NullString = *string
+
+func (v *Root) UnmarshalJSON(b []byte) error {
+	var raw map[string]json.RawMessage
+	if err := unmarshalNonNull(b, &raw); err != nil {
+		return validationErrorAt(err)
+	}
+
+	if r, ok := raw["notnull_ref_notnull_string"]; ok {
+		if err := unmarshalNonNull(r, &v.NotnullRefNotnullString); err != nil {
+			return validationErrorAt(err, "notnull_ref_notnull_string")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"notnull_ref_notnull_string\""}
+	}
+
+	if r, ok := raw["notnull_ref_null_string"]; ok {
+		if string(r) == "null" {
+			v.NotnullRefNullString = nil
+		} else {
+			v.NotnullRefNullString = new(string)
+			if err := unmarshalNonNull(r, v.NotnullRefNullString); err != nil {
+				return validationErrorAt(err, "notnull_ref_null_string")
+			}
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"notnull_ref_null_string\""}
+	}
+
+	if r, ok := raw["notnull_string"]; ok {
+		if err := unmarshalNonNull(r, &v.NotnullString); err != nil {
+			return validationErrorAt(err, "notnull_string")
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"notnull_string\""}
+	}
+
+	if r, ok := raw["null_ref_notnull_string"]; ok {
+		if string(r) == "null" {
+			v.NullRefNotnullString = nil
+		} else {
+			v.NullRefNotnullString = new(NotnullString)
+			if err := unmarshalNonNull(r, v.NullRefNotnullString); err != nil {
+				return validationErrorAt(err, "null_ref_notnull_string")
+			}
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"null_ref_notnull_string\""}
+	}
+
+	if r, ok := raw["null_ref_null_string"]; ok {
+		if string(r) == "null" {
+			v.NullRefNullString = nil
+		} else {
+			v.NullRefNullString = new(NullString)
+			if string(r) == "null" {
+				(*v.NullRefNullString) = nil
+			} else {
+				(*v.NullRefNullString) = new(string)
+				if err := unmarshalNonNull(r, (*v.NullRefNullString)); err != nil {
+					return validationErrorAt(err, "null_ref_null_string")
+				}
+			}
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"null_ref_null_string\""}
+	}
+
+	if r, ok := raw["null_string"]; ok {
+		if string(r) == "null" {
+			v.NullString = nil
+		} else {
+			v.NullString = new(string)
+			if err := unmarshalNonNull(r, v.NullString); err != nil {
+				return validationErrorAt(err, "null_string")
+			}
+		}
+	} else {
+		return &ValidationError{Message: "missing required property \"null_string\""}
+	}
+
+	return nil
+}
+
+// ValidationError is returned when unmarshaling JSON that doesn't satisfy
+// the schema.
+type ValidationError struct {
+	// Path is a JSON Pointer to the offending value.
+	Path string
+
+	// Message says what's wrong with the offending value.
+	Message string
+}
+
+func (e *ValidationError) Error() string {
+	return fmt.Sprintf("%q: %s", e.Path, e.Message)
+}
+
+// validationErrorAt converts err into a ValidationError, if it isn't one
+// already, and prefixes its path with the given reference tokens. Tokens
+// are either property names or array indices.
+func validationErrorAt(err error, tokens ...interface{}) error {
+	var e *ValidationError
+	if !errors.As(err, &e) {
+		e = &ValidationError{Message: err.Error()}
+	}
+
+	path := ""
+	for _, token := range tokens {
+		path += "/" + strings.NewReplacer("~", "~0", "/", "~1").Replace(fmt.Sprint(token))
+	}
+
+	return &ValidationError{Path: path + e.Path, Message: e.Message}
+}
+
+// unmarshalNonNull is like json.Unmarshal, except that it rejects null.
+func unmarshalNonNull(b []byte, v interface{}) error {
+	if string(b) == "null" {
+		return &ValidationError{Message: "must not be null"}
+	}
+
+	return json.Unmarshal(b, v)
+}
@@ -0,0 +1,19 @@
+package com.example;
+
+import java.util.Scanner;
+
+import com.google.gson.Gson;
+import com.google.gson.GsonBuilder;
+
+public class Main {
+    public static void main(String[] args) {
+        Gson gson = new GsonBuilder().serializeNulls().create();
+
+        Scanner scanner = new Scanner(System.in);
+
+        while (scanner.hasNextLine()) {
+            MAIN value = gson.fromJson(scanner.nextLine(), MAIN.class);
+            System.out.println(gson.toJson(value, MAIN.class));
+        }
+    }
+}
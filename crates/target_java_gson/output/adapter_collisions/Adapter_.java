@@ -0,0 +1,27 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class Adapter_ {
+    @SerializedName("n")
+    private String n;
+
+    public Adapter_() {
+    }
+
+    /**
+     * Getter for n.<p>
+     */
+    public String getN() {
+        return n;
+    }
+
+    /**
+     * Setter for n.<p>
+     */
+    public void setN(String n) {
+        this.n = n;
+    }
+}
@@ -0,0 +1,57 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+import java.util.List;
+
+@JsonAdapter(Adapters.Adapter.class)
+public class Adapters {
+    private List<Adapter_> value;
+
+    public Adapters() {
+    }
+
+    public Adapters(List<Adapter_> value) {
+        this.value = value;
+    }
+
+    public List<Adapter_> getValue() {
+        return value;
+    }
+
+    public void setValue(List<Adapter_> value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != Adapters.class) {
+                return null;
+            }
+
+            final TypeAdapter<List<Adapter_>> delegate = gson.getAdapter(new TypeToken<List<Adapter_>>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<Adapters>() {
+                @Override
+                public void write(JsonWriter out, Adapters value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public Adapters read(JsonReader in) throws IOException {
+                    return new Adapters(delegate.read(in));
+                }
+            };
+        }
+    }
+}
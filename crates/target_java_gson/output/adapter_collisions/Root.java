@@ -0,0 +1,201 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.annotations.SerializedName;
+import java.time.OffsetDateTime;
+
+public class Root {
+    @SerializedName("a")
+    private UnsignedByte_ a;
+
+    @SerializedName("b")
+    private UnsignedShort_ b;
+
+    @SerializedName("c")
+    private UnsignedInteger_ c;
+
+    @SerializedName("d")
+    private UnsignedByte d;
+
+    @SerializedName("e")
+    private UnsignedShort e;
+
+    @SerializedName("f")
+    private UnsignedInteger f;
+
+    @JsonAdapter(OffsetDateTimeAdapter.class)
+    @SerializedName("v")
+    private OffsetDateTime v;
+
+    @SerializedName("x")
+    private Adapters x;
+
+    @SerializedName("y")
+    private OffsetDateTimeAdapter_ y;
+
+    @SerializedName("z")
+    private OptionalPropertyAdapter_ z;
+
+    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)
+    @SerializedName("w")
+    private OffsetDateTime w;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for a.<p>
+     */
+    public UnsignedByte_ getA() {
+        return a;
+    }
+
+    /**
+     * Setter for a.<p>
+     */
+    public void setA(UnsignedByte_ a) {
+        this.a = a;
+    }
+
+    /**
+     * Getter for b.<p>
+     */
+    public UnsignedShort_ getB() {
+        return b;
+    }
+
+    /**
+     * Setter for b.<p>
+     */
+    public void setB(UnsignedShort_ b) {
+        this.b = b;
+    }
+
+    /**
+     * Getter for c.<p>
+     */
+    public UnsignedInteger_ getC() {
+        return c;
+    }
+
+    /**
+     * Setter for c.<p>
+     */
+    public void setC(UnsignedInteger_ c) {
+        this.c = c;
+    }
+
+    /**
+     * Getter for d.<p>
+     */
+    public UnsignedByte getD() {
+        return d;
+    }
+
+    /**
+     * Setter for d.<p>
+     */
+    public void setD(UnsignedByte d) {
+        this.d = d;
+    }
+
+    /**
+     * Getter for e.<p>
+     */
+    public UnsignedShort getE() {
+        return e;
+    }
+
+    /**
+     * Setter for e.<p>
+     */
+    public void setE(UnsignedShort e) {
+        this.e = e;
+    }
+
+    /**
+     * Getter for f.<p>
+     */
+    public UnsignedInteger getF() {
+        return f;
+    }
+
+    /**
+     * Setter for f.<p>
+     */
+    public void setF(UnsignedInteger f) {
+        this.f = f;
+    }
+
+    /**
+     * Getter for v.<p>
+     */
+    public OffsetDateTime getV() {
+        return v;
+    }
+
+    /**
+     * Setter for v.<p>
+     */
+    public void setV(OffsetDateTime v) {
+        this.v = v;
+    }
+
+    /**
+     * Getter for x.<p>
+     */
+    public Adapters getX() {
+        return x;
+    }
+
+    /**
+     * Setter for x.<p>
+     */
+    public void setX(Adapters x) {
+        this.x = x;
+    }
+
+    /**
+     * Getter for y.<p>
+     */
+    public OffsetDateTimeAdapter_ getY() {
+        return y;
+    }
+
+    /**
+     * Setter for y.<p>
+     */
+    public void setY(OffsetDateTimeAdapter_ y) {
+        this.y = y;
+    }
+
+    /**
+     * Getter for z.<p>
+     */
+    public OptionalPropertyAdapter_ getZ() {
+        return z;
+    }
+
+    /**
+     * Setter for z.<p>
+     */
+    public void setZ(OptionalPropertyAdapter_ z) {
+        this.z = z;
+    }
+
+    /**
+     * Getter for w.<p>
+     */
+    public OffsetDateTime getW() {
+        return w;
+    }
+
+    /**
+     * Setter for w.<p>
+     */
+    public void setW(OffsetDateTime w) {
+        this.w = w;
+    }
+}
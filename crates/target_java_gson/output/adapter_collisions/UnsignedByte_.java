@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(UnsignedByte_.Adapter.class)
+public class UnsignedByte_ {
+    private String value;
+
+    public UnsignedByte_() {
+    }
+
+    public UnsignedByte_(String value) {
+        this.value = value;
+    }
+
+    public String getValue() {
+        return value;
+    }
+
+    public void setValue(String value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != UnsignedByte_.class) {
+                return null;
+            }
+
+            final TypeAdapter<String> delegate = gson.getAdapter(new TypeToken<String>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<UnsignedByte_>() {
+                @Override
+                public void write(JsonWriter out, UnsignedByte_ value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public UnsignedByte_ read(JsonReader in) throws IOException {
+                    return new UnsignedByte_(delegate.read(in));
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.JsonElement;
+import com.google.gson.JsonObject;
+import com.google.gson.JsonParseException;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+import java.util.Map;
+
+@JsonAdapter(Root.Adapter.class)
+public abstract class Root {
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != Root.class) {
+                return null;
+            }
+
+            final TypeAdapter<JsonElement> elementAdapter = gson.getAdapter(JsonElement.class);
+
+            return (TypeAdapter<T>) new TypeAdapter<Root>() {
+                @Override
+                public void write(JsonWriter out, Root value) throws IOException {
+                    String tag;
+                    JsonElement element;
+                    if (value instanceof RootBarBaz) {
+                        tag = "BAR_BAZ";
+                        element = gson.getAdapter(RootBarBaz.class).toJsonTree((RootBarBaz) value);
+                    } else if (value instanceof RootQuux) {
+                        tag = "QUUX";
+                        element = gson.getAdapter(RootQuux.class).toJsonTree((RootQuux) value);
+                    } else {
+                        throw new IllegalArgumentException("unknown variant of Root: " + value.getClass());
+                    }
+
+                    JsonObject object = new JsonObject();
+                    object.addProperty("foo", tag);
+                    for (Map.Entry<String, JsonElement> entry : element.getAsJsonObject().entrySet()) {
+                        object.add(entry.getKey(), entry.getValue());
+                    }
+
+                    elementAdapter.write(out, object);
+                }
+
+                @Override
+                public Root read(JsonReader in) throws IOException {
+                    JsonObject object = elementAdapter.read(in).getAsJsonObject();
+                    JsonElement tag = object.get("foo");
+                    if (tag == null || !tag.isJsonPrimitive()) {
+                        throw new JsonParseException("Root is missing property \"foo\"");
+                    }
+
+                    switch (tag.getAsString()) {
+                        case "BAR_BAZ":
+                            return gson.getAdapter(RootBarBaz.class).fromJsonTree(object);
+                        case "QUUX":
+                            return gson.getAdapter(RootQuux.class).fromJsonTree(object);
+                        default:
+                            throw new JsonParseException("unknown value of \"foo\" for Root: " + tag);
+                    }
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class RootQuux extends Root {
+    @SerializedName("quuz")
+    private String quuz;
+
+    public RootQuux() {
+    }
+
+    /**
+     * Getter for quuz.<p>
+     */
+    public String getQuuz() {
+        return quuz;
+    }
+
+    /**
+     * Setter for quuz.<p>
+     */
+    public void setQuuz(String quuz) {
+        this.quuz = quuz;
+    }
+}
@@ -0,0 +1,16 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public enum Root {
+    @SerializedName("Bar")
+    BAR,
+
+    @SerializedName("Baz")
+    BAZ,
+
+    @SerializedName("Foo")
+    FOO,
+}
@@ -0,0 +1,50 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+
+import java.io.IOException;
+import java.time.OffsetDateTime;
+
+/**
+ * Leaves optional properties out when they are null. Generated classes need
+ * Gson to serialize nulls, so that nullable properties are written out, and so
+ * they put this adapter on the fields of optional properties. Timestamps are
+ * read and written with OffsetDateTimeAdapter, which Gson needn't have
+ * registered.
+ */
+public class OptionalPropertyAdapter implements TypeAdapterFactory {
+    @Override
+    @SuppressWarnings("unchecked")
+    public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+        final TypeAdapter<T> delegate = type.getRawType() == OffsetDateTime.class
+            ? (TypeAdapter<T>) new OffsetDateTimeAdapter()
+            : gson.getAdapter(type);
+
+        return new TypeAdapter<T>() {
+            @Override
+            public void write(JsonWriter out, T value) throws IOException {
+                if (value == null) {
+                    boolean serializeNulls = out.getSerializeNulls();
+                    out.setSerializeNulls(false);
+                    out.nullValue();
+                    out.setSerializeNulls(serializeNulls);
+                    return;
+                }
+
+                delegate.write(out, value);
+            }
+
+            @Override
+            public T read(JsonReader in) throws IOException {
+                return delegate.read(in);
+            }
+        };
+    }
+}
@@ -0,0 +1,8 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+public class RootOverrideTypeDiscriminatorBaz extends Object {
+    public RootOverrideTypeDiscriminatorBaz() {
+    }
+}
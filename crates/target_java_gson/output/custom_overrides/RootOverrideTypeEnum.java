@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public enum RootOverrideTypeEnum {
+    @SerializedName("BAR")
+    BAR,
+
+    @SerializedName("FOO")
+    FOO,
+}
@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(Bar.Adapter.class)
+public class Bar {
+    private Bar0 value;
+
+    public Bar() {
+    }
+
+    public Bar(Bar0 value) {
+        this.value = value;
+    }
+
+    public Bar0 getValue() {
+        return value;
+    }
+
+    public void setValue(Bar0 value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != Bar.class) {
+                return null;
+            }
+
+            final TypeAdapter<Bar0> delegate = gson.getAdapter(new TypeToken<Bar0>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<Bar>() {
+                @Override
+                public void write(JsonWriter out, Bar value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public Bar read(JsonReader in) throws IOException {
+                    return new Bar(delegate.read(in));
+                }
+            };
+        }
+    }
+}
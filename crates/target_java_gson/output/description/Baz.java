@@ -0,0 +1,59 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+/**
+ * A description for a definition
+ */
+@JsonAdapter(Baz.Adapter.class)
+public class Baz {
+    private String value;
+
+    public Baz() {
+    }
+
+    public Baz(String value) {
+        this.value = value;
+    }
+
+    public String getValue() {
+        return value;
+    }
+
+    public void setValue(String value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != Baz.class) {
+                return null;
+            }
+
+            final TypeAdapter<String> delegate = gson.getAdapter(new TypeToken<String>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<Baz>() {
+                @Override
+                public void write(JsonWriter out, Baz value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public Baz read(JsonReader in) throws IOException {
+                    return new Baz(delegate.read(in));
+                }
+            };
+        }
+    }
+}
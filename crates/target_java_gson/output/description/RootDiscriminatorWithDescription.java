@@ -0,0 +1,72 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.JsonElement;
+import com.google.gson.JsonObject;
+import com.google.gson.JsonParseException;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+import java.util.Map;
+
+/**
+ * A description for discriminator
+ */
+@JsonAdapter(RootDiscriminatorWithDescription.Adapter.class)
+public abstract class RootDiscriminatorWithDescription {
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != RootDiscriminatorWithDescription.class) {
+                return null;
+            }
+
+            final TypeAdapter<JsonElement> elementAdapter = gson.getAdapter(JsonElement.class);
+
+            return (TypeAdapter<T>) new TypeAdapter<RootDiscriminatorWithDescription>() {
+                @Override
+                public void write(JsonWriter out, RootDiscriminatorWithDescription value) throws IOException {
+                    String tag;
+                    JsonElement element;
+                    if (value instanceof RootDiscriminatorWithDescriptionBar) {
+                        tag = "bar";
+                        element = gson.getAdapter(RootDiscriminatorWithDescriptionBar.class).toJsonTree((RootDiscriminatorWithDescriptionBar) value);
+                    } else {
+                        throw new IllegalArgumentException("unknown variant of RootDiscriminatorWithDescription: " + value.getClass());
+                    }
+
+                    JsonObject object = new JsonObject();
+                    object.addProperty("foo", tag);
+                    for (Map.Entry<String, JsonElement> entry : element.getAsJsonObject().entrySet()) {
+                        object.add(entry.getKey(), entry.getValue());
+                    }
+
+                    elementAdapter.write(out, object);
+                }
+
+                @Override
+                public RootDiscriminatorWithDescription read(JsonReader in) throws IOException {
+                    JsonObject object = elementAdapter.read(in).getAsJsonObject();
+                    JsonElement tag = object.get("foo");
+                    if (tag == null || !tag.isJsonPrimitive()) {
+                        throw new JsonParseException("RootDiscriminatorWithDescription is missing property \"foo\"");
+                    }
+
+                    switch (tag.getAsString()) {
+                        case "bar":
+                            return gson.getAdapter(RootDiscriminatorWithDescriptionBar.class).fromJsonTree(object);
+                        default:
+                            throw new JsonParseException("unknown value of \"foo\" for RootDiscriminatorWithDescription: " + tag);
+                    }
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+/**
+ * A description for discriminator variant
+ */
+public class RootDiscriminatorWithDescriptionBar extends RootDiscriminatorWithDescription {
+    public RootDiscriminatorWithDescriptionBar() {
+    }
+}
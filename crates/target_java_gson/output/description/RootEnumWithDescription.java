@@ -0,0 +1,28 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+/**
+ * A description for enum
+ */
+public enum RootEnumWithDescription {
+    /**
+     * A description for X
+     */
+    @SerializedName("X")
+    X,
+
+    /**
+     * A description for Y
+     */
+    @SerializedName("Y")
+    Y,
+
+    /**
+     * A description for Z
+     */
+    @SerializedName("Z")
+    Z,
+}
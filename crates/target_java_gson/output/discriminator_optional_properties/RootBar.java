@@ -0,0 +1,48 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.annotations.SerializedName;
+import java.util.List;
+
+public class RootBar extends Root {
+    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)
+    @SerializedName("baz")
+    private List<String> baz;
+
+    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)
+    @SerializedName("quux")
+    private Boolean quux;
+
+    public RootBar() {
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    public List<String> getBaz() {
+        return baz;
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(List<String> baz) {
+        this.baz = baz;
+    }
+
+    /**
+     * Getter for quux.<p>
+     */
+    public Boolean getQuux() {
+        return quux;
+    }
+
+    /**
+     * Setter for quux.<p>
+     */
+    public void setQuux(Boolean quux) {
+        this.quux = quux;
+    }
+}
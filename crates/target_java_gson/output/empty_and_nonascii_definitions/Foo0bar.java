@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(Foo0bar.Adapter.class)
+public class Foo0bar {
+    private String value;
+
+    public Foo0bar() {
+    }
+
+    public Foo0bar(String value) {
+        this.value = value;
+    }
+
+    public String getValue() {
+        return value;
+    }
+
+    public void setValue(String value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != Foo0bar.class) {
+                return null;
+            }
+
+            final TypeAdapter<String> delegate = gson.getAdapter(new TypeToken<String>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<Foo0bar>() {
+                @Override
+                public void write(JsonWriter out, Foo0bar value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public Foo0bar read(JsonReader in) throws IOException {
+                    return new Foo0bar(delegate.read(in));
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(FooBar0.Adapter.class)
+public class FooBar0 {
+    private String value;
+
+    public FooBar0() {
+    }
+
+    public FooBar0(String value) {
+        this.value = value;
+    }
+
+    public String getValue() {
+        return value;
+    }
+
+    public void setValue(String value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != FooBar0.class) {
+                return null;
+            }
+
+            final TypeAdapter<String> delegate = gson.getAdapter(new TypeToken<String>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<FooBar0>() {
+                @Override
+                public void write(JsonWriter out, FooBar0 value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public FooBar0 read(JsonReader in) throws IOException {
+                    return new FooBar0(delegate.read(in));
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(FooBar1.Adapter.class)
+public class FooBar1 {
+    private String value;
+
+    public FooBar1() {
+    }
+
+    public FooBar1(String value) {
+        this.value = value;
+    }
+
+    public String getValue() {
+        return value;
+    }
+
+    public void setValue(String value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != FooBar1.class) {
+                return null;
+            }
+
+            final TypeAdapter<String> delegate = gson.getAdapter(new TypeToken<String>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<FooBar1>() {
+                @Override
+                public void write(JsonWriter out, FooBar1 value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public FooBar1 read(JsonReader in) throws IOException {
+                    return new FooBar1(delegate.read(in));
+                }
+            };
+        }
+    }
+}
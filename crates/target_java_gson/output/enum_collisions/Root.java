@@ -0,0 +1,44 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class Root {
+    @SerializedName("foo")
+    private RootFoo foo;
+
+    @SerializedName("foo_bar")
+    private RootFooBar0 fooBar;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public RootFoo getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(RootFoo foo) {
+        this.foo = foo;
+    }
+
+    /**
+     * Getter for fooBar.<p>
+     */
+    public RootFooBar0 getFooBar() {
+        return fooBar;
+    }
+
+    /**
+     * Setter for fooBar.<p>
+     */
+    public void setFooBar(RootFooBar0 fooBar) {
+        this.fooBar = fooBar;
+    }
+}
@@ -0,0 +1,16 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public enum Root {
+    @SerializedName("FOO")
+    FOO,
+
+    @SerializedName("Foo")
+    FOO0,
+
+    @SerializedName("foo")
+    FOO1,
+}
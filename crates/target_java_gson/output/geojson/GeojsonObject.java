@@ -0,0 +1,124 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.JsonElement;
+import com.google.gson.JsonObject;
+import com.google.gson.JsonParseException;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+import java.util.Map;
+
+/**
+ * A Geometry object represents points, curves, and surfaces in coordinate
+ * space.  Every Geometry object is a GeoJSON object no matter where it
+ * occurs in a GeoJSON text.
+ * 
+ * o  The value of a Geometry object's "type" member MUST be one of the
+ *     seven geometry types (see Section 1.4).
+ * 
+ * o  A GeoJSON Geometry object of any type other than
+ *     "GeometryCollection" has a member with the name "coordinates". The
+ *     value of the "coordinates" member is an array.  The structure of the
+ *     elements in this array is determined by the type of geometry.
+ *     GeoJSON processors MAY interpret Geometry objects with empty
+ *     "coordinates" arrays as null objects.
+ */
+@JsonAdapter(GeojsonObject.Adapter.class)
+public abstract class GeojsonObject {
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != GeojsonObject.class) {
+                return null;
+            }
+
+            final TypeAdapter<JsonElement> elementAdapter = gson.getAdapter(JsonElement.class);
+
+            return (TypeAdapter<T>) new TypeAdapter<GeojsonObject>() {
+                @Override
+                public void write(JsonWriter out, GeojsonObject value) throws IOException {
+                    String tag;
+                    JsonElement element;
+                    if (value instanceof GeojsonObjectFeature) {
+                        tag = "Feature";
+                        element = gson.getAdapter(GeojsonObjectFeature.class).toJsonTree((GeojsonObjectFeature) value);
+                    } else if (value instanceof GeojsonObjectFeatureCollection) {
+                        tag = "FeatureCollection";
+                        element = gson.getAdapter(GeojsonObjectFeatureCollection.class).toJsonTree((GeojsonObjectFeatureCollection) value);
+                    } else if (value instanceof GeojsonObjectGeometryCollection) {
+                        tag = "GeometryCollection";
+                        element = gson.getAdapter(GeojsonObjectGeometryCollection.class).toJsonTree((GeojsonObjectGeometryCollection) value);
+                    } else if (value instanceof GeojsonObjectLineString) {
+                        tag = "LineString";
+                        element = gson.getAdapter(GeojsonObjectLineString.class).toJsonTree((GeojsonObjectLineString) value);
+                    } else if (value instanceof GeojsonObjectMultiLineString) {
+                        tag = "MultiLineString";
+                        element = gson.getAdapter(GeojsonObjectMultiLineString.class).toJsonTree((GeojsonObjectMultiLineString) value);
+                    } else if (value instanceof GeojsonObjectMultiPoint) {
+                        tag = "MultiPoint";
+                        element = gson.getAdapter(GeojsonObjectMultiPoint.class).toJsonTree((GeojsonObjectMultiPoint) value);
+                    } else if (value instanceof GeojsonObjectMultiPolygon) {
+                        tag = "MultiPolygon";
+                        element = gson.getAdapter(GeojsonObjectMultiPolygon.class).toJsonTree((GeojsonObjectMultiPolygon) value);
+                    } else if (value instanceof GeojsonObjectPoint) {
+                        tag = "Point";
+                        element = gson.getAdapter(GeojsonObjectPoint.class).toJsonTree((GeojsonObjectPoint) value);
+                    } else if (value instanceof GeojsonObjectPolygon) {
+                        tag = "Polygon";
+                        element = gson.getAdapter(GeojsonObjectPolygon.class).toJsonTree((GeojsonObjectPolygon) value);
+                    } else {
+                        throw new IllegalArgumentException("unknown variant of GeojsonObject: " + value.getClass());
+                    }
+
+                    JsonObject object = new JsonObject();
+                    object.addProperty("type", tag);
+                    for (Map.Entry<String, JsonElement> entry : element.getAsJsonObject().entrySet()) {
+                        object.add(entry.getKey(), entry.getValue());
+                    }
+
+                    elementAdapter.write(out, object);
+                }
+
+                @Override
+                public GeojsonObject read(JsonReader in) throws IOException {
+                    JsonObject object = elementAdapter.read(in).getAsJsonObject();
+                    JsonElement tag = object.get("type");
+                    if (tag == null || !tag.isJsonPrimitive()) {
+                        throw new JsonParseException("GeojsonObject is missing property \"type\"");
+                    }
+
+                    switch (tag.getAsString()) {
+                        case "Feature":
+                            return gson.getAdapter(GeojsonObjectFeature.class).fromJsonTree(object);
+                        case "FeatureCollection":
+                            return gson.getAdapter(GeojsonObjectFeatureCollection.class).fromJsonTree(object);
+                        case "GeometryCollection":
+                            return gson.getAdapter(GeojsonObjectGeometryCollection.class).fromJsonTree(object);
+                        case "LineString":
+                            return gson.getAdapter(GeojsonObjectLineString.class).fromJsonTree(object);
+                        case "MultiLineString":
+                            return gson.getAdapter(GeojsonObjectMultiLineString.class).fromJsonTree(object);
+                        case "MultiPoint":
+                            return gson.getAdapter(GeojsonObjectMultiPoint.class).fromJsonTree(object);
+                        case "MultiPolygon":
+                            return gson.getAdapter(GeojsonObjectMultiPolygon.class).fromJsonTree(object);
+                        case "Point":
+                            return gson.getAdapter(GeojsonObjectPoint.class).fromJsonTree(object);
+                        case "Polygon":
+                            return gson.getAdapter(GeojsonObjectPolygon.class).fromJsonTree(object);
+                        default:
+                            throw new JsonParseException("unknown value of \"type\" for GeojsonObject: " + tag);
+                    }
+                }
+            };
+        }
+    }
+}
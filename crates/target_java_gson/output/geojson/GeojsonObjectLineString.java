@@ -0,0 +1,51 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.annotations.SerializedName;
+import java.util.List;
+
+/**
+ * For type "LineString", the "coordinates" member is an array of two
+ * or more positions.
+ */
+public class GeojsonObjectLineString extends GeojsonObject {
+    @SerializedName("coordinates")
+    private List<Position> coordinates;
+
+    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)
+    @SerializedName("bbox")
+    private BoundingBox bbox;
+
+    public GeojsonObjectLineString() {
+    }
+
+    /**
+     * Getter for coordinates.<p>
+     */
+    public List<Position> getCoordinates() {
+        return coordinates;
+    }
+
+    /**
+     * Setter for coordinates.<p>
+     */
+    public void setCoordinates(List<Position> coordinates) {
+        this.coordinates = coordinates;
+    }
+
+    /**
+     * Getter for bbox.<p>
+     */
+    public BoundingBox getBbox() {
+        return bbox;
+    }
+
+    /**
+     * Setter for bbox.<p>
+     */
+    public void setBbox(BoundingBox bbox) {
+        this.bbox = bbox;
+    }
+}
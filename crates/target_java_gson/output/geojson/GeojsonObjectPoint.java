@@ -0,0 +1,49 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.annotations.SerializedName;
+
+/**
+ * For type "Point", the "coordinates" member is a single position.
+ */
+public class GeojsonObjectPoint extends GeojsonObject {
+    @SerializedName("coordinates")
+    private Position coordinates;
+
+    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)
+    @SerializedName("bbox")
+    private BoundingBox bbox;
+
+    public GeojsonObjectPoint() {
+    }
+
+    /**
+     * Getter for coordinates.<p>
+     */
+    public Position getCoordinates() {
+        return coordinates;
+    }
+
+    /**
+     * Setter for coordinates.<p>
+     */
+    public void setCoordinates(Position coordinates) {
+        this.coordinates = coordinates;
+    }
+
+    /**
+     * Getter for bbox.<p>
+     */
+    public BoundingBox getBbox() {
+        return bbox;
+    }
+
+    /**
+     * Setter for bbox.<p>
+     */
+    public void setBbox(BoundingBox bbox) {
+        this.bbox = bbox;
+    }
+}
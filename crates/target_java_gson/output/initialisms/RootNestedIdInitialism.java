@@ -0,0 +1,44 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class RootNestedIdInitialism {
+    @SerializedName("json")
+    private String json;
+
+    @SerializedName("normalword")
+    private String normalword;
+
+    public RootNestedIdInitialism() {
+    }
+
+    /**
+     * Getter for json.<p>
+     */
+    public String getJson() {
+        return json;
+    }
+
+    /**
+     * Setter for json.<p>
+     */
+    public void setJson(String json) {
+        this.json = json;
+    }
+
+    /**
+     * Getter for normalword.<p>
+     */
+    public String getNormalword() {
+        return normalword;
+    }
+
+    /**
+     * Setter for normalword.<p>
+     */
+    public void setNormalword(String normalword) {
+        this.normalword = normalword;
+    }
+}
@@ -0,0 +1,44 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class Root {
+    @SerializedName("for")
+    private For for_;
+
+    @SerializedName("object")
+    private Object object;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for for_.<p>
+     */
+    public For getFor_() {
+        return for_;
+    }
+
+    /**
+     * Setter for for_.<p>
+     */
+    public void setFor_(For for_) {
+        this.for_ = for_;
+    }
+
+    /**
+     * Getter for object.<p>
+     */
+    public Object getObject() {
+        return object;
+    }
+
+    /**
+     * Setter for object.<p>
+     */
+    public void setObject(Object object) {
+        this.object = object;
+    }
+}
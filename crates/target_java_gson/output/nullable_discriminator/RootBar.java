@@ -0,0 +1,27 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class RootBar extends Root {
+    @SerializedName("baz")
+    private String baz;
+
+    public RootBar() {
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    public String getBaz() {
+        return baz;
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(String baz) {
+        this.baz = baz;
+    }
+}
@@ -0,0 +1,39 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.TypeAdapter;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+
+import java.io.IOException;
+
+@JsonAdapter(UnsignedShort.Adapter.class)
+public class UnsignedShort {
+    private short value;
+
+    public UnsignedShort(short value) {
+        this.value = value;
+    }
+
+    public short getValue() {
+        return value;
+    }
+
+    public void setValue(short value) {
+        this.value = value;
+    }
+
+    public static class Adapter extends TypeAdapter<UnsignedShort> {
+        @Override
+        public void write(JsonWriter out, UnsignedShort value) throws IOException {
+            out.value(Short.toUnsignedLong(value.getValue()));
+        }
+
+        @Override
+        public UnsignedShort read(JsonReader in) throws IOException {
+            return new UnsignedShort((short) in.nextLong());
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+import java.util.List;
+
+public class Root {
+    @SerializedName("bar")
+    private String bar;
+
+    @SerializedName("baz")
+    private List<Boolean> baz;
+
+    @SerializedName("foo")
+    private Boolean foo;
+
+    @SerializedName("quux")
+    private List<Boolean> quux;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for bar.<p>
+     */
+    public String getBar() {
+        return bar;
+    }
+
+    /**
+     * Setter for bar.<p>
+     */
+    public void setBar(String bar) {
+        this.bar = bar;
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    public List<Boolean> getBaz() {
+        return baz;
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(List<Boolean> baz) {
+        this.baz = baz;
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public Boolean getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(Boolean foo) {
+        this.foo = foo;
+    }
+
+    /**
+     * Getter for quux.<p>
+     */
+    public List<Boolean> getQuux() {
+        return quux;
+    }
+
+    /**
+     * Setter for quux.<p>
+     */
+    public void setQuux(List<Boolean> quux) {
+        this.quux = quux;
+    }
+}
@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(NotnullRefNotnullString.Adapter.class)
+public class NotnullRefNotnullString {
+    private NotnullString value;
+
+    public NotnullRefNotnullString() {
+    }
+
+    public NotnullRefNotnullString(NotnullString value) {
+        this.value = value;
+    }
+
+    public NotnullString getValue() {
+        return value;
+    }
+
+    public void setValue(NotnullString value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != NotnullRefNotnullString.class) {
+                return null;
+            }
+
+            final TypeAdapter<NotnullString> delegate = gson.getAdapter(new TypeToken<NotnullString>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<NotnullRefNotnullString>() {
+                @Override
+                public void write(JsonWriter out, NotnullRefNotnullString value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public NotnullRefNotnullString read(JsonReader in) throws IOException {
+                    return new NotnullRefNotnullString(delegate.read(in));
+                }
+            };
+        }
+    }
+}
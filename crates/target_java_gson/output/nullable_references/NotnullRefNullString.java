@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(NotnullRefNullString.Adapter.class)
+public class NotnullRefNullString {
+    private NullString value;
+
+    public NotnullRefNullString() {
+    }
+
+    public NotnullRefNullString(NullString value) {
+        this.value = value;
+    }
+
+    public NullString getValue() {
+        return value;
+    }
+
+    public void setValue(NullString value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != NotnullRefNullString.class) {
+                return null;
+            }
+
+            final TypeAdapter<NullString> delegate = gson.getAdapter(new TypeToken<NullString>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<NotnullRefNullString>() {
+                @Override
+                public void write(JsonWriter out, NotnullRefNullString value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public NotnullRefNullString read(JsonReader in) throws IOException {
+                    return new NotnullRefNullString(delegate.read(in));
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(NullRefNotnullString.Adapter.class)
+public class NullRefNotnullString {
+    private NotnullString value;
+
+    public NullRefNotnullString() {
+    }
+
+    public NullRefNotnullString(NotnullString value) {
+        this.value = value;
+    }
+
+    public NotnullString getValue() {
+        return value;
+    }
+
+    public void setValue(NotnullString value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != NullRefNotnullString.class) {
+                return null;
+            }
+
+            final TypeAdapter<NotnullString> delegate = gson.getAdapter(new TypeToken<NotnullString>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<NullRefNotnullString>() {
+                @Override
+                public void write(JsonWriter out, NullRefNotnullString value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public NullRefNotnullString read(JsonReader in) throws IOException {
+                    return new NullRefNotnullString(delegate.read(in));
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(NullRefNullString.Adapter.class)
+public class NullRefNullString {
+    private NullString value;
+
+    public NullRefNullString() {
+    }
+
+    public NullRefNullString(NullString value) {
+        this.value = value;
+    }
+
+    public NullString getValue() {
+        return value;
+    }
+
+    public void setValue(NullString value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != NullRefNullString.class) {
+                return null;
+            }
+
+            final TypeAdapter<NullString> delegate = gson.getAdapter(new TypeToken<NullString>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<NullRefNullString>() {
+                @Override
+                public void write(JsonWriter out, NullRefNullString value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public NullRefNullString read(JsonReader in) throws IOException {
+                    return new NullRefNullString(delegate.read(in));
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class Root {
+    @SerializedName("notnull_ref_notnull_string")
+    private NotnullRefNotnullString notnullRefNotnullString;
+
+    @SerializedName("notnull_ref_null_string")
+    private NotnullRefNullString notnullRefNullString;
+
+    @SerializedName("notnull_string")
+    private NotnullString notnullString;
+
+    @SerializedName("null_ref_notnull_string")
+    private NullRefNotnullString nullRefNotnullString;
+
+    @SerializedName("null_ref_null_string")
+    private NullRefNullString nullRefNullString;
+
+    @SerializedName("null_string")
+    private NullString nullString;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for notnullRefNotnullString.<p>
+     */
+    public NotnullRefNotnullString getNotnullRefNotnullString() {
+        return notnullRefNotnullString;
+    }
+
+    /**
+     * Setter for notnullRefNotnullString.<p>
+     */
+    public void setNotnullRefNotnullString(NotnullRefNotnullString notnullRefNotnullString) {
+        this.notnullRefNotnullString = notnullRefNotnullString;
+    }
+
+    /**
+     * Getter for notnullRefNullString.<p>
+     */
+    public NotnullRefNullString getNotnullRefNullString() {
+        return notnullRefNullString;
+    }
+
+    /**
+     * Setter for notnullRefNullString.<p>
+     */
+    public void setNotnullRefNullString(NotnullRefNullString notnullRefNullString) {
+        this.notnullRefNullString = notnullRefNullString;
+    }
+
+    /**
+     * Getter for notnullString.<p>
+     */
+    public NotnullString getNotnullString() {
+        return notnullString;
+    }
+
+    /**
+     * Setter for notnullString.<p>
+     */
+    public void setNotnullString(NotnullString notnullString) {
+        this.notnullString = notnullString;
+    }
+
+    /**
+     * Getter for nullRefNotnullString.<p>
+     */
+    public NullRefNotnullString getNullRefNotnullString() {
+        return nullRefNotnullString;
+    }
+
+    /**
+     * Setter for nullRefNotnullString.<p>
+     */
+    public void setNullRefNotnullString(NullRefNotnullString nullRefNotnullString) {
+        this.nullRefNotnullString = nullRefNotnullString;
+    }
+
+    /**
+     * Getter for nullRefNullString.<p>
+     */
+    public NullRefNullString getNullRefNullString() {
+        return nullRefNullString;
+    }
+
+    /**
+     * Setter for nullRefNullString.<p>
+     */
+    public void setNullRefNullString(NullRefNullString nullRefNullString) {
+        this.nullRefNullString = nullRefNullString;
+    }
+
+    /**
+     * Getter for nullString.<p>
+     */
+    public NullString getNullString() {
+        return nullString;
+    }
+
+    /**
+     * Setter for nullString.<p>
+     */
+    public void setNullString(NullString nullString) {
+        this.nullString = nullString;
+    }
+}
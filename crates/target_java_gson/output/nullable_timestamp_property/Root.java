@@ -0,0 +1,30 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.annotations.SerializedName;
+import java.time.OffsetDateTime;
+
+public class Root {
+    @JsonAdapter(OffsetDateTimeAdapter.class)
+    @SerializedName("foo")
+    private OffsetDateTime foo;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public OffsetDateTime getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(OffsetDateTime foo) {
+        this.foo = foo;
+    }
+}
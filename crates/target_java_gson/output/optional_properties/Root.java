@@ -0,0 +1,66 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.annotations.SerializedName;
+import java.util.List;
+
+public class Root {
+    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)
+    @SerializedName("bar")
+    private List<String> bar;
+
+    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)
+    @SerializedName("baz")
+    private Boolean baz;
+
+    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)
+    @SerializedName("foo")
+    private String foo;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for bar.<p>
+     */
+    public List<String> getBar() {
+        return bar;
+    }
+
+    /**
+     * Setter for bar.<p>
+     */
+    public void setBar(List<String> bar) {
+        this.bar = bar;
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    public Boolean getBaz() {
+        return baz;
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(Boolean baz) {
+        this.baz = baz;
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public String getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(String foo) {
+        this.foo = foo;
+    }
+}
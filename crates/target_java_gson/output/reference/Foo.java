@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(Foo.Adapter.class)
+public class Foo {
+    private Bar value;
+
+    public Foo() {
+    }
+
+    public Foo(Bar value) {
+        this.value = value;
+    }
+
+    public Bar getValue() {
+        return value;
+    }
+
+    public void setValue(Bar value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != Foo.class) {
+                return null;
+            }
+
+            final TypeAdapter<Bar> delegate = gson.getAdapter(new TypeToken<Bar>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<Foo>() {
+                @Override
+                public void write(JsonWriter out, Foo value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public Foo read(JsonReader in) throws IOException {
+                    return new Foo(delegate.read(in));
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.Gson;
+import com.google.gson.TypeAdapter;
+import com.google.gson.TypeAdapterFactory;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.reflect.TypeToken;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+import java.io.IOException;
+
+@JsonAdapter(Root.Adapter.class)
+public class Root {
+    private Foo value;
+
+    public Root() {
+    }
+
+    public Root(Foo value) {
+        this.value = value;
+    }
+
+    public Foo getValue() {
+        return value;
+    }
+
+    public void setValue(Foo value) {
+        this.value = value;
+    }
+
+    public static class Adapter implements TypeAdapterFactory {
+        @Override
+        @SuppressWarnings("unchecked")
+        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {
+            if (type.getRawType() != Root.class) {
+                return null;
+            }
+
+            final TypeAdapter<Foo> delegate = gson.getAdapter(new TypeToken<Foo>() {});
+
+            return (TypeAdapter<T>) new TypeAdapter<Root>() {
+                @Override
+                public void write(JsonWriter out, Root value) throws IOException {
+                    delegate.write(out, value.getValue());
+                }
+
+                @Override
+                public Root read(JsonReader in) throws IOException {
+                    return new Root(delegate.read(in));
+                }
+            };
+        }
+    }
+}
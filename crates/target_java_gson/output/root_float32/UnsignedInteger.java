@@ -0,0 +1,39 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.TypeAdapter;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+
+import java.io.IOException;
+
+@JsonAdapter(UnsignedInteger.Adapter.class)
+public class UnsignedInteger {
+    private int value;
+
+    public UnsignedInteger(int value) {
+        this.value = value;
+    }
+
+    public int getValue() {
+        return value;
+    }
+
+    public void setValue(int value) {
+        this.value = value;
+    }
+
+    public static class Adapter extends TypeAdapter<UnsignedInteger> {
+        @Override
+        public void write(JsonWriter out, UnsignedInteger value) throws IOException {
+            out.value(Integer.toUnsignedLong(value.getValue()));
+        }
+
+        @Override
+        public UnsignedInteger read(JsonReader in) throws IOException {
+            return new UnsignedInteger((int) in.nextLong());
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.TypeAdapter;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonToken;
+import com.google.gson.stream.JsonWriter;
+
+import java.io.IOException;
+import java.time.OffsetDateTime;
+import java.time.format.DateTimeFormatter;
+
+/**
+ * Reads and writes timestamps as RFC 3339 strings. Generated classes put this
+ * adapter on their timestamp fields, but Gson has no adapter for
+ * OffsetDateTime of its own, so timestamps in arrays or dictionaries need this
+ * one registered with
+ * GsonBuilder.registerTypeAdapter(OffsetDateTime.class, new OffsetDateTimeAdapter()).
+ */
+public class OffsetDateTimeAdapter extends TypeAdapter<OffsetDateTime> {
+    @Override
+    public void write(JsonWriter out, OffsetDateTime value) throws IOException {
+        if (value == null) {
+            out.nullValue();
+            return;
+        }
+
+        out.value(value.format(DateTimeFormatter.ISO_OFFSET_DATE_TIME));
+    }
+
+    @Override
+    public OffsetDateTime read(JsonReader in) throws IOException {
+        if (in.peek() == JsonToken.NULL) {
+            in.nextNull();
+            return null;
+        }
+
+        return OffsetDateTime.parse(in.nextString(), DateTimeFormatter.ISO_OFFSET_DATE_TIME);
+    }
+}
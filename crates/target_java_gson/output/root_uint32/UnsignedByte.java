@@ -0,0 +1,39 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.TypeAdapter;
+import com.google.gson.annotations.JsonAdapter;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonWriter;
+
+import java.io.IOException;
+
+@JsonAdapter(UnsignedByte.Adapter.class)
+public class UnsignedByte {
+    private byte value;
+
+    public UnsignedByte(byte value) {
+        this.value = value;
+    }
+
+    public byte getValue() {
+        return value;
+    }
+
+    public void setValue(byte value) {
+        this.value = value;
+    }
+
+    public static class Adapter extends TypeAdapter<UnsignedByte> {
+        @Override
+        public void write(JsonWriter out, UnsignedByte value) throws IOException {
+            out.value(Byte.toUnsignedLong(value.getValue()));
+        }
+
+        @Override
+        public UnsignedByte read(JsonReader in) throws IOException {
+            return new UnsignedByte((byte) in.nextLong());
+        }
+    }
+}
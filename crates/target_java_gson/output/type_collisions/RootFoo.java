@@ -0,0 +1,27 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class RootFoo {
+    @SerializedName("bar")
+    private RootFooBar bar;
+
+    public RootFoo() {
+    }
+
+    /**
+     * Getter for bar.<p>
+     */
+    public RootFooBar getBar() {
+        return bar;
+    }
+
+    /**
+     * Setter for bar.<p>
+     */
+    public void setBar(RootFooBar bar) {
+        this.bar = bar;
+    }
+}
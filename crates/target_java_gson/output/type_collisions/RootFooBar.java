@@ -0,0 +1,27 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class RootFooBar {
+    @SerializedName("x")
+    private Boolean x;
+
+    public RootFooBar() {
+    }
+
+    /**
+     * Getter for x.<p>
+     */
+    public Boolean getX() {
+        return x;
+    }
+
+    /**
+     * Setter for x.<p>
+     */
+    public void setX(Boolean x) {
+        this.x = x;
+    }
+}
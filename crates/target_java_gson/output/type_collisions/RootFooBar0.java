@@ -0,0 +1,27 @@
+// Code generated by jtd-codegen for Java + Gson v0.1.0
+
+package com.example;
+
+import com.google.gson.annotations.SerializedName;
+
+public class RootFooBar0 {
+    @SerializedName("x")
+    private String x;
+
+    public RootFooBar0() {
+    }
+
+    /**
+     * Getter for x.<p>
+     */
+    public String getX() {
+        return x;
+    }
+
+    /**
+     * Setter for x.<p>
+     */
+    public void setX(String x) {
+        this.x = x;
+    }
+}
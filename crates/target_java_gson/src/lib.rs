@@ -0,0 +1,792 @@
+use askama::Template;
+use jtd_codegen::target::{self, inflect, metadata};
+use jtd_codegen::Result;
+use lazy_static::lazy_static;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+lazy_static! {
+    static ref KEYWORDS: BTreeSet<String> = include_str!("keywords")
+        .lines()
+        .map(str::to_owned)
+        .collect();
+    static ref TYPE_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
+        Box::new(inflect::KeywordAvoidingInflector::new(
+            KEYWORDS.clone(),
+            inflect::CombiningInflector::new(inflect::Case::pascal_case())
+        ));
+    static ref FIELD_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
+        Box::new(inflect::KeywordAvoidingInflector::new(
+            KEYWORDS.clone(),
+            inflect::TailInflector::new(inflect::Case::camel_case())
+        ));
+    static ref ENUM_MEMBER_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
+        Box::new(inflect::KeywordAvoidingInflector::new(
+            KEYWORDS.clone(),
+            inflect::TailInflector::new(inflect::Case::screaming_snake_case())
+        ));
+}
+
+/// Generates classes for Gson.
+///
+/// Generated code needs a `Gson` that serializes nulls. Timestamp properties
+/// and aliases are read and written with the generated `OffsetDateTimeAdapter`,
+/// but timestamps in arrays or dictionaries need it registered for
+/// `OffsetDateTime`. Values of a discriminator must be written out as that type, as in `gson.toJson(value,
+/// Root.class)`, or else their tag is left out.
+///
+/// Gson reads numbers in `{}` values, which are generated as `Object`, as
+/// `Double`, so `48` is written back out as `48.0`. To keep numbers as they
+/// were, give the `Gson` an object-to-number strategy, as in
+/// `setObjectToNumberStrategy(ToNumberPolicy.LAZILY_PARSED_NUMBER)`.
+pub struct Target {
+    package: String,
+}
+
+impl Target {
+    pub fn new(package: String) -> Self {
+        Self { package }
+    }
+
+    // Whether generated types can't be given a name, because this target
+    // declares something else by that name.
+    fn reserves(&self, name: &str) -> bool {
+        // Aliases and discriminators declare a nested Adapter, which would
+        // shadow a type of that name in them.
+        let nested_names = ["Adapter"];
+
+        // Types can't share a name, or a file, with the auxiliary classes.
+        let auxiliary_names = [
+            "OffsetDateTimeAdapter",
+            "OptionalPropertyAdapter",
+            "UnsignedByte",
+            "UnsignedShort",
+            "UnsignedInteger",
+        ];
+
+        nested_names.contains(&name) || auxiliary_names.contains(&name)
+    }
+}
+
+impl jtd_codegen::target::Target for Target {
+    type FileState = FileState;
+
+    fn strategy(&self) -> target::Strategy {
+        target::Strategy {
+            file_partitioning: target::FilePartitioningStrategy::FilePerType("java".into()),
+            enum_member_naming: target::EnumMemberNamingStrategy::Modularized,
+            optional_property_handling: target::OptionalPropertyHandlingStrategy::WrapWithNullable,
+            booleans_are_nullable: true,
+            int8s_are_nullable: true,
+            uint8s_are_nullable: true,
+            int16s_are_nullable: true,
+            uint16s_are_nullable: true,
+            int32s_are_nullable: true,
+            uint32s_are_nullable: true,
+            float32s_are_nullable: true,
+            float64s_are_nullable: true,
+            strings_are_nullable: true,
+            timestamps_are_nullable: true,
+            arrays_are_nullable: true,
+            dicts_are_nullable: true,
+            aliases_are_nullable: true,
+            enums_are_nullable: true,
+            structs_are_nullable: true,
+            discriminators_are_nullable: true,
+        }
+    }
+
+    fn name(&self, kind: target::NameableKind, parts: &[String]) -> String {
+        match kind {
+            target::NameableKind::Type => {
+                let name = TYPE_NAMING_CONVENTION.inflect(parts);
+                if self.reserves(&name) {
+                    format!("{}_", name)
+                } else {
+                    name
+                }
+            }
+            target::NameableKind::Field => FIELD_NAMING_CONVENTION.inflect(parts),
+            target::NameableKind::EnumMember => ENUM_MEMBER_NAMING_CONVENTION.inflect(parts),
+        }
+    }
+
+    fn expr(
+        &self,
+        state: &mut FileState,
+        metadata: metadata::Metadata,
+        expr: target::Expr,
+    ) -> Result<String> {
+        if let Some(s) = metadata.get("javaGsonType").and_then(|v| v.as_str()) {
+            return Ok(s.into());
+        }
+
+        Ok(match expr {
+            target::Expr::Empty => "Object".into(),
+            target::Expr::Boolean => "Boolean".into(),
+            target::Expr::Int8 => "Byte".into(),
+            target::Expr::Uint8 => "UnsignedByte".into(),
+            target::Expr::Int16 => "Short".into(),
+            target::Expr::Uint16 => "UnsignedShort".into(),
+            target::Expr::Int32 => "Integer".into(),
+            target::Expr::Uint32 => "UnsignedInteger".into(),
+            target::Expr::Float32 => "Float".into(),
+            target::Expr::Float64 => "Double".into(),
+            target::Expr::String => "String".into(),
+            target::Expr::Timestamp => {
+                state.imports.insert("java.time.OffsetDateTime".into());
+                "OffsetDateTime".into()
+            }
+            target::Expr::ArrayOf(sub_expr) => {
+                if let Some(s) = metadata.get("javaGsonContainer").and_then(|v| v.as_str()) {
+                    return Ok(format!("{}<{}>", s, sub_expr));
+                }
+
+                state.imports.insert("java.util.List".into());
+                format!("List<{}>", sub_expr)
+            }
+            target::Expr::DictOf(sub_expr) => {
+                if let Some(s) = metadata.get("javaGsonContainer").and_then(|v| v.as_str()) {
+                    return Ok(format!("{}<String, {}>", s, sub_expr));
+                }
+
+                state.imports.insert("java.util.Map".into());
+                format!("Map<String, {}>", sub_expr)
+            }
+            target::Expr::NullableOf(sub_expr) => sub_expr, // everything is already nullable
+        })
+    }
+
+    fn item(
+        &self,
+        out: &mut dyn Write,
+        state: &mut FileState,
+        item: target::Item,
+    ) -> Result<Option<String>> {
+        Ok(match item {
+            target::Item::Auxiliary { out_dir, .. } => {
+                let mut out = File::create(Path::join(&out_dir, "UnsignedByte.java"))?;
+                writeln!(
+                    out,
+                    "{}",
+                    UnsignedByteTemplate {
+                        version: env!("CARGO_PKG_VERSION"),
+                        package: &self.package,
+                    }
+                    .render()
+                    .unwrap()
+                )?;
+
+                let mut out = File::create(Path::join(&out_dir, "UnsignedShort.java"))?;
+                writeln!(
+                    out,
+                    "{}",
+                    UnsignedShortTemplate {
+                        version: env!("CARGO_PKG_VERSION"),
+                        package: &self.package,
+                    }
+                    .render()
+                    .unwrap()
+                )?;
+
+                let mut out = File::create(Path::join(&out_dir, "UnsignedInteger.java"))?;
+                writeln!(
+                    out,
+                    "{}",
+                    UnsignedIntegerTemplate {
+                        version: env!("CARGO_PKG_VERSION"),
+                        package: &self.package,
+                    }
+                    .render()
+                    .unwrap()
+                )?;
+
+                let mut out = File::create(Path::join(&out_dir, "OffsetDateTimeAdapter.java"))?;
+                writeln!(
+                    out,
+                    "{}",
+                    OffsetDateTimeAdapterTemplate {
+                        version: env!("CARGO_PKG_VERSION"),
+                        package: &self.package,
+                    }
+                    .render()
+                    .unwrap()
+                )?;
+
+                let mut out = File::create(Path::join(&out_dir, "OptionalPropertyAdapter.java"))?;
+                writeln!(
+                    out,
+                    "{}",
+                    OptionalPropertyAdapterTemplate {
+                        version: env!("CARGO_PKG_VERSION"),
+                        package: &self.package,
+                    }
+                    .render()
+                    .unwrap()
+                )?;
+
+                None
+            }
+
+            target::Item::Preamble => {
+                writeln!(
+                    out,
+                    "// Code generated by jtd-codegen for Java + Gson v{}",
+                    env!("CARGO_PKG_VERSION")
+                )?;
+                writeln!(out)?;
+
+                writeln!(out, "package {};", &self.package)?;
+
+                if !state.imports.is_empty() {
+                    writeln!(out)?;
+                }
+
+                for import in &state.imports {
+                    writeln!(out, "import {};", import)?;
+                }
+
+                writeln!(out)?;
+
+                None
+            }
+
+            target::Item::Postamble => None,
+
+            target::Item::Alias {
+                metadata,
+                name,
+                type_,
+            } => {
+                state.imports.extend(vec![
+                    "com.google.gson.Gson".to_string(),
+                    "com.google.gson.TypeAdapter".to_string(),
+                    "com.google.gson.TypeAdapterFactory".to_string(),
+                    "com.google.gson.annotations.JsonAdapter".to_string(),
+                    "com.google.gson.reflect.TypeToken".to_string(),
+                    "com.google.gson.stream.JsonReader".to_string(),
+                    "com.google.gson.stream.JsonWriter".to_string(),
+                    "java.io.IOException".to_string(),
+                ]);
+
+                write!(out, "{}", description(&metadata, 0))?;
+                writeln!(out, "@JsonAdapter({}.Adapter.class)", name)?;
+                writeln!(out, "public class {} {{", name)?;
+                writeln!(out, "    private {} value;", type_)?;
+                writeln!(out)?;
+                writeln!(out, "    public {}() {{", name)?;
+                writeln!(out, "    }}")?;
+                writeln!(out)?;
+                writeln!(out, "    public {}({} value) {{", name, type_)?;
+                writeln!(out, "        this.value = value;")?;
+                writeln!(out, "    }}")?;
+                writeln!(out)?;
+                writeln!(out, "    public {} getValue() {{", type_)?;
+                writeln!(out, "        return value;")?;
+                writeln!(out, "    }}")?;
+                writeln!(out)?;
+                writeln!(out, "    public void setValue({} value) {{", type_)?;
+                writeln!(out, "        this.value = value;")?;
+                writeln!(out, "    }}")?;
+                writeln!(out)?;
+
+                // An alias is written out as the value it wraps. Gson has no
+                // adapter for timestamps of its own.
+                write_adapter_factory_start(out, name.as_str())?;
+                if type_ == "OffsetDateTime" {
+                    writeln!(
+                        out,
+                        "            final TypeAdapter<OffsetDateTime> delegate = new OffsetDateTimeAdapter();"
+                    )?;
+                } else {
+                    writeln!(
+                        out,
+                        "            final TypeAdapter<{0}> delegate = gson.getAdapter(new TypeToken<{0}>() {{}});",
+                        type_
+                    )?;
+                }
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "            return (TypeAdapter<T>) new TypeAdapter<{}>() {{",
+                    name
+                )?;
+                writeln!(out, "                @Override")?;
+                writeln!(
+                    out,
+                    "                public void write(JsonWriter out, {} value) throws IOException {{",
+                    name
+                )?;
+                writeln!(
+                    out,
+                    "                    delegate.write(out, value.getValue());"
+                )?;
+                writeln!(out, "                }}")?;
+                writeln!(out)?;
+                writeln!(out, "                @Override")?;
+                writeln!(
+                    out,
+                    "                public {} read(JsonReader in) throws IOException {{",
+                    name
+                )?;
+                writeln!(
+                    out,
+                    "                    return new {}(delegate.read(in));",
+                    name
+                )?;
+                writeln!(out, "                }}")?;
+                write_adapter_factory_end(out)?;
+                writeln!(out, "}}")?;
+
+                None
+            }
+
+            target::Item::Enum {
+                metadata,
+                name,
+                members,
+            } => {
+                if let Some(s) = metadata.get("javaGsonType").and_then(|v| v.as_str()) {
+                    return Ok(Some(s.into()));
+                }
+
+                state
+                    .imports
+                    .insert("com.google.gson.annotations.SerializedName".into());
+
+                write!(out, "{}", description(&metadata, 0))?;
+                writeln!(out, "public enum {} {{", name)?;
+                for (index, member) in members.iter().enumerate() {
+                    if index != 0 {
+                        writeln!(out)?;
+                    }
+
+                    write!(
+                        out,
+                        "{}",
+                        enum_variant_description(&metadata, 1, &member.json_value)
+                    )?;
+                    writeln!(out, "    @SerializedName({:?})", member.json_value)?;
+                    writeln!(out, "    {},", member.name)?;
+                }
+                writeln!(out, "}}")?;
+
+                None
+            }
+
+            target::Item::Struct {
+                metadata,
+                name,
+                fields,
+                ..
+            } => {
+                if let Some(s) = metadata.get("javaGsonType").and_then(|v| v.as_str()) {
+                    return Ok(Some(s.into()));
+                }
+
+                write!(out, "{}", description(&metadata, 0))?;
+                writeln!(out, "public class {} {{", name)?;
+                write_fields(out, state, &name, &fields)?;
+                writeln!(out, "}}")?;
+
+                None
+            }
+
+            target::Item::Discriminator {
+                metadata,
+                name,
+                tag_json_name,
+                variants,
+                ..
+            } => {
+                if let Some(s) = metadata.get("javaGsonType").and_then(|v| v.as_str()) {
+                    return Ok(Some(s.into()));
+                }
+
+                state.imports.extend(vec![
+                    "com.google.gson.Gson".to_string(),
+                    "com.google.gson.JsonElement".to_string(),
+                    "com.google.gson.JsonObject".to_string(),
+                    "com.google.gson.JsonParseException".to_string(),
+                    "com.google.gson.TypeAdapter".to_string(),
+                    "com.google.gson.TypeAdapterFactory".to_string(),
+                    "com.google.gson.annotations.JsonAdapter".to_string(),
+                    "com.google.gson.reflect.TypeToken".to_string(),
+                    "com.google.gson.stream.JsonReader".to_string(),
+                    "com.google.gson.stream.JsonWriter".to_string(),
+                    "java.io.IOException".to_string(),
+                    "java.util.Map".to_string(),
+                ]);
+
+                write!(out, "{}", description(&metadata, 0))?;
+                writeln!(out, "@JsonAdapter({}.Adapter.class)", name)?;
+                writeln!(out, "public abstract class {} {{", name)?;
+
+                // Variants are read and written by Gson's own adapters for
+                // them, with the tag added to or ignored in their properties.
+                write_adapter_factory_start(out, &name)?;
+                writeln!(
+                    out,
+                    "            final TypeAdapter<JsonElement> elementAdapter = gson.getAdapter(JsonElement.class);"
+                )?;
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "            return (TypeAdapter<T>) new TypeAdapter<{}>() {{",
+                    name
+                )?;
+                writeln!(out, "                @Override")?;
+                writeln!(
+                    out,
+                    "                public void write(JsonWriter out, {} value) throws IOException {{",
+                    name
+                )?;
+                writeln!(out, "                    String tag;")?;
+                writeln!(out, "                    JsonElement element;")?;
+                for (index, variant) in variants.iter().enumerate() {
+                    writeln!(
+                        out,
+                        "                    {}if (value instanceof {}) {{",
+                        if index == 0 { "" } else { "} else " },
+                        variant.type_name
+                    )?;
+                    writeln!(
+                        out,
+                        "                        tag = {:?};",
+                        variant.tag_value
+                    )?;
+                    writeln!(
+                        out,
+                        "                        element = gson.getAdapter({0}.class).toJsonTree(({0}) value);",
+                        variant.type_name
+                    )?;
+                }
+                if !variants.is_empty() {
+                    write!(out, "                    }} else ")?;
+                } else {
+                    write!(out, "                    ")?;
+                }
+                writeln!(out, "{{")?;
+                writeln!(
+                    out,
+                    "                        throw new IllegalArgumentException(\"unknown variant of {}: \" + value.getClass());",
+                    name
+                )?;
+                writeln!(out, "                    }}")?;
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "                    JsonObject object = new JsonObject();"
+                )?;
+                writeln!(
+                    out,
+                    "                    object.addProperty({:?}, tag);",
+                    tag_json_name
+                )?;
+                writeln!(
+                    out,
+                    "                    for (Map.Entry<String, JsonElement> entry : element.getAsJsonObject().entrySet()) {{"
+                )?;
+                writeln!(
+                    out,
+                    "                        object.add(entry.getKey(), entry.getValue());"
+                )?;
+                writeln!(out, "                    }}")?;
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "                    elementAdapter.write(out, object);"
+                )?;
+                writeln!(out, "                }}")?;
+                writeln!(out)?;
+                writeln!(out, "                @Override")?;
+                writeln!(
+                    out,
+                    "                public {} read(JsonReader in) throws IOException {{",
+                    name
+                )?;
+                writeln!(
+                    out,
+                    "                    JsonObject object = elementAdapter.read(in).getAsJsonObject();"
+                )?;
+                writeln!(
+                    out,
+                    "                    JsonElement tag = object.get({:?});",
+                    tag_json_name
+                )?;
+                writeln!(
+                    out,
+                    "                    if (tag == null || !tag.isJsonPrimitive()) {{"
+                )?;
+                writeln!(
+                    out,
+                    "                        throw new JsonParseException({:?});",
+                    format!("{} is missing property {:?}", name, tag_json_name)
+                )?;
+                writeln!(out, "                    }}")?;
+                writeln!(out)?;
+                writeln!(out, "                    switch (tag.getAsString()) {{")?;
+                for variant in &variants {
+                    writeln!(out, "                        case {:?}:", variant.tag_value)?;
+                    writeln!(
+                        out,
+                        "                            return gson.getAdapter({}.class).fromJsonTree(object);",
+                        variant.type_name
+                    )?;
+                }
+                writeln!(out, "                        default:")?;
+                writeln!(
+                    out,
+                    "                            throw new JsonParseException({:?} + tag);",
+                    format!("unknown value of {:?} for {}: ", tag_json_name, name)
+                )?;
+                writeln!(out, "                    }}")?;
+                writeln!(out, "                }}")?;
+                write_adapter_factory_end(out)?;
+                writeln!(out, "}}")?;
+
+                None
+            }
+
+            target::Item::DiscriminatorVariant {
+                metadata,
+                name,
+                parent_name,
+                fields,
+                ..
+            } => {
+                if let Some(s) = metadata.get("javaGsonType").and_then(|v| v.as_str()) {
+                    return Ok(Some(s.into()));
+                }
+
+                write!(out, "{}", description(&metadata, 0))?;
+                writeln!(out, "public class {} extends {} {{", name, parent_name)?;
+                write_fields(out, state, &name, &fields)?;
+                writeln!(out, "}}")?;
+
+                None
+            }
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct FileState {
+    imports: BTreeSet<String>,
+}
+
+#[derive(Template)]
+#[template(path = "UnsignedByte")]
+struct UnsignedByteTemplate<'a> {
+    version: &'a str,
+    package: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "UnsignedShort")]
+struct UnsignedShortTemplate<'a> {
+    version: &'a str,
+    package: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "UnsignedInteger")]
+struct UnsignedIntegerTemplate<'a> {
+    version: &'a str,
+    package: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "OffsetDateTimeAdapter")]
+struct OffsetDateTimeAdapterTemplate<'a> {
+    version: &'a str,
+    package: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "OptionalPropertyAdapter")]
+struct OptionalPropertyAdapterTemplate<'a> {
+    version: &'a str,
+    package: &'a str,
+}
+
+// Writes out the fields, constructor, getters and setters of a class. Gson
+// ignores properties it doesn't know, so there's nothing to do for additional
+// properties.
+fn write_fields(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    name: &str,
+    fields: &[target::Field],
+) -> Result<()> {
+    if !fields.is_empty() {
+        state
+            .imports
+            .insert("com.google.gson.annotations.SerializedName".into());
+    }
+
+    if fields
+        .iter()
+        .any(|field| field.optional || field.type_ == "OffsetDateTime")
+    {
+        state
+            .imports
+            .insert("com.google.gson.annotations.JsonAdapter".into());
+    }
+
+    for field in fields {
+        // OptionalPropertyAdapter reads and writes timestamps with
+        // OffsetDateTimeAdapter itself.
+        if field.optional {
+            writeln!(
+                out,
+                "    @JsonAdapter(value = OptionalPropertyAdapter.class, nullSafe = false)"
+            )?;
+        } else if field.type_ == "OffsetDateTime" {
+            writeln!(out, "    @JsonAdapter(OffsetDateTimeAdapter.class)")?;
+        }
+
+        writeln!(out, "    @SerializedName({:?})", field.json_name)?;
+        writeln!(out, "    private {} {};", field.type_, field.name)?;
+        writeln!(out)?;
+    }
+    writeln!(out, "    public {}() {{", name)?;
+    writeln!(out, "    }}")?;
+
+    for field in fields {
+        writeln!(out)?;
+
+        write!(
+            out,
+            "{}",
+            description_with_message(
+                &field.metadata,
+                1,
+                &format!("Getter for {}.<p>", field.name)
+            )
+        )?;
+        writeln!(
+            out,
+            "    public {} get{}() {{",
+            field.type_,
+            capitalize_first_letter(&field.name)
+        )?;
+        writeln!(out, "        return {};", field.name)?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        write!(
+            out,
+            "{}",
+            description_with_message(
+                &field.metadata,
+                1,
+                &format!("Setter for {}.<p>", field.name)
+            )
+        )?;
+        writeln!(
+            out,
+            "    public void set{}({} {}) {{",
+            capitalize_first_letter(&field.name),
+            field.type_,
+            field.name
+        )?;
+        writeln!(out, "        this.{0} = {0};", field.name)?;
+        writeln!(out, "    }}")?;
+    }
+
+    Ok(())
+}
+
+// Writes out the start of a static nested TypeAdapterFactory, which the
+// @JsonAdapter of the given class refers to. Factories are given the Gson
+// instance, so their adapters can use Gson's adapters for other types.
+fn write_adapter_factory_start(out: &mut dyn Write, name: &str) -> Result<()> {
+    writeln!(
+        out,
+        "    public static class Adapter implements TypeAdapterFactory {{"
+    )?;
+    writeln!(out, "        @Override")?;
+    writeln!(out, "        @SuppressWarnings(\"unchecked\")")?;
+    writeln!(
+        out,
+        "        public <T> TypeAdapter<T> create(Gson gson, TypeToken<T> type) {{"
+    )?;
+    writeln!(
+        out,
+        "            if (type.getRawType() != {}.class) {{",
+        name
+    )?;
+    writeln!(out, "                return null;")?;
+    writeln!(out, "            }}")?;
+    writeln!(out)?;
+
+    Ok(())
+}
+
+fn write_adapter_factory_end(out: &mut dyn Write) -> Result<()> {
+    writeln!(out, "            }};")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+
+    Ok(())
+}
+
+fn description(metadata: &BTreeMap<String, Value>, indent: usize) -> String {
+    doc(indent, jtd_codegen::target::metadata::description(metadata))
+}
+
+fn description_with_message(
+    metadata: &BTreeMap<String, Value>,
+    indent: usize,
+    message: &str,
+) -> String {
+    doc(
+        indent,
+        &format!(
+            "{}\n{}",
+            message,
+            jtd_codegen::target::metadata::description(metadata)
+        ),
+    )
+}
+
+fn capitalize_first_letter(s: &str) -> String {
+    let (head, tail) = s.split_at(1);
+    head.to_uppercase() + tail
+}
+
+fn enum_variant_description(
+    metadata: &BTreeMap<String, Value>,
+    indent: usize,
+    value: &str,
+) -> String {
+    doc(
+        indent,
+        jtd_codegen::target::metadata::enum_variant_description(metadata, value),
+    )
+}
+
+fn doc(ident: usize, s: &str) -> String {
+    let prefix = "    ".repeat(ident);
+    jtd_codegen::target::fmt::comment_block(
+        &format!("{}/**", prefix),
+        &format!("{} * ", prefix),
+        &format!("{} */", prefix),
+        s,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    mod std_tests {
+        jtd_codegen_test::std_test_cases!(&crate::Target::new("com.example".into()));
+
+        jtd_codegen_test::strict_std_test_case!(
+            &crate::Target::new("com.example".into()),
+            adapter_collisions
+        );
+    }
+}
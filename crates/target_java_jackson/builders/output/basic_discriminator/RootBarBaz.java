@@ -0,0 +1,90 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class RootBarBaz extends Root {
+    @JsonProperty("baz")
+    private String baz;
+
+    public RootBarBaz() {
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    public String getBaz() {
+        return baz;
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(String baz) {
+        this.baz = baz;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootBarBaz that = (RootBarBaz) o;
+        return java.util.Objects.equals(this.baz, that.baz);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.baz);
+    }
+
+    @Override
+    public String toString() {
+        return "RootBarBaz{"
+            + "baz=" + this.baz
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootBarBaz.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootBarBaz.
+     */
+    public static class Builder {
+        private String baz;
+
+        private Builder() {
+        }
+
+        public Builder baz(String baz) {
+            this.baz = baz;
+            return this;
+        }
+
+        /**
+         * Builds a RootBarBaz.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootBarBaz build() {
+            if (this.baz == null) {
+                throw new IllegalStateException("RootBarBaz is missing required property baz");
+            }
+
+            RootBarBaz value = new RootBarBaz();
+            value.setBaz(this.baz);
+            return value;
+        }
+    }
+}
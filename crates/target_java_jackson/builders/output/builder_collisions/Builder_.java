@@ -0,0 +1,90 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class Builder_ {
+    @JsonProperty("n")
+    private String n;
+
+    public Builder_() {
+    }
+
+    /**
+     * Getter for n.<p>
+     */
+    public String getN() {
+        return n;
+    }
+
+    /**
+     * Setter for n.<p>
+     */
+    public void setN(String n) {
+        this.n = n;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Builder_ that = (Builder_) o;
+        return java.util.Objects.equals(this.n, that.n);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.n);
+    }
+
+    @Override
+    public String toString() {
+        return "Builder_{"
+            + "n=" + this.n
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Builder_.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Builder_.
+     */
+    public static class Builder {
+        private String n;
+
+        private Builder() {
+        }
+
+        public Builder n(String n) {
+            this.n = n;
+            return this;
+        }
+
+        /**
+         * Builds a Builder_.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Builder_ build() {
+            if (this.n == null) {
+                throw new IllegalStateException("Builder_ is missing required property n");
+            }
+
+            Builder_ value = new Builder_();
+            value.setN(this.n);
+            return value;
+        }
+    }
+}
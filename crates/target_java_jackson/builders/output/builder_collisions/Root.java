@@ -0,0 +1,90 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("x")
+    private Builder_ x;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for x.<p>
+     */
+    public Builder_ getX() {
+        return x;
+    }
+
+    /**
+     * Setter for x.<p>
+     */
+    public void setX(Builder_ x) {
+        this.x = x;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.x, that.x);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.x);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "x=" + this.x
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private Builder_ x;
+
+        private Builder() {
+        }
+
+        public Builder x(Builder_ x) {
+            this.x = x;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.x == null) {
+                throw new IllegalStateException("Root is missing required property x");
+            }
+
+            Root value = new Root();
+            value.setX(this.x);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,240 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("override_elements_container")
+    private java.util.ArrayList<String> overrideElementsContainer;
+
+    @JsonProperty("override_type_discriminator")
+    private Object overrideTypeDiscriminator;
+
+    @JsonProperty("override_type_enum")
+    private RootOverrideTypeEnum overrideTypeEnum;
+
+    @JsonProperty("override_type_expr")
+    private Object overrideTypeExpr;
+
+    @JsonProperty("override_type_properties")
+    private Object overrideTypeProperties;
+
+    @JsonProperty("override_values_container")
+    private java.util.HashMap<String, String> overrideValuesContainer;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for overrideElementsContainer.<p>
+     */
+    public java.util.ArrayList<String> getOverrideElementsContainer() {
+        return overrideElementsContainer;
+    }
+
+    /**
+     * Setter for overrideElementsContainer.<p>
+     */
+    public void setOverrideElementsContainer(java.util.ArrayList<String> overrideElementsContainer) {
+        this.overrideElementsContainer = overrideElementsContainer;
+    }
+
+    /**
+     * Getter for overrideTypeDiscriminator.<p>
+     */
+    public Object getOverrideTypeDiscriminator() {
+        return overrideTypeDiscriminator;
+    }
+
+    /**
+     * Setter for overrideTypeDiscriminator.<p>
+     */
+    public void setOverrideTypeDiscriminator(Object overrideTypeDiscriminator) {
+        this.overrideTypeDiscriminator = overrideTypeDiscriminator;
+    }
+
+    /**
+     * Getter for overrideTypeEnum.<p>
+     */
+    public RootOverrideTypeEnum getOverrideTypeEnum() {
+        return overrideTypeEnum;
+    }
+
+    /**
+     * Setter for overrideTypeEnum.<p>
+     */
+    public void setOverrideTypeEnum(RootOverrideTypeEnum overrideTypeEnum) {
+        this.overrideTypeEnum = overrideTypeEnum;
+    }
+
+    /**
+     * Getter for overrideTypeExpr.<p>
+     */
+    public Object getOverrideTypeExpr() {
+        return overrideTypeExpr;
+    }
+
+    /**
+     * Setter for overrideTypeExpr.<p>
+     */
+    public void setOverrideTypeExpr(Object overrideTypeExpr) {
+        this.overrideTypeExpr = overrideTypeExpr;
+    }
+
+    /**
+     * Getter for overrideTypeProperties.<p>
+     */
+    public Object getOverrideTypeProperties() {
+        return overrideTypeProperties;
+    }
+
+    /**
+     * Setter for overrideTypeProperties.<p>
+     */
+    public void setOverrideTypeProperties(Object overrideTypeProperties) {
+        this.overrideTypeProperties = overrideTypeProperties;
+    }
+
+    /**
+     * Getter for overrideValuesContainer.<p>
+     */
+    public java.util.HashMap<String, String> getOverrideValuesContainer() {
+        return overrideValuesContainer;
+    }
+
+    /**
+     * Setter for overrideValuesContainer.<p>
+     */
+    public void setOverrideValuesContainer(java.util.HashMap<String, String> overrideValuesContainer) {
+        this.overrideValuesContainer = overrideValuesContainer;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.overrideElementsContainer, that.overrideElementsContainer)
+            && java.util.Objects.equals(this.overrideTypeDiscriminator, that.overrideTypeDiscriminator)
+            && java.util.Objects.equals(this.overrideTypeEnum, that.overrideTypeEnum)
+            && java.util.Objects.equals(this.overrideTypeExpr, that.overrideTypeExpr)
+            && java.util.Objects.equals(this.overrideTypeProperties, that.overrideTypeProperties)
+            && java.util.Objects.equals(this.overrideValuesContainer, that.overrideValuesContainer);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.overrideElementsContainer, this.overrideTypeDiscriminator, this.overrideTypeEnum, this.overrideTypeExpr, this.overrideTypeProperties, this.overrideValuesContainer);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "overrideElementsContainer=" + this.overrideElementsContainer
+            + ", overrideTypeDiscriminator=" + this.overrideTypeDiscriminator
+            + ", overrideTypeEnum=" + this.overrideTypeEnum
+            + ", overrideTypeExpr=" + this.overrideTypeExpr
+            + ", overrideTypeProperties=" + this.overrideTypeProperties
+            + ", overrideValuesContainer=" + this.overrideValuesContainer
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private java.util.ArrayList<String> overrideElementsContainer;
+        private Object overrideTypeDiscriminator;
+        private RootOverrideTypeEnum overrideTypeEnum;
+        private Object overrideTypeExpr;
+        private Object overrideTypeProperties;
+        private java.util.HashMap<String, String> overrideValuesContainer;
+
+        private Builder() {
+        }
+
+        public Builder overrideElementsContainer(java.util.ArrayList<String> overrideElementsContainer) {
+            this.overrideElementsContainer = overrideElementsContainer;
+            return this;
+        }
+
+        public Builder overrideTypeDiscriminator(Object overrideTypeDiscriminator) {
+            this.overrideTypeDiscriminator = overrideTypeDiscriminator;
+            return this;
+        }
+
+        public Builder overrideTypeEnum(RootOverrideTypeEnum overrideTypeEnum) {
+            this.overrideTypeEnum = overrideTypeEnum;
+            return this;
+        }
+
+        public Builder overrideTypeExpr(Object overrideTypeExpr) {
+            this.overrideTypeExpr = overrideTypeExpr;
+            return this;
+        }
+
+        public Builder overrideTypeProperties(Object overrideTypeProperties) {
+            this.overrideTypeProperties = overrideTypeProperties;
+            return this;
+        }
+
+        public Builder overrideValuesContainer(java.util.HashMap<String, String> overrideValuesContainer) {
+            this.overrideValuesContainer = overrideValuesContainer;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.overrideElementsContainer == null) {
+                throw new IllegalStateException("Root is missing required property overrideElementsContainer");
+            }
+
+            if (this.overrideTypeDiscriminator == null) {
+                throw new IllegalStateException("Root is missing required property overrideTypeDiscriminator");
+            }
+
+            if (this.overrideTypeEnum == null) {
+                throw new IllegalStateException("Root is missing required property overrideTypeEnum");
+            }
+
+            if (this.overrideTypeExpr == null) {
+                throw new IllegalStateException("Root is missing required property overrideTypeExpr");
+            }
+
+            if (this.overrideTypeProperties == null) {
+                throw new IllegalStateException("Root is missing required property overrideTypeProperties");
+            }
+
+            if (this.overrideValuesContainer == null) {
+                throw new IllegalStateException("Root is missing required property overrideValuesContainer");
+            }
+
+            Root value = new Root();
+            value.setOverrideElementsContainer(this.overrideElementsContainer);
+            value.setOverrideTypeDiscriminator(this.overrideTypeDiscriminator);
+            value.setOverrideTypeEnum(this.overrideTypeEnum);
+            value.setOverrideTypeExpr(this.overrideTypeExpr);
+            value.setOverrideTypeProperties(this.overrideTypeProperties);
+            value.setOverrideValuesContainer(this.overrideValuesContainer);
+            return value;
+        }
+    }
+}
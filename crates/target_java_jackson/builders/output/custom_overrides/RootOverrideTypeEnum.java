@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public enum RootOverrideTypeEnum {
+    @JsonProperty("BAR")
+    BAR,
+
+    @JsonProperty("FOO")
+    FOO,
+}
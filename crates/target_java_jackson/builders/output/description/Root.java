@@ -0,0 +1,260 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("discriminator_with_description")
+    private RootDiscriminatorWithDescription discriminatorWithDescription;
+
+    @JsonProperty("enum_with_description")
+    private RootEnumWithDescription enumWithDescription;
+
+    @JsonProperty("long_description")
+    private String longDescription;
+
+    @JsonProperty("properties_with_description")
+    private RootPropertiesWithDescription propertiesWithDescription;
+
+    @JsonProperty("ref_with_description")
+    private Baz refWithDescription;
+
+    @JsonProperty("string_with_description")
+    private String stringWithDescription;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for discriminatorWithDescription.<p>
+     * A description for discriminator
+     */
+    public RootDiscriminatorWithDescription getDiscriminatorWithDescription() {
+        return discriminatorWithDescription;
+    }
+
+    /**
+     * Setter for discriminatorWithDescription.<p>
+     * A description for discriminator
+     */
+    public void setDiscriminatorWithDescription(RootDiscriminatorWithDescription discriminatorWithDescription) {
+        this.discriminatorWithDescription = discriminatorWithDescription;
+    }
+
+    /**
+     * Getter for enumWithDescription.<p>
+     * A description for enum
+     */
+    public RootEnumWithDescription getEnumWithDescription() {
+        return enumWithDescription;
+    }
+
+    /**
+     * Setter for enumWithDescription.<p>
+     * A description for enum
+     */
+    public void setEnumWithDescription(RootEnumWithDescription enumWithDescription) {
+        this.enumWithDescription = enumWithDescription;
+    }
+
+    /**
+     * Getter for longDescription.<p>
+     * Whereas disregard and contempt for human rights have resulted in
+     * barbarous acts which have outraged the conscience of mankind, and the
+     * advent of a world in which human beings shall enjoy freedom of speech and
+     * belief and freedom from fear and want has been proclaimed as the highest
+     * aspiration of the common people,
+     */
+    public String getLongDescription() {
+        return longDescription;
+    }
+
+    /**
+     * Setter for longDescription.<p>
+     * Whereas disregard and contempt for human rights have resulted in
+     * barbarous acts which have outraged the conscience of mankind, and the
+     * advent of a world in which human beings shall enjoy freedom of speech and
+     * belief and freedom from fear and want has been proclaimed as the highest
+     * aspiration of the common people,
+     */
+    public void setLongDescription(String longDescription) {
+        this.longDescription = longDescription;
+    }
+
+    /**
+     * Getter for propertiesWithDescription.<p>
+     * A description for properties
+     */
+    public RootPropertiesWithDescription getPropertiesWithDescription() {
+        return propertiesWithDescription;
+    }
+
+    /**
+     * Setter for propertiesWithDescription.<p>
+     * A description for properties
+     */
+    public void setPropertiesWithDescription(RootPropertiesWithDescription propertiesWithDescription) {
+        this.propertiesWithDescription = propertiesWithDescription;
+    }
+
+    /**
+     * Getter for refWithDescription.<p>
+     * A description for ref
+     */
+    public Baz getRefWithDescription() {
+        return refWithDescription;
+    }
+
+    /**
+     * Setter for refWithDescription.<p>
+     * A description for ref
+     */
+    public void setRefWithDescription(Baz refWithDescription) {
+        this.refWithDescription = refWithDescription;
+    }
+
+    /**
+     * Getter for stringWithDescription.<p>
+     * A description for string
+     */
+    public String getStringWithDescription() {
+        return stringWithDescription;
+    }
+
+    /**
+     * Setter for stringWithDescription.<p>
+     * A description for string
+     */
+    public void setStringWithDescription(String stringWithDescription) {
+        this.stringWithDescription = stringWithDescription;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.discriminatorWithDescription, that.discriminatorWithDescription)
+            && java.util.Objects.equals(this.enumWithDescription, that.enumWithDescription)
+            && java.util.Objects.equals(this.longDescription, that.longDescription)
+            && java.util.Objects.equals(this.propertiesWithDescription, that.propertiesWithDescription)
+            && java.util.Objects.equals(this.refWithDescription, that.refWithDescription)
+            && java.util.Objects.equals(this.stringWithDescription, that.stringWithDescription);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.discriminatorWithDescription, this.enumWithDescription, this.longDescription, this.propertiesWithDescription, this.refWithDescription, this.stringWithDescription);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "discriminatorWithDescription=" + this.discriminatorWithDescription
+            + ", enumWithDescription=" + this.enumWithDescription
+            + ", longDescription=" + this.longDescription
+            + ", propertiesWithDescription=" + this.propertiesWithDescription
+            + ", refWithDescription=" + this.refWithDescription
+            + ", stringWithDescription=" + this.stringWithDescription
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private RootDiscriminatorWithDescription discriminatorWithDescription;
+        private RootEnumWithDescription enumWithDescription;
+        private String longDescription;
+        private RootPropertiesWithDescription propertiesWithDescription;
+        private Baz refWithDescription;
+        private String stringWithDescription;
+
+        private Builder() {
+        }
+
+        public Builder discriminatorWithDescription(RootDiscriminatorWithDescription discriminatorWithDescription) {
+            this.discriminatorWithDescription = discriminatorWithDescription;
+            return this;
+        }
+
+        public Builder enumWithDescription(RootEnumWithDescription enumWithDescription) {
+            this.enumWithDescription = enumWithDescription;
+            return this;
+        }
+
+        public Builder longDescription(String longDescription) {
+            this.longDescription = longDescription;
+            return this;
+        }
+
+        public Builder propertiesWithDescription(RootPropertiesWithDescription propertiesWithDescription) {
+            this.propertiesWithDescription = propertiesWithDescription;
+            return this;
+        }
+
+        public Builder refWithDescription(Baz refWithDescription) {
+            this.refWithDescription = refWithDescription;
+            return this;
+        }
+
+        public Builder stringWithDescription(String stringWithDescription) {
+            this.stringWithDescription = stringWithDescription;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.discriminatorWithDescription == null) {
+                throw new IllegalStateException("Root is missing required property discriminatorWithDescription");
+            }
+
+            if (this.enumWithDescription == null) {
+                throw new IllegalStateException("Root is missing required property enumWithDescription");
+            }
+
+            if (this.longDescription == null) {
+                throw new IllegalStateException("Root is missing required property longDescription");
+            }
+
+            if (this.propertiesWithDescription == null) {
+                throw new IllegalStateException("Root is missing required property propertiesWithDescription");
+            }
+
+            if (this.refWithDescription == null) {
+                throw new IllegalStateException("Root is missing required property refWithDescription");
+            }
+
+            if (this.stringWithDescription == null) {
+                throw new IllegalStateException("Root is missing required property stringWithDescription");
+            }
+
+            Root value = new Root();
+            value.setDiscriminatorWithDescription(this.discriminatorWithDescription);
+            value.setEnumWithDescription(this.enumWithDescription);
+            value.setLongDescription(this.longDescription);
+            value.setPropertiesWithDescription(this.propertiesWithDescription);
+            value.setRefWithDescription(this.refWithDescription);
+            value.setStringWithDescription(this.stringWithDescription);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+import java.util.List;
+
+public class Root {
+    @JsonValue
+    private List<String> value;
+
+    public Root() {
+    }
+
+    @JsonCreator
+    public Root(List<String> value) {
+        this.value = value;
+    }
+
+    public List<String> getValue() {
+        return value;
+    }
+
+    public void setValue(List<String> value) {
+        this.value = value;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.value, that.value);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.value);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "value=" + this.value
+            + "}";
+    }
+}
@@ -0,0 +1,120 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("foo")
+    private RootFoo foo;
+
+    @JsonProperty("foo_bar")
+    private RootFooBar0 fooBar;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public RootFoo getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(RootFoo foo) {
+        this.foo = foo;
+    }
+
+    /**
+     * Getter for fooBar.<p>
+     */
+    public RootFooBar0 getFooBar() {
+        return fooBar;
+    }
+
+    /**
+     * Setter for fooBar.<p>
+     */
+    public void setFooBar(RootFooBar0 fooBar) {
+        this.fooBar = fooBar;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.foo, that.foo)
+            && java.util.Objects.equals(this.fooBar, that.fooBar);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.foo, this.fooBar);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "foo=" + this.foo
+            + ", fooBar=" + this.fooBar
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private RootFoo foo;
+        private RootFooBar0 fooBar;
+
+        private Builder() {
+        }
+
+        public Builder foo(RootFoo foo) {
+            this.foo = foo;
+            return this;
+        }
+
+        public Builder fooBar(RootFooBar0 fooBar) {
+            this.fooBar = fooBar;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.foo == null) {
+                throw new IllegalStateException("Root is missing required property foo");
+            }
+
+            if (this.fooBar == null) {
+                throw new IllegalStateException("Root is missing required property fooBar");
+            }
+
+            Root value = new Root();
+            value.setFoo(this.foo);
+            value.setFooBar(this.fooBar);
+            return value;
+        }
+    }
+}
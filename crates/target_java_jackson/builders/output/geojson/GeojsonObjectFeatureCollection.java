@@ -0,0 +1,98 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+
+/**
+ * A GeoJSON object with the type "FeatureCollection" is a
+ * FeatureCollection object.  A FeatureCollection object has a member
+ * with the name "features".  The value of "features" is a JSON array.
+ * Each element of the array is a Feature object as defined above.  It
+ * is possible for this array to be empty.
+ */
+@JsonSerialize
+public class GeojsonObjectFeatureCollection extends GeojsonObject {
+    @JsonProperty("features")
+    private List<GeojsonObject> features;
+
+    public GeojsonObjectFeatureCollection() {
+    }
+
+    /**
+     * Getter for features.<p>
+     */
+    public List<GeojsonObject> getFeatures() {
+        return features;
+    }
+
+    /**
+     * Setter for features.<p>
+     */
+    public void setFeatures(List<GeojsonObject> features) {
+        this.features = features;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        GeojsonObjectFeatureCollection that = (GeojsonObjectFeatureCollection) o;
+        return java.util.Objects.equals(this.features, that.features);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.features);
+    }
+
+    @Override
+    public String toString() {
+        return "GeojsonObjectFeatureCollection{"
+            + "features=" + this.features
+            + "}";
+    }
+
+    /**
+     * Returns a builder for GeojsonObjectFeatureCollection.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for GeojsonObjectFeatureCollection.
+     */
+    public static class Builder {
+        private List<GeojsonObject> features;
+
+        private Builder() {
+        }
+
+        public Builder features(List<GeojsonObject> features) {
+            this.features = features;
+            return this;
+        }
+
+        /**
+         * Builds a GeojsonObjectFeatureCollection.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public GeojsonObjectFeatureCollection build() {
+            if (this.features == null) {
+                throw new IllegalStateException("GeojsonObjectFeatureCollection is missing required property features");
+            }
+
+            GeojsonObjectFeatureCollection value = new GeojsonObjectFeatureCollection();
+            value.setFeatures(this.features);
+            return value;
+        }
+    }
+}
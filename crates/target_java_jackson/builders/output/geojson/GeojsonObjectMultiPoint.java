@@ -0,0 +1,125 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+
+/**
+ * For type "MultiPoint", the "coordinates" member is an array of
+ * positions.
+ */
+@JsonSerialize
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class GeojsonObjectMultiPoint extends GeojsonObject {
+    @JsonProperty("coordinates")
+    private List<Position> coordinates;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("bbox")
+    private BoundingBox bbox;
+
+    public GeojsonObjectMultiPoint() {
+    }
+
+    /**
+     * Getter for coordinates.<p>
+     */
+    public List<Position> getCoordinates() {
+        return coordinates;
+    }
+
+    /**
+     * Setter for coordinates.<p>
+     */
+    public void setCoordinates(List<Position> coordinates) {
+        this.coordinates = coordinates;
+    }
+
+    /**
+     * Getter for bbox.<p>
+     */
+    public BoundingBox getBbox() {
+        return bbox;
+    }
+
+    /**
+     * Setter for bbox.<p>
+     */
+    public void setBbox(BoundingBox bbox) {
+        this.bbox = bbox;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        GeojsonObjectMultiPoint that = (GeojsonObjectMultiPoint) o;
+        return java.util.Objects.equals(this.coordinates, that.coordinates)
+            && java.util.Objects.equals(this.bbox, that.bbox);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.coordinates, this.bbox);
+    }
+
+    @Override
+    public String toString() {
+        return "GeojsonObjectMultiPoint{"
+            + "coordinates=" + this.coordinates
+            + ", bbox=" + this.bbox
+            + "}";
+    }
+
+    /**
+     * Returns a builder for GeojsonObjectMultiPoint.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for GeojsonObjectMultiPoint.
+     */
+    public static class Builder {
+        private List<Position> coordinates;
+        private BoundingBox bbox;
+
+        private Builder() {
+        }
+
+        public Builder coordinates(List<Position> coordinates) {
+            this.coordinates = coordinates;
+            return this;
+        }
+
+        public Builder bbox(BoundingBox bbox) {
+            this.bbox = bbox;
+            return this;
+        }
+
+        /**
+         * Builds a GeojsonObjectMultiPoint.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public GeojsonObjectMultiPoint build() {
+            if (this.coordinates == null) {
+                throw new IllegalStateException("GeojsonObjectMultiPoint is missing required property coordinates");
+            }
+
+            GeojsonObjectMultiPoint value = new GeojsonObjectMultiPoint();
+            value.setCoordinates(this.coordinates);
+            value.setBbox(this.bbox);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+/**
+ * For type "Point", the "coordinates" member is a single position.
+ */
+@JsonSerialize
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class GeojsonObjectPoint extends GeojsonObject {
+    @JsonProperty("coordinates")
+    private Position coordinates;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("bbox")
+    private BoundingBox bbox;
+
+    public GeojsonObjectPoint() {
+    }
+
+    /**
+     * Getter for coordinates.<p>
+     */
+    public Position getCoordinates() {
+        return coordinates;
+    }
+
+    /**
+     * Setter for coordinates.<p>
+     */
+    public void setCoordinates(Position coordinates) {
+        this.coordinates = coordinates;
+    }
+
+    /**
+     * Getter for bbox.<p>
+     */
+    public BoundingBox getBbox() {
+        return bbox;
+    }
+
+    /**
+     * Setter for bbox.<p>
+     */
+    public void setBbox(BoundingBox bbox) {
+        this.bbox = bbox;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        GeojsonObjectPoint that = (GeojsonObjectPoint) o;
+        return java.util.Objects.equals(this.coordinates, that.coordinates)
+            && java.util.Objects.equals(this.bbox, that.bbox);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.coordinates, this.bbox);
+    }
+
+    @Override
+    public String toString() {
+        return "GeojsonObjectPoint{"
+            + "coordinates=" + this.coordinates
+            + ", bbox=" + this.bbox
+            + "}";
+    }
+
+    /**
+     * Returns a builder for GeojsonObjectPoint.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for GeojsonObjectPoint.
+     */
+    public static class Builder {
+        private Position coordinates;
+        private BoundingBox bbox;
+
+        private Builder() {
+        }
+
+        public Builder coordinates(Position coordinates) {
+            this.coordinates = coordinates;
+            return this;
+        }
+
+        public Builder bbox(BoundingBox bbox) {
+            this.bbox = bbox;
+            return this;
+        }
+
+        /**
+         * Builds a GeojsonObjectPoint.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public GeojsonObjectPoint build() {
+            if (this.coordinates == null) {
+                throw new IllegalStateException("GeojsonObjectPoint is missing required property coordinates");
+            }
+
+            GeojsonObjectPoint value = new GeojsonObjectPoint();
+            value.setCoordinates(this.coordinates);
+            value.setBbox(this.bbox);
+            return value;
+        }
+    }
+}
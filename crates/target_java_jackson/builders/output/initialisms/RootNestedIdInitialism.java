@@ -0,0 +1,120 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class RootNestedIdInitialism {
+    @JsonProperty("json")
+    private String json;
+
+    @JsonProperty("normalword")
+    private String normalword;
+
+    public RootNestedIdInitialism() {
+    }
+
+    /**
+     * Getter for json.<p>
+     */
+    public String getJson() {
+        return json;
+    }
+
+    /**
+     * Setter for json.<p>
+     */
+    public void setJson(String json) {
+        this.json = json;
+    }
+
+    /**
+     * Getter for normalword.<p>
+     */
+    public String getNormalword() {
+        return normalword;
+    }
+
+    /**
+     * Setter for normalword.<p>
+     */
+    public void setNormalword(String normalword) {
+        this.normalword = normalword;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootNestedIdInitialism that = (RootNestedIdInitialism) o;
+        return java.util.Objects.equals(this.json, that.json)
+            && java.util.Objects.equals(this.normalword, that.normalword);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.json, this.normalword);
+    }
+
+    @Override
+    public String toString() {
+        return "RootNestedIdInitialism{"
+            + "json=" + this.json
+            + ", normalword=" + this.normalword
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootNestedIdInitialism.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootNestedIdInitialism.
+     */
+    public static class Builder {
+        private String json;
+        private String normalword;
+
+        private Builder() {
+        }
+
+        public Builder json(String json) {
+            this.json = json;
+            return this;
+        }
+
+        public Builder normalword(String normalword) {
+            this.normalword = normalword;
+            return this;
+        }
+
+        /**
+         * Builds a RootNestedIdInitialism.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootNestedIdInitialism build() {
+            if (this.json == null) {
+                throw new IllegalStateException("RootNestedIdInitialism is missing required property json");
+            }
+
+            if (this.normalword == null) {
+                throw new IllegalStateException("RootNestedIdInitialism is missing required property normalword");
+            }
+
+            RootNestedIdInitialism value = new RootNestedIdInitialism();
+            value.setJson(this.json);
+            value.setNormalword(this.normalword);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class RootQuux extends Root {
+    @JsonProperty("quuz")
+    private String quuz;
+
+    public RootQuux() {
+    }
+
+    /**
+     * Getter for quuz.<p>
+     */
+    public String getQuuz() {
+        return quuz;
+    }
+
+    /**
+     * Setter for quuz.<p>
+     */
+    public void setQuuz(String quuz) {
+        this.quuz = quuz;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootQuux that = (RootQuux) o;
+        return java.util.Objects.equals(this.quuz, that.quuz);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.quuz);
+    }
+
+    @Override
+    public String toString() {
+        return "RootQuux{"
+            + "quuz=" + this.quuz
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootQuux.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootQuux.
+     */
+    public static class Builder {
+        private String quuz;
+
+        private Builder() {
+        }
+
+        public Builder quuz(String quuz) {
+            this.quuz = quuz;
+            return this;
+        }
+
+        /**
+         * Builds a RootQuux.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootQuux build() {
+            if (this.quuz == null) {
+                throw new IllegalStateException("RootQuux is missing required property quuz");
+            }
+
+            RootQuux value = new RootQuux();
+            value.setQuuz(this.quuz);
+            return value;
+        }
+    }
+}
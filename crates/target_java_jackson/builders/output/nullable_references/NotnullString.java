@@ -0,0 +1,51 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public class NotnullString {
+    @JsonValue
+    private String value;
+
+    public NotnullString() {
+    }
+
+    @JsonCreator
+    public NotnullString(String value) {
+        this.value = value;
+    }
+
+    public String getValue() {
+        return value;
+    }
+
+    public void setValue(String value) {
+        this.value = value;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        NotnullString that = (NotnullString) o;
+        return java.util.Objects.equals(this.value, that.value);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.value);
+    }
+
+    @Override
+    public String toString() {
+        return "NotnullString{"
+            + "value=" + this.value
+            + "}";
+    }
+}
@@ -0,0 +1,87 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.time.OffsetDateTime;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("foo")
+    private OffsetDateTime foo;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public OffsetDateTime getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(OffsetDateTime foo) {
+        this.foo = foo;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.foo, that.foo);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.foo);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "foo=" + this.foo
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private OffsetDateTime foo;
+
+        private Builder() {
+        }
+
+        public Builder foo(OffsetDateTime foo) {
+            this.foo = foo;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            Root value = new Root();
+            value.setFoo(this.foo);
+            return value;
+        }
+    }
+}
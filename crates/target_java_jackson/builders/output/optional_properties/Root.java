@@ -0,0 +1,143 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+
+@JsonSerialize
+public class Root {
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("bar")
+    private List<String> bar;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("baz")
+    private Boolean baz;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("foo")
+    private String foo;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for bar.<p>
+     */
+    public List<String> getBar() {
+        return bar;
+    }
+
+    /**
+     * Setter for bar.<p>
+     */
+    public void setBar(List<String> bar) {
+        this.bar = bar;
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    public Boolean getBaz() {
+        return baz;
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(Boolean baz) {
+        this.baz = baz;
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public String getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(String foo) {
+        this.foo = foo;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.bar, that.bar)
+            && java.util.Objects.equals(this.baz, that.baz)
+            && java.util.Objects.equals(this.foo, that.foo);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.bar, this.baz, this.foo);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "bar=" + this.bar
+            + ", baz=" + this.baz
+            + ", foo=" + this.foo
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private List<String> bar;
+        private Boolean baz;
+        private String foo;
+
+        private Builder() {
+        }
+
+        public Builder bar(List<String> bar) {
+            this.bar = bar;
+            return this;
+        }
+
+        public Builder baz(Boolean baz) {
+            this.baz = baz;
+            return this;
+        }
+
+        public Builder foo(String foo) {
+            this.foo = foo;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            Root value = new Root();
+            value.setBar(this.bar);
+            value.setBaz(this.baz);
+            value.setFoo(this.foo);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("Foo")
+    private String foo;
+
+    @JsonProperty("foo")
+    private String foo0;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public String getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(String foo) {
+        this.foo = foo;
+    }
+
+    /**
+     * Getter for foo0.<p>
+     */
+    public String getFoo0() {
+        return foo0;
+    }
+
+    /**
+     * Setter for foo0.<p>
+     */
+    public void setFoo0(String foo0) {
+        this.foo0 = foo0;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.foo, that.foo)
+            && java.util.Objects.equals(this.foo0, that.foo0);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.foo, this.foo0);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "foo=" + this.foo
+            + ", foo0=" + this.foo0
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private String foo;
+        private String foo0;
+
+        private Builder() {
+        }
+
+        public Builder foo(String foo) {
+            this.foo = foo;
+            return this;
+        }
+
+        public Builder foo0(String foo0) {
+            this.foo0 = foo0;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.foo == null) {
+                throw new IllegalStateException("Root is missing required property foo");
+            }
+
+            if (this.foo0 == null) {
+                throw new IllegalStateException("Root is missing required property foo0");
+            }
+
+            Root value = new Root();
+            value.setFoo(this.foo);
+            value.setFoo0(this.foo0);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class RootFooBar {
+    @JsonProperty("x")
+    private Boolean x;
+
+    public RootFooBar() {
+    }
+
+    /**
+     * Getter for x.<p>
+     */
+    public Boolean getX() {
+        return x;
+    }
+
+    /**
+     * Setter for x.<p>
+     */
+    public void setX(Boolean x) {
+        this.x = x;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootFooBar that = (RootFooBar) o;
+        return java.util.Objects.equals(this.x, that.x);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.x);
+    }
+
+    @Override
+    public String toString() {
+        return "RootFooBar{"
+            + "x=" + this.x
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootFooBar.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootFooBar.
+     */
+    public static class Builder {
+        private Boolean x;
+
+        private Builder() {
+        }
+
+        public Builder x(Boolean x) {
+            this.x = x;
+            return this;
+        }
+
+        /**
+         * Builds a RootFooBar.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootFooBar build() {
+            if (this.x == null) {
+                throw new IllegalStateException("RootFooBar is missing required property x");
+            }
+
+            RootFooBar value = new RootFooBar();
+            value.setX(this.x);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class RootOverrideTypeDiscriminatorBaz extends Object {
+    public RootOverrideTypeDiscriminatorBaz() {
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        return true;
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash();
+    }
+
+    @Override
+    public String toString() {
+        return "RootOverrideTypeDiscriminatorBaz{"
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootOverrideTypeDiscriminatorBaz.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootOverrideTypeDiscriminatorBaz.
+     */
+    public static class Builder {
+        private Builder() {
+        }
+
+        /**
+         * Builds a RootOverrideTypeDiscriminatorBaz.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootOverrideTypeDiscriminatorBaz build() {
+            RootOverrideTypeDiscriminatorBaz value = new RootOverrideTypeDiscriminatorBaz();
+            return value;
+        }
+    }
+}
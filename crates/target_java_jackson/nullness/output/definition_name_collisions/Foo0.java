@@ -0,0 +1,51 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public class Foo0 {
+    @JsonValue
+    private Foo value;
+
+    public Foo0() {
+    }
+
+    @JsonCreator
+    public Foo0(Foo value) {
+        this.value = value;
+    }
+
+    public Foo getValue() {
+        return value;
+    }
+
+    public void setValue(Foo value) {
+        this.value = value;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Foo0 that = (Foo0) o;
+        return java.util.Objects.equals(this.value, that.value);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.value);
+    }
+
+    @Override
+    public String toString() {
+        return "Foo0{"
+            + "value=" + this.value
+            + "}";
+    }
+}
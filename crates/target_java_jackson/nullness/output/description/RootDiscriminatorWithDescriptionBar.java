@@ -0,0 +1,62 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+/**
+ * A description for discriminator variant
+ */
+@JsonSerialize
+public class RootDiscriminatorWithDescriptionBar extends RootDiscriminatorWithDescription {
+    public RootDiscriminatorWithDescriptionBar() {
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        return true;
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash();
+    }
+
+    @Override
+    public String toString() {
+        return "RootDiscriminatorWithDescriptionBar{"
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootDiscriminatorWithDescriptionBar.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootDiscriminatorWithDescriptionBar.
+     */
+    public static class Builder {
+        private Builder() {
+        }
+
+        /**
+         * Builds a RootDiscriminatorWithDescriptionBar.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootDiscriminatorWithDescriptionBar build() {
+            RootDiscriminatorWithDescriptionBar value = new RootDiscriminatorWithDescriptionBar();
+            return value;
+        }
+    }
+}
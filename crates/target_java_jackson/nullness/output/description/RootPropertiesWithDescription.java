@@ -0,0 +1,62 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+/**
+ * A description for properties
+ */
+@JsonSerialize
+public class RootPropertiesWithDescription {
+    public RootPropertiesWithDescription() {
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        return true;
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash();
+    }
+
+    @Override
+    public String toString() {
+        return "RootPropertiesWithDescription{"
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootPropertiesWithDescription.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootPropertiesWithDescription.
+     */
+    public static class Builder {
+        private Builder() {
+        }
+
+        /**
+         * Builds a RootPropertiesWithDescription.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootPropertiesWithDescription build() {
+            RootPropertiesWithDescription value = new RootPropertiesWithDescription();
+            return value;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnore;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+import org.jspecify.annotations.NonNull;
+import org.jspecify.annotations.Nullable;
+
+@JsonSerialize
+public class RootBar extends Root {
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("baz")
+    private @Nullable List<String> baz;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("quux")
+    private @Nullable Boolean quux;
+
+    public RootBar() {
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    @JsonIgnore
+    public java.util.@NonNull Optional<List<String>> getBaz() {
+        return java.util.Optional.ofNullable(baz);
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(@Nullable List<String> baz) {
+        this.baz = baz;
+    }
+
+    /**
+     * Getter for quux.<p>
+     */
+    @JsonIgnore
+    public java.util.@NonNull Optional<Boolean> getQuux() {
+        return java.util.Optional.ofNullable(quux);
+    }
+
+    /**
+     * Setter for quux.<p>
+     */
+    public void setQuux(@Nullable Boolean quux) {
+        this.quux = quux;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootBar that = (RootBar) o;
+        return java.util.Objects.equals(this.baz, that.baz)
+            && java.util.Objects.equals(this.quux, that.quux);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.baz, this.quux);
+    }
+
+    @Override
+    public String toString() {
+        return "RootBar{"
+            + "baz=" + this.baz
+            + ", quux=" + this.quux
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootBar.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootBar.
+     */
+    public static class Builder {
+        private List<String> baz;
+        private Boolean quux;
+
+        private Builder() {
+        }
+
+        public Builder baz(@Nullable List<String> baz) {
+            this.baz = baz;
+            return this;
+        }
+
+        public Builder quux(@Nullable Boolean quux) {
+            this.quux = quux;
+            return this;
+        }
+
+        /**
+         * Builds a RootBar.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootBar build() {
+            RootBar value = new RootBar();
+            value.setBaz(this.baz);
+            value.setQuux(this.quux);
+            return value;
+        }
+    }
+}
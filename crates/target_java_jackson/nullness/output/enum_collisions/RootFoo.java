@@ -0,0 +1,91 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import org.jspecify.annotations.NonNull;
+
+@JsonSerialize
+public class RootFoo {
+    @JsonProperty("bar")
+    private @NonNull RootFooBar bar;
+
+    public RootFoo() {
+    }
+
+    /**
+     * Getter for bar.<p>
+     */
+    public @NonNull RootFooBar getBar() {
+        return bar;
+    }
+
+    /**
+     * Setter for bar.<p>
+     */
+    public void setBar(@NonNull RootFooBar bar) {
+        this.bar = bar;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootFoo that = (RootFoo) o;
+        return java.util.Objects.equals(this.bar, that.bar);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.bar);
+    }
+
+    @Override
+    public String toString() {
+        return "RootFoo{"
+            + "bar=" + this.bar
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootFoo.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootFoo.
+     */
+    public static class Builder {
+        private RootFooBar bar;
+
+        private Builder() {
+        }
+
+        public Builder bar(@NonNull RootFooBar bar) {
+            this.bar = bar;
+            return this;
+        }
+
+        /**
+         * Builds a RootFoo.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootFoo build() {
+            if (this.bar == null) {
+                throw new IllegalStateException("RootFoo is missing required property bar");
+            }
+
+            RootFoo value = new RootFoo();
+            value.setBar(this.bar);
+            return value;
+        }
+    }
+}
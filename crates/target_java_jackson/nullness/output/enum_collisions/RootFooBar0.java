@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public enum RootFooBar0 {
+    @JsonProperty("x")
+    X,
+
+    @JsonProperty("y")
+    Y,
+}
@@ -0,0 +1,129 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnore;
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+import org.jspecify.annotations.NonNull;
+import org.jspecify.annotations.Nullable;
+
+/**
+ * For type "MultiPolygon", the "coordinates" member is an array of
+ * Polygon coordinate arrays.
+ */
+@JsonSerialize
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class GeojsonObjectMultiPolygon extends GeojsonObject {
+    @JsonProperty("coordinates")
+    private @NonNull List<LinearRing> coordinates;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("bbox")
+    private @Nullable BoundingBox bbox;
+
+    public GeojsonObjectMultiPolygon() {
+    }
+
+    /**
+     * Getter for coordinates.<p>
+     */
+    public @NonNull List<LinearRing> getCoordinates() {
+        return coordinates;
+    }
+
+    /**
+     * Setter for coordinates.<p>
+     */
+    public void setCoordinates(@NonNull List<LinearRing> coordinates) {
+        this.coordinates = coordinates;
+    }
+
+    /**
+     * Getter for bbox.<p>
+     */
+    @JsonIgnore
+    public java.util.@NonNull Optional<BoundingBox> getBbox() {
+        return java.util.Optional.ofNullable(bbox);
+    }
+
+    /**
+     * Setter for bbox.<p>
+     */
+    public void setBbox(@Nullable BoundingBox bbox) {
+        this.bbox = bbox;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        GeojsonObjectMultiPolygon that = (GeojsonObjectMultiPolygon) o;
+        return java.util.Objects.equals(this.coordinates, that.coordinates)
+            && java.util.Objects.equals(this.bbox, that.bbox);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.coordinates, this.bbox);
+    }
+
+    @Override
+    public String toString() {
+        return "GeojsonObjectMultiPolygon{"
+            + "coordinates=" + this.coordinates
+            + ", bbox=" + this.bbox
+            + "}";
+    }
+
+    /**
+     * Returns a builder for GeojsonObjectMultiPolygon.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for GeojsonObjectMultiPolygon.
+     */
+    public static class Builder {
+        private List<LinearRing> coordinates;
+        private BoundingBox bbox;
+
+        private Builder() {
+        }
+
+        public Builder coordinates(@NonNull List<LinearRing> coordinates) {
+            this.coordinates = coordinates;
+            return this;
+        }
+
+        public Builder bbox(@Nullable BoundingBox bbox) {
+            this.bbox = bbox;
+            return this;
+        }
+
+        /**
+         * Builds a GeojsonObjectMultiPolygon.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public GeojsonObjectMultiPolygon build() {
+            if (this.coordinates == null) {
+                throw new IllegalStateException("GeojsonObjectMultiPolygon is missing required property coordinates");
+            }
+
+            GeojsonObjectMultiPolygon value = new GeojsonObjectMultiPolygon();
+            value.setCoordinates(this.coordinates);
+            value.setBbox(this.bbox);
+            return value;
+        }
+    }
+}
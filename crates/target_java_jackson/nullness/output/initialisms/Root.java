@@ -0,0 +1,241 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import org.jspecify.annotations.NonNull;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("http")
+    private @NonNull String http;
+
+    @JsonProperty("id")
+    private @NonNull String id;
+
+    @JsonProperty("nested_id_initialism")
+    private @NonNull RootNestedIdInitialism nestedIdInitialism;
+
+    @JsonProperty("utf8")
+    private @NonNull String utf8;
+
+    @JsonProperty("word_with_embedded_id_initialism")
+    private @NonNull String wordWithEmbeddedIdInitialism;
+
+    @JsonProperty("word_with_trailing_initialism_id")
+    private @NonNull String wordWithTrailingInitialismId;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for http.<p>
+     */
+    public @NonNull String getHttp() {
+        return http;
+    }
+
+    /**
+     * Setter for http.<p>
+     */
+    public void setHttp(@NonNull String http) {
+        this.http = http;
+    }
+
+    /**
+     * Getter for id.<p>
+     */
+    public @NonNull String getId() {
+        return id;
+    }
+
+    /**
+     * Setter for id.<p>
+     */
+    public void setId(@NonNull String id) {
+        this.id = id;
+    }
+
+    /**
+     * Getter for nestedIdInitialism.<p>
+     */
+    public @NonNull RootNestedIdInitialism getNestedIdInitialism() {
+        return nestedIdInitialism;
+    }
+
+    /**
+     * Setter for nestedIdInitialism.<p>
+     */
+    public void setNestedIdInitialism(@NonNull RootNestedIdInitialism nestedIdInitialism) {
+        this.nestedIdInitialism = nestedIdInitialism;
+    }
+
+    /**
+     * Getter for utf8.<p>
+     */
+    public @NonNull String getUtf8() {
+        return utf8;
+    }
+
+    /**
+     * Setter for utf8.<p>
+     */
+    public void setUtf8(@NonNull String utf8) {
+        this.utf8 = utf8;
+    }
+
+    /**
+     * Getter for wordWithEmbeddedIdInitialism.<p>
+     */
+    public @NonNull String getWordWithEmbeddedIdInitialism() {
+        return wordWithEmbeddedIdInitialism;
+    }
+
+    /**
+     * Setter for wordWithEmbeddedIdInitialism.<p>
+     */
+    public void setWordWithEmbeddedIdInitialism(@NonNull String wordWithEmbeddedIdInitialism) {
+        this.wordWithEmbeddedIdInitialism = wordWithEmbeddedIdInitialism;
+    }
+
+    /**
+     * Getter for wordWithTrailingInitialismId.<p>
+     */
+    public @NonNull String getWordWithTrailingInitialismId() {
+        return wordWithTrailingInitialismId;
+    }
+
+    /**
+     * Setter for wordWithTrailingInitialismId.<p>
+     */
+    public void setWordWithTrailingInitialismId(@NonNull String wordWithTrailingInitialismId) {
+        this.wordWithTrailingInitialismId = wordWithTrailingInitialismId;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.http, that.http)
+            && java.util.Objects.equals(this.id, that.id)
+            && java.util.Objects.equals(this.nestedIdInitialism, that.nestedIdInitialism)
+            && java.util.Objects.equals(this.utf8, that.utf8)
+            && java.util.Objects.equals(this.wordWithEmbeddedIdInitialism, that.wordWithEmbeddedIdInitialism)
+            && java.util.Objects.equals(this.wordWithTrailingInitialismId, that.wordWithTrailingInitialismId);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.http, this.id, this.nestedIdInitialism, this.utf8, this.wordWithEmbeddedIdInitialism, this.wordWithTrailingInitialismId);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "http=" + this.http
+            + ", id=" + this.id
+            + ", nestedIdInitialism=" + this.nestedIdInitialism
+            + ", utf8=" + this.utf8
+            + ", wordWithEmbeddedIdInitialism=" + this.wordWithEmbeddedIdInitialism
+            + ", wordWithTrailingInitialismId=" + this.wordWithTrailingInitialismId
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private String http;
+        private String id;
+        private RootNestedIdInitialism nestedIdInitialism;
+        private String utf8;
+        private String wordWithEmbeddedIdInitialism;
+        private String wordWithTrailingInitialismId;
+
+        private Builder() {
+        }
+
+        public Builder http(@NonNull String http) {
+            this.http = http;
+            return this;
+        }
+
+        public Builder id(@NonNull String id) {
+            this.id = id;
+            return this;
+        }
+
+        public Builder nestedIdInitialism(@NonNull RootNestedIdInitialism nestedIdInitialism) {
+            this.nestedIdInitialism = nestedIdInitialism;
+            return this;
+        }
+
+        public Builder utf8(@NonNull String utf8) {
+            this.utf8 = utf8;
+            return this;
+        }
+
+        public Builder wordWithEmbeddedIdInitialism(@NonNull String wordWithEmbeddedIdInitialism) {
+            this.wordWithEmbeddedIdInitialism = wordWithEmbeddedIdInitialism;
+            return this;
+        }
+
+        public Builder wordWithTrailingInitialismId(@NonNull String wordWithTrailingInitialismId) {
+            this.wordWithTrailingInitialismId = wordWithTrailingInitialismId;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.http == null) {
+                throw new IllegalStateException("Root is missing required property http");
+            }
+
+            if (this.id == null) {
+                throw new IllegalStateException("Root is missing required property id");
+            }
+
+            if (this.nestedIdInitialism == null) {
+                throw new IllegalStateException("Root is missing required property nestedIdInitialism");
+            }
+
+            if (this.utf8 == null) {
+                throw new IllegalStateException("Root is missing required property utf8");
+            }
+
+            if (this.wordWithEmbeddedIdInitialism == null) {
+                throw new IllegalStateException("Root is missing required property wordWithEmbeddedIdInitialism");
+            }
+
+            if (this.wordWithTrailingInitialismId == null) {
+                throw new IllegalStateException("Root is missing required property wordWithTrailingInitialismId");
+            }
+
+            Root value = new Root();
+            value.setHttp(this.http);
+            value.setId(this.id);
+            value.setNestedIdInitialism(this.nestedIdInitialism);
+            value.setUtf8(this.utf8);
+            value.setWordWithEmbeddedIdInitialism(this.wordWithEmbeddedIdInitialism);
+            value.setWordWithTrailingInitialismId(this.wordWithTrailingInitialismId);
+            return value;
+        }
+    }
+}
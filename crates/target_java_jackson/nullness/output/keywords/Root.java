@@ -0,0 +1,121 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import org.jspecify.annotations.NonNull;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("for")
+    private @NonNull For for_;
+
+    @JsonProperty("object")
+    private @NonNull Object object;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for for_.<p>
+     */
+    public @NonNull For getFor_() {
+        return for_;
+    }
+
+    /**
+     * Setter for for_.<p>
+     */
+    public void setFor_(@NonNull For for_) {
+        this.for_ = for_;
+    }
+
+    /**
+     * Getter for object.<p>
+     */
+    public @NonNull Object getObject() {
+        return object;
+    }
+
+    /**
+     * Setter for object.<p>
+     */
+    public void setObject(@NonNull Object object) {
+        this.object = object;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.for_, that.for_)
+            && java.util.Objects.equals(this.object, that.object);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.for_, this.object);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "for_=" + this.for_
+            + ", object=" + this.object
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private For for_;
+        private Object object;
+
+        private Builder() {
+        }
+
+        public Builder for_(@NonNull For for_) {
+            this.for_ = for_;
+            return this;
+        }
+
+        public Builder object(@NonNull Object object) {
+            this.object = object;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.for_ == null) {
+                throw new IllegalStateException("Root is missing required property for_");
+            }
+
+            if (this.object == null) {
+                throw new IllegalStateException("Root is missing required property object");
+            }
+
+            Root value = new Root();
+            value.setFor_(this.for_);
+            value.setObject(this.object);
+            return value;
+        }
+    }
+}
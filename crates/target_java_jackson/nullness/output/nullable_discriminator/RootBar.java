@@ -0,0 +1,91 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import org.jspecify.annotations.NonNull;
+
+@JsonSerialize
+public class RootBar extends Root {
+    @JsonProperty("baz")
+    private @NonNull String baz;
+
+    public RootBar() {
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    public @NonNull String getBaz() {
+        return baz;
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(@NonNull String baz) {
+        this.baz = baz;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootBar that = (RootBar) o;
+        return java.util.Objects.equals(this.baz, that.baz);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.baz);
+    }
+
+    @Override
+    public String toString() {
+        return "RootBar{"
+            + "baz=" + this.baz
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootBar.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootBar.
+     */
+    public static class Builder {
+        private String baz;
+
+        private Builder() {
+        }
+
+        public Builder baz(@NonNull String baz) {
+            this.baz = baz;
+            return this;
+        }
+
+        /**
+         * Builds a RootBar.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootBar build() {
+            if (this.baz == null) {
+                throw new IllegalStateException("RootBar is missing required property baz");
+            }
+
+            RootBar value = new RootBar();
+            value.setBaz(this.baz);
+            return value;
+        }
+    }
+}
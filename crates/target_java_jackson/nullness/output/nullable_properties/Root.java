@@ -0,0 +1,182 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+import org.jspecify.annotations.NonNull;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("bar")
+    private @NonNull String bar;
+
+    @JsonProperty("baz")
+    private @NonNull List<Boolean> baz;
+
+    @JsonProperty("foo")
+    private @NonNull Boolean foo;
+
+    @JsonProperty("quux")
+    private @NonNull List<Boolean> quux;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for bar.<p>
+     */
+    public @NonNull String getBar() {
+        return bar;
+    }
+
+    /**
+     * Setter for bar.<p>
+     */
+    public void setBar(@NonNull String bar) {
+        this.bar = bar;
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    public @NonNull List<Boolean> getBaz() {
+        return baz;
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(@NonNull List<Boolean> baz) {
+        this.baz = baz;
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    public @NonNull Boolean getFoo() {
+        return foo;
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(@NonNull Boolean foo) {
+        this.foo = foo;
+    }
+
+    /**
+     * Getter for quux.<p>
+     */
+    public @NonNull List<Boolean> getQuux() {
+        return quux;
+    }
+
+    /**
+     * Setter for quux.<p>
+     */
+    public void setQuux(@NonNull List<Boolean> quux) {
+        this.quux = quux;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.bar, that.bar)
+            && java.util.Objects.equals(this.baz, that.baz)
+            && java.util.Objects.equals(this.foo, that.foo)
+            && java.util.Objects.equals(this.quux, that.quux);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.bar, this.baz, this.foo, this.quux);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "bar=" + this.bar
+            + ", baz=" + this.baz
+            + ", foo=" + this.foo
+            + ", quux=" + this.quux
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private String bar;
+        private List<Boolean> baz;
+        private Boolean foo;
+        private List<Boolean> quux;
+
+        private Builder() {
+        }
+
+        public Builder bar(@NonNull String bar) {
+            this.bar = bar;
+            return this;
+        }
+
+        public Builder baz(@NonNull List<Boolean> baz) {
+            this.baz = baz;
+            return this;
+        }
+
+        public Builder foo(@NonNull Boolean foo) {
+            this.foo = foo;
+            return this;
+        }
+
+        public Builder quux(@NonNull List<Boolean> quux) {
+            this.quux = quux;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.bar == null) {
+                throw new IllegalStateException("Root is missing required property bar");
+            }
+
+            if (this.baz == null) {
+                throw new IllegalStateException("Root is missing required property baz");
+            }
+
+            if (this.foo == null) {
+                throw new IllegalStateException("Root is missing required property foo");
+            }
+
+            if (this.quux == null) {
+                throw new IllegalStateException("Root is missing required property quux");
+            }
+
+            Root value = new Root();
+            value.setBar(this.bar);
+            value.setBaz(this.baz);
+            value.setFoo(this.foo);
+            value.setQuux(this.quux);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public class NotnullRefNullString {
+    @JsonValue
+    private NullString value;
+
+    public NotnullRefNullString() {
+    }
+
+    @JsonCreator
+    public NotnullRefNullString(NullString value) {
+        this.value = value;
+    }
+
+    public NullString getValue() {
+        return value;
+    }
+
+    public void setValue(NullString value) {
+        this.value = value;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        NotnullRefNullString that = (NotnullRefNullString) o;
+        return java.util.Objects.equals(this.value, that.value);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.value);
+    }
+
+    @Override
+    public String toString() {
+        return "NotnullRefNullString{"
+            + "value=" + this.value
+            + "}";
+    }
+}
@@ -0,0 +1,241 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import org.jspecify.annotations.NonNull;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("notnull_ref_notnull_string")
+    private @NonNull NotnullRefNotnullString notnullRefNotnullString;
+
+    @JsonProperty("notnull_ref_null_string")
+    private @NonNull NotnullRefNullString notnullRefNullString;
+
+    @JsonProperty("notnull_string")
+    private @NonNull NotnullString notnullString;
+
+    @JsonProperty("null_ref_notnull_string")
+    private @NonNull NullRefNotnullString nullRefNotnullString;
+
+    @JsonProperty("null_ref_null_string")
+    private @NonNull NullRefNullString nullRefNullString;
+
+    @JsonProperty("null_string")
+    private @NonNull NullString nullString;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for notnullRefNotnullString.<p>
+     */
+    public @NonNull NotnullRefNotnullString getNotnullRefNotnullString() {
+        return notnullRefNotnullString;
+    }
+
+    /**
+     * Setter for notnullRefNotnullString.<p>
+     */
+    public void setNotnullRefNotnullString(@NonNull NotnullRefNotnullString notnullRefNotnullString) {
+        this.notnullRefNotnullString = notnullRefNotnullString;
+    }
+
+    /**
+     * Getter for notnullRefNullString.<p>
+     */
+    public @NonNull NotnullRefNullString getNotnullRefNullString() {
+        return notnullRefNullString;
+    }
+
+    /**
+     * Setter for notnullRefNullString.<p>
+     */
+    public void setNotnullRefNullString(@NonNull NotnullRefNullString notnullRefNullString) {
+        this.notnullRefNullString = notnullRefNullString;
+    }
+
+    /**
+     * Getter for notnullString.<p>
+     */
+    public @NonNull NotnullString getNotnullString() {
+        return notnullString;
+    }
+
+    /**
+     * Setter for notnullString.<p>
+     */
+    public void setNotnullString(@NonNull NotnullString notnullString) {
+        this.notnullString = notnullString;
+    }
+
+    /**
+     * Getter for nullRefNotnullString.<p>
+     */
+    public @NonNull NullRefNotnullString getNullRefNotnullString() {
+        return nullRefNotnullString;
+    }
+
+    /**
+     * Setter for nullRefNotnullString.<p>
+     */
+    public void setNullRefNotnullString(@NonNull NullRefNotnullString nullRefNotnullString) {
+        this.nullRefNotnullString = nullRefNotnullString;
+    }
+
+    /**
+     * Getter for nullRefNullString.<p>
+     */
+    public @NonNull NullRefNullString getNullRefNullString() {
+        return nullRefNullString;
+    }
+
+    /**
+     * Setter for nullRefNullString.<p>
+     */
+    public void setNullRefNullString(@NonNull NullRefNullString nullRefNullString) {
+        this.nullRefNullString = nullRefNullString;
+    }
+
+    /**
+     * Getter for nullString.<p>
+     */
+    public @NonNull NullString getNullString() {
+        return nullString;
+    }
+
+    /**
+     * Setter for nullString.<p>
+     */
+    public void setNullString(@NonNull NullString nullString) {
+        this.nullString = nullString;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.notnullRefNotnullString, that.notnullRefNotnullString)
+            && java.util.Objects.equals(this.notnullRefNullString, that.notnullRefNullString)
+            && java.util.Objects.equals(this.notnullString, that.notnullString)
+            && java.util.Objects.equals(this.nullRefNotnullString, that.nullRefNotnullString)
+            && java.util.Objects.equals(this.nullRefNullString, that.nullRefNullString)
+            && java.util.Objects.equals(this.nullString, that.nullString);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.notnullRefNotnullString, this.notnullRefNullString, this.notnullString, this.nullRefNotnullString, this.nullRefNullString, this.nullString);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "notnullRefNotnullString=" + this.notnullRefNotnullString
+            + ", notnullRefNullString=" + this.notnullRefNullString
+            + ", notnullString=" + this.notnullString
+            + ", nullRefNotnullString=" + this.nullRefNotnullString
+            + ", nullRefNullString=" + this.nullRefNullString
+            + ", nullString=" + this.nullString
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private NotnullRefNotnullString notnullRefNotnullString;
+        private NotnullRefNullString notnullRefNullString;
+        private NotnullString notnullString;
+        private NullRefNotnullString nullRefNotnullString;
+        private NullRefNullString nullRefNullString;
+        private NullString nullString;
+
+        private Builder() {
+        }
+
+        public Builder notnullRefNotnullString(@NonNull NotnullRefNotnullString notnullRefNotnullString) {
+            this.notnullRefNotnullString = notnullRefNotnullString;
+            return this;
+        }
+
+        public Builder notnullRefNullString(@NonNull NotnullRefNullString notnullRefNullString) {
+            this.notnullRefNullString = notnullRefNullString;
+            return this;
+        }
+
+        public Builder notnullString(@NonNull NotnullString notnullString) {
+            this.notnullString = notnullString;
+            return this;
+        }
+
+        public Builder nullRefNotnullString(@NonNull NullRefNotnullString nullRefNotnullString) {
+            this.nullRefNotnullString = nullRefNotnullString;
+            return this;
+        }
+
+        public Builder nullRefNullString(@NonNull NullRefNullString nullRefNullString) {
+            this.nullRefNullString = nullRefNullString;
+            return this;
+        }
+
+        public Builder nullString(@NonNull NullString nullString) {
+            this.nullString = nullString;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.notnullRefNotnullString == null) {
+                throw new IllegalStateException("Root is missing required property notnullRefNotnullString");
+            }
+
+            if (this.notnullRefNullString == null) {
+                throw new IllegalStateException("Root is missing required property notnullRefNullString");
+            }
+
+            if (this.notnullString == null) {
+                throw new IllegalStateException("Root is missing required property notnullString");
+            }
+
+            if (this.nullRefNotnullString == null) {
+                throw new IllegalStateException("Root is missing required property nullRefNotnullString");
+            }
+
+            if (this.nullRefNullString == null) {
+                throw new IllegalStateException("Root is missing required property nullRefNullString");
+            }
+
+            if (this.nullString == null) {
+                throw new IllegalStateException("Root is missing required property nullString");
+            }
+
+            Root value = new Root();
+            value.setNotnullRefNotnullString(this.notnullRefNotnullString);
+            value.setNotnullRefNullString(this.notnullRefNullString);
+            value.setNotnullString(this.notnullString);
+            value.setNullRefNotnullString(this.nullRefNotnullString);
+            value.setNullRefNullString(this.nullRefNullString);
+            value.setNullString(this.nullString);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import org.jspecify.annotations.NonNull;
+
+@JsonSerialize
+public class Optional {
+    @JsonProperty("n")
+    private @NonNull String n;
+
+    public Optional() {
+    }
+
+    /**
+     * Getter for n.<p>
+     */
+    public @NonNull String getN() {
+        return n;
+    }
+
+    /**
+     * Setter for n.<p>
+     */
+    public void setN(@NonNull String n) {
+        this.n = n;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Optional that = (Optional) o;
+        return java.util.Objects.equals(this.n, that.n);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.n);
+    }
+
+    @Override
+    public String toString() {
+        return "Optional{"
+            + "n=" + this.n
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Optional.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Optional.
+     */
+    public static class Builder {
+        private String n;
+
+        private Builder() {
+        }
+
+        public Builder n(@NonNull String n) {
+            this.n = n;
+            return this;
+        }
+
+        /**
+         * Builds a Optional.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Optional build() {
+            if (this.n == null) {
+                throw new IllegalStateException("Optional is missing required property n");
+            }
+
+            Optional value = new Optional();
+            value.setN(this.n);
+            return value;
+        }
+    }
+}
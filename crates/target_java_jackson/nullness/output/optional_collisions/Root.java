@@ -0,0 +1,122 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnore;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import org.jspecify.annotations.NonNull;
+import org.jspecify.annotations.Nullable;
+
+@JsonSerialize
+public class Root {
+    @JsonProperty("x")
+    private @NonNull Optional x;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("y")
+    private @Nullable Optional y;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for x.<p>
+     */
+    public @NonNull Optional getX() {
+        return x;
+    }
+
+    /**
+     * Setter for x.<p>
+     */
+    public void setX(@NonNull Optional x) {
+        this.x = x;
+    }
+
+    /**
+     * Getter for y.<p>
+     */
+    @JsonIgnore
+    public java.util.@NonNull Optional<Optional> getY() {
+        return java.util.Optional.ofNullable(y);
+    }
+
+    /**
+     * Setter for y.<p>
+     */
+    public void setY(@Nullable Optional y) {
+        this.y = y;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.x, that.x)
+            && java.util.Objects.equals(this.y, that.y);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.x, this.y);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "x=" + this.x
+            + ", y=" + this.y
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private Optional x;
+        private Optional y;
+
+        private Builder() {
+        }
+
+        public Builder x(@NonNull Optional x) {
+            this.x = x;
+            return this;
+        }
+
+        public Builder y(@Nullable Optional y) {
+            this.y = y;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            if (this.x == null) {
+                throw new IllegalStateException("Root is missing required property x");
+            }
+
+            Root value = new Root();
+            value.setX(this.x);
+            value.setY(this.y);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,149 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnore;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+import org.jspecify.annotations.NonNull;
+import org.jspecify.annotations.Nullable;
+
+@JsonSerialize
+public class Root {
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("bar")
+    private @Nullable List<String> bar;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("baz")
+    private @Nullable Boolean baz;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("foo")
+    private @Nullable String foo;
+
+    public Root() {
+    }
+
+    /**
+     * Getter for bar.<p>
+     */
+    @JsonIgnore
+    public java.util.@NonNull Optional<List<String>> getBar() {
+        return java.util.Optional.ofNullable(bar);
+    }
+
+    /**
+     * Setter for bar.<p>
+     */
+    public void setBar(@Nullable List<String> bar) {
+        this.bar = bar;
+    }
+
+    /**
+     * Getter for baz.<p>
+     */
+    @JsonIgnore
+    public java.util.@NonNull Optional<Boolean> getBaz() {
+        return java.util.Optional.ofNullable(baz);
+    }
+
+    /**
+     * Setter for baz.<p>
+     */
+    public void setBaz(@Nullable Boolean baz) {
+        this.baz = baz;
+    }
+
+    /**
+     * Getter for foo.<p>
+     */
+    @JsonIgnore
+    public java.util.@NonNull Optional<String> getFoo() {
+        return java.util.Optional.ofNullable(foo);
+    }
+
+    /**
+     * Setter for foo.<p>
+     */
+    public void setFoo(@Nullable String foo) {
+        this.foo = foo;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.bar, that.bar)
+            && java.util.Objects.equals(this.baz, that.baz)
+            && java.util.Objects.equals(this.foo, that.foo);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.bar, this.baz, this.foo);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "bar=" + this.bar
+            + ", baz=" + this.baz
+            + ", foo=" + this.foo
+            + "}";
+    }
+
+    /**
+     * Returns a builder for Root.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for Root.
+     */
+    public static class Builder {
+        private List<String> bar;
+        private Boolean baz;
+        private String foo;
+
+        private Builder() {
+        }
+
+        public Builder bar(@Nullable List<String> bar) {
+            this.bar = bar;
+            return this;
+        }
+
+        public Builder baz(@Nullable Boolean baz) {
+            this.baz = baz;
+            return this;
+        }
+
+        public Builder foo(@Nullable String foo) {
+            this.foo = foo;
+            return this;
+        }
+
+        /**
+         * Builds a Root.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public Root build() {
+            Root value = new Root();
+            value.setBar(this.bar);
+            value.setBaz(this.baz);
+            value.setFoo(this.foo);
+            return value;
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import org.jspecify.annotations.NonNull;
+
+@JsonSerialize
+public class RootFooBar0 {
+    @JsonProperty("x")
+    private @NonNull String x;
+
+    public RootFooBar0() {
+    }
+
+    /**
+     * Getter for x.<p>
+     */
+    public @NonNull String getX() {
+        return x;
+    }
+
+    /**
+     * Setter for x.<p>
+     */
+    public void setX(@NonNull String x) {
+        this.x = x;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootFooBar0 that = (RootFooBar0) o;
+        return java.util.Objects.equals(this.x, that.x);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.x);
+    }
+
+    @Override
+    public String toString() {
+        return "RootFooBar0{"
+            + "x=" + this.x
+            + "}";
+    }
+
+    /**
+     * Returns a builder for RootFooBar0.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builder for RootFooBar0.
+     */
+    public static class Builder {
+        private String x;
+
+        private Builder() {
+        }
+
+        public Builder x(@NonNull String x) {
+            this.x = x;
+            return this;
+        }
+
+        /**
+         * Builds a RootFooBar0.
+         *
+         * @throws IllegalStateException if a required property that isn't nullable is null
+         */
+        public RootFooBar0 build() {
+            if (this.x == null) {
+                throw new IllegalStateException("RootFooBar0 is missing required property x");
+            }
+
+            RootFooBar0 value = new RootFooBar0();
+            value.setX(this.x);
+            return value;
+        }
+    }
+}
@@ -26,4 +26,28 @@ public class RootBarBaz extends Root {
     public void setBaz(String baz) {
         this.baz = baz;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootBarBaz that = (RootBarBaz) o;
+        return java.util.Objects.equals(this.baz, that.baz);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.baz);
+    }
+
+    @Override
+    public String toString() {
+        return "RootBarBaz{"
+            + "baz=" + this.baz
+            + "}";
+    }
 }
@@ -26,4 +26,28 @@ public class RootQuux extends Root {
     public void setQuuz(String quuz) {
         this.quuz = quuz;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootQuux that = (RootQuux) o;
+        return java.util.Objects.equals(this.quuz, that.quuz);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.quuz);
+    }
+
+    @Override
+    public String toString() {
+        return "RootQuux{"
+            + "quuz=" + this.quuz
+            + "}";
+    }
 }
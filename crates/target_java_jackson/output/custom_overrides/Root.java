@@ -111,4 +111,38 @@ public class Root {
     public void setOverrideValuesContainer(java.util.HashMap<String, String> overrideValuesContainer) {
         this.overrideValuesContainer = overrideValuesContainer;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.overrideElementsContainer, that.overrideElementsContainer)
+            && java.util.Objects.equals(this.overrideTypeDiscriminator, that.overrideTypeDiscriminator)
+            && java.util.Objects.equals(this.overrideTypeEnum, that.overrideTypeEnum)
+            && java.util.Objects.equals(this.overrideTypeExpr, that.overrideTypeExpr)
+            && java.util.Objects.equals(this.overrideTypeProperties, that.overrideTypeProperties)
+            && java.util.Objects.equals(this.overrideValuesContainer, that.overrideValuesContainer);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.overrideElementsContainer, this.overrideTypeDiscriminator, this.overrideTypeEnum, this.overrideTypeExpr, this.overrideTypeProperties, this.overrideValuesContainer);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "overrideElementsContainer=" + this.overrideElementsContainer
+            + ", overrideTypeDiscriminator=" + this.overrideTypeDiscriminator
+            + ", overrideTypeEnum=" + this.overrideTypeEnum
+            + ", overrideTypeExpr=" + this.overrideTypeExpr
+            + ", overrideTypeProperties=" + this.overrideTypeProperties
+            + ", overrideValuesContainer=" + this.overrideValuesContainer
+            + "}";
+    }
 }
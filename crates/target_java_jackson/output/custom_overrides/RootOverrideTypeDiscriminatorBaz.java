@@ -9,4 +9,26 @@ import com.fasterxml.jackson.databind.annotation.JsonSerialize;
 public class RootOverrideTypeDiscriminatorBaz extends Object {
     public RootOverrideTypeDiscriminatorBaz() {
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        return true;
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash();
+    }
+
+    @Override
+    public String toString() {
+        return "RootOverrideTypeDiscriminatorBaz{"
+            + "}";
+    }
 }
@@ -131,4 +131,38 @@ public class Root {
     public void setStringWithDescription(String stringWithDescription) {
         this.stringWithDescription = stringWithDescription;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.discriminatorWithDescription, that.discriminatorWithDescription)
+            && java.util.Objects.equals(this.enumWithDescription, that.enumWithDescription)
+            && java.util.Objects.equals(this.longDescription, that.longDescription)
+            && java.util.Objects.equals(this.propertiesWithDescription, that.propertiesWithDescription)
+            && java.util.Objects.equals(this.refWithDescription, that.refWithDescription)
+            && java.util.Objects.equals(this.stringWithDescription, that.stringWithDescription);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.discriminatorWithDescription, this.enumWithDescription, this.longDescription, this.propertiesWithDescription, this.refWithDescription, this.stringWithDescription);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "discriminatorWithDescription=" + this.discriminatorWithDescription
+            + ", enumWithDescription=" + this.enumWithDescription
+            + ", longDescription=" + this.longDescription
+            + ", propertiesWithDescription=" + this.propertiesWithDescription
+            + ", refWithDescription=" + this.refWithDescription
+            + ", stringWithDescription=" + this.stringWithDescription
+            + "}";
+    }
 }
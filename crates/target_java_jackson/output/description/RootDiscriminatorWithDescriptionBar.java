@@ -12,4 +12,26 @@ import com.fasterxml.jackson.databind.annotation.JsonSerialize;
 public class RootDiscriminatorWithDescriptionBar extends RootDiscriminatorWithDescription {
     public RootDiscriminatorWithDescriptionBar() {
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        return true;
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash();
+    }
+
+    @Override
+    public String toString() {
+        return "RootDiscriminatorWithDescriptionBar{"
+            + "}";
+    }
 }
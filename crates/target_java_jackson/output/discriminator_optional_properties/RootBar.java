@@ -47,4 +47,30 @@ public class RootBar extends Root {
     public void setQuux(Boolean quux) {
         this.quux = quux;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootBar that = (RootBar) o;
+        return java.util.Objects.equals(this.baz, that.baz)
+            && java.util.Objects.equals(this.quux, that.quux);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.baz, this.quux);
+    }
+
+    @Override
+    public String toString() {
+        return "RootBar{"
+            + "baz=" + this.baz
+            + ", quux=" + this.quux
+            + "}";
+    }
 }
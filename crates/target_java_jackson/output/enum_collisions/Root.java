@@ -43,4 +43,30 @@ public class Root {
     public void setFooBar(RootFooBar0 fooBar) {
         this.fooBar = fooBar;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.foo, that.foo)
+            && java.util.Objects.equals(this.fooBar, that.fooBar);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.foo, this.fooBar);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "foo=" + this.foo
+            + ", fooBar=" + this.fooBar
+            + "}";
+    }
 }
@@ -98,4 +98,28 @@ public class BoundingBox {
     public void setValue(List<Double> value) {
         this.value = value;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        BoundingBox that = (BoundingBox) o;
+        return java.util.Objects.equals(this.value, that.value);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.value);
+    }
+
+    @Override
+    public String toString() {
+        return "BoundingBox{"
+            + "value=" + this.value
+            + "}";
+    }
 }
@@ -93,4 +93,32 @@ public class GeojsonObjectFeature extends GeojsonObject {
     public void setId(Object id) {
         this.id = id;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        GeojsonObjectFeature that = (GeojsonObjectFeature) o;
+        return java.util.Objects.equals(this.geometry, that.geometry)
+            && java.util.Objects.equals(this.properties, that.properties)
+            && java.util.Objects.equals(this.id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.geometry, this.properties, this.id);
+    }
+
+    @Override
+    public String toString() {
+        return "GeojsonObjectFeature{"
+            + "geometry=" + this.geometry
+            + ", properties=" + this.properties
+            + ", id=" + this.id
+            + "}";
+    }
 }
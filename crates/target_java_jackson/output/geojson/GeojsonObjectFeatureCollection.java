@@ -34,4 +34,28 @@ public class GeojsonObjectFeatureCollection extends GeojsonObject {
     public void setFeatures(List<GeojsonObject> features) {
         this.features = features;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        GeojsonObjectFeatureCollection that = (GeojsonObjectFeatureCollection) o;
+        return java.util.Objects.equals(this.features, that.features);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.features);
+    }
+
+    @Override
+    public String toString() {
+        return "GeojsonObjectFeatureCollection{"
+            + "features=" + this.features
+            + "}";
+    }
 }
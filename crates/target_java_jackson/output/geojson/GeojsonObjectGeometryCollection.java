@@ -77,4 +77,30 @@ public class GeojsonObjectGeometryCollection extends GeojsonObject {
     public void setBbox(BoundingBox bbox) {
         this.bbox = bbox;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        GeojsonObjectGeometryCollection that = (GeojsonObjectGeometryCollection) o;
+        return java.util.Objects.equals(this.geometries, that.geometries)
+            && java.util.Objects.equals(this.bbox, that.bbox);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.geometries, this.bbox);
+    }
+
+    @Override
+    public String toString() {
+        return "GeojsonObjectGeometryCollection{"
+            + "geometries=" + this.geometries
+            + ", bbox=" + this.bbox
+            + "}";
+    }
 }
@@ -111,4 +111,38 @@ public class Root {
     public void setWordWithTrailingInitialismId(String wordWithTrailingInitialismId) {
         this.wordWithTrailingInitialismId = wordWithTrailingInitialismId;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.http, that.http)
+            && java.util.Objects.equals(this.id, that.id)
+            && java.util.Objects.equals(this.nestedIdInitialism, that.nestedIdInitialism)
+            && java.util.Objects.equals(this.utf8, that.utf8)
+            && java.util.Objects.equals(this.wordWithEmbeddedIdInitialism, that.wordWithEmbeddedIdInitialism)
+            && java.util.Objects.equals(this.wordWithTrailingInitialismId, that.wordWithTrailingInitialismId);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.http, this.id, this.nestedIdInitialism, this.utf8, this.wordWithEmbeddedIdInitialism, this.wordWithTrailingInitialismId);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "http=" + this.http
+            + ", id=" + this.id
+            + ", nestedIdInitialism=" + this.nestedIdInitialism
+            + ", utf8=" + this.utf8
+            + ", wordWithEmbeddedIdInitialism=" + this.wordWithEmbeddedIdInitialism
+            + ", wordWithTrailingInitialismId=" + this.wordWithTrailingInitialismId
+            + "}";
+    }
 }
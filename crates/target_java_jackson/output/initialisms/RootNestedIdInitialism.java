@@ -43,4 +43,30 @@ public class RootNestedIdInitialism {
     public void setNormalword(String normalword) {
         this.normalword = normalword;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootNestedIdInitialism that = (RootNestedIdInitialism) o;
+        return java.util.Objects.equals(this.json, that.json)
+            && java.util.Objects.equals(this.normalword, that.normalword);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.json, this.normalword);
+    }
+
+    @Override
+    public String toString() {
+        return "RootNestedIdInitialism{"
+            + "json=" + this.json
+            + ", normalword=" + this.normalword
+            + "}";
+    }
 }
@@ -43,4 +43,30 @@ public class Root {
     public void setObject(Object object) {
         this.object = object;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.for_, that.for_)
+            && java.util.Objects.equals(this.object, that.object);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.for_, this.object);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "for_=" + this.for_
+            + ", object=" + this.object
+            + "}";
+    }
 }
@@ -78,4 +78,34 @@ public class Root {
     public void setQuux(List<Boolean> quux) {
         this.quux = quux;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.bar, that.bar)
+            && java.util.Objects.equals(this.baz, that.baz)
+            && java.util.Objects.equals(this.foo, that.foo)
+            && java.util.Objects.equals(this.quux, that.quux);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.bar, this.baz, this.foo, this.quux);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "bar=" + this.bar
+            + ", baz=" + this.baz
+            + ", foo=" + this.foo
+            + ", quux=" + this.quux
+            + "}";
+    }
 }
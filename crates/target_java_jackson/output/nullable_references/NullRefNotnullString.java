@@ -24,4 +24,28 @@ public class NullRefNotnullString {
     public void setValue(NotnullString value) {
         this.value = value;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        NullRefNotnullString that = (NullRefNotnullString) o;
+        return java.util.Objects.equals(this.value, that.value);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.value);
+    }
+
+    @Override
+    public String toString() {
+        return "NullRefNotnullString{"
+            + "value=" + this.value
+            + "}";
+    }
 }
@@ -24,4 +24,28 @@ public class NullRefNullString {
     public void setValue(NullString value) {
         this.value = value;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        NullRefNullString that = (NullRefNullString) o;
+        return java.util.Objects.equals(this.value, that.value);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.value);
+    }
+
+    @Override
+    public String toString() {
+        return "NullRefNullString{"
+            + "value=" + this.value
+            + "}";
+    }
 }
@@ -111,4 +111,38 @@ public class Root {
     public void setNullString(NullString nullString) {
         this.nullString = nullString;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.notnullRefNotnullString, that.notnullRefNotnullString)
+            && java.util.Objects.equals(this.notnullRefNullString, that.notnullRefNullString)
+            && java.util.Objects.equals(this.notnullString, that.notnullString)
+            && java.util.Objects.equals(this.nullRefNotnullString, that.nullRefNotnullString)
+            && java.util.Objects.equals(this.nullRefNullString, that.nullRefNullString)
+            && java.util.Objects.equals(this.nullString, that.nullString);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.notnullRefNotnullString, this.notnullRefNullString, this.notnullString, this.nullRefNotnullString, this.nullRefNullString, this.nullString);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "notnullRefNotnullString=" + this.notnullRefNotnullString
+            + ", notnullRefNullString=" + this.notnullRefNullString
+            + ", notnullString=" + this.notnullString
+            + ", nullRefNotnullString=" + this.nullRefNotnullString
+            + ", nullRefNullString=" + this.nullRefNullString
+            + ", nullString=" + this.nullString
+            + "}";
+    }
 }
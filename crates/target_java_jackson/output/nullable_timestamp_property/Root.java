@@ -27,4 +27,28 @@ public class Root {
     public void setFoo(OffsetDateTime foo) {
         this.foo = foo;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Root that = (Root) o;
+        return java.util.Objects.equals(this.foo, that.foo);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.foo);
+    }
+
+    @Override
+    public String toString() {
+        return "Root{"
+            + "foo=" + this.foo
+            + "}";
+    }
 }
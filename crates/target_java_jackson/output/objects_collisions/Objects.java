@@ -0,0 +1,53 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public class Objects {
+    @JsonProperty("n")
+    private String n;
+
+    public Objects() {
+    }
+
+    /**
+     * Getter for n.<p>
+     */
+    public String getN() {
+        return n;
+    }
+
+    /**
+     * Setter for n.<p>
+     */
+    public void setN(String n) {
+        this.n = n;
+    }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        Objects that = (Objects) o;
+        return java.util.Objects.equals(this.n, that.n);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.n);
+    }
+
+    @Override
+    public String toString() {
+        return "Objects{"
+            + "n=" + this.n
+            + "}";
+    }
+}
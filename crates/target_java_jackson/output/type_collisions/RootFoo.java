@@ -26,4 +26,28 @@ public class RootFoo {
     public void setBar(RootFooBar bar) {
         this.bar = bar;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootFoo that = (RootFoo) o;
+        return java.util.Objects.equals(this.bar, that.bar);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.bar);
+    }
+
+    @Override
+    public String toString() {
+        return "RootFoo{"
+            + "bar=" + this.bar
+            + "}";
+    }
 }
@@ -26,4 +26,28 @@ public class RootFooBar {
     public void setX(Boolean x) {
         this.x = x;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootFooBar that = (RootFooBar) o;
+        return java.util.Objects.equals(this.x, that.x);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.x);
+    }
+
+    @Override
+    public String toString() {
+        return "RootFooBar{"
+            + "x=" + this.x
+            + "}";
+    }
 }
@@ -26,4 +26,28 @@ public class RootFooBar0 {
     public void setX(String x) {
         this.x = x;
     }
+
+    @Override
+    public boolean equals(java.lang.Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (o == null || getClass() != o.getClass()) {
+            return false;
+        }
+        RootFooBar0 that = (RootFooBar0) o;
+        return java.util.Objects.equals(this.x, that.x);
+    }
+
+    @Override
+    public int hashCode() {
+        return java.util.Objects.hash(this.x);
+    }
+
+    @Override
+    public String toString() {
+        return "RootFooBar0{"
+            + "x=" + this.x
+            + "}";
+    }
 }
@@ -0,0 +1,28 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public record Root(
+    @JsonProperty("override_elements_container")
+    java.util.ArrayList<String> overrideElementsContainer,
+
+    @JsonProperty("override_type_discriminator")
+    Object overrideTypeDiscriminator,
+
+    @JsonProperty("override_type_enum")
+    RootOverrideTypeEnum overrideTypeEnum,
+
+    @JsonProperty("override_type_expr")
+    Object overrideTypeExpr,
+
+    @JsonProperty("override_type_properties")
+    Object overrideTypeProperties,
+
+    @JsonProperty("override_values_container")
+    java.util.HashMap<String, String> overrideValuesContainer
+) {
+}
@@ -0,0 +1,17 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public record Bar(Bar0 value) {
+    @JsonCreator
+    public Bar {
+    }
+
+    @JsonValue
+    public Bar0 value() {
+        return value;
+    }
+}
@@ -0,0 +1,20 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+/**
+ * A description for a definition
+ */
+public record Baz(String value) {
+    @JsonCreator
+    public Baz {
+    }
+
+    @JsonValue
+    public String value() {
+        return value;
+    }
+}
@@ -0,0 +1,40 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+/**
+ * @param discriminatorWithDescription A description for discriminator
+ * @param enumWithDescription A description for enum
+ * @param longDescription Whereas disregard and contempt for human rights have
+ * resulted in barbarous acts which have outraged the conscience of mankind,
+ * and the advent of a world in which human beings shall enjoy freedom of speech
+ * and belief and freedom from fear and want has been proclaimed as the highest
+ * aspiration of the common people,
+ * @param propertiesWithDescription A description for properties
+ * @param refWithDescription A description for ref
+ * @param stringWithDescription A description for string
+ */
+@JsonSerialize
+public record Root(
+    @JsonProperty("discriminator_with_description")
+    RootDiscriminatorWithDescription discriminatorWithDescription,
+
+    @JsonProperty("enum_with_description")
+    RootEnumWithDescription enumWithDescription,
+
+    @JsonProperty("long_description")
+    String longDescription,
+
+    @JsonProperty("properties_with_description")
+    RootPropertiesWithDescription propertiesWithDescription,
+
+    @JsonProperty("ref_with_description")
+    Baz refWithDescription,
+
+    @JsonProperty("string_with_description")
+    String stringWithDescription
+) {
+}
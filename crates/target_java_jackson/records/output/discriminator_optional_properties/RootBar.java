@@ -0,0 +1,20 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+
+@JsonSerialize
+public record RootBar(
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("baz")
+    List<String> baz,
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("quux")
+    Boolean quux
+) implements Root {
+}
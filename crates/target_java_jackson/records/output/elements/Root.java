@@ -0,0 +1,18 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+import java.util.List;
+
+public record Root(List<String> value) {
+    @JsonCreator
+    public Root {
+    }
+
+    @JsonValue
+    public List<String> value() {
+        return value;
+    }
+}
@@ -0,0 +1,17 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public record DefaultName(String value) {
+    @JsonCreator
+    public DefaultName {
+    }
+
+    @JsonValue
+    public String value() {
+        return value;
+    }
+}
@@ -0,0 +1,17 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public record Foo0(String value) {
+    @JsonCreator
+    public Foo0 {
+    }
+
+    @JsonValue
+    public String value() {
+        return value;
+    }
+}
@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+
+/**
+ * For type "LineString", the "coordinates" member is an array of two
+ * or more positions.
+ */
+@JsonSerialize
+@JsonIgnoreProperties(ignoreUnknown = true)
+public record GeojsonObjectLineString(
+    @JsonProperty("coordinates")
+    List<Position> coordinates,
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("bbox")
+    BoundingBox bbox
+) implements GeojsonObject {
+}
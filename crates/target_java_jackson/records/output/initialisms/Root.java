@@ -0,0 +1,28 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public record Root(
+    @JsonProperty("http")
+    String http,
+
+    @JsonProperty("id")
+    String id,
+
+    @JsonProperty("nested_id_initialism")
+    RootNestedIdInitialism nestedIdInitialism,
+
+    @JsonProperty("utf8")
+    String utf8,
+
+    @JsonProperty("word_with_embedded_id_initialism")
+    String wordWithEmbeddedIdInitialism,
+
+    @JsonProperty("word_with_trailing_initialism_id")
+    String wordWithTrailingInitialismId
+) {
+}
@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+
+@JsonSerialize
+public record Root(
+    @JsonProperty("bar")
+    String bar,
+
+    @JsonProperty("baz")
+    List<Boolean> baz,
+
+    @JsonProperty("foo")
+    Boolean foo,
+
+    @JsonProperty("quux")
+    List<Boolean> quux
+) {
+}
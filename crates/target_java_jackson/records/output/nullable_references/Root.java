@@ -0,0 +1,28 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public record Root(
+    @JsonProperty("notnull_ref_notnull_string")
+    NotnullRefNotnullString notnullRefNotnullString,
+
+    @JsonProperty("notnull_ref_null_string")
+    NotnullRefNullString notnullRefNullString,
+
+    @JsonProperty("notnull_string")
+    NotnullString notnullString,
+
+    @JsonProperty("null_ref_notnull_string")
+    NullRefNotnullString nullRefNotnullString,
+
+    @JsonProperty("null_ref_null_string")
+    NullRefNullString nullRefNullString,
+
+    @JsonProperty("null_string")
+    NullString nullString
+) {
+}
@@ -0,0 +1,14 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.time.OffsetDateTime;
+
+@JsonSerialize
+public record Root(
+    @JsonProperty("foo")
+    OffsetDateTime foo
+) {
+}
@@ -0,0 +1,24 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.util.List;
+
+@JsonSerialize
+public record Root(
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("bar")
+    List<String> bar,
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("baz")
+    Boolean baz,
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("foo")
+    String foo
+) {
+}
@@ -0,0 +1,16 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+
+@JsonSerialize
+public record Root(
+    @JsonProperty("Foo")
+    String foo,
+
+    @JsonProperty("foo")
+    String foo0
+) {
+}
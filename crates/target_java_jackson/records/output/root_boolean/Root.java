@@ -0,0 +1,17 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public record Root(Boolean value) {
+    @JsonCreator
+    public Root {
+    }
+
+    @JsonValue
+    public Boolean value() {
+        return value;
+    }
+}
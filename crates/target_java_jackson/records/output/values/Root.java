@@ -0,0 +1,18 @@
+// Code generated by jtd-codegen for Java + Jackson v0.2.1
+
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+import java.util.Map;
+
+public record Root(Map<String, String> value) {
+    @JsonCreator
+    public Root {
+    }
+
+    @JsonValue
+    public Map<String, String> value() {
+        return value;
+    }
+}
@@ -1,3 +1,5 @@
+mod records;
+
 use askama::Template;
 use jtd_codegen::target::{self, inflect, metadata};
 use jtd_codegen::Result;
@@ -23,6 +25,15 @@ lazy_static! {
             KEYWORDS.clone(),
             inflect::TailInflector::new(inflect::Case::camel_case())
         ));
+    static ref RECORD_COMPONENT_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
+        Box::new(inflect::KeywordAvoidingInflector::new(
+            KEYWORDS
+                .iter()
+                .cloned()
+                .chain(include_str!("record_components").lines().map(str::to_owned))
+                .collect(),
+            inflect::TailInflector::new(inflect::Case::camel_case())
+        ));
     static ref ENUM_MEMBER_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
         Box::new(inflect::KeywordAvoidingInflector::new(
             KEYWORDS.clone(),
@@ -32,11 +43,153 @@ lazy_static! {
 
 pub struct Target {
     package: String,
+    records: bool,
+    builders: bool,
+    optionals: bool,
+    nullness_annotations: Option<NullnessAnnotations>,
+}
+
+/// Libraries of annotations that say whether a type may be null.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NullnessAnnotations {
+    /// `@Nullable` and `@NonNull` from `org.jspecify.annotations`.
+    JSpecify,
+
+    /// `@Nullable` and `@NotNull` from `org.jetbrains.annotations`.
+    JetBrains,
+}
+
+impl NullnessAnnotations {
+    // The annotation for a type that is or isn't nullable.
+    fn annotation(self, state: &mut FileState, nullable: bool) -> &'static str {
+        let (import, annotation) = match (self, nullable) {
+            (Self::JSpecify, true) => ("org.jspecify.annotations.Nullable", "@Nullable"),
+            (Self::JSpecify, false) => ("org.jspecify.annotations.NonNull", "@NonNull"),
+            (Self::JetBrains, true) => ("org.jetbrains.annotations.Nullable", "@Nullable"),
+            (Self::JetBrains, false) => ("org.jetbrains.annotations.NotNull", "@NotNull"),
+        };
+
+        state.imports.insert(import.into());
+        annotation
+    }
 }
 
 impl Target {
     pub fn new(package: String) -> Self {
-        Self { package }
+        Self {
+            package,
+            records: false,
+            builders: false,
+            optionals: false,
+            nullness_annotations: None,
+        }
+    }
+
+    /// Whether to generate records, rather than classes with a getter and
+    /// setter for every field. Records need Java 17 and Jackson 2.12.
+    ///
+    /// Discriminators become sealed interfaces, which their variants'
+    /// records implement, and aliases become records with a single `value`. A
+    /// discriminator overridden with `javaJacksonType` must be an interface,
+    /// or `Object`.
+    pub fn with_records(mut self, records: bool) -> Self {
+        self.records = records;
+        self
+    }
+
+    /// Whether classes should have a static nested `Builder`, which checks
+    /// that required properties aren't null when it builds. Required
+    /// properties that are nullable may be left unset. This has no effect on
+    /// records.
+    pub fn with_builders(mut self, builders: bool) -> Self {
+        self.builders = builders;
+        self
+    }
+
+    /// Whether the getters of optional properties should return an
+    /// `Optional`, which is empty when the property is absent or null. Jackson
+    /// ignores these getters, so `jackson-datatype-jdk8` isn't needed. This
+    /// has no effect on records.
+    pub fn with_optionals(mut self, optionals: bool) -> Self {
+        self.optionals = optionals;
+        self
+    }
+
+    /// Which annotations, if any, should say whether fields, getters and
+    /// setters may be null. Optional properties, and properties whose schema
+    /// is nullable, may be null, and all others may not.
+    pub fn with_nullness_annotations(
+        mut self,
+        nullness_annotations: Option<NullnessAnnotations>,
+    ) -> Self {
+        self.nullness_annotations = nullness_annotations;
+        self
+    }
+
+    // Whether generated types can't be given a name, because this target
+    // declares something else by that name.
+    fn reserves(&self, name: &str) -> bool {
+        // Classes with a builder declare a nested Builder, which would shadow
+        // a type of that name in them.
+        let builder_names = ["Builder"];
+
+        self.builders && !self.records && builder_names.contains(&name)
+    }
+
+    fn annotated_type(&self, state: &mut FileState, type_: &str, nullable: bool) -> String {
+        annotated_type(self.nullness_annotations, state, type_, nullable)
+    }
+
+    // Records take their fields' types as they are, so nullness annotations
+    // go into the types beforehand.
+    fn annotate_fields(&self, state: &mut FileState, fields: &mut [target::Field]) {
+        for field in fields {
+            field.type_ = self.annotated_type(state, &field.type_, may_be_null(field));
+        }
+    }
+
+    fn write_getter(
+        &self,
+        out: &mut dyn Write,
+        state: &mut FileState,
+        field: &target::Field,
+    ) -> Result<()> {
+        if self.optionals && field.optional {
+            state
+                .imports
+                .insert("com.fasterxml.jackson.annotation.JsonIgnore".into());
+
+            // Optional is qualified, because schemas can define a type with
+            // that name.
+            let type_ = format!("java.util.Optional<{}>", field.type_);
+
+            writeln!(out, "    @JsonIgnore")?;
+            writeln!(
+                out,
+                "    public {} get{}() {{",
+                self.annotated_type(state, &type_, false),
+                capitalize_first_letter(&field.name)
+            )?;
+            writeln!(
+                out,
+                "        return java.util.Optional.ofNullable({});",
+                field.name
+            )?;
+            writeln!(out, "    }}")?;
+
+            return Ok(());
+        }
+
+        writeln!(
+            out,
+            "    public {} get{}() {{",
+            self.annotated_type(state, &field.type_, may_be_null(field)),
+            capitalize_first_letter(&field.name)
+        )?;
+        writeln!(out, "        return {};", field.name)?;
+        writeln!(out, "    }}")?;
+
+        Ok(())
     }
 }
 
@@ -70,7 +223,18 @@ impl jtd_codegen::target::Target for Target {
 
     fn name(&self, kind: target::NameableKind, parts: &[String]) -> String {
         match kind {
-            target::NameableKind::Type => TYPE_NAMING_CONVENTION.inflect(parts),
+            target::NameableKind::Type => {
+                let name = TYPE_NAMING_CONVENTION.inflect(parts);
+                if self.reserves(&name) {
+                    format!("{}_", name)
+                } else {
+                    name
+                }
+            }
+            // Components can't have the name of a method every record has.
+            target::NameableKind::Field if self.records => {
+                RECORD_COMPONENT_NAMING_CONVENTION.inflect(parts)
+            }
             target::NameableKind::Field => FIELD_NAMING_CONVENTION.inflect(parts),
             target::NameableKind::EnumMember => ENUM_MEMBER_NAMING_CONVENTION.inflect(parts),
         }
@@ -81,12 +245,12 @@ impl jtd_codegen::target::Target for Target {
         state: &mut FileState,
         metadata: metadata::Metadata,
         expr: target::Expr,
-    ) -> String {
+    ) -> Result<String> {
         if let Some(s) = metadata.get("javaJacksonType").and_then(|v| v.as_str()) {
-            return s.into();
+            return Ok(s.into());
         }
 
-        match expr {
+        Ok(match expr {
             target::Expr::Empty => "Object".into(),
             target::Expr::Boolean => "Boolean".into(),
             target::Expr::Int8 => "Byte".into(),
@@ -107,7 +271,7 @@ impl jtd_codegen::target::Target for Target {
                     .get("javaJacksonContainer")
                     .and_then(|v| v.as_str())
                 {
-                    return format!("{}<{}>", s, sub_expr);
+                    return Ok(format!("{}<{}>", s, sub_expr));
                 }
 
                 state.imports.insert("java.util.List".into());
@@ -118,14 +282,14 @@ impl jtd_codegen::target::Target for Target {
                     .get("javaJacksonContainer")
                     .and_then(|v| v.as_str())
                 {
-                    return format!("{}<String, {}>", s, sub_expr);
+                    return Ok(format!("{}<String, {}>", s, sub_expr));
                 }
 
                 state.imports.insert("java.util.Map".into());
                 format!("Map<String, {}>", sub_expr)
             }
             target::Expr::NullableOf(sub_expr) => sub_expr, // everything is already nullable
-        }
+        })
     }
 
     fn item(
@@ -135,7 +299,7 @@ impl jtd_codegen::target::Target for Target {
         item: target::Item,
     ) -> Result<Option<String>> {
         Ok(match item {
-            target::Item::Auxiliary { out_dir } => {
+            target::Item::Auxiliary { out_dir, .. } => {
                 let mut out = File::create(Path::join(&out_dir, "UnsignedByte.java"))?;
                 writeln!(
                     out,
@@ -205,6 +369,11 @@ impl jtd_codegen::target::Target for Target {
                 name,
                 type_,
             } => {
+                if self.records {
+                    records::write_alias(out, state, &metadata, &name, &type_)?;
+                    return Ok(None);
+                }
+
                 state.imports.extend(vec![
                     "com.fasterxml.jackson.annotation.JsonValue".to_string(),
                     "com.fasterxml.jackson.annotation.JsonCreator".to_string(),
@@ -230,6 +399,7 @@ impl jtd_codegen::target::Target for Target {
                 writeln!(out, "    public void setValue({} value) {{", type_)?;
                 writeln!(out, "        this.value = value;")?;
                 writeln!(out, "    }}")?;
+                write_value_methods(out, &name, &["value".to_owned()])?;
                 writeln!(out, "}}")?;
 
                 None
@@ -272,12 +442,18 @@ impl jtd_codegen::target::Target for Target {
                 metadata,
                 name,
                 has_additional,
-                fields,
+                mut fields,
             } => {
                 if let Some(s) = metadata.get("javaJacksonType").and_then(|v| v.as_str()) {
                     return Ok(Some(s.into()));
                 }
 
+                if self.records {
+                    self.annotate_fields(state, &mut fields);
+                    records::write_struct(out, state, &metadata, &name, has_additional, &fields)?;
+                    return Ok(None);
+                }
+
                 state.imports.extend(vec![
                     "com.fasterxml.jackson.annotation.JsonProperty".to_string(),
                     "com.fasterxml.jackson.databind.annotation.JsonSerialize".to_string(),
@@ -309,7 +485,12 @@ impl jtd_codegen::target::Target for Target {
                     }
 
                     writeln!(out, "    @JsonProperty({:?})", field.json_name)?;
-                    writeln!(out, "    private {} {};", field.type_, field.name)?;
+                    writeln!(
+                        out,
+                        "    private {} {};",
+                        self.annotated_type(state, &field.type_, may_be_null(field)),
+                        field.name
+                    )?;
                     writeln!(out)?;
                 }
                 writeln!(out, "    public {}() {{", name)?;
@@ -327,14 +508,7 @@ impl jtd_codegen::target::Target for Target {
                             &format!("Getter for {}.<p>", field.name)
                         )
                     )?;
-                    writeln!(
-                        out,
-                        "    public {} get{}() {{",
-                        field.type_,
-                        capitalize_first_letter(&field.name)
-                    )?;
-                    writeln!(out, "        return {};", field.name)?;
-                    writeln!(out, "    }}")?;
+                    self.write_getter(out, state, field)?;
                     writeln!(out)?;
                     write!(
                         out,
@@ -349,13 +523,20 @@ impl jtd_codegen::target::Target for Target {
                         out,
                         "    public void set{}({} {}) {{",
                         capitalize_first_letter(&field.name),
-                        field.type_,
+                        self.annotated_type(state, &field.type_, may_be_null(field)),
                         field.name
                     )?;
                     writeln!(out, "        this.{0} = {0};", field.name)?;
                     writeln!(out, "    }}")?;
                 }
 
+                let field_names: Vec<_> = fields.iter().map(|field| field.name.clone()).collect();
+                write_value_methods(out, &name, &field_names)?;
+
+                if self.builders {
+                    write_builder(out, state, self.nullness_annotations, &name, &fields)?;
+                }
+
                 writeln!(out, "}}")?;
 
                 None
@@ -372,6 +553,18 @@ impl jtd_codegen::target::Target for Target {
                     return Ok(Some(s.into()));
                 }
 
+                if self.records {
+                    records::write_discriminator(
+                        out,
+                        state,
+                        &metadata,
+                        &name,
+                        &tag_json_name,
+                        &variants,
+                    )?;
+                    return Ok(None);
+                }
+
                 state.imports.extend(vec![
                     "com.fasterxml.jackson.annotation.JsonTypeInfo".to_string(),
                     "com.fasterxml.jackson.annotation.JsonSubTypes".to_string(),
@@ -403,13 +596,27 @@ impl jtd_codegen::target::Target for Target {
                 name,
                 parent_name,
                 has_additional,
-                fields,
+                mut fields,
                 ..
             } => {
                 if let Some(s) = metadata.get("javaJacksonType").and_then(|v| v.as_str()) {
                     return Ok(Some(s.into()));
                 }
 
+                if self.records {
+                    self.annotate_fields(state, &mut fields);
+                    records::write_discriminator_variant(
+                        out,
+                        state,
+                        &metadata,
+                        &name,
+                        &parent_name,
+                        has_additional,
+                        &fields,
+                    )?;
+                    return Ok(None);
+                }
+
                 state.imports.extend(vec![
                     "com.fasterxml.jackson.annotation.JsonProperty".to_string(),
                     "com.fasterxml.jackson.databind.annotation.JsonSerialize".to_string(),
@@ -441,7 +648,12 @@ impl jtd_codegen::target::Target for Target {
                     }
 
                     writeln!(out, "    @JsonProperty({:?})", field.json_name)?;
-                    writeln!(out, "    private {} {};", field.type_, field.name)?;
+                    writeln!(
+                        out,
+                        "    private {} {};",
+                        self.annotated_type(state, &field.type_, may_be_null(field)),
+                        field.name
+                    )?;
                     writeln!(out)?;
                 }
                 writeln!(out, "    public {}() {{", name)?;
@@ -459,14 +671,7 @@ impl jtd_codegen::target::Target for Target {
                             &format!("Getter for {}.<p>", field.name)
                         )
                     )?;
-                    writeln!(
-                        out,
-                        "    public {} get{}() {{",
-                        field.type_,
-                        capitalize_first_letter(&field.name)
-                    )?;
-                    writeln!(out, "        return {};", field.name)?;
-                    writeln!(out, "    }}")?;
+                    self.write_getter(out, state, field)?;
                     writeln!(out)?;
                     write!(
                         out,
@@ -481,13 +686,20 @@ impl jtd_codegen::target::Target for Target {
                         out,
                         "    public void set{}({} {}) {{",
                         capitalize_first_letter(&field.name),
-                        field.type_,
+                        self.annotated_type(state, &field.type_, may_be_null(field)),
                         field.name
                     )?;
                     writeln!(out, "        this.{0} = {0};", field.name)?;
                     writeln!(out, "    }}")?;
                 }
 
+                let field_names: Vec<_> = fields.iter().map(|field| field.name.clone()).collect();
+                write_value_methods(out, &name, &field_names)?;
+
+                if self.builders {
+                    write_builder(out, state, self.nullness_annotations, &name, &fields)?;
+                }
+
                 writeln!(out, "}}")?;
 
                 None
@@ -541,6 +753,190 @@ fn description_with_message(
     )
 }
 
+// Writes out equals, hashCode and toString methods, which compare and print
+// the given fields.
+fn write_value_methods(out: &mut dyn Write, name: &str, field_names: &[String]) -> Result<()> {
+    let this_fields: Vec<_> = field_names
+        .iter()
+        .map(|field_name| format!("this.{}", field_name))
+        .collect();
+
+    // Object and Objects are qualified, because schemas can define types with
+    // those names.
+    writeln!(out)?;
+    writeln!(out, "    @Override")?;
+    writeln!(out, "    public boolean equals(java.lang.Object o) {{")?;
+    writeln!(out, "        if (this == o) {{")?;
+    writeln!(out, "            return true;")?;
+    writeln!(out, "        }}")?;
+    writeln!(
+        out,
+        "        if (o == null || getClass() != o.getClass()) {{"
+    )?;
+    writeln!(out, "            return false;")?;
+    writeln!(out, "        }}")?;
+    if field_names.is_empty() {
+        writeln!(out, "        return true;")?;
+    } else {
+        writeln!(out, "        {0} that = ({0}) o;", name)?;
+        for (index, field_name) in field_names.iter().enumerate() {
+            writeln!(
+                out,
+                "        {} java.util.Objects.equals(this.{1}, that.{1}){2}",
+                if index == 0 { "return" } else { "    &&" },
+                field_name,
+                if index == field_names.len() - 1 {
+                    ";"
+                } else {
+                    ""
+                }
+            )?;
+        }
+    }
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+    writeln!(out, "    @Override")?;
+    writeln!(out, "    public int hashCode() {{")?;
+    writeln!(
+        out,
+        "        return java.util.Objects.hash({});",
+        this_fields.join(", ")
+    )?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+    writeln!(out, "    @Override")?;
+    writeln!(out, "    public String toString() {{")?;
+    writeln!(out, "        return \"{}{{\"", name)?;
+    for (index, field_name) in field_names.iter().enumerate() {
+        writeln!(
+            out,
+            "            + \"{}{}=\" + this.{1}",
+            if index == 0 { "" } else { ", " },
+            field_name
+        )?;
+    }
+    writeln!(out, "            + \"}}\";")?;
+    writeln!(out, "    }}")?;
+
+    Ok(())
+}
+
+// Writes out a static nested Builder, and a static method to get one.
+fn write_builder(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    nullness_annotations: Option<NullnessAnnotations>,
+    name: &str,
+    fields: &[target::Field],
+) -> Result<()> {
+    writeln!(out)?;
+    writeln!(out, "    /**")?;
+    writeln!(out, "     * Returns a builder for {}.", name)?;
+    writeln!(out, "     */")?;
+    writeln!(out, "    public static Builder builder() {{")?;
+    writeln!(out, "        return new Builder();")?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+    writeln!(out, "    /**")?;
+    writeln!(out, "     * Builder for {}.", name)?;
+    writeln!(out, "     */")?;
+    writeln!(out, "    public static class Builder {{")?;
+    for field in fields {
+        writeln!(out, "        private {} {};", field.type_, field.name)?;
+    }
+    if !fields.is_empty() {
+        writeln!(out)?;
+    }
+    writeln!(out, "        private Builder() {{")?;
+    writeln!(out, "        }}")?;
+
+    for field in fields {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "        public Builder {0}({1} {0}) {{",
+            field.name,
+            annotated_type(
+                nullness_annotations,
+                state,
+                &field.type_,
+                may_be_null(field)
+            )
+        )?;
+        writeln!(out, "            this.{0} = {0};", field.name)?;
+        writeln!(out, "            return this;")?;
+        writeln!(out, "        }}")?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "        /**")?;
+    writeln!(out, "         * Builds a {}.", name)?;
+    writeln!(out, "         *")?;
+    writeln!(
+        out,
+        "         * @throws IllegalStateException if a required property that isn't nullable is null"
+    )?;
+    writeln!(out, "         */")?;
+    writeln!(out, "        public {} build() {{", name)?;
+    for field in fields {
+        if !field.optional && !field.nullable {
+            writeln!(out, "            if (this.{} == null) {{", field.name)?;
+            writeln!(
+                out,
+                "                throw new IllegalStateException(\"{} is missing required property {}\");",
+                name, field.name
+            )?;
+            writeln!(out, "            }}")?;
+            writeln!(out)?;
+        }
+    }
+    writeln!(out, "            {0} value = new {0}();", name)?;
+    for field in fields {
+        writeln!(
+            out,
+            "            value.set{}(this.{});",
+            capitalize_first_letter(&field.name),
+            field.name
+        )?;
+    }
+    writeln!(out, "            return value;")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+
+    Ok(())
+}
+
+// Whether a field may be null, because it's optional or its schema is
+// nullable.
+fn may_be_null(field: &target::Field) -> bool {
+    field.optional || field.nullable
+}
+
+// A type with a nullness annotation, if there is one. Annotations go right
+// before the simple name of a type, as in java.util.@Nullable List, so that
+// type annotations are allowed on types that javaJacksonType qualifies.
+fn annotated_type(
+    nullness_annotations: Option<NullnessAnnotations>,
+    state: &mut FileState,
+    type_: &str,
+    nullable: bool,
+) -> String {
+    let annotation = match nullness_annotations {
+        Some(annotations) => annotations.annotation(state, nullable),
+        None => return type_.to_owned(),
+    };
+
+    let name_end = type_.find('<').unwrap_or(type_.len());
+    let name_start = type_[..name_end].rfind('.').map_or(0, |i| i + 1);
+
+    format!(
+        "{}{} {}",
+        &type_[..name_start],
+        annotation,
+        &type_[name_start..]
+    )
+}
+
 fn capitalize_first_letter(s: &str) -> String {
     let (head, tail) = s.split_at(1);
     head.to_uppercase() + tail
@@ -571,5 +967,49 @@ fn doc(ident: usize, s: &str) -> String {
 mod tests {
     mod std_tests {
         jtd_codegen_test::std_test_cases!(&crate::Target::new("com.example".into()));
+
+        jtd_codegen_test::strict_std_test_case!(
+            &crate::Target::new("com.example".into()),
+            objects_collisions
+        );
+    }
+
+    mod builders_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/builders"),
+            &crate::Target::new("com.example".into()).with_builders(true)
+        );
+
+        jtd_codegen_test::strict_std_test_case!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/builders"),
+            &crate::Target::new("com.example".into()).with_builders(true),
+            builder_collisions
+        );
+    }
+
+    mod nullness_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/nullness"),
+            &crate::Target::new("com.example".into())
+                .with_builders(true)
+                .with_optionals(true)
+                .with_nullness_annotations(Some(crate::NullnessAnnotations::JSpecify))
+        );
+
+        jtd_codegen_test::strict_std_test_case!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/nullness"),
+            &crate::Target::new("com.example".into())
+                .with_builders(true)
+                .with_optionals(true)
+                .with_nullness_annotations(Some(crate::NullnessAnnotations::JSpecify)),
+            optional_collisions
+        );
+    }
+
+    mod records_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/records"),
+            &crate::Target::new("com.example".into()).with_records(true)
+        );
     }
 }
@@ -0,0 +1,201 @@
+// Writes out the records that Target::with_records enables.
+//
+// Records are immutable, so Jackson constructs them through their canonical
+// constructor, whose parameters carry the same @JsonProperty annotations as the
+// fields and accessors that back them.
+
+use crate::{description, doc, FileState};
+use jtd_codegen::target::{self, metadata};
+use jtd_codegen::Result;
+use std::io::Write;
+
+pub fn write_alias(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    type_: &str,
+) -> Result<()> {
+    state.imports.extend(vec![
+        "com.fasterxml.jackson.annotation.JsonValue".to_string(),
+        "com.fasterxml.jackson.annotation.JsonCreator".to_string(),
+    ]);
+
+    // @JsonValue goes on the accessor alone, because on the component Jackson
+    // would find it on both the accessor and the field.
+    write!(out, "{}", description(metadata, 0))?;
+    writeln!(out, "public record {}({} value) {{", name, type_)?;
+    writeln!(out, "    @JsonCreator")?;
+    writeln!(out, "    public {} {{", name)?;
+    writeln!(out, "    }}")?;
+    writeln!(out)?;
+    writeln!(out, "    @JsonValue")?;
+    writeln!(out, "    public {} value() {{", type_)?;
+    writeln!(out, "        return value;")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+pub fn write_struct(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    has_additional: bool,
+    fields: &[target::Field],
+) -> Result<()> {
+    write_record(out, state, metadata, name, None, has_additional, fields)
+}
+
+pub fn write_discriminator(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    tag_json_name: &str,
+    variants: &[target::DiscriminatorVariantInfo],
+) -> Result<()> {
+    state.imports.extend(vec![
+        "com.fasterxml.jackson.annotation.JsonTypeInfo".to_string(),
+        "com.fasterxml.jackson.annotation.JsonSubTypes".to_string(),
+    ]);
+
+    let permits: Vec<_> = variants
+        .iter()
+        .map(|variant| variant.type_name.clone())
+        .collect();
+
+    write!(out, "{}", description(metadata, 0))?;
+    writeln!(
+        out,
+        "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, property = {:?})",
+        tag_json_name
+    )?;
+    writeln!(out, "@JsonSubTypes({{")?;
+    for variant in variants {
+        writeln!(
+            out,
+            "    @JsonSubTypes.Type(name = {:?}, value = {}.class),",
+            variant.tag_value, variant.type_name
+        )?;
+    }
+    writeln!(out, "}})")?;
+    writeln!(
+        out,
+        "public sealed interface {} permits {} {{",
+        name,
+        permits.join(", ")
+    )?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+pub fn write_discriminator_variant(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    parent_name: &str,
+    has_additional: bool,
+    fields: &[target::Field],
+) -> Result<()> {
+    write_record(
+        out,
+        state,
+        metadata,
+        name,
+        Some(parent_name),
+        has_additional,
+        fields,
+    )
+}
+
+// Writes out a record. Discriminator variants implement the interface of their
+// discriminator, and Jackson writes out their tag from its @JsonTypeInfo.
+fn write_record(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    parent_name: Option<&str>,
+    has_additional: bool,
+    fields: &[target::Field],
+) -> Result<()> {
+    state.imports.extend(vec![
+        "com.fasterxml.jackson.annotation.JsonProperty".to_string(),
+        "com.fasterxml.jackson.databind.annotation.JsonSerialize".to_string(),
+    ]);
+
+    if has_additional {
+        state
+            .imports
+            .insert("com.fasterxml.jackson.annotation.JsonIgnoreProperties".into());
+    }
+
+    if fields.iter().any(|field| field.optional) {
+        state
+            .imports
+            .insert("com.fasterxml.jackson.annotation.JsonInclude".into());
+    }
+
+    // Components are described with @param tags in the record's own
+    // documentation.
+    let params: Vec<_> = fields
+        .iter()
+        .filter(|field| !metadata::description(&field.metadata).is_empty())
+        .map(|field| {
+            format!(
+                "@param {} {}",
+                field.name,
+                metadata::description(&field.metadata)
+            )
+        })
+        .collect();
+
+    let mut docs = vec![
+        metadata::description(metadata).to_owned(),
+        params.join("\n"),
+    ];
+    docs.retain(|s| !s.is_empty());
+
+    write!(out, "{}", doc(0, &docs.join("\n\n")))?;
+    writeln!(out, "@JsonSerialize")?;
+    if has_additional {
+        writeln!(out, "@JsonIgnoreProperties(ignoreUnknown = true)")?;
+    }
+
+    if fields.is_empty() {
+        write!(out, "public record {}()", name)?;
+    } else {
+        writeln!(out, "public record {}(", name)?;
+        for (index, field) in fields.iter().enumerate() {
+            if index != 0 {
+                writeln!(out, ",")?;
+                writeln!(out)?;
+            }
+
+            if field.optional {
+                writeln!(out, "    @JsonInclude(JsonInclude.Include.NON_NULL)")?;
+            }
+
+            writeln!(out, "    @JsonProperty({:?})", field.json_name)?;
+            write!(out, "    {} {}", field.type_, field.name)?;
+        }
+        writeln!(out)?;
+        write!(out, ")")?;
+    }
+
+    // A discriminator whose type is overridden with javaJacksonType has to be
+    // an interface, unless it's Object, which every record already is.
+    if let Some(parent_name) = parent_name.filter(|name| *name != "Object") {
+        write!(out, " implements {}", parent_name)?;
+    }
+
+    writeln!(out, " {{")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
@@ -0,0 +1,224 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from datetime import datetime, timedelta, timezone
+from enum import StrEnum
+from types import UnionType
+from typing import Any, Union, get_args, get_origin
+
+
+@dataclass(slots=True, frozen=True)
+class RootDiscriminatorWithDescription:
+    """
+    A description for discriminator
+    """
+
+    foo: str
+
+    @classmethod
+    def from_json_data(cls, data: Any) -> RootDiscriminatorWithDescription:
+        variants: dict[str, type[RootDiscriminatorWithDescription]] = {
+            "bar": RootDiscriminatorWithDescriptionBar,
+        }
+
+        return variants[data["foo"]].from_json_data(data)
+
+    def to_json_data(self) -> Any:
+        pass
+
+@dataclass(slots=True, frozen=True)
+class RootDiscriminatorWithDescriptionBar(RootDiscriminatorWithDescription):
+    """
+    A description for discriminator variant
+    """
+
+
+    __match_args__ = ()
+
+    @classmethod
+    def from_json_data(cls, data: Any) -> RootDiscriminatorWithDescriptionBar:
+        return cls(
+            "bar",
+        )
+
+    def to_json_data(self) -> Any:
+        data = { "foo": "bar" }
+        return data
+
+class RootEnumWithDescription(StrEnum):
+    """
+    A description for enum
+    """
+
+    X = "X"
+    """
+    A description for X
+    """
+
+    Y = "Y"
+    """
+    A description for Y
+    """
+
+    Z = "Z"
+    """
+    A description for Z
+    """
+
+    @classmethod
+    def from_json_data(cls, data: Any) -> RootEnumWithDescription:
+        return cls(data)
+
+    def to_json_data(self) -> Any:
+        return self.value
+
+@dataclass(slots=True, frozen=True)
+class RootPropertiesWithDescription:
+    """
+    A description for properties
+    """
+
+
+    @classmethod
+    def from_json_data(cls, data: Any) -> RootPropertiesWithDescription:
+        return cls(
+        )
+
+    def to_json_data(self) -> Any:
+        data: dict[str, Any] = {}
+        return data
+
+@dataclass(slots=True, frozen=True)
+class Root:
+    discriminator_with_description: RootDiscriminatorWithDescription
+    """
+    A description for discriminator
+    """
+
+    enum_with_description: RootEnumWithDescription
+    """
+    A description for enum
+    """
+
+    long_description: str
+    """
+    Whereas disregard and contempt for human rights have resulted in barbarous
+    acts which have outraged the conscience of mankind, and the advent of a
+    world in which human beings shall enjoy freedom of speech and belief and
+    freedom from fear and want has been proclaimed as the highest aspiration of
+    the common people,
+    """
+
+    properties_with_description: RootPropertiesWithDescription
+    """
+    A description for properties
+    """
+
+    ref_with_description: Baz
+    """
+    A description for ref
+    """
+
+    string_with_description: str
+    """
+    A description for string
+    """
+
+
+    @classmethod
+    def from_json_data(cls, data: Any) -> Root:
+        return cls(
+            _from_json_data(RootDiscriminatorWithDescription, data.get("discriminator_with_description")),
+            _from_json_data(RootEnumWithDescription, data.get("enum_with_description")),
+            _from_json_data(str, data.get("long_description")),
+            _from_json_data(RootPropertiesWithDescription, data.get("properties_with_description")),
+            _from_json_data(Baz, data.get("ref_with_description")),
+            _from_json_data(str, data.get("string_with_description")),
+        )
+
+    def to_json_data(self) -> Any:
+        data: dict[str, Any] = {}
+        data["discriminator_with_description"] = _to_json_data(self.discriminator_with_description)
+        data["enum_with_description"] = _to_json_data(self.enum_with_description)
+        data["long_description"] = _to_json_data(self.long_description)
+        data["properties_with_description"] = _to_json_data(self.properties_with_description)
+        data["ref_with_description"] = _to_json_data(self.ref_with_description)
+        data["string_with_description"] = _to_json_data(self.string_with_description)
+        return data
+
+@dataclass(slots=True, frozen=True)
+class Baz:
+    """
+    A description for a definition
+    """
+
+    value: str
+
+    @classmethod
+    def from_json_data(cls, data: Any) -> Baz:
+        return cls(_from_json_data(str, data))
+
+    def to_json_data(self) -> Any:
+        return _to_json_data(self.value)
+
+def _from_json_data(cls: Any, data: Any) -> Any:
+    if data is None or cls in [bool, int, float, str, object] or cls is Any:
+        return data
+    if cls is datetime:
+        return _parse_rfc3339(data)
+    if get_origin(cls) in (Union, UnionType):
+        return _from_json_data(get_args(cls)[0], data)
+    if get_origin(cls) is list:
+        return [_from_json_data(get_args(cls)[0], d) for d in data]
+    if get_origin(cls) is dict:
+        return { k: _from_json_data(get_args(cls)[1], v) for k, v in data.items() }
+    return cls.from_json_data(data)
+
+def _to_json_data(data: Any) -> Any:
+    if data is None or type(data) in [bool, int, float, str, object]:
+        return data
+    if type(data) is datetime:
+        return data.isoformat()
+    if type(data) is list:
+        return [_to_json_data(d) for d in data]
+    if type(data) is dict:
+        return { k: _to_json_data(v) for k, v in data.items() }
+    return data.to_json_data()
+
+def _parse_rfc3339(s: str) -> datetime:
+    datetime_re = '^(\d{4})-(\d{2})-(\d{2})[tT](\d{2}):(\d{2}):(\d{2})(\.\d+)?([zZ]|((\+|-)(\d{2}):(\d{2})))$'
+    match = re.match(datetime_re, s)
+    if not match:
+        raise ValueError('Invalid RFC3339 date/time', s)
+
+    (year, month, day, hour, minute, second, frac_seconds, offset,
+     *tz) = match.groups()
+
+    frac_seconds_parsed = None
+    if frac_seconds:
+        frac_seconds_parsed = int(float(frac_seconds) * 1_000_000)
+    else:
+        frac_seconds_parsed = 0
+
+    tzinfo = None
+    if offset in ('Z', 'z'):
+        tzinfo = timezone.utc
+    else:
+        hours = int(tz[2])
+        minutes = int(tz[3])
+        sign = 1 if tz[1] == '+' else -1
+
+        if minutes not in range(60):
+            raise ValueError('minute offset must be in 0..59')
+
+        tzinfo = timezone(timedelta(minutes=sign * (60 * hours + minutes)))
+
+    second_parsed = int(second)
+    if second_parsed == 60:
+        second_parsed = 59
+
+    return datetime(int(year), int(month), int(day), int(hour), int(minute),
+                    second_parsed, frac_seconds_parsed, tzinfo)            
@@ -145,7 +145,7 @@ def _parse_rfc3339(s: str) -> datetime:
         frac_seconds_parsed = 0
 
     tzinfo = None
-    if offset == 'Z':
+    if offset in ('Z', 'z'):
         tzinfo = timezone.utc
     else:
         hours = int(tz[2])
@@ -0,0 +1,8 @@
+import sys
+from pydantic import TypeAdapter
+from gen import MAIN
+
+adapter = TypeAdapter(MAIN)
+for line in sys.stdin:
+    value = adapter.validate_json(line)
+    print(adapter.dump_json(value, by_alias=True, exclude_unset=True).decode())
@@ -0,0 +1,23 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field, RootModel
+from typing import Literal, Union
+from typing_extensions import Annotated
+
+
+class Root(RootModel['Annotated[Union[RootBarBaz, RootQuux], Field(discriminator="foo")]']):
+    pass
+
+class RootBarBaz(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    foo: Literal["BAR_BAZ"] = Field(alias="foo")
+    baz: str = Field(alias="baz")
+
+class RootQuux(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    foo: Literal["QUUX"] = Field(alias="foo")
+    quuz: str = Field(alias="quuz")
@@ -0,0 +1,11 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from enum import Enum
+
+
+class Root(Enum):
+    BAR = "Bar"
+    BAZ = "Baz"
+    FOO = "Foo"
@@ -0,0 +1,17 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field
+from typing import Dict, List
+
+
+class Root(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    override_elements_container: List[str] = Field(alias="override_elements_container")
+    override_type_discriminator: object = Field(alias="override_type_discriminator")
+    override_type_enum: object = Field(alias="override_type_enum")
+    override_type_expr: object = Field(alias="override_type_expr")
+    override_type_properties: object = Field(alias="override_type_properties")
+    override_values_container: Dict[str, str] = Field(alias="override_values_container")
@@ -0,0 +1,21 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import RootModel
+
+
+class Root(RootModel['Foo0']):
+    pass
+
+class Bar(RootModel['Bar0']):
+    pass
+
+class Foo(RootModel['Bar']):
+    pass
+
+class Bar0(RootModel['str']):
+    pass
+
+class Foo0(RootModel['Foo']):
+    pass
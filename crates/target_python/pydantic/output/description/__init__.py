@@ -0,0 +1,96 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import BaseModel, ConfigDict, Field, RootModel
+from typing import Literal, Union
+from typing_extensions import Annotated
+
+
+class RootDiscriminatorWithDescription(RootModel['Annotated[Union[RootDiscriminatorWithDescriptionBar], Field(discriminator="foo")]']):
+    """
+    A description for discriminator
+    """
+
+
+class RootDiscriminatorWithDescriptionBar(BaseModel):
+    """
+    A description for discriminator variant
+    """
+
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    foo: Literal["bar"] = Field(alias="foo")
+
+class RootEnumWithDescription(Enum):
+    """
+    A description for enum
+    """
+
+    X = "X"
+    """
+    A description for X
+    """
+
+    Y = "Y"
+    """
+    A description for Y
+    """
+
+    Z = "Z"
+    """
+    A description for Z
+    """
+
+
+class RootPropertiesWithDescription(BaseModel):
+    """
+    A description for properties
+    """
+
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+class Root(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    discriminator_with_description: RootDiscriminatorWithDescription = Field(alias="discriminator_with_description")
+    """
+    A description for discriminator
+    """
+
+    enum_with_description: RootEnumWithDescription = Field(alias="enum_with_description")
+    """
+    A description for enum
+    """
+
+    long_description: str = Field(alias="long_description")
+    """
+    Whereas disregard and contempt for human rights have resulted in barbarous
+    acts which have outraged the conscience of mankind, and the advent of a
+    world in which human beings shall enjoy freedom of speech and belief and
+    freedom from fear and want has been proclaimed as the highest aspiration of
+    the common people,
+    """
+
+    properties_with_description: RootPropertiesWithDescription = Field(alias="properties_with_description")
+    """
+    A description for properties
+    """
+
+    ref_with_description: Baz = Field(alias="ref_with_description")
+    """
+    A description for ref
+    """
+
+    string_with_description: str = Field(alias="string_with_description")
+    """
+    A description for string
+    """
+
+
+class Baz(RootModel['str']):
+    """
+    A description for a definition
+    """
+
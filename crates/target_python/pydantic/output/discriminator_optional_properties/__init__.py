@@ -0,0 +1,18 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field, RootModel
+from typing import List, Literal, Optional, Union
+from typing_extensions import Annotated
+
+
+class Root(RootModel['Annotated[Union[RootBar], Field(discriminator="foo")]']):
+    pass
+
+class RootBar(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    foo: Literal["bar"] = Field(alias="foo")
+    baz: Optional[List[str]] = Field(default=None, alias="baz")
+    quux: Optional[bool] = Field(default=None, alias="quux")
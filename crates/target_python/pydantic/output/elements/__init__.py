@@ -0,0 +1,10 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import RootModel
+from typing import List
+
+
+class Root(RootModel['List[str]']):
+    pass
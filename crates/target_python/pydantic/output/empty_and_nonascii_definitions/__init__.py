@@ -0,0 +1,33 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import RootModel
+
+
+class Root(RootModel['str']):
+    pass
+
+class DefaultName(RootModel['str']):
+    pass
+
+class Foo(RootModel['str']):
+    pass
+
+class Foo0(RootModel['str']):
+    pass
+
+class Foo1(RootModel['str']):
+    pass
+
+class FooBar(RootModel['str']):
+    pass
+
+class FooBar0(RootModel['str']):
+    pass
+
+class Foo0bar(RootModel['str']):
+    pass
+
+class FooBar1(RootModel['str']):
+    pass
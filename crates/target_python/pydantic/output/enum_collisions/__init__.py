@@ -0,0 +1,26 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class RootFooBar(Enum):
+    X = "x"
+    Y = "y"
+
+class RootFoo(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    bar: RootFooBar = Field(alias="bar")
+
+class RootFooBar0(Enum):
+    X = "x"
+    Y = "y"
+
+class Root(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    foo: RootFoo = Field(alias="foo")
+    foo_bar: RootFooBar0 = Field(alias="foo_bar")
@@ -0,0 +1,22 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class RootNestedIDInitialism(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    json_: str = Field(alias="json")
+    normalword: str = Field(alias="normalword")
+
+class Root(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    http: str = Field(alias="http")
+    id: str = Field(alias="id")
+    nested_id_initialism: RootNestedIDInitialism = Field(alias="nested_id_initialism")
+    utf8: str = Field(alias="utf8")
+    word_with_embedded_id_initialism: str = Field(alias="word_with_embedded_id_initialism")
+    word_with_trailing_initialism_id: str = Field(alias="word_with_trailing_initialism_id")
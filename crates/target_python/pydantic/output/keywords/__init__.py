@@ -0,0 +1,18 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field, RootModel
+
+
+class Root(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    for_: For = Field(alias="for")
+    object: Object = Field(alias="object")
+
+class For(RootModel['str']):
+    pass
+
+class Object(RootModel['str']):
+    pass
@@ -0,0 +1,26 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field, RootModel
+from typing import Literal, Optional, Union
+from typing_extensions import Annotated
+
+
+class Root0(RootModel['Annotated[Union[RootBar, RootQuux], Field(discriminator="foo")]']):
+    pass
+
+class RootBar(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    foo: Literal["bar"] = Field(alias="foo")
+    baz: str = Field(alias="baz")
+
+class RootQuux(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    foo: Literal["quux"] = Field(alias="foo")
+    quuz: str = Field(alias="quuz")
+
+class Root(RootModel['Optional[Root0]']):
+    pass
@@ -0,0 +1,16 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import RootModel
+from typing import Optional
+
+
+class Root0(Enum):
+    BAR = "Bar"
+    BAZ = "Baz"
+    FOO = "Foo"
+
+class Root(RootModel['Optional[Root0]']):
+    pass
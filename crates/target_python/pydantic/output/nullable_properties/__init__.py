@@ -0,0 +1,18 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field, RootModel
+from typing import List, Optional
+
+
+class Root0(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    bar: str = Field(alias="bar")
+    baz: List[bool] = Field(alias="baz")
+    foo: bool = Field(alias="foo")
+    quux: List[bool] = Field(alias="quux")
+
+class Root(RootModel['Optional[Root0]']):
+    pass
@@ -0,0 +1,35 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field, RootModel
+from typing import Optional
+
+
+class Root(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    notnull_ref_notnull_string: NotnullRefNotnullString = Field(alias="notnull_ref_notnull_string")
+    notnull_ref_null_string: NotnullRefNullString = Field(alias="notnull_ref_null_string")
+    notnull_string: NotnullString = Field(alias="notnull_string")
+    null_ref_notnull_string: NullRefNotnullString = Field(alias="null_ref_notnull_string")
+    null_ref_null_string: NullRefNullString = Field(alias="null_ref_null_string")
+    null_string: NullString = Field(alias="null_string")
+
+class NotnullRefNotnullString(RootModel['NotnullString']):
+    pass
+
+class NotnullRefNullString(RootModel['NullString']):
+    pass
+
+class NotnullString(RootModel['str']):
+    pass
+
+class NullRefNotnullString(RootModel['Optional[NotnullString]']):
+    pass
+
+class NullRefNullString(RootModel['Optional[NullString]']):
+    pass
+
+class NullString(RootModel['Optional[str]']):
+    pass
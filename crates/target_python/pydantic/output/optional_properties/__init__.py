@@ -0,0 +1,14 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field
+from typing import List, Optional
+
+
+class Root(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    bar: Optional[List[str]] = Field(default=None, alias="bar")
+    baz: Optional[bool] = Field(default=None, alias="baz")
+    foo: Optional[str] = Field(default=None, alias="foo")
@@ -0,0 +1,18 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import RootModel
+
+
+class Root(RootModel['Foo']):
+    pass
+
+class Bar(RootModel['Baz']):
+    pass
+
+class Baz(RootModel['str']):
+    pass
+
+class Foo(RootModel['Bar']):
+    pass
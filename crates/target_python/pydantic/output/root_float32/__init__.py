@@ -0,0 +1,9 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import RootModel
+
+
+class Root(RootModel['float']):
+    pass
@@ -0,0 +1,10 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import Field, RootModel
+from typing_extensions import Annotated
+
+
+class Root(RootModel['Annotated[int, Field(ge=0, le=4294967295)]']):
+    pass
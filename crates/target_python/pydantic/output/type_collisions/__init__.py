@@ -0,0 +1,27 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class RootFooBar(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    x: bool = Field(alias="x")
+
+class RootFoo(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    bar: RootFooBar = Field(alias="bar")
+
+class RootFooBar0(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    x: str = Field(alias="x")
+
+class Root(BaseModel):
+    model_config = ConfigDict(extra="forbid", populate_by_name=True)
+
+    foo: RootFoo = Field(alias="foo")
+    foo_bar: RootFooBar0 = Field(alias="foo_bar")
@@ -1,3 +1,7 @@
+mod pydantic;
+mod typed_dict;
+mod validation;
+
 use jtd_codegen::target::{self, inflect, metadata};
 use jtd_codegen::Result;
 use lazy_static::lazy_static;
@@ -26,6 +30,19 @@ lazy_static! {
             KEYWORDS.clone(),
             inflect::TailInflector::new(inflect::Case::snake_case())
         ));
+    static ref PYDANTIC_FIELD_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
+        Box::new(inflect::KeywordAvoidingInflector::new(
+            KEYWORDS
+                .iter()
+                .cloned()
+                .chain(
+                    include_str!("pydantic_attributes")
+                        .lines()
+                        .map(str::to_owned)
+                )
+                .collect(),
+            inflect::TailInflector::new(inflect::Case::snake_case())
+        ));
     static ref ENUM_MEMBER_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
         Box::new(inflect::KeywordAvoidingInflector::new(
             KEYWORDS.clone(),
@@ -33,11 +50,268 @@ lazy_static! {
         ));
 }
 
-pub struct Target {}
+// What typing_extensions backports, and from which Python 3.minor typing has it
+// too. TypedDict is only as good as typing_extensions' from 3.11, and typing's
+// get_origin and get_args don't know typing_extensions' Annotated.
+const TYPING_BACKPORTS: &[(&str, u32)] = &[
+    ("Annotated", 9),
+    ("get_args", 9),
+    ("get_origin", 9),
+    ("TypeAlias", 10),
+    ("NotRequired", 11),
+    ("TypedDict", 11),
+];
+
+pub struct Target {
+    pydantic: bool,
+    typed_dict: bool,
+    validation: bool,
+    python_version: (u32, u32),
+    frozen: bool,
+}
 
 impl Target {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            pydantic: false,
+            typed_dict: false,
+            validation: false,
+            python_version: (3, 8),
+            frozen: false,
+        }
+    }
+
+    /// Whether to generate pydantic v2 models, rather than dataclasses with
+    /// `from_json_data` and `to_json_data` methods.
+    ///
+    /// The models validate JSON as they parse it: properties are looked up by
+    /// their JSON name, integers are checked against their range, and
+    /// timestamps must have a time zone.
+    pub fn with_pydantic(mut self, pydantic: bool) -> Self {
+        self.pydantic = pydantic;
+        self
+    }
+
+    /// Whether to generate TypedDicts, which are types for the values that
+    /// `json.loads` returns, rather than classes to convert those values into.
+    ///
+    /// Enums become unions of `Literal`s, and discriminators become unions of
+    /// TypedDicts that have a `Literal` tag. Timestamps are left as strings.
+    /// This takes precedence over `with_pydantic`.
+    pub fn with_typed_dict(mut self, typed_dict: bool) -> Self {
+        self.typed_dict = typed_dict;
+        self
+    }
+
+    /// Whether `from_json_data` should check that data satisfies the schema,
+    /// rather than trusting it.
+    ///
+    /// Types, integer ranges, enum values, discriminator tags and required
+    /// properties are all checked, and optional properties are only null if
+    /// they're nullable. Problems raise a `ValidationError`, which has a JSON
+    /// Pointer to the bad value and a description of what it should have
+    /// been. This has no effect on pydantic models or TypedDicts.
+    ///
+    /// Before 3.9, validation imports `Annotated` from `typing_extensions`.
+    pub fn with_validation(mut self, validation: bool) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// The oldest version of Python that generated code has to run on, as a
+    /// major and minor version. The default is 3.8.
+    ///
+    /// Newer versions get newer syntax, so that linters like ruff and
+    /// pyupgrade have nothing to rewrite. From 3.9, generics are spelled
+    /// `list[...]` and `dict[...]`. From 3.10, nullable types are `X | None`,
+    /// annotations aren't quoted, dataclasses have slots, and discriminator
+    /// variants have a `__match_args__` without their tag. From 3.11, enums
+    /// are `StrEnum`s.
+    pub fn with_python_version(mut self, python_version: (u32, u32)) -> Self {
+        self.python_version = python_version;
+        self
+    }
+
+    /// Whether dataclasses should be frozen, so that their fields can't be
+    /// assigned to. This has no effect on pydantic models or TypedDicts.
+    pub fn with_frozen(mut self, frozen: bool) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    // Whether generated code can use what's new in Python 3.minor.
+    fn supports(&self, minor: u32) -> bool {
+        self.python_version >= (3, minor)
+    }
+
+    // Backports are only imported where typing doesn't have them.
+    fn import_backports(&self, state: &mut FileState) {
+        for (ident, minor) in TYPING_BACKPORTS {
+            if self.supports(*minor) {
+                let backports = state.imports.entry("typing_extensions".into()).or_default();
+                if backports.remove(*ident) {
+                    state.import("typing", ident);
+                }
+            }
+        }
+        state.imports.retain(|_, idents| !idents.is_empty());
+    }
+
+    // From 3.10, generated dataclasses start with `from __future__ import
+    // annotations`, which stops annotations being evaluated, so they don't
+    // need to be quoted to refer to types later in the file.
+    fn annotation(&self, type_: &str) -> String {
+        if self.supports(10) {
+            type_.to_owned()
+        } else {
+            format!("'{}'", type_)
+        }
+    }
+
+    // The decorator of every dataclass.
+    fn dataclass(&self, state: &mut FileState) -> String {
+        state.import("dataclasses", "dataclass");
+
+        let mut args = Vec::new();
+        if self.supports(10) {
+            args.push("slots=True");
+        }
+        if self.frozen {
+            args.push("frozen=True");
+        }
+
+        if args.is_empty() {
+            "@dataclass".into()
+        } else {
+            format!("@dataclass({})", args.join(", "))
+        }
+    }
+
+    // The base class of enums, whose members are all strings.
+    fn enum_base(&self, state: &mut FileState) -> &'static str {
+        let base = if self.supports(11) { "StrEnum" } else { "Enum" };
+        state.import("enum", base);
+        base
+    }
+
+    // The type that's any of types.
+    fn union(&self, state: &mut FileState, types: &[String]) -> String {
+        if self.supports(10) {
+            return types.join(" | ");
+        }
+
+        state.import("typing", "Union");
+        format!("Union[{}]", types.join(", "))
+    }
+
+    // How the helpers recognize a nullable type. From 3.10, `X | None` is a
+    // UnionType, except where X is a typing construct, like Annotated or Any.
+    fn union_origin(&self) -> &'static str {
+        if self.supports(10) {
+            "in (Union, UnionType)"
+        } else {
+            "is Union"
+        }
+    }
+
+    // Whether from_json_data validates its input.
+    fn validates(&self) -> bool {
+        self.validation && !self.pydantic && !self.typed_dict
+    }
+
+    // The type of an integer that's at least min and at most max.
+    fn int(&self, state: &mut FileState, min: i64, max: i64) -> String {
+        if self.validates() {
+            state.import("typing_extensions", "Annotated");
+            return format!("Annotated[int, ({}, {})]", min, max);
+        }
+
+        if self.typed_dict || !self.pydantic {
+            return "int".into();
+        }
+
+        state.import("pydantic", "Field");
+        state.import("typing_extensions", "Annotated");
+
+        format!("Annotated[int, Field(ge={}, le={})]", min, max)
+    }
+
+    fn write_from_json_data_def(&self, out: &mut dyn Write, name: &str) -> Result<()> {
+        if self.validates() {
+            writeln!(
+                out,
+                "    def from_json_data(cls, data: Any, path: str = '') -> {}:",
+                self.annotation(name)
+            )?;
+        } else {
+            writeln!(
+                out,
+                "    def from_json_data(cls, data: Any) -> {}:",
+                self.annotation(name)
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Validation checks that structs and discriminators are objects before
+    // looking inside them.
+    fn write_object_check(&self, out: &mut dyn Write, name: &str) -> Result<()> {
+        if self.validates() {
+            writeln!(out, "        if type(data) is not dict:")?;
+            writeln!(out, "            raise ValidationError(path, {:?})", name)?;
+            writeln!(out)?;
+        }
+
+        Ok(())
+    }
+
+    // Converts the value of a field of a struct or discriminator variant.
+    fn field_from_json_data(&self, field: &target::Field) -> String {
+        if !self.validates() {
+            return format!(
+                "_from_json_data({}, data.get({:?}))",
+                field.type_, field.json_name
+            );
+        }
+
+        if !field.optional {
+            return format!(
+                "_from_json_data({}, data.get({:?}, _MISSING), {})",
+                field.type_,
+                field.json_name,
+                validation::path(&field.json_name)
+            );
+        }
+
+        // Absent optional properties are None. Unless the property is
+        // nullable, that None doesn't extend to null ones.
+        let type_ = if field.nullable {
+            &field.type_
+        } else {
+            self.unwrap_optional(&field.type_)
+        };
+
+        format!(
+            "_optional({}, data.get({:?}, _MISSING), {})",
+            type_,
+            field.json_name,
+            validation::path(&field.json_name)
+        )
+    }
+
+    // The type of an optional property without the None that stands for its
+    // absence, which NullableOf added unless pythonType overrode the type.
+    fn unwrap_optional<'a>(&self, type_: &'a str) -> &'a str {
+        let unwrapped = if self.supports(10) {
+            type_.strip_suffix(" | None")
+        } else {
+            type_
+                .strip_prefix("Optional[")
+                .and_then(|type_| type_.strip_suffix(']'))
+        };
+
+        unwrapped.unwrap_or(type_)
     }
 }
 
@@ -48,7 +322,12 @@ impl jtd_codegen::target::Target for Target {
         target::Strategy {
             file_partitioning: target::FilePartitioningStrategy::SingleFile("__init__.py".into()),
             enum_member_naming: target::EnumMemberNamingStrategy::Modularized,
-            optional_property_handling: target::OptionalPropertyHandlingStrategy::WrapWithNullable,
+            // TypedDicts tell absent keys apart from null ones.
+            optional_property_handling: if self.typed_dict {
+                target::OptionalPropertyHandlingStrategy::NativeSupport
+            } else {
+                target::OptionalPropertyHandlingStrategy::WrapWithNullable
+            },
             booleans_are_nullable: false,
             int8s_are_nullable: false,
             uint8s_are_nullable: false,
@@ -71,7 +350,20 @@ impl jtd_codegen::target::Target for Target {
 
     fn name(&self, kind: target::NameableKind, parts: &[String]) -> String {
         match kind {
+            target::NameableKind::Type if self.validates() => {
+                // Validation declares a ValidationError alongside generated
+                // types.
+                let name = TYPE_NAMING_CONVENTION.inflect(parts);
+                if name == "ValidationError" {
+                    format!("{}_", name)
+                } else {
+                    name
+                }
+            }
             target::NameableKind::Type => TYPE_NAMING_CONVENTION.inflect(parts),
+            target::NameableKind::Field if self.pydantic && !self.typed_dict => {
+                PYDANTIC_FIELD_NAMING_CONVENTION.inflect(parts)
+            }
             target::NameableKind::Field => FIELD_NAMING_CONVENTION.inflect(parts),
             target::NameableKind::EnumMember => ENUM_MEMBER_NAMING_CONVENTION.inflect(parts),
         }
@@ -82,12 +374,12 @@ impl jtd_codegen::target::Target for Target {
         state: &mut FileState,
         metadata: metadata::Metadata,
         expr: target::Expr,
-    ) -> String {
+    ) -> Result<String> {
         if let Some(s) = metadata.get("pythonType").and_then(|v| v.as_str()) {
-            return s.into();
+            return Ok(s.into());
         }
 
-        match expr {
+        Ok(match expr {
             target::Expr::Empty => {
                 state
                     .imports
@@ -98,16 +390,22 @@ impl jtd_codegen::target::Target for Target {
                 "Any".into()
             }
             target::Expr::Boolean => "bool".into(),
-            target::Expr::Int8 => "int".into(),
-            target::Expr::Uint8 => "int".into(),
-            target::Expr::Int16 => "int".into(),
-            target::Expr::Uint16 => "int".into(),
-            target::Expr::Int32 => "int".into(),
-            target::Expr::Uint32 => "int".into(),
+            target::Expr::Int8 => self.int(state, i8::MIN.into(), i8::MAX.into()),
+            target::Expr::Uint8 => self.int(state, u8::MIN.into(), u8::MAX.into()),
+            target::Expr::Int16 => self.int(state, i16::MIN.into(), i16::MAX.into()),
+            target::Expr::Uint16 => self.int(state, u16::MIN.into(), u16::MAX.into()),
+            target::Expr::Int32 => self.int(state, i32::MIN.into(), i32::MAX.into()),
+            target::Expr::Uint32 => self.int(state, u32::MIN.into(), u32::MAX.into()),
             target::Expr::Float32 => "float".into(),
             target::Expr::Float64 => "float".into(),
             target::Expr::String => "str".into(),
+            target::Expr::Timestamp if self.typed_dict => "str".into(),
+            target::Expr::Timestamp if self.pydantic => {
+                state.import("pydantic", "AwareDatetime");
+                "AwareDatetime".into()
+            }
             target::Expr::Timestamp => "datetime".into(),
+            target::Expr::ArrayOf(sub_expr) if self.supports(9) => format!("list[{}]", sub_expr),
             target::Expr::ArrayOf(sub_expr) => {
                 state
                     .imports
@@ -117,6 +415,9 @@ impl jtd_codegen::target::Target for Target {
 
                 format!("List[{}]", sub_expr)
             }
+            target::Expr::DictOf(sub_expr) if self.supports(9) => {
+                format!("dict[str, {}]", sub_expr)
+            }
             target::Expr::DictOf(sub_expr) => {
                 state
                     .imports
@@ -126,6 +427,9 @@ impl jtd_codegen::target::Target for Target {
 
                 format!("Dict[str, {}]", sub_expr)
             }
+            target::Expr::NullableOf(sub_expr) if self.supports(10) => {
+                format!("{} | None", sub_expr)
+            }
             target::Expr::NullableOf(sub_expr) => {
                 state
                     .imports
@@ -135,7 +439,7 @@ impl jtd_codegen::target::Target for Target {
 
                 format!("Optional[{}]", sub_expr)
             }
-        }
+        })
     }
 
     fn item(
@@ -150,17 +454,33 @@ impl jtd_codegen::target::Target for Target {
                 None
             }
 
+            target::Item::Preamble if self.pydantic || self.typed_dict => {
+                self.import_backports(state);
+                write_preamble(out, state)?;
+
+                None
+            }
+
             target::Item::Preamble => {
                 state
                     .imports
                     .entry("typing".into())
                     .or_default()
-                    .extend(vec![
-                        "Any".into(),
-                        "Union".into(),
-                        "get_origin".into(),
-                        "get_args".into(),
-                    ]);
+                    .extend(vec!["Any".into(), "Union".into()]);
+
+                // Validation looks inside the Annotated types of integers.
+                if self.validates() {
+                    state.import("typing_extensions", "Annotated");
+                    state.import("typing_extensions", "get_origin");
+                    state.import("typing_extensions", "get_args");
+                } else {
+                    state.import("typing", "get_origin");
+                    state.import("typing", "get_args");
+                }
+
+                if self.supports(10) {
+                    state.import("types", "UnionType");
+                }
 
                 state
                     .imports
@@ -172,6 +492,8 @@ impl jtd_codegen::target::Target for Target {
                         "timezone".into(),
                     ]);
 
+                self.import_backports(state);
+
                 writeln!(
                     out,
                     "# Code generated by jtd-codegen for Python v{}",
@@ -179,6 +501,11 @@ impl jtd_codegen::target::Target for Target {
                 )?;
                 writeln!(out)?;
 
+                if self.supports(10) {
+                    writeln!(out, "from __future__ import annotations")?;
+                    writeln!(out)?;
+                }
+
                 // To avoid having to "import match from re" (which we use in
                 // _parse_rfc3339), we special-case this import.
                 writeln!(out, "import re")?;
@@ -196,29 +523,37 @@ impl jtd_codegen::target::Target for Target {
                 None
             }
 
+            // pydantic does the parsing and serializing that the helpers
+            // otherwise do, and TypedDicts don't need any.
+            target::Item::Postamble if self.pydantic || self.typed_dict => None,
+
             target::Item::Postamble => {
-                writeln!(out)?;
-                writeln!(out, "def _from_json_data(cls: Any, data: Any) -> Any:")?;
-                writeln!(
+                if self.validates() {
+                    validation::write_helpers(out, self.union_origin())?;
+                } else {
+                    writeln!(out)?;
+                    writeln!(out, "def _from_json_data(cls: Any, data: Any) -> Any:")?;
+                    writeln!(
                     out,
                     "    if data is None or cls in [bool, int, float, str, object] or cls is Any:"
                 )?;
-                writeln!(out, "        return data")?;
-                writeln!(out, "    if cls is datetime:")?;
-                writeln!(out, "        return _parse_rfc3339(data)")?;
-                writeln!(out, "    if get_origin(cls) is Union:")?;
-                writeln!(
-                    out,
-                    "        return _from_json_data(get_args(cls)[0], data)"
-                )?;
-                writeln!(out, "    if get_origin(cls) is list:")?;
-                writeln!(
-                    out,
-                    "        return [_from_json_data(get_args(cls)[0], d) for d in data]"
-                )?;
-                writeln!(out, "    if get_origin(cls) is dict:")?;
-                writeln!(out, "        return {{ k: _from_json_data(get_args(cls)[1], v) for k, v in data.items() }}")?;
-                writeln!(out, "    return cls.from_json_data(data)")?;
+                    writeln!(out, "        return data")?;
+                    writeln!(out, "    if cls is datetime:")?;
+                    writeln!(out, "        return _parse_rfc3339(data)")?;
+                    writeln!(out, "    if get_origin(cls) {}:", self.union_origin())?;
+                    writeln!(
+                        out,
+                        "        return _from_json_data(get_args(cls)[0], data)"
+                    )?;
+                    writeln!(out, "    if get_origin(cls) is list:")?;
+                    writeln!(
+                        out,
+                        "        return [_from_json_data(get_args(cls)[0], d) for d in data]"
+                    )?;
+                    writeln!(out, "    if get_origin(cls) is dict:")?;
+                    writeln!(out, "        return {{ k: _from_json_data(get_args(cls)[1], v) for k, v in data.items() }}")?;
+                    writeln!(out, "    return cls.from_json_data(data)")?;
+                }
                 writeln!(out)?;
                 writeln!(out, "def _to_json_data(data: Any) -> Any:")?;
                 writeln!(
@@ -262,7 +597,7 @@ impl jtd_codegen::target::Target for Target {
                 writeln!(out, "        frac_seconds_parsed = 0")?;
                 writeln!(out)?;
                 writeln!(out, "    tzinfo = None")?;
-                writeln!(out, "    if offset == 'Z':")?;
+                writeln!(out, "    if offset in ('Z', 'z'):")?;
                 writeln!(out, "        tzinfo = timezone.utc")?;
                 writeln!(out, "    else:")?;
                 writeln!(out, "        hours = int(tz[2])")?;
@@ -301,21 +636,35 @@ impl jtd_codegen::target::Target for Target {
                 name,
                 type_,
             } => {
-                state
-                    .imports
-                    .entry("dataclasses".into())
-                    .or_default()
-                    .insert("dataclass".into());
+                if self.typed_dict {
+                    typed_dict::write_alias(out, state, &metadata, &name, &type_)?;
+                    return Ok(None);
+                }
+
+                if self.pydantic {
+                    pydantic::write_alias(out, state, &metadata, &name, &type_)?;
+                    return Ok(None);
+                }
+
+                let dataclass = self.dataclass(state);
 
                 writeln!(out)?;
-                writeln!(out, "@dataclass")?;
+                writeln!(out, "{}", dataclass)?;
                 writeln!(out, "class {}:", name)?;
                 write!(out, "{}", description(&metadata, 1))?;
-                writeln!(out, "    value: '{}'", type_)?;
+                writeln!(out, "    value: {}", self.annotation(&type_))?;
                 writeln!(out)?;
                 writeln!(out, "    @classmethod")?;
-                writeln!(out, "    def from_json_data(cls, data: Any) -> '{}':", name)?;
-                writeln!(out, "        return cls(_from_json_data({}, data))", type_)?;
+                self.write_from_json_data_def(out, &name)?;
+                if self.validates() {
+                    writeln!(
+                        out,
+                        "        return cls(_from_json_data({}, data, path))",
+                        type_
+                    )?;
+                } else {
+                    writeln!(out, "        return cls(_from_json_data({}, data))", type_)?;
+                }
                 writeln!(out)?;
                 writeln!(out, "    def to_json_data(self) -> Any:")?;
                 writeln!(out, "        return _to_json_data(self.value)")?;
@@ -332,14 +681,21 @@ impl jtd_codegen::target::Target for Target {
                     return Ok(Some(s.into()));
                 }
 
-                state
-                    .imports
-                    .entry("enum".into())
-                    .or_default()
-                    .insert("Enum".into());
+                if self.typed_dict {
+                    typed_dict::write_enum(out, state, &metadata, &name, &members)?;
+                    return Ok(None);
+                }
+
+                if self.pydantic {
+                    let base = self.enum_base(state);
+                    pydantic::write_enum(out, &metadata, &name, base, &members)?;
+                    return Ok(None);
+                }
+
+                let base = self.enum_base(state);
 
                 writeln!(out)?;
-                writeln!(out, "class {}(Enum):", name)?;
+                writeln!(out, "class {}({}):", name, base)?;
                 write!(out, "{}", description(&metadata, 1))?;
                 for member in &members {
                     writeln!(out, "    {} = {:?}", member.name, member.json_value,)?;
@@ -350,7 +706,15 @@ impl jtd_codegen::target::Target for Target {
                     )?;
                 }
                 writeln!(out, "    @classmethod")?;
-                writeln!(out, "    def from_json_data(cls, data: Any) -> '{}':", name)?;
+                self.write_from_json_data_def(out, &name)?;
+                if self.validates() {
+                    writeln!(out, "        values = [member.value for member in cls]")?;
+                    writeln!(out, "        if data not in values:")?;
+                    writeln!(
+                        out,
+                        "            raise ValidationError(path, f'one of {{values}}')"
+                    )?;
+                }
                 writeln!(out, "        return cls(data)")?;
                 writeln!(out)?;
                 writeln!(out, "    def to_json_data(self) -> Any:")?;
@@ -362,49 +726,58 @@ impl jtd_codegen::target::Target for Target {
             target::Item::Struct {
                 metadata,
                 name,
-                has_additional: _,
+                has_additional,
                 fields,
             } => {
                 if let Some(s) = metadata.get("pythonType").and_then(|v| v.as_str()) {
                     return Ok(Some(s.into()));
                 }
 
-                state
-                    .imports
-                    .entry("dataclasses".into())
-                    .or_default()
-                    .insert("dataclass".into());
+                if self.typed_dict {
+                    typed_dict::write_struct(out, state, &metadata, &name, &fields)?;
+                    return Ok(None);
+                }
 
-                state
-                    .imports
-                    .entry("typing".into())
-                    .or_default()
-                    .extend(vec!["Dict".into(), "Optional".into()]);
+                if self.pydantic {
+                    pydantic::write_struct(out, state, &metadata, &name, has_additional, &fields)?;
+                    return Ok(None);
+                }
+
+                let dataclass = self.dataclass(state);
+
+                if !self.supports(9) {
+                    state
+                        .imports
+                        .entry("typing".into())
+                        .or_default()
+                        .extend(vec!["Dict".into(), "Optional".into()]);
+                }
 
                 writeln!(out)?;
-                writeln!(out, "@dataclass")?;
+                writeln!(out, "{}", dataclass)?;
                 writeln!(out, "class {}:", name)?;
                 write!(out, "{}", description(&metadata, 1))?;
                 for field in &fields {
-                    writeln!(out, "    {}: '{}'", field.name, field.type_,)?;
+                    writeln!(out, "    {}: {}", field.name, self.annotation(&field.type_))?;
                     write!(out, "{}", description(&field.metadata, 1))?;
                 }
 
                 writeln!(out)?;
                 writeln!(out, "    @classmethod")?;
-                writeln!(out, "    def from_json_data(cls, data: Any) -> '{}':", name)?;
+                self.write_from_json_data_def(out, &name)?;
+                self.write_object_check(out, &name)?;
                 writeln!(out, "        return cls(")?;
                 for field in &fields {
-                    writeln!(
-                        out,
-                        "            _from_json_data({}, data.get({:?})),",
-                        field.type_, field.json_name
-                    )?;
+                    writeln!(out, "            {},", self.field_from_json_data(field))?;
                 }
                 writeln!(out, "        )")?;
                 writeln!(out)?;
                 writeln!(out, "    def to_json_data(self) -> Any:")?;
-                writeln!(out, "        data: Dict[str, Any] = {{}}")?;
+                if self.supports(9) {
+                    writeln!(out, "        data: dict[str, Any] = {{}}")?;
+                } else {
+                    writeln!(out, "        data: Dict[str, Any] = {{}}")?;
+                }
                 for field in &fields {
                     if field.optional {
                         writeln!(out, "        if self.{} is not None:", field.name)?;
@@ -437,27 +810,49 @@ impl jtd_codegen::target::Target for Target {
                     return Ok(Some(s.into()));
                 }
 
-                state
-                    .imports
-                    .entry("dataclasses".into())
-                    .or_default()
-                    .insert("dataclass".into());
+                if self.typed_dict {
+                    let union = self.union(state, &variant_names(&variants));
+                    typed_dict::write_discriminator(out, state, &metadata, &name, &union)?;
+                    return Ok(None);
+                }
 
-                state
-                    .imports
-                    .entry("typing".into())
-                    .or_default()
-                    .extend(vec!["Type".into(), "Dict".into()]);
+                if self.pydantic {
+                    let union = self.union(state, &variant_names(&variants));
+                    pydantic::write_discriminator(
+                        out,
+                        state,
+                        &metadata,
+                        &name,
+                        &tag_field_name,
+                        &union,
+                    )?;
+                    return Ok(None);
+                }
+
+                let dataclass = self.dataclass(state);
+
+                if !self.supports(9) {
+                    state
+                        .imports
+                        .entry("typing".into())
+                        .or_default()
+                        .extend(vec!["Type".into(), "Dict".into()]);
+                }
 
                 writeln!(out)?;
-                writeln!(out, "@dataclass")?;
+                writeln!(out, "{}", dataclass)?;
                 writeln!(out, "class {}:", name)?;
                 write!(out, "{}", description(&metadata, 1))?;
-                writeln!(out, "    {}: 'str'", tag_field_name)?;
+                writeln!(out, "    {}: {}", tag_field_name, self.annotation("str"))?;
                 writeln!(out)?;
                 writeln!(out, "    @classmethod")?;
-                writeln!(out, "    def from_json_data(cls, data: Any) -> '{}':", name)?;
-                writeln!(out, "        variants: Dict[str, Type[{}]] = {{", name)?;
+                self.write_from_json_data_def(out, &name)?;
+                self.write_object_check(out, &name)?;
+                if self.supports(9) {
+                    writeln!(out, "        variants: dict[str, type[{}]] = {{", name)?;
+                } else {
+                    writeln!(out, "        variants: Dict[str, Type[{}]] = {{", name)?;
+                }
                 for variant in &variants {
                     writeln!(
                         out,
@@ -467,11 +862,29 @@ impl jtd_codegen::target::Target for Target {
                 }
                 writeln!(out, "        }}")?;
                 writeln!(out)?;
-                writeln!(
-                    out,
-                    "        return variants[data[{:?}]].from_json_data(data)",
-                    tag_json_name
-                )?;
+                if self.validates() {
+                    writeln!(out, "        tag = data.get({:?})", tag_json_name)?;
+                    writeln!(
+                        out,
+                        "        if type(tag) is not str or tag not in variants:"
+                    )?;
+                    writeln!(
+                        out,
+                        "            raise ValidationError({}, f'one of {{list(variants)}}')",
+                        validation::path(&tag_json_name)
+                    )?;
+                    writeln!(out)?;
+                    writeln!(
+                        out,
+                        "        return variants[tag].from_json_data(data, path)"
+                    )?;
+                } else {
+                    writeln!(
+                        out,
+                        "        return variants[data[{:?}]].from_json_data(data)",
+                        tag_json_name
+                    )?;
+                }
                 writeln!(out)?;
                 writeln!(out, "    def to_json_data(self) -> Any:")?;
                 writeln!(out, "        pass")?;
@@ -482,41 +895,75 @@ impl jtd_codegen::target::Target for Target {
                 metadata,
                 name,
                 parent_name,
+                tag_field_name,
                 tag_json_name,
                 tag_value,
+                has_additional,
                 fields,
-                ..
             } => {
                 if let Some(s) = metadata.get("pythonType").and_then(|v| v.as_str()) {
                     return Ok(Some(s.into()));
                 }
 
-                state
-                    .imports
-                    .entry("dataclasses".into())
-                    .or_default()
-                    .insert("dataclass".into());
+                if self.typed_dict {
+                    typed_dict::write_discriminator_variant(
+                        out,
+                        state,
+                        &metadata,
+                        &name,
+                        (&tag_json_name, &tag_value),
+                        &fields,
+                    )?;
+                    return Ok(None);
+                }
+
+                if self.pydantic {
+                    pydantic::write_discriminator_variant(
+                        out,
+                        state,
+                        &metadata,
+                        &name,
+                        has_additional,
+                        (&tag_field_name, &tag_json_name, &tag_value),
+                        &fields,
+                    )?;
+                    return Ok(None);
+                }
+
+                let dataclass = self.dataclass(state);
 
                 writeln!(out)?;
-                writeln!(out, "@dataclass")?;
+                writeln!(out, "{}", dataclass)?;
                 writeln!(out, "class {}({}):", name, parent_name)?;
                 write!(out, "{}", description(&metadata, 1))?;
                 for field in &fields {
-                    writeln!(out, "    {}: '{}'", field.name, field.type_,)?;
+                    writeln!(out, "    {}: {}", field.name, self.annotation(&field.type_))?;
                     write!(out, "{}", description(&field.metadata, 1))?;
                 }
 
+                // The class already says what the tag is, so patterns only
+                // match positionally against the other fields.
+                if self.supports(10) {
+                    let names: Vec<_> = fields
+                        .iter()
+                        .map(|field| format!("{:?}", field.name))
+                        .collect();
+
+                    // A tuple of one needs a trailing comma.
+                    let comma = if names.len() == 1 { "," } else { "" };
+
+                    writeln!(out)?;
+                    writeln!(out, "    __match_args__ = ({}{})", names.join(", "), comma)?;
+                }
+
                 writeln!(out)?;
                 writeln!(out, "    @classmethod")?;
-                writeln!(out, "    def from_json_data(cls, data: Any) -> '{}':", name)?;
+                self.write_from_json_data_def(out, &name)?;
+                self.write_object_check(out, &name)?;
                 writeln!(out, "        return cls(")?;
                 writeln!(out, "            {:?},", tag_value)?;
                 for field in &fields {
-                    writeln!(
-                        out,
-                        "            _from_json_data({}, data.get({:?})),",
-                        field.type_, field.json_name
-                    )?;
+                    writeln!(out, "            {},", self.field_from_json_data(field))?;
                 }
                 writeln!(out, "        )")?;
                 writeln!(out)?;
@@ -555,6 +1002,48 @@ pub struct FileState {
     imports: BTreeMap<String, BTreeSet<String>>,
 }
 
+impl FileState {
+    fn import(&mut self, module: &str, ident: &str) {
+        self.imports
+            .entry(module.into())
+            .or_default()
+            .insert(ident.into());
+    }
+}
+
+// Writes out the preamble of pydantic models and TypedDicts, which defer
+// evaluating annotations so that types can refer to ones later in the file.
+fn write_preamble(out: &mut dyn Write, state: &FileState) -> Result<()> {
+    writeln!(
+        out,
+        "# Code generated by jtd-codegen for Python v{}",
+        env!("CARGO_PKG_VERSION")
+    )?;
+    writeln!(out)?;
+    writeln!(out, "from __future__ import annotations")?;
+    writeln!(out)?;
+
+    for (module, idents) in &state.imports {
+        writeln!(
+            out,
+            "from {} import {}",
+            module,
+            idents.iter().cloned().collect::<Vec<_>>().join(", ")
+        )?;
+    }
+
+    writeln!(out)?;
+
+    Ok(())
+}
+
+fn variant_names(variants: &[target::DiscriminatorVariantInfo]) -> Vec<String> {
+    variants
+        .iter()
+        .map(|variant| variant.type_name.clone())
+        .collect()
+}
+
 fn description(metadata: &BTreeMap<String, Value>, indent: usize) -> String {
     doc(indent, jtd_codegen::target::metadata::description(metadata))
 }
@@ -586,12 +1075,53 @@ fn doc(ident: usize, s: &str) -> String {
     }
 }
 
+fn comment(ident: usize, s: &str) -> String {
+    let prefix = "    ".repeat(ident);
+    jtd_codegen::target::fmt::comment_block("", &format!("{}# ", prefix), "", s)
+}
+
 #[cfg(test)]
 mod tests {
     mod std_tests {
         jtd_codegen_test::std_test_cases!(&crate::Target::new());
     }
 
+    mod pydantic_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/pydantic"),
+            &crate::Target::new().with_pydantic(true)
+        );
+    }
+
+    mod typed_dict_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/typed_dict"),
+            &crate::Target::new().with_typed_dict(true)
+        );
+
+        jtd_codegen_test::strict_std_test_case!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/typed_dict"),
+            &crate::Target::new().with_typed_dict(true),
+            empty_and_nonascii_properties
+        );
+    }
+
+    mod validation_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/validation"),
+            &crate::Target::new().with_validation(true)
+        );
+    }
+
+    mod modern_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/modern"),
+            &crate::Target::new()
+                .with_python_version((3, 11))
+                .with_frozen(true)
+        );
+    }
+
     mod optional_std_tests {
         jtd_codegen_test::strict_std_test_case!(
             &crate::Target::new(),
@@ -603,4 +1133,84 @@ mod tests {
             empty_and_nonascii_enum_values
         );
     }
+
+    #[test]
+    fn test_validation_violations() {
+        let schema = serde_json::json!({
+            "properties": {
+                "name": { "type": "string" },
+                "at": { "type": "timestamp" }
+            },
+            "optionalProperties": {
+                "nickname": { "type": "string" },
+                "age": { "type": "uint8", "nullable": true }
+            }
+        });
+
+        let output = jtd_codegen_test::docker_output(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/validation/violations"),
+            &crate::Target::new().with_validation(true),
+            schema,
+            concat!(
+                "{\"name\":\"a\",\"at\":\"2020-01-01T00:00:00z\"}\n",
+                "{\"name\":\"a\",\"at\":\"2020-01-01T00:00:00Z\",\"age\":null}\n",
+                "{\"at\":\"2020-01-01T00:00:00Z\"}\n",
+                "{\"name\":\"a\",\"at\":\"2020-01-01T00:00:00Z\",\"nickname\":null}\n",
+            ),
+        );
+
+        let expected = concat!(
+            "null\n",
+            "null\n",
+            "[\"/name\", \"'/name': missing required property \\\"name\\\"\"]\n",
+            "[\"/nickname\", \"'/nickname': expected string\"]\n",
+        );
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_typed_dict_mypy() {
+        let schema = serde_json::json!({
+            "discriminator": "type",
+            "mapping": {
+                "foo": {
+                    "properties": { "name": { "type": "string" }},
+                    "optionalProperties": { "tags": { "elements": { "type": "string" }}}
+                },
+                "bar": {
+                    "properties": { "count": { "type": "int32" }}
+                }
+            }
+        });
+
+        let output = jtd_codegen_test::docker_output(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/typed_dict/mypy"),
+            &crate::Target::new().with_typed_dict(true),
+            schema,
+            "",
+        );
+
+        // mypy reports errors as "file:line: error: message  [code]".
+        let errors: Vec<String> = output
+            .lines()
+            .filter_map(|line| {
+                let (location, message) = line.split_once(": error: ")?;
+                let code = message.rsplit_once('[')?.1.trim_end_matches(']');
+                Some(format!("{}: {}", location, code))
+            })
+            .collect();
+
+        let check = include_str!("../typed_dict/mypy/check.py");
+        let expected: Vec<String> = check
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let code = line.split_once("# expect: ")?.1;
+                Some(format!("check.py:{}: {}", i + 1, code))
+            })
+            .collect();
+
+        assert!(!expected.is_empty());
+        assert_eq!(expected, errors, "mypy output:\n{}", output);
+    }
 }
@@ -0,0 +1,176 @@
+// Writes out the pydantic models that Target::with_pydantic enables.
+//
+// Every generated type is a class, and annotations are deferred with
+// `from __future__ import annotations`, so types can refer to ones that come
+// later in the file. For the same reason, the types RootModels wrap are
+// quoted.
+
+use crate::{description, enum_variant_description, FileState};
+use jtd_codegen::target::{self, metadata};
+use jtd_codegen::Result;
+use std::io::Write;
+
+pub fn write_alias(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    type_: &str,
+) -> Result<()> {
+    write_root_model(out, state, metadata, name, type_)
+}
+
+pub fn write_enum(
+    out: &mut dyn Write,
+    metadata: &metadata::Metadata,
+    name: &str,
+    base: &str,
+    members: &[target::EnumMember],
+) -> Result<()> {
+    writeln!(out)?;
+    writeln!(out, "class {}({}):", name, base)?;
+    write!(out, "{}", description(metadata, 1))?;
+    for member in members {
+        writeln!(out, "    {} = {:?}", member.name, member.json_value)?;
+        write!(
+            out,
+            "{}",
+            enum_variant_description(metadata, 1, &member.json_value)
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn write_struct(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    has_additional: bool,
+    fields: &[target::Field],
+) -> Result<()> {
+    write_model(out, state, metadata, name, has_additional, None, fields)
+}
+
+pub fn write_discriminator(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    tag_field_name: &str,
+    union: &str,
+) -> Result<()> {
+    state.import("pydantic", "Field");
+    state.import("typing_extensions", "Annotated");
+
+    let type_ = format!(
+        "Annotated[{}, Field(discriminator={:?})]",
+        union, tag_field_name
+    );
+
+    write_root_model(out, state, metadata, name, &type_)
+}
+
+pub fn write_discriminator_variant(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    has_additional: bool,
+    tag: (&str, &str, &str),
+    fields: &[target::Field],
+) -> Result<()> {
+    write_model(
+        out,
+        state,
+        metadata,
+        name,
+        has_additional,
+        Some(tag),
+        fields,
+    )
+}
+
+fn write_root_model(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    type_: &str,
+) -> Result<()> {
+    state.import("pydantic", "RootModel");
+
+    let description = description(metadata, 1);
+
+    writeln!(out)?;
+    writeln!(out, "class {}(RootModel['{}']):", name, type_)?;
+    if description.is_empty() {
+        writeln!(out, "    pass")?;
+    } else {
+        write!(out, "{}", description)?;
+    }
+
+    Ok(())
+}
+
+// Writes out a BaseModel. Discriminator variants have a tag, which is the name
+// and JSON name of their tag field and their tag value.
+fn write_model(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    has_additional: bool,
+    tag: Option<(&str, &str, &str)>,
+    fields: &[target::Field],
+) -> Result<()> {
+    state.import("pydantic", "BaseModel");
+    state.import("pydantic", "ConfigDict");
+
+    writeln!(out)?;
+    writeln!(out, "class {}(BaseModel):", name)?;
+    write!(out, "{}", description(metadata, 1))?;
+
+    // Fields are populated by their JSON name when validating JSON, but by
+    // their own name when constructing a model in Python.
+    writeln!(
+        out,
+        "    model_config = ConfigDict(extra={:?}, populate_by_name=True)",
+        if has_additional { "allow" } else { "forbid" }
+    )?;
+
+    if tag.is_some() || !fields.is_empty() {
+        state.import("pydantic", "Field");
+        writeln!(out)?;
+    }
+
+    if let Some((tag_field_name, tag_json_name, tag_value)) = tag {
+        state.import("typing", "Literal");
+        writeln!(
+            out,
+            "    {}: Literal[{:?}] = Field(alias={:?})",
+            tag_field_name, tag_value, tag_json_name
+        )?;
+    }
+
+    for field in fields {
+        if field.optional {
+            writeln!(
+                out,
+                "    {}: {} = Field(default=None, alias={:?})",
+                field.name, field.type_, field.json_name
+            )?;
+        } else {
+            writeln!(
+                out,
+                "    {}: {} = Field(alias={:?})",
+                field.name, field.type_, field.json_name
+            )?;
+        }
+
+        write!(out, "{}", description(&field.metadata, 1))?;
+    }
+
+    Ok(())
+}
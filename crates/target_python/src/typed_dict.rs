@@ -0,0 +1,177 @@
+// Writes out the TypedDicts that Target::with_typed_dict enables.
+//
+// These are types for the plain values json.loads returns, so there's no code
+// to write, only types. Aliases refer to types with a string, so that they can
+// refer to types that come later in the file.
+
+use crate::{comment, description, doc, FileState, KEYWORDS};
+use jtd_codegen::target::{self, metadata};
+use jtd_codegen::Result;
+use std::io::Write;
+
+pub fn write_alias(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    type_: &str,
+) -> Result<()> {
+    state.import("typing_extensions", "TypeAlias");
+
+    writeln!(out)?;
+    writeln!(out, "{}: TypeAlias = {:?}", name, type_)?;
+    write!(out, "{}", description(metadata, 0))?;
+
+    Ok(())
+}
+
+pub fn write_enum(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    members: &[target::EnumMember],
+) -> Result<()> {
+    state.import("typing", "Literal");
+    state.import("typing_extensions", "TypeAlias");
+
+    let described = members
+        .iter()
+        .any(|member| !metadata::enum_variant_description(metadata, &member.json_value).is_empty());
+
+    writeln!(out)?;
+    if !described {
+        let values: Vec<_> = members
+            .iter()
+            .map(|member| format!("{:?}", member.json_value))
+            .collect();
+
+        writeln!(out, "{}: TypeAlias = Literal[{}]", name, values.join(", "))?;
+    } else {
+        // Docstrings can't go inside Literal, so members are described with
+        // comments instead.
+        writeln!(out, "{}: TypeAlias = Literal[", name)?;
+        for member in members {
+            write!(
+                out,
+                "{}",
+                comment(
+                    1,
+                    metadata::enum_variant_description(metadata, &member.json_value)
+                )
+            )?;
+            writeln!(out, "    {:?},", member.json_value)?;
+        }
+        writeln!(out, "]")?;
+    }
+    write!(out, "{}", description(metadata, 0))?;
+
+    Ok(())
+}
+
+pub fn write_struct(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    fields: &[target::Field],
+) -> Result<()> {
+    write_typed_dict(out, state, metadata, name, None, fields)
+}
+
+pub fn write_discriminator(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    union: &str,
+) -> Result<()> {
+    write_alias(out, state, metadata, name, union)
+}
+
+pub fn write_discriminator_variant(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    tag: (&str, &str),
+    fields: &[target::Field],
+) -> Result<()> {
+    write_typed_dict(out, state, metadata, name, Some(tag), fields)
+}
+
+// Writes out a TypedDict. Discriminator variants have a tag, which is the JSON
+// name of their tag field and their tag value.
+fn write_typed_dict(
+    out: &mut dyn Write,
+    state: &mut FileState,
+    metadata: &metadata::Metadata,
+    name: &str,
+    tag: Option<(&str, &str)>,
+    fields: &[target::Field],
+) -> Result<()> {
+    state.import("typing_extensions", "TypedDict");
+
+    // The keys of a TypedDict, and their types and descriptions.
+    let mut keys = Vec::new();
+
+    if let Some((tag_json_name, tag_value)) = tag {
+        state.import("typing", "Literal");
+        keys.push((
+            tag_json_name.to_owned(),
+            format!("Literal[{:?}]", tag_value),
+            "",
+        ));
+    }
+
+    for field in fields {
+        let type_ = if field.optional {
+            state.import("typing_extensions", "NotRequired");
+            format!("NotRequired[{}]", field.type_)
+        } else {
+            field.type_.clone()
+        };
+
+        keys.push((
+            field.json_name.clone(),
+            type_,
+            metadata::description(&field.metadata),
+        ));
+    }
+
+    writeln!(out)?;
+
+    // Keys that aren't identifiers can only be declared with the functional
+    // syntax, where types are expressions rather than annotations.
+    if keys.iter().all(|(key, _, _)| is_identifier(key)) {
+        let description = description(metadata, 1);
+
+        writeln!(out, "class {}(TypedDict):", name)?;
+        write!(out, "{}", description)?;
+        for (key, type_, description) in &keys {
+            writeln!(out, "    {}: {}", key, type_)?;
+            write!(out, "{}", doc(1, description))?;
+        }
+
+        if keys.is_empty() && description.is_empty() {
+            writeln!(out, "    pass")?;
+        }
+    } else {
+        writeln!(out, "{} = TypedDict({:?}, {{", name, name)?;
+        for (key, type_, description) in &keys {
+            write!(out, "{}", comment(1, description))?;
+            writeln!(out, "    {:?}: {:?},", key, type_)?;
+        }
+        writeln!(out, "}})")?;
+        write!(out, "{}", description(metadata, 0))?;
+    }
+
+    Ok(())
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    let starts_well = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_');
+
+    starts_well && chars.all(|c| c.is_ascii_alphanumeric() || c == '_') && !KEYWORDS.contains(s)
+}
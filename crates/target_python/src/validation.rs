@@ -0,0 +1,153 @@
+// Writes out the helpers that Target::with_validation enables.
+//
+// from_json_data checks data against the runtime types it's given, the same way
+// it converts data without validation. Integers carry their range in an
+// Annotated type.
+
+use jtd_codegen::Result;
+use std::io::Write;
+
+// The JSON Pointer of the value of key in the object at path.
+pub fn path(key: &str) -> String {
+    format!(
+        "path + {:?}",
+        format!("/{}", key.replace('~', "~0").replace('/', "~1"))
+    )
+}
+
+// union_origin is how a nullable type is recognized, as in Target::union_origin.
+pub fn write_helpers(out: &mut dyn Write, union_origin: &str) -> Result<()> {
+    writeln!(out)?;
+    writeln!(out, "class ValidationError(ValueError):")?;
+    writeln!(out, "    \"\"\"")?;
+    writeln!(
+        out,
+        "    Raised by from_json_data when data doesn't satisfy its schema. path is a"
+    )?;
+    writeln!(
+        out,
+        "    JSON Pointer to the bad value, and expected describes what it should be."
+    )?;
+    writeln!(
+        out,
+        "    If a required property is missing, path points to where it should be."
+    )?;
+    writeln!(out, "    \"\"\"")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    def __init__(self, path: str, expected: str, missing: bool = False) -> None:"
+    )?;
+    writeln!(out, "        if missing:")?;
+    writeln!(
+        out,
+        "            key = path.rsplit('/', 1)[-1].replace('~1', '/').replace('~0', '~')"
+    )?;
+    writeln!(
+        out,
+        "            super().__init__(f'{{path!r}}: missing required property \"{{key}}\"')"
+    )?;
+    writeln!(out, "        else:")?;
+    writeln!(
+        out,
+        "            super().__init__(f'{{path!r}}: expected {{expected}}')"
+    )?;
+    writeln!(out, "        self.path = path")?;
+    writeln!(out, "        self.expected = expected")?;
+    writeln!(out)?;
+    writeln!(out, "# The value of a property that's missing.")?;
+    writeln!(out, "_MISSING = object()")?;
+    writeln!(out)?;
+    writeln!(out, "def _optional(cls: Any, data: Any, path: str) -> Any:")?;
+    writeln!(out, "    if data is _MISSING:")?;
+    writeln!(out, "        return None")?;
+    writeln!(out, "    return _from_json_data(cls, data, path)")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "def _from_json_data(cls: Any, data: Any, path: str) -> Any:"
+    )?;
+    writeln!(out, "    if data is _MISSING:")?;
+    writeln!(
+        out,
+        "        raise ValidationError(path, _expected(cls), missing=True)"
+    )?;
+    writeln!(out, "    if cls is Any or cls is object:")?;
+    writeln!(out, "        return data")?;
+    writeln!(out, "    if get_origin(cls) {}:", union_origin)?;
+    writeln!(out, "        if data is None:")?;
+    writeln!(out, "            return None")?;
+    writeln!(
+        out,
+        "        return _from_json_data(get_args(cls)[0], data, path)"
+    )?;
+    writeln!(out, "    if get_origin(cls) is Annotated:")?;
+    writeln!(out, "        (lo, hi) = get_args(cls)[1]")?;
+    writeln!(out, "        if type(data) is float and data.is_integer():")?;
+    writeln!(out, "            data = int(data)")?;
+    writeln!(out, "        if type(data) is int and lo <= data <= hi:")?;
+    writeln!(out, "            return data")?;
+    writeln!(out, "    elif cls is bool:")?;
+    writeln!(out, "        if type(data) is bool:")?;
+    writeln!(out, "            return data")?;
+    writeln!(out, "    elif cls is int:")?;
+    writeln!(out, "        if type(data) is int:")?;
+    writeln!(out, "            return data")?;
+    writeln!(out, "    elif cls is float:")?;
+    writeln!(out, "        if type(data) in [int, float]:")?;
+    writeln!(out, "            return data")?;
+    writeln!(out, "    elif cls is str:")?;
+    writeln!(out, "        if type(data) is str:")?;
+    writeln!(out, "            return data")?;
+    writeln!(out, "    elif cls is datetime:")?;
+    writeln!(out, "        if type(data) is str:")?;
+    writeln!(out, "            try:")?;
+    writeln!(out, "                return _parse_rfc3339(data)")?;
+    writeln!(out, "            except ValueError:")?;
+    writeln!(out, "                pass")?;
+    writeln!(out, "    elif get_origin(cls) is list:")?;
+    writeln!(out, "        if type(data) is list:")?;
+    writeln!(
+        out,
+        "            return [_from_json_data(get_args(cls)[0], d, f'{{path}}/{{i}}') for i, d in enumerate(data)]"
+    )?;
+    writeln!(out, "    elif get_origin(cls) is dict:")?;
+    writeln!(out, "        if type(data) is dict:")?;
+    writeln!(
+        out,
+        "            return {{ k: _from_json_data(get_args(cls)[1], v, _path(path, k)) for k, v in data.items() }}"
+    )?;
+    writeln!(out, "    else:")?;
+    writeln!(out, "        return cls.from_json_data(data, path)")?;
+    writeln!(out, "    raise ValidationError(path, _expected(cls))")?;
+    writeln!(out)?;
+    writeln!(out, "def _expected(cls: Any) -> str:")?;
+    writeln!(out, "    if get_origin(cls) {}:", union_origin)?;
+    writeln!(
+        out,
+        "        return f'{{_expected(get_args(cls)[0])}} or null'"
+    )?;
+    writeln!(out, "    if get_origin(cls) is Annotated:")?;
+    writeln!(out, "        (lo, hi) = get_args(cls)[1]")?;
+    writeln!(out, "        return f'integer from {{lo}} to {{hi}}'")?;
+    writeln!(out, "    if get_origin(cls) is list:")?;
+    writeln!(out, "        return 'array'")?;
+    writeln!(out, "    if get_origin(cls) is dict:")?;
+    writeln!(out, "        return 'object'")?;
+    writeln!(
+        out,
+        "    names = {{ bool: 'boolean', float: 'number', str: 'string', datetime: 'timestamp' }}"
+    )?;
+    writeln!(
+        out,
+        "    return names.get(cls) or str(getattr(cls, '__name__', cls))"
+    )?;
+    writeln!(out)?;
+    writeln!(out, "def _path(path: str, key: str) -> str:")?;
+    writeln!(
+        out,
+        "    return path + '/' + key.replace('~', '~0').replace('/', '~1')"
+    )?;
+
+    Ok(())
+}
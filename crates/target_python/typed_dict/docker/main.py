@@ -0,0 +1,7 @@
+import json
+import sys
+from gen import MAIN
+
+for line in sys.stdin:
+    value: MAIN = json.loads(line)
+    print(json.dumps(value))
@@ -0,0 +1,33 @@
+# Uses of the TypedDicts generated for test_typed_dict_mypy. mypy --strict has
+# to accept the first few, and report each line marked "expect: <code>" with
+# that error code.
+from typing import List
+
+from gen import Root, RootBar, RootFoo
+
+
+def build() -> Root:
+    return {"type": "foo", "name": "a", "tags": ["b"]}
+
+
+def narrow(root: Root) -> str:
+    if root["type"] == "foo":
+        return root["name"]
+
+    return str(root["count"])
+
+
+def tags(foo: RootFoo) -> List[str]:
+    return foo.get("tags", [])
+
+
+def missing_key() -> RootBar:
+    return {"type": "bar"}  # expect: typeddict-item
+
+
+def wrong_type(foo: RootFoo) -> int:
+    return foo["name"]  # expect: return-value
+
+
+def unknown_key(foo: RootFoo) -> object:
+    return foo["nickname"]  # expect: typeddict-item
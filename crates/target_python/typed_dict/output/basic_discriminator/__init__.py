@@ -0,0 +1,17 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Literal, Union
+from typing_extensions import TypeAlias, TypedDict
+
+
+Root: TypeAlias = "Union[RootBarBaz, RootQuux]"
+
+class RootBarBaz(TypedDict):
+    foo: Literal["BAR_BAZ"]
+    baz: str
+
+class RootQuux(TypedDict):
+    foo: Literal["QUUX"]
+    quuz: str
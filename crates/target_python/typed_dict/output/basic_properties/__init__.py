@@ -0,0 +1,13 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import List
+from typing_extensions import TypedDict
+
+
+class Root(TypedDict):
+    bar: str
+    baz: List[bool]
+    foo: bool
+    quux: List[bool]
@@ -0,0 +1,15 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Dict, List
+from typing_extensions import TypedDict
+
+
+class Root(TypedDict):
+    override_elements_container: List[str]
+    override_type_discriminator: object
+    override_type_enum: object
+    override_type_expr: object
+    override_type_properties: object
+    override_values_container: Dict[str, str]
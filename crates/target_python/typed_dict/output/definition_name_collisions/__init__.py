@@ -0,0 +1,16 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing_extensions import TypeAlias
+
+
+Root: TypeAlias = "Foo0"
+
+Bar: TypeAlias = "Bar0"
+
+Foo: TypeAlias = "Bar"
+
+Bar0: TypeAlias = "str"
+
+Foo0: TypeAlias = "Foo"
@@ -0,0 +1,81 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Literal, Union
+from typing_extensions import TypeAlias, TypedDict
+
+
+RootDiscriminatorWithDescription: TypeAlias = "Union[RootDiscriminatorWithDescriptionBar]"
+"""
+A description for discriminator
+"""
+
+
+class RootDiscriminatorWithDescriptionBar(TypedDict):
+    """
+    A description for discriminator variant
+    """
+
+    foo: Literal["bar"]
+
+RootEnumWithDescription: TypeAlias = Literal[
+    # A description for X
+    "X",
+    # A description for Y
+    "Y",
+    # A description for Z
+    "Z",
+]
+"""
+A description for enum
+"""
+
+
+class RootPropertiesWithDescription(TypedDict):
+    """
+    A description for properties
+    """
+
+
+class Root(TypedDict):
+    discriminator_with_description: RootDiscriminatorWithDescription
+    """
+    A description for discriminator
+    """
+
+    enum_with_description: RootEnumWithDescription
+    """
+    A description for enum
+    """
+
+    long_description: str
+    """
+    Whereas disregard and contempt for human rights have resulted in barbarous
+    acts which have outraged the conscience of mankind, and the advent of a
+    world in which human beings shall enjoy freedom of speech and belief and
+    freedom from fear and want has been proclaimed as the highest aspiration of
+    the common people,
+    """
+
+    properties_with_description: RootPropertiesWithDescription
+    """
+    A description for properties
+    """
+
+    ref_with_description: Baz
+    """
+    A description for ref
+    """
+
+    string_with_description: str
+    """
+    A description for string
+    """
+
+
+Baz: TypeAlias = "str"
+"""
+A description for a definition
+"""
+
@@ -0,0 +1,14 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import List, Literal, Union
+from typing_extensions import NotRequired, TypeAlias, TypedDict
+
+
+Root: TypeAlias = "Union[RootBar]"
+
+class RootBar(TypedDict):
+    foo: Literal["bar"]
+    baz: NotRequired[List[str]]
+    quux: NotRequired[bool]
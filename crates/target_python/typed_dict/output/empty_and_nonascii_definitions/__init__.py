@@ -0,0 +1,24 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing_extensions import TypeAlias
+
+
+Root: TypeAlias = "str"
+
+DefaultName: TypeAlias = "str"
+
+Foo: TypeAlias = "str"
+
+Foo0: TypeAlias = "str"
+
+Foo1: TypeAlias = "str"
+
+FooBar: TypeAlias = "str"
+
+FooBar0: TypeAlias = "str"
+
+Foo0bar: TypeAlias = "str"
+
+FooBar1: TypeAlias = "str"
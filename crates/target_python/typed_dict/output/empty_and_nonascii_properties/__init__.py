@@ -0,0 +1,17 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing_extensions import TypedDict
+
+
+Root = TypedDict("Root", {
+    "": "str",
+    "$foo": "str",
+    "0foo": "str",
+    "_foo": "str",
+    "foo\nbar": "str",
+    "foo bar": "str",
+    "foo0bar": "str",
+    "foo﷽bar": "str",
+})
@@ -0,0 +1,18 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Literal
+from typing_extensions import TypeAlias, TypedDict
+
+
+RootFooBar: TypeAlias = Literal["x", "y"]
+
+class RootFoo(TypedDict):
+    bar: RootFooBar
+
+RootFooBar0: TypeAlias = Literal["x", "y"]
+
+class Root(TypedDict):
+    foo: RootFoo
+    foo_bar: RootFooBar0
@@ -0,0 +1,18 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing_extensions import TypedDict
+
+
+class RootNestedIDInitialism(TypedDict):
+    json: str
+    normalword: str
+
+class Root(TypedDict):
+    http: str
+    id: str
+    nested_id_initialism: RootNestedIDInitialism
+    utf8: str
+    word_with_embedded_id_initialism: str
+    word_with_trailing_initialism_id: str
@@ -0,0 +1,15 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing_extensions import TypeAlias, TypedDict
+
+
+Root = TypedDict("Root", {
+    "for": "For",
+    "object": "Object",
+})
+
+For: TypeAlias = "str"
+
+Object: TypeAlias = "str"
@@ -0,0 +1,19 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Literal, Optional, Union
+from typing_extensions import TypeAlias, TypedDict
+
+
+Root0: TypeAlias = "Union[RootBar, RootQuux]"
+
+class RootBar(TypedDict):
+    foo: Literal["bar"]
+    baz: str
+
+class RootQuux(TypedDict):
+    foo: Literal["quux"]
+    quuz: str
+
+Root: TypeAlias = "Optional[Root0]"
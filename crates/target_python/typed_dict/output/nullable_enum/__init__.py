@@ -0,0 +1,11 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Literal, Optional
+from typing_extensions import TypeAlias
+
+
+Root0: TypeAlias = Literal["Bar", "Baz", "Foo"]
+
+Root: TypeAlias = "Optional[Root0]"
@@ -0,0 +1,15 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import List, Optional
+from typing_extensions import TypeAlias, TypedDict
+
+
+class Root0(TypedDict):
+    bar: str
+    baz: List[bool]
+    foo: bool
+    quux: List[bool]
+
+Root: TypeAlias = "Optional[Root0]"
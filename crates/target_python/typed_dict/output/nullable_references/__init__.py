@@ -0,0 +1,27 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Optional
+from typing_extensions import TypeAlias, TypedDict
+
+
+class Root(TypedDict):
+    notnull_ref_notnull_string: NotnullRefNotnullString
+    notnull_ref_null_string: NotnullRefNullString
+    notnull_string: NotnullString
+    null_ref_notnull_string: NullRefNotnullString
+    null_ref_null_string: NullRefNullString
+    null_string: NullString
+
+NotnullRefNotnullString: TypeAlias = "NotnullString"
+
+NotnullRefNullString: TypeAlias = "NullString"
+
+NotnullString: TypeAlias = "str"
+
+NullRefNotnullString: TypeAlias = "Optional[NotnullString]"
+
+NullRefNullString: TypeAlias = "Optional[NullString]"
+
+NullString: TypeAlias = "Optional[str]"
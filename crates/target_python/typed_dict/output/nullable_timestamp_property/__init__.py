@@ -0,0 +1,10 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Optional
+from typing_extensions import TypedDict
+
+
+class Root(TypedDict):
+    foo: Optional[str]
@@ -0,0 +1,12 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import List
+from typing_extensions import NotRequired, TypedDict
+
+
+class Root(TypedDict):
+    bar: NotRequired[List[str]]
+    baz: NotRequired[bool]
+    foo: NotRequired[str]
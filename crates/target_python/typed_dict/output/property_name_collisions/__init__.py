@@ -0,0 +1,10 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing_extensions import TypedDict
+
+
+class Root(TypedDict):
+    Foo: str
+    foo: str
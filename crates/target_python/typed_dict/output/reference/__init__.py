@@ -0,0 +1,14 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing_extensions import TypeAlias
+
+
+Root: TypeAlias = "Foo"
+
+Bar: TypeAlias = "Baz"
+
+Baz: TypeAlias = "str"
+
+Foo: TypeAlias = "Bar"
@@ -0,0 +1,9 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing import Any
+from typing_extensions import TypeAlias
+
+
+Root: TypeAlias = "Any"
@@ -0,0 +1,19 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+from __future__ import annotations
+
+from typing_extensions import TypedDict
+
+
+class RootFooBar(TypedDict):
+    x: bool
+
+class RootFoo(TypedDict):
+    bar: RootFooBar
+
+class RootFooBar0(TypedDict):
+    x: str
+
+class Root(TypedDict):
+    foo: RootFoo
+    foo_bar: RootFooBar0
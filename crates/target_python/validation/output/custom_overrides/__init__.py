@@ -0,0 +1,170 @@
+# Code generated by jtd-codegen for Python v0.3.1
+
+import re
+from dataclasses import dataclass
+from datetime import datetime, timedelta, timezone
+from typing import Any, Dict, List, Optional, Union
+from typing_extensions import Annotated, get_args, get_origin
+
+
+@dataclass
+class Root:
+    override_elements_container: 'List[str]'
+    override_type_discriminator: 'object'
+    override_type_enum: 'object'
+    override_type_expr: 'object'
+    override_type_properties: 'object'
+    override_values_container: 'Dict[str, str]'
+
+    @classmethod
+    def from_json_data(cls, data: Any, path: str = '') -> 'Root':
+        if type(data) is not dict:
+            raise ValidationError(path, "Root")
+
+        return cls(
+            _from_json_data(List[str], data.get("override_elements_container", _MISSING), path + "/override_elements_container"),
+            _from_json_data(object, data.get("override_type_discriminator", _MISSING), path + "/override_type_discriminator"),
+            _from_json_data(object, data.get("override_type_enum", _MISSING), path + "/override_type_enum"),
+            _from_json_data(object, data.get("override_type_expr", _MISSING), path + "/override_type_expr"),
+            _from_json_data(object, data.get("override_type_properties", _MISSING), path + "/override_type_properties"),
+            _from_json_data(Dict[str, str], data.get("override_values_container", _MISSING), path + "/override_values_container"),
+        )
+
+    def to_json_data(self) -> Any:
+        data: Dict[str, Any] = {}
+        data["override_elements_container"] = _to_json_data(self.override_elements_container)
+        data["override_type_discriminator"] = _to_json_data(self.override_type_discriminator)
+        data["override_type_enum"] = _to_json_data(self.override_type_enum)
+        data["override_type_expr"] = _to_json_data(self.override_type_expr)
+        data["override_type_properties"] = _to_json_data(self.override_type_properties)
+        data["override_values_container"] = _to_json_data(self.override_values_container)
+        return data
+
+class ValidationError(ValueError):
+    """
+    Raised by from_json_data when data doesn't satisfy its schema. path is a
+    JSON Pointer to the bad value, and expected describes what it should be.
+    If a required property is missing, path points to where it should be.
+    """
+
+    def __init__(self, path: str, expected: str, missing: bool = False) -> None:
+        if missing:
+            key = path.rsplit('/', 1)[-1].replace('~1', '/').replace('~0', '~')
+            super().__init__(f'{path!r}: missing required property "{key}"')
+        else:
+            super().__init__(f'{path!r}: expected {expected}')
+        self.path = path
+        self.expected = expected
+
+# The value of a property that's missing.
+_MISSING = object()
+
+def _optional(cls: Any, data: Any, path: str) -> Any:
+    if data is _MISSING:
+        return None
+    return _from_json_data(cls, data, path)
+
+def _from_json_data(cls: Any, data: Any, path: str) -> Any:
+    if data is _MISSING:
+        raise ValidationError(path, _expected(cls), missing=True)
+    if cls is Any or cls is object:
+        return data
+    if get_origin(cls) is Union:
+        if data is None:
+            return None
+        return _from_json_data(get_args(cls)[0], data, path)
+    if get_origin(cls) is Annotated:
+        (lo, hi) = get_args(cls)[1]
+        if type(data) is float and data.is_integer():
+            data = int(data)
+        if type(data) is int and lo <= data <= hi:
+            return data
+    elif cls is bool:
+        if type(data) is bool:
+            return data
+    elif cls is int:
+        if type(data) is int:
+            return data
+    elif cls is float:
+        if type(data) in [int, float]:
+            return data
+    elif cls is str:
+        if type(data) is str:
+            return data
+    elif cls is datetime:
+        if type(data) is str:
+            try:
+                return _parse_rfc3339(data)
+            except ValueError:
+                pass
+    elif get_origin(cls) is list:
+        if type(data) is list:
+            return [_from_json_data(get_args(cls)[0], d, f'{path}/{i}') for i, d in enumerate(data)]
+    elif get_origin(cls) is dict:
+        if type(data) is dict:
+            return { k: _from_json_data(get_args(cls)[1], v, _path(path, k)) for k, v in data.items() }
+    else:
+        return cls.from_json_data(data, path)
+    raise ValidationError(path, _expected(cls))
+
+def _expected(cls: Any) -> str:
+    if get_origin(cls) is Union:
+        return f'{_expected(get_args(cls)[0])} or null'
+    if get_origin(cls) is Annotated:
+        (lo, hi) = get_args(cls)[1]
+        return f'integer from {lo} to {hi}'
+    if get_origin(cls) is list:
+        return 'array'
+    if get_origin(cls) is dict:
+        return 'object'
+    names = { bool: 'boolean', float: 'number', str: 'string', datetime: 'timestamp' }
+    return names.get(cls) or str(getattr(cls, '__name__', cls))
+
+def _path(path: str, key: str) -> str:
+    return path + '/' + key.replace('~', '~0').replace('/', '~1')
+
+def _to_json_data(data: Any) -> Any:
+    if data is None or type(data) in [bool, int, float, str, object]:
+        return data
+    if type(data) is datetime:
+        return data.isoformat()
+    if type(data) is list:
+        return [_to_json_data(d) for d in data]
+    if type(data) is dict:
+        return { k: _to_json_data(v) for k, v in data.items() }
+    return data.to_json_data()
+
+def _parse_rfc3339(s: str) -> datetime:
+    datetime_re = '^(\d{4})-(\d{2})-(\d{2})[tT](\d{2}):(\d{2}):(\d{2})(\.\d+)?([zZ]|((\+|-)(\d{2}):(\d{2})))$'
+    match = re.match(datetime_re, s)
+    if not match:
+        raise ValueError('Invalid RFC3339 date/time', s)
+
+    (year, month, day, hour, minute, second, frac_seconds, offset,
+     *tz) = match.groups()
+
+    frac_seconds_parsed = None
+    if frac_seconds:
+        frac_seconds_parsed = int(float(frac_seconds) * 1_000_000)
+    else:
+        frac_seconds_parsed = 0
+
+    tzinfo = None
+    if offset in ('Z', 'z'):
+        tzinfo = timezone.utc
+    else:
+        hours = int(tz[2])
+        minutes = int(tz[3])
+        sign = 1 if tz[1] == '+' else -1
+
+        if minutes not in range(60):
+            raise ValueError('minute offset must be in 0..59')
+
+        tzinfo = timezone(timedelta(minutes=sign * (60 * hours + minutes)))
+
+    second_parsed = int(second)
+    if second_parsed == 60:
+        second_parsed = 59
+
+    return datetime(int(year), int(month), int(day), int(hour), int(minute),
+                    second_parsed, frac_seconds_parsed, tzinfo)            
@@ -0,0 +1,13 @@
+# Passes each line of input through the generated from_json_data, and writes
+# out the path and message of the ValidationError it raises, or null if it
+# raises none.
+import json
+import sys
+from gen import MAIN, ValidationError
+
+for line in sys.stdin:
+    try:
+        MAIN.from_json_data(json.loads(line))
+        print(json.dumps(None))
+    except ValidationError as err:
+        print(json.dumps([err.path, str(err)]))
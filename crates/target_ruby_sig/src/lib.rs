@@ -91,12 +91,12 @@ impl jtd_codegen::target::Target for Target {
         _state: &mut FileState,
         metadata: metadata::Metadata,
         expr: target::Expr,
-    ) -> String {
+    ) -> Result<String> {
         if let Some(s) = metadata.get("rubyType").and_then(|v| v.as_str()) {
-            return s.into();
+            return Ok(s.into());
         }
 
-        match expr {
+        Ok(match expr {
             target::Expr::Empty => "untyped".into(),
             target::Expr::Boolean => "bool".into(),
             target::Expr::Int8 => "Integer".into(),
@@ -116,7 +116,7 @@ impl jtd_codegen::target::Target for Target {
                 format!("Hash[String, {}]", sub_expr)
             }
             target::Expr::NullableOf(sub_expr) => format!("{}?", sub_expr),
-        }
+        })
     }
 
     fn item(
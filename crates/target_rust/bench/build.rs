@@ -0,0 +1,22 @@
+use jtd_codegen_target_rust::Target;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const SCHEMA: &str = "../../test/schemas/roundtrip_loose/geojson.jtd.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SCHEMA);
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let modes = vec![
+        ("serde", Target::new()),
+        ("native", Target::new().with_native_json(true)),
+    ];
+
+    for (name, target) in modes {
+        let out_dir = Path::new(&out_dir).join(name);
+        fs::create_dir_all(&out_dir).unwrap();
+        target.generate(SCHEMA, out_dir).unwrap();
+    }
+}
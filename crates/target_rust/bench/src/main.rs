@@ -0,0 +1,117 @@
+//! Compares the serde and native JSON modes of the Rust target, by timing how
+//! long code generated in each mode takes to parse and write out fuzzed
+//! instances of the geojson schema.
+//!
+//! Usage: `cargo run --release -- [instances] [iterations]`
+
+use jtd::{Schema, SerdeSchema};
+use rand::SeedableRng;
+use rand_pcg::Pcg32;
+use std::convert::TryInto;
+use std::time::{Duration, Instant};
+
+mod serde_mode {
+    #![allow(dead_code)]
+    include!(concat!(env!("OUT_DIR"), "/serde/mod.rs"));
+}
+
+mod native_mode {
+    #![allow(dead_code)]
+    include!(concat!(env!("OUT_DIR"), "/native/mod.rs"));
+}
+
+const SCHEMA: &str = include_str!("../../../test/schemas/roundtrip_loose/geojson.jtd.json");
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let instances: usize = args.next().map_or(1000, |s| s.parse().unwrap());
+    let iterations: usize = args.next().map_or(20, |s| s.parse().unwrap());
+
+    let schema: SerdeSchema = serde_json::from_str(SCHEMA).unwrap();
+    let schema: Schema = schema.try_into().unwrap();
+
+    let mut rng = Pcg32::seed_from_u64(8927);
+    let fuzzed: Vec<String> = (0..instances)
+        .map(|_| jtd_fuzz::fuzz(&schema, &mut rng).to_string())
+        .collect();
+
+    // Both modes must agree on what each input means before their speed is
+    // worth comparing.
+    for input in &fuzzed {
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&serde_round_trip(input)).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&native_round_trip(input)).unwrap(),
+            "modes disagree on input: {}",
+            input
+        );
+    }
+
+    // The fuzzer sorts the properties of objects, which puts the "type" tag
+    // of most geojson objects last. Discriminators then have to read ahead
+    // through the rest of the object to find out what to parse, which is
+    // rarely necessary with real data. So the inputs are also timed as the
+    // generated code writes them out, with tags first.
+    let tags_first: Vec<String> = fuzzed.iter().map(|i| native_round_trip(i)).collect();
+
+    println!("{} geojson instances, {} iterations", instances, iterations);
+    bench("fuzzed", iterations, &fuzzed);
+    bench("tags first", iterations, &tags_first);
+}
+
+fn serde_round_trip(input: &str) -> String {
+    let value: serde_mode::Geojson = serde_json::from_str(input).unwrap();
+    serde_json::to_string(&value).unwrap()
+}
+
+fn native_round_trip(input: &str) -> String {
+    let value: native_mode::Geojson = native_mode::json::from_json(input).unwrap();
+    native_mode::json::to_json(&value)
+}
+
+fn bench(name: &str, iterations: usize, inputs: &[String]) {
+    let bytes = inputs.iter().map(String::len).sum::<usize>() * iterations;
+    println!("\n{} ({} bytes per iteration):", name, bytes / iterations);
+
+    report(
+        "serde",
+        bytes,
+        time(iterations, inputs, |input| {
+            serde_json::from_str::<serde_mode::Geojson>(input).unwrap()
+        }),
+        time(iterations, inputs, serde_round_trip),
+    );
+
+    report(
+        "native",
+        bytes,
+        time(iterations, inputs, |input| {
+            native_mode::json::from_json::<native_mode::Geojson>(input).unwrap()
+        }),
+        time(iterations, inputs, native_round_trip),
+    );
+}
+
+// Times how long it takes to run f over every input, iterations times over.
+fn time<T, F: Fn(&str) -> T>(iterations: usize, inputs: &[String], f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        for input in inputs {
+            std::hint::black_box(f(input));
+        }
+    }
+
+    start.elapsed()
+}
+
+fn report(mode: &str, bytes: usize, parse: Duration, round_trip: Duration) {
+    let throughput = |duration: Duration| bytes as f64 / duration.as_secs_f64() / 1_000_000.0;
+
+    println!(
+        "{:>8}: parse {:>8.1?} ({:>7.1} MB/s), parse + write {:>8.1?} ({:>7.1} MB/s)",
+        mode,
+        parse,
+        throughput(parse),
+        round_trip,
+        throughput(round_trip)
+    );
+}
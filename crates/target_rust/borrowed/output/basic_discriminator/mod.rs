@@ -0,0 +1,249 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+#[derive(Serialize)]
+#[serde(tag = "foo")]
+pub enum Root<'a> {
+    #[serde(rename = "BAR_BAZ")]
+    BarBaz(RootBarBaz<'a>),
+    #[serde(rename = "QUUX")]
+    Quux(RootQuux<'a>),
+}
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for Root<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Tag<'a> {
+            #[serde(rename = "foo")]
+            #[serde(borrow)]
+            tag: std::borrow::Cow<'a, str>,
+        }
+        let raw: &'de serde_json::value::RawValue = serde::Deserialize::deserialize(
+            deserializer,
+        )?;
+        let tag: Tag = serde_json::from_str(raw.get())
+            .map_err(serde::de::Error::custom)?;
+        match tag.tag.as_ref() {
+            "BAR_BAZ" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::BarBaz)
+                    .map_err(serde::de::Error::custom)
+            }
+            "QUUX" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::Quux)
+                    .map_err(serde::de::Error::custom)
+            }
+            tag => {
+                std::result::Result::Err(
+                    serde::de::Error::unknown_variant(tag, &["BAR_BAZ", "QUUX"]),
+                )
+            }
+        }
+    }
+}
+
+impl<'de: 'a, 'a> borrow::Deserialize<'de> for Root<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+impl<'a> Root<'a> {
+    pub const TAGS: &'static [&'static str] = &["BAR_BAZ", "QUUX"];
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Self::BarBaz(_) => "BAR_BAZ",
+            Self::Quux(_) => "QUUX",
+        }
+    }
+    pub fn as_bar_baz(&self) -> Option<&RootBarBaz<'a>> {
+        match self {
+            Self::BarBaz(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_quux(&self) -> Option<&RootQuux<'a>> {
+        match self {
+            Self::Quux(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> From<RootBarBaz<'a>> for Root<'a> {
+    fn from(value: RootBarBaz<'a>) -> Self {
+        Self::BarBaz(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<Root<'a>> for RootBarBaz<'a> {
+    type Error = Root<'a>;
+    fn try_from(value: Root<'a>) -> std::result::Result<Self, Root<'a>> {
+        match value {
+            Root::BarBaz(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<RootQuux<'a>> for Root<'a> {
+    fn from(value: RootQuux<'a>) -> Self {
+        Self::Quux(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<Root<'a>> for RootQuux<'a> {
+    type Error = Root<'a>;
+    fn try_from(value: Root<'a>) -> std::result::Result<Self, Root<'a>> {
+        match value {
+            Root::Quux(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RootBarBaz<'a> {
+    #[serde(rename = "baz")]
+    #[serde(borrow)]
+    pub baz: Cow<'a, str>,
+}
+
+impl<'de: 'a, 'a> borrow::Deserialize<'de> for RootBarBaz<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RootQuux<'a> {
+    #[serde(rename = "quuz")]
+    #[serde(borrow)]
+    pub quuz: Cow<'a, str>,
+}
+
+impl<'de: 'a, 'a> borrow::Deserialize<'de> for RootQuux<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+/// Deserializing values that borrow strings from their input, even
+/// where serde's own impls would copy them out.
+pub mod borrow {
+    use std::borrow::Cow;
+
+    /// Like `serde::Deserialize`, except that strings are borrowed
+    /// wherever they are nested in options, boxes, arrays and maps.
+    pub trait Deserialize<'de>: Sized {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error>;
+    }
+
+    /// Deserializes a value with its impl of this module's
+    /// `Deserialize`. Fields that borrow are deserialized with this.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>, T: Deserialize<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+
+    /// A value deserialized with this module's `Deserialize`, for
+    /// containers to deserialize their items as.
+    struct Item<T>(T);
+
+    impl<'de, T: Deserialize<'de>> serde::Deserialize<'de> for Item<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Item)
+        }
+    }
+
+    impl<'de: 'a, 'a> Deserialize<'de> for Cow<'a, str> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            struct Visitor;
+            impl<'de> serde::de::Visitor<'de> for Visitor {
+                type Value = Cow<'de, str>;
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("a string")
+                }
+                fn visit_borrowed_str<E: serde::de::Error>(
+                    self,
+                    value: &'de str,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Borrowed(value))
+                }
+                fn visit_str<E: serde::de::Error>(
+                    self,
+                    value: &str,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Owned(String::from(value)))
+                }
+                fn visit_string<E: serde::de::Error>(
+                    self,
+                    value: String,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Owned(value))
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Option<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            let value: Option<Item<T>> = serde::Deserialize::deserialize(deserializer)?;
+            Ok(value.map(|Item(value)| value))
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Box<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Box::new)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            struct Visitor<T>(core::marker::PhantomData<T>);
+            impl<'de, T: Deserialize<'de>> serde::de::Visitor<'de> for Visitor<T> {
+                type Value = Vec<T>;
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("an array")
+                }
+                fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                    self,
+                    mut seq: A,
+                ) -> core::result::Result<Self::Value, A::Error> {
+                    let mut values = Vec::new();
+                    while let Some(Item(value)) = seq.next_element()? {
+                        values.push(value);
+                    }
+                    Ok(values)
+                }
+            }
+            deserializer.deserialize_seq(Visitor(core::marker::PhantomData))
+        }
+    }
+}
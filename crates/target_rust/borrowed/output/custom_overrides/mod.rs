@@ -0,0 +1,171 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+pub struct RootOverrideTypeDiscriminatorBaz {}
+
+#[derive(Serialize, Deserialize)]
+pub struct Root<'a> {
+    #[serde(rename = "override_elements_container")]
+    #[serde(borrow)]
+    #[serde(deserialize_with = "borrow::deserialize")]
+    pub overrideElementsContainer: Vec<Cow<'a, str>>,
+    #[serde(rename = "override_type_discriminator")]
+    pub overrideTypeDiscriminator: serde_json::Value,
+    #[serde(rename = "override_type_enum")]
+    pub overrideTypeEnum: serde_json::Value,
+    #[serde(rename = "override_type_expr")]
+    pub overrideTypeExpr: serde_json::Value,
+    #[serde(rename = "override_type_properties")]
+    pub overrideTypeProperties: serde_json::Value,
+    #[serde(rename = "override_values_container")]
+    #[serde(borrow)]
+    #[serde(deserialize_with = "borrow::deserialize")]
+    pub overrideValuesContainer: HashMap<String, Cow<'a, str>>,
+}
+
+impl<'de: 'a, 'a> borrow::Deserialize<'de> for Root<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+/// Deserializing values that borrow strings from their input, even
+/// where serde's own impls would copy them out.
+pub mod borrow {
+    use std::borrow::Cow;
+
+    /// Like `serde::Deserialize`, except that strings are borrowed
+    /// wherever they are nested in options, boxes, arrays and maps.
+    pub trait Deserialize<'de>: Sized {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error>;
+    }
+
+    /// Deserializes a value with its impl of this module's
+    /// `Deserialize`. Fields that borrow are deserialized with this.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>, T: Deserialize<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+
+    /// A value deserialized with this module's `Deserialize`, for
+    /// containers to deserialize their items as.
+    struct Item<T>(T);
+
+    impl<'de, T: Deserialize<'de>> serde::Deserialize<'de> for Item<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Item)
+        }
+    }
+
+    impl<'de: 'a, 'a> Deserialize<'de> for Cow<'a, str> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            struct Visitor;
+            impl<'de> serde::de::Visitor<'de> for Visitor {
+                type Value = Cow<'de, str>;
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("a string")
+                }
+                fn visit_borrowed_str<E: serde::de::Error>(
+                    self,
+                    value: &'de str,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Borrowed(value))
+                }
+                fn visit_str<E: serde::de::Error>(
+                    self,
+                    value: &str,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Owned(String::from(value)))
+                }
+                fn visit_string<E: serde::de::Error>(
+                    self,
+                    value: String,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Owned(value))
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Option<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            let value: Option<Item<T>> = serde::Deserialize::deserialize(deserializer)?;
+            Ok(value.map(|Item(value)| value))
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Box<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Box::new)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            struct Visitor<T>(core::marker::PhantomData<T>);
+            impl<'de, T: Deserialize<'de>> serde::de::Visitor<'de> for Visitor<T> {
+                type Value = Vec<T>;
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("an array")
+                }
+                fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                    self,
+                    mut seq: A,
+                ) -> core::result::Result<Self::Value, A::Error> {
+                    let mut values = Vec::new();
+                    while let Some(Item(value)) = seq.next_element()? {
+                        values.push(value);
+                    }
+                    Ok(values)
+                }
+            }
+            deserializer.deserialize_seq(Visitor(core::marker::PhantomData))
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de>
+    for std::collections::HashMap<String, T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            struct Visitor<T>(core::marker::PhantomData<T>);
+            impl<'de, T: Deserialize<'de>> serde::de::Visitor<'de> for Visitor<T> {
+                type Value = std::collections::HashMap<String, T>;
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("an object")
+                }
+                fn visit_map<A: serde::de::MapAccess<'de>>(
+                    self,
+                    mut map: A,
+                ) -> core::result::Result<Self::Value, A::Error> {
+                    let mut values = std::collections::HashMap::new();
+                    while let Some((key, Item(value))) = map.next_entry()? {
+                        values.insert(key, value);
+                    }
+                    Ok(values)
+                }
+            }
+            deserializer.deserialize_map(Visitor(core::marker::PhantomData))
+        }
+    }
+}
@@ -0,0 +1,844 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::collections::HashMap;
+
+pub type Root<'a> = GeojsonObject<'a>;
+
+/// A GeoJSON object MAY have a member named "bbox" to include information
+/// on the coordinate range for its Geometries, Features, or
+/// FeatureCollections.  The value of the bbox member MUST be an array of
+/// length 2*n where n is the number of dimensions represented in the
+/// contained geometries, with all axes of the most southwesterly point
+/// followed by all axes of the more northeasterly point.  The axes order of
+/// a bbox follows the axes order of geometries.
+///
+/// The "bbox" values define shapes with edges that follow lines of constant
+/// longitude, latitude, and elevation.
+///
+/// The four lines of the bounding box are defined fully within the
+/// coordinate reference system; that is, for a box bounded by the values
+/// "west", "south", "east", and "north", every point on the northernmost
+/// line can be expressed as
+///
+/// (lon, lat) = (west + (east - west) * t, north)
+///
+/// with 0 <= t <= 1.
+///
+/// Consider a set of point Features within the Fiji archipelago, straddling
+/// the antimeridian between 16 degrees S and 20 degrees S. The southwest
+/// corner of the box containing these Features is at 20 degrees S and 177
+/// degrees E, and the northwest corner is at 16 degrees S and 178 degrees
+/// W.  The antimeridian-spanning GeoJSON bounding box for this
+/// FeatureCollection is
+///
+/// "bbox": [177.0, -20.0, -178.0, -16.0]
+///
+/// and covers 5 degrees of longitude.
+///
+/// The complementary bounding box for the same latitude band, not crossing
+/// the antimeridian, is
+///
+/// "bbox": [-178.0, -20.0, 177.0, -16.0]
+///
+/// and covers 355 degrees of longitude.
+///
+/// The latitude of the northeast corner is always greater than the latitude
+/// of the southwest corner, but bounding boxes that cross the antimeridian
+/// have a northeast corner longitude that is less than the longitude of the
+/// southwest corner.
+///
+/// A bounding box that contains the North Pole extends from a southwest
+/// corner of "minlat" degrees N, 180 degrees W to a northeast corner of 90
+/// degrees N, 180 degrees E.  Viewed on a globe, this bounding box
+/// approximates a spherical cap bounded by the "minlat" circle of latitude.
+///
+/// "bbox": [-180.0, minlat, 180.0, 90.0]
+///
+/// A bounding box that contains the South Pole extends from a southwest
+/// corner of 90 degrees S, 180 degrees W to a northeast corner of "maxlat"
+/// degrees S, 180 degrees E.
+///
+/// "bbox": [-180.0, -90.0, 180.0, maxlat]
+///
+/// A bounding box that just touches the North Pole and forms a slice of an
+/// approximate spherical cap when viewed on a globe extends from a
+/// southwest corner of "minlat" degrees N and "westlon" degrees E to a
+/// northeast corner of 90 degrees N and "eastlon" degrees E.
+///
+/// "bbox": [westlon, minlat, eastlon, 90.0]
+///
+/// Similarly, a bounding box that just touches the South Pole and forms a
+/// slice of an approximate spherical cap when viewed on a globe has the
+/// following representation in GeoJSON.
+///
+/// "bbox": [westlon, -90.0, eastlon, maxlat]
+///
+/// Implementers MUST NOT use latitude values greater than 90 or less than
+/// -90 to imply an extent that is not a spherical cap.
+pub type BoundingBox = Vec<f64>;
+
+/// A Geometry object represents points, curves, and surfaces in coordinate
+/// space.  Every Geometry object is a GeoJSON object no matter where it
+/// occurs in a GeoJSON text.
+///
+/// o  The value of a Geometry object's "type" member MUST be one of the
+///     seven geometry types (see Section 1.4).
+///
+/// o  A GeoJSON Geometry object of any type other than
+///     "GeometryCollection" has a member with the name "coordinates". The
+///     value of the "coordinates" member is an array.  The structure of the
+///     elements in this array is determined by the type of geometry.
+///     GeoJSON processors MAY interpret Geometry objects with empty
+///     "coordinates" arrays as null objects.
+#[derive(Serialize)]
+#[serde(tag = "type")]
+pub enum GeojsonObject<'a> {
+    #[serde(rename = "Feature")]
+    Feature(GeojsonObjectFeature<'a>),
+    #[serde(rename = "FeatureCollection")]
+    FeatureCollection(GeojsonObjectFeatureCollection<'a>),
+    #[serde(rename = "GeometryCollection")]
+    GeometryCollection(GeojsonObjectGeometryCollection<'a>),
+    #[serde(rename = "LineString")]
+    LineString(GeojsonObjectLineString),
+    #[serde(rename = "MultiLineString")]
+    MultiLineString(GeojsonObjectMultiLineString),
+    #[serde(rename = "MultiPoint")]
+    MultiPoint(GeojsonObjectMultiPoint),
+    #[serde(rename = "MultiPolygon")]
+    MultiPolygon(GeojsonObjectMultiPolygon),
+    #[serde(rename = "Point")]
+    Point(GeojsonObjectPoint),
+    #[serde(rename = "Polygon")]
+    Polygon(GeojsonObjectPolygon),
+}
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for GeojsonObject<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Tag<'a> {
+            #[serde(rename = "type")]
+            #[serde(borrow)]
+            tag: std::borrow::Cow<'a, str>,
+        }
+        let raw: &'de serde_json::value::RawValue = serde::Deserialize::deserialize(
+            deserializer,
+        )?;
+        let tag: Tag = serde_json::from_str(raw.get())
+            .map_err(serde::de::Error::custom)?;
+        match tag.tag.as_ref() {
+            "Feature" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::Feature)
+                    .map_err(serde::de::Error::custom)
+            }
+            "FeatureCollection" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::FeatureCollection)
+                    .map_err(serde::de::Error::custom)
+            }
+            "GeometryCollection" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::GeometryCollection)
+                    .map_err(serde::de::Error::custom)
+            }
+            "LineString" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::LineString)
+                    .map_err(serde::de::Error::custom)
+            }
+            "MultiLineString" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::MultiLineString)
+                    .map_err(serde::de::Error::custom)
+            }
+            "MultiPoint" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::MultiPoint)
+                    .map_err(serde::de::Error::custom)
+            }
+            "MultiPolygon" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::MultiPolygon)
+                    .map_err(serde::de::Error::custom)
+            }
+            "Point" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::Point)
+                    .map_err(serde::de::Error::custom)
+            }
+            "Polygon" => {
+                serde_json::from_str(raw.get())
+                    .map(Self::Polygon)
+                    .map_err(serde::de::Error::custom)
+            }
+            tag => {
+                std::result::Result::Err(
+                    serde::de::Error::unknown_variant(
+                        tag,
+                        &[
+                            "Feature",
+                            "FeatureCollection",
+                            "GeometryCollection",
+                            "LineString",
+                            "MultiLineString",
+                            "MultiPoint",
+                            "MultiPolygon",
+                            "Point",
+                            "Polygon",
+                        ],
+                    ),
+                )
+            }
+        }
+    }
+}
+
+impl<'de: 'a, 'a> borrow::Deserialize<'de> for GeojsonObject<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+impl<'a> GeojsonObject<'a> {
+    pub const TAGS: &'static [&'static str] = &[
+        "Feature",
+        "FeatureCollection",
+        "GeometryCollection",
+        "LineString",
+        "MultiLineString",
+        "MultiPoint",
+        "MultiPolygon",
+        "Point",
+        "Polygon",
+    ];
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Self::Feature(_) => "Feature",
+            Self::FeatureCollection(_) => "FeatureCollection",
+            Self::GeometryCollection(_) => "GeometryCollection",
+            Self::LineString(_) => "LineString",
+            Self::MultiLineString(_) => "MultiLineString",
+            Self::MultiPoint(_) => "MultiPoint",
+            Self::MultiPolygon(_) => "MultiPolygon",
+            Self::Point(_) => "Point",
+            Self::Polygon(_) => "Polygon",
+        }
+    }
+    pub fn as_feature(&self) -> Option<&GeojsonObjectFeature<'a>> {
+        match self {
+            Self::Feature(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_feature_collection(&self) -> Option<&GeojsonObjectFeatureCollection<'a>> {
+        match self {
+            Self::FeatureCollection(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_geometry_collection(
+        &self,
+    ) -> Option<&GeojsonObjectGeometryCollection<'a>> {
+        match self {
+            Self::GeometryCollection(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_line_string(&self) -> Option<&GeojsonObjectLineString> {
+        match self {
+            Self::LineString(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_multi_line_string(&self) -> Option<&GeojsonObjectMultiLineString> {
+        match self {
+            Self::MultiLineString(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_multi_point(&self) -> Option<&GeojsonObjectMultiPoint> {
+        match self {
+            Self::MultiPoint(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_multi_polygon(&self) -> Option<&GeojsonObjectMultiPolygon> {
+        match self {
+            Self::MultiPolygon(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_point(&self) -> Option<&GeojsonObjectPoint> {
+        match self {
+            Self::Point(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_polygon(&self) -> Option<&GeojsonObjectPolygon> {
+        match self {
+            Self::Polygon(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectFeature<'a>> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectFeature<'a>) -> Self {
+        Self::Feature(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>> for GeojsonObjectFeature<'a> {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::Feature(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectFeatureCollection<'a>> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectFeatureCollection<'a>) -> Self {
+        Self::FeatureCollection(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>>
+for GeojsonObjectFeatureCollection<'a> {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::FeatureCollection(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectGeometryCollection<'a>> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectGeometryCollection<'a>) -> Self {
+        Self::GeometryCollection(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>>
+for GeojsonObjectGeometryCollection<'a> {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::GeometryCollection(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectLineString> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectLineString) -> Self {
+        Self::LineString(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>> for GeojsonObjectLineString {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::LineString(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectMultiLineString> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectMultiLineString) -> Self {
+        Self::MultiLineString(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>> for GeojsonObjectMultiLineString {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::MultiLineString(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectMultiPoint> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectMultiPoint) -> Self {
+        Self::MultiPoint(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>> for GeojsonObjectMultiPoint {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::MultiPoint(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectMultiPolygon> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectMultiPolygon) -> Self {
+        Self::MultiPolygon(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>> for GeojsonObjectMultiPolygon {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::MultiPolygon(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectPoint> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectPoint) -> Self {
+        Self::Point(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>> for GeojsonObjectPoint {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::Point(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl<'a> From<GeojsonObjectPolygon> for GeojsonObject<'a> {
+    fn from(value: GeojsonObjectPolygon) -> Self {
+        Self::Polygon(value)
+    }
+}
+
+impl<'a> std::convert::TryFrom<GeojsonObject<'a>> for GeojsonObjectPolygon {
+    type Error = GeojsonObject<'a>;
+    fn try_from(
+        value: GeojsonObject<'a>,
+    ) -> std::result::Result<Self, GeojsonObject<'a>> {
+        match value {
+            GeojsonObject::Polygon(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+/// A Feature object represents a spatially bounded thing.  Every
+/// Feature object is a GeoJSON object no matter where it occurs in a
+/// GeoJSON text.
+///
+/// o  A Feature object has a "type" member with the value "Feature".
+///
+/// o  A Feature object has a member with the name "geometry".  The
+///     value of the geometry member SHALL be either a Geometry object
+///     as defined above or, in the case that the Feature is unlocated,
+///     a JSON null value.
+///
+/// o  A Feature object has a member with the name "properties".  The
+///     value of the properties member is an object (any JSON object or
+///     a JSON null value).
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectFeature<'a> {
+    /// The GeoJSON specification requires that these elements be
+    /// GeoJSON geometry objects, but such a constraint can't be
+    /// expressed in JSON Type Definition.
+    ///
+    /// It is semantically invalid at the GeoJSON level for this
+    /// member to be any GeoJSON object type other than one of the
+    /// geometry types.
+    #[serde(rename = "geometry")]
+    #[serde(borrow)]
+    #[serde(deserialize_with = "borrow::deserialize")]
+    pub geometry: Option<Box<GeojsonObject<'a>>>,
+    #[serde(rename = "properties")]
+    #[serde(borrow)]
+    #[serde(deserialize_with = "borrow::deserialize")]
+    pub properties: HashMap<String, Option<&'a RawValue>>,
+    #[serde(rename = "id")]
+    #[serde(borrow)]
+    #[serde(deserialize_with = "borrow::deserialize")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a RawValue>,
+}
+
+impl<'de: 'a, 'a> borrow::Deserialize<'de> for GeojsonObjectFeature<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+/// A GeoJSON object with the type "FeatureCollection" is a
+/// FeatureCollection object.  A FeatureCollection object has a member
+/// with the name "features".  The value of "features" is a JSON array.
+/// Each element of the array is a Feature object as defined above.  It
+/// is possible for this array to be empty.
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectFeatureCollection<'a> {
+    #[serde(rename = "features")]
+    #[serde(borrow)]
+    #[serde(deserialize_with = "borrow::deserialize")]
+    pub features: Vec<GeojsonObject<'a>>,
+}
+
+impl<'de: 'a, 'a> borrow::Deserialize<'de> for GeojsonObjectFeatureCollection<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+/// A GeoJSON object with type "GeometryCollection" is a Geometry
+/// object. A GeometryCollection has a member with the name
+/// "geometries".  The value of "geometries" is an array.  Each element
+/// of this array is a GeoJSON Geometry object.  It is possible for this
+/// array to be empty.
+///
+/// Unlike the other geometry types described above, a
+/// GeometryCollection can be a heterogeneous composition of smaller
+/// Geometry objects.  For example, a Geometry object in the shape of a
+/// lowercase roman "i" can be composed of one point and one LineString.
+///
+/// GeometryCollections have a different syntax from single type
+/// Geometry objects (Point, LineString, and Polygon) and homogeneously
+/// typed multipart Geometry objects (MultiPoint, MultiLineString, and
+/// MultiPolygon) but have no different semantics.  Although a
+/// GeometryCollection object has no "coordinates" member, it does have
+/// coordinates: the coordinates of all its parts belong to the
+/// collection.  The "geometries" member of a GeometryCollection
+/// describes the parts of this composition.  Implementations SHOULD NOT
+/// apply any additional semantics to the "geometries" array.
+///
+/// To maximize interoperability, implementations SHOULD avoid nested
+/// GeometryCollections.  Furthermore, GeometryCollections composed of a
+/// single part or a number of parts of a single type SHOULD be avoided
+/// when that single part or a single object of multipart type
+/// (MultiPoint, MultiLineString, or MultiPolygon) could be used
+/// instead.
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectGeometryCollection<'a> {
+    #[serde(rename = "geometries")]
+    #[serde(borrow)]
+    #[serde(deserialize_with = "borrow::deserialize")]
+    pub geometries: Vec<GeojsonObject<'a>>,
+    #[serde(rename = "bbox")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<Box<BoundingBox>>,
+}
+
+impl<'de: 'a, 'a> borrow::Deserialize<'de> for GeojsonObjectGeometryCollection<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+/// For type "LineString", the "coordinates" member is an array of two
+/// or more positions.
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectLineString {
+    #[serde(rename = "coordinates")]
+    pub coordinates: Vec<Position>,
+    #[serde(rename = "bbox")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<Box<BoundingBox>>,
+}
+
+/// For type "MultiLineString", the "coordinates" member is an array of
+/// LineString coordinate arrays.
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectMultiLineString {
+    #[serde(rename = "coordinates")]
+    pub coordinates: Vec<Position>,
+    #[serde(rename = "bbox")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<Box<BoundingBox>>,
+}
+
+/// For type "MultiPoint", the "coordinates" member is an array of
+/// positions.
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectMultiPoint {
+    #[serde(rename = "coordinates")]
+    pub coordinates: Vec<Position>,
+    #[serde(rename = "bbox")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<Box<BoundingBox>>,
+}
+
+/// For type "MultiPolygon", the "coordinates" member is an array of
+/// Polygon coordinate arrays.
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectMultiPolygon {
+    #[serde(rename = "coordinates")]
+    pub coordinates: Vec<LinearRing>,
+    #[serde(rename = "bbox")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<Box<BoundingBox>>,
+}
+
+/// For type "Point", the "coordinates" member is a single position.
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectPoint {
+    #[serde(rename = "coordinates")]
+    pub coordinates: Position,
+    #[serde(rename = "bbox")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<Box<BoundingBox>>,
+}
+
+/// For type "Polygon", the "coordinates" member MUST be an array of
+/// linear ring coordinate arrays.
+#[derive(Serialize, Deserialize)]
+pub struct GeojsonObjectPolygon {
+    #[serde(rename = "coordinates")]
+    pub coordinates: Vec<LinearRing>,
+    #[serde(rename = "bbox")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<Box<BoundingBox>>,
+}
+
+/// To specify a constraint specific to Polygons, it is useful to
+/// introduce the concept of a linear ring:
+///
+/// o  A linear ring is a closed LineString with four or more positions.
+///
+/// o  The first and last positions are equivalent, and they MUST contain
+///     identical values; their representation SHOULD also be identical.
+///
+/// o  A linear ring is the boundary of a surface or the boundary of a
+///     hole in a surface.
+///
+/// o  A linear ring MUST follow the right-hand rule with respect to the
+///     area it bounds, i.e., exterior rings are counterclockwise, and holes
+///     are clockwise.
+///
+/// Note: the [GJ2008] specification did not discuss linear ring winding
+/// order.  For backwards compatibility, parsers SHOULD NOT reject Polygons
+/// that do not follow the right-hand rule.
+///
+/// Though a linear ring is not explicitly represented as a GeoJSON geometry
+/// type, it leads to a canonical formulation of the Polygon geometry type
+/// definition as follows:
+///
+/// For Polygons with more than one of these rings, the first MUST be the
+/// exterior ring, and any others MUST be interior rings.  The exterior ring
+/// bounds the surface, and the interior rings (if present) bound holes
+/// within the surface.
+pub type LinearRing = Vec<Position>;
+
+/// A position is the fundamental geometry construct.
+///
+/// A position is an array of numbers.  There MUST be two or more elements.
+/// The first two elements are longitude and latitude, or easting and
+/// northing, precisely in that order and using decimal numbers.  Altitude
+/// or elevation MAY be included as an optional third element.
+///
+/// Implementations SHOULD NOT extend positions beyond three elements
+/// because the semantics of extra elements are unspecified and ambiguous.
+/// Historically, some implementations have used a fourth element to carry a
+/// linear referencing measure (sometimes denoted as "M") or a numerical
+/// timestamp, but in most situations a parser will not be able to properly
+/// interpret these values.  The interpretation and meaning of additional
+/// elements is beyond the scope of this specification, and additional
+/// elements MAY be ignored by parsers.
+///
+/// A line between two positions is a straight Cartesian line, the shortest
+/// line between those two points in the coordinate reference system (see
+/// Section 4).
+///
+/// In other words, every point on a line that does not cross the
+/// antimeridian between a point (lon0, lat0) and (lon1, lat1) can be
+/// calculated as
+///
+/// F(lon, lat) = (lon0 + (lon1 - lon0) * t, lat0 + (lat1 - lat0) * t)
+///
+/// with t being a real number greater than or equal to 0 and smaller than
+/// or equal to 1.  Note that this line may markedly differ from the
+/// geodesic path along the curved surface of the reference ellipsoid.
+///
+/// The same applies to the optional height element with the proviso that
+/// the direction of the height is as specified in the coordinate reference
+/// system.
+///
+/// Note that, again, this does not mean that a surface with equal height
+/// follows, for example, the curvature of a body of water.  Nor is a
+/// surface of equal height perpendicular to a plumb line.
+pub type Position = Vec<f64>;
+
+/// Deserializing values that borrow strings from their input, even
+/// where serde's own impls would copy them out.
+pub mod borrow {
+    use std::borrow::Cow;
+
+    /// Like `serde::Deserialize`, except that strings are borrowed
+    /// wherever they are nested in options, boxes, arrays and maps.
+    pub trait Deserialize<'de>: Sized {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error>;
+    }
+
+    /// Deserializes a value with its impl of this module's
+    /// `Deserialize`. Fields that borrow are deserialized with this.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>, T: Deserialize<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+
+    /// A value deserialized with this module's `Deserialize`, for
+    /// containers to deserialize their items as.
+    struct Item<T>(T);
+
+    impl<'de, T: Deserialize<'de>> serde::Deserialize<'de> for Item<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Item)
+        }
+    }
+
+    impl<'de: 'a, 'a> Deserialize<'de> for Cow<'a, str> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            struct Visitor;
+            impl<'de> serde::de::Visitor<'de> for Visitor {
+                type Value = Cow<'de, str>;
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("a string")
+                }
+                fn visit_borrowed_str<E: serde::de::Error>(
+                    self,
+                    value: &'de str,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Borrowed(value))
+                }
+                fn visit_str<E: serde::de::Error>(
+                    self,
+                    value: &str,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Owned(String::from(value)))
+                }
+                fn visit_string<E: serde::de::Error>(
+                    self,
+                    value: String,
+                ) -> core::result::Result<Self::Value, E> {
+                    Ok(Cow::Owned(value))
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl<'de: 'a, 'a> Deserialize<'de> for &'a serde_json::value::RawValue {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            serde::Deserialize::deserialize(deserializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Option<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            let value: Option<Item<T>> = serde::Deserialize::deserialize(deserializer)?;
+            Ok(value.map(|Item(value)| value))
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Box<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Box::new)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec<T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            struct Visitor<T>(core::marker::PhantomData<T>);
+            impl<'de, T: Deserialize<'de>> serde::de::Visitor<'de> for Visitor<T> {
+                type Value = Vec<T>;
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("an array")
+                }
+                fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                    self,
+                    mut seq: A,
+                ) -> core::result::Result<Self::Value, A::Error> {
+                    let mut values = Vec::new();
+                    while let Some(Item(value)) = seq.next_element()? {
+                        values.push(value);
+                    }
+                    Ok(values)
+                }
+            }
+            deserializer.deserialize_seq(Visitor(core::marker::PhantomData))
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de>
+    for std::collections::HashMap<String, T> {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error> {
+            struct Visitor<T>(core::marker::PhantomData<T>);
+            impl<'de, T: Deserialize<'de>> serde::de::Visitor<'de> for Visitor<T> {
+                type Value = std::collections::HashMap<String, T>;
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("an object")
+                }
+                fn visit_map<A: serde::de::MapAccess<'de>>(
+                    self,
+                    mut map: A,
+                ) -> core::result::Result<Self::Value, A::Error> {
+                    let mut values = std::collections::HashMap::new();
+                    while let Some((key, Item(value))) = map.next_entry()? {
+                        values.insert(key, value);
+                    }
+                    Ok(values)
+                }
+            }
+            deserializer.deserialize_map(Visitor(core::marker::PhantomData))
+        }
+    }
+}
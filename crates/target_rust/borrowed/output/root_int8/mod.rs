@@ -0,0 +1,3 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+pub type Root = i8;
@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use chrono::{DateTime, FixedOffset};
+
+pub type Root = DateTime<FixedOffset>;
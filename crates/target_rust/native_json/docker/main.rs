@@ -0,0 +1,13 @@
+mod jtd_codegen_e2e;
+
+use jtd_codegen_e2e::json;
+use std::io::BufRead;
+
+fn main() {
+    for line in std::io::stdin().lock().lines() {
+        let line = line.unwrap();
+        let input: jtd_codegen_e2e::MAIN = json::from_json(&line).unwrap();
+
+        println!("{}", json::to_json(&input));
+    }
+}
@@ -0,0 +1,1050 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+pub type Root = Foo0;
+
+pub type Bar = Bar0;
+
+pub type Foo = Bar;
+
+pub type Bar0 = String;
+
+pub type Foo0 = Foo;
+
+/// Parsing and writing JSON for the types in this module, without
+/// depending on serde.
+pub mod json {
+    use std::borrow::Cow;
+    use std::collections::BTreeMap;
+    use core::convert::TryFrom;
+    use core::fmt::{self, Write};
+
+    /// The greatest depth of nested arrays and objects that will be parsed.
+    pub const MAX_DEPTH: usize = 128;
+
+    /// An error encountered while parsing a JSON document.
+    ///
+    /// This covers both input that is not valid JSON, and JSON that does not
+    /// satisfy the schema that the type being parsed was generated from.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Error {
+        /// The byte offset into the input at which the error was detected.
+        pub offset: usize,
+        /// A description of what was wrong with the input.
+        pub message: &'static str,
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} at offset {}", self.message, self.offset)
+        }
+    }
+
+    /// Parses a value out of a JSON document.
+    pub fn from_json<T: FromJson>(input: &str) -> Result<T, Error> {
+        let mut reader = Reader::new(input);
+        let value = T::read_json(&mut reader)?;
+        reader.end()?;
+        Ok(value)
+    }
+
+    /// Parses a value out of a JSON document held in bytes, which must be UTF-8.
+    pub fn from_json_slice<T: FromJson>(input: &[u8]) -> Result<T, Error> {
+        match core::str::from_utf8(input) {
+            Ok(input) => from_json(input),
+            Err(err) => {
+                Err(Error {
+                    offset: err.valid_up_to(),
+                    message: "invalid UTF-8",
+                })
+            }
+        }
+    }
+
+    /// Writes out a value as a JSON document.
+    pub fn to_json<T: ToJson + ?Sized>(value: &T) -> String {
+        let mut writer = Writer::new();
+        value.write_json(&mut writer);
+        writer.into_string()
+    }
+
+    /// Types that can be parsed out of JSON.
+    pub trait FromJson: Sized {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error>;
+    }
+
+    /// Types that can be written out as JSON.
+    pub trait ToJson {
+        fn write_json(&self, writer: &mut Writer);
+    }
+
+    /// Types that are written out as JSON objects, and whose properties can be
+    /// written out as part of another object.
+    ///
+    /// This is how discriminators write their tag alongside the properties of
+    /// their variants.
+    pub trait ToJsonProperties {
+        fn write_json_properties(&self, writer: &mut Writer, first: &mut bool);
+    }
+
+    /// Arbitrary JSON data, for schemas that accept any value.
+    ///
+    /// Numbers are kept as they appeared in the input, so no precision is lost.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Value {
+        Null,
+        Bool(bool),
+        Number(String),
+        String(String),
+        Array(Vec<Value>),
+        Object(BTreeMap<String, Value>),
+    }
+
+    /// Reads JSON tokens out of a string.
+    pub struct Reader<'de> {
+        lexer: Lexer<'de>,
+        tape: Vec<(usize, Token<'de>)>,
+        position: usize,
+    }
+
+    enum Token<'de> {
+        BeginObject(usize),
+        EndObject,
+        BeginArray(usize),
+        EndArray,
+        Str(Cow<'de, str>),
+        Number(&'de str),
+        Bool(bool),
+        Null,
+    }
+
+    impl<'de> Reader<'de> {
+        pub fn new(input: &'de str) -> Self {
+            Self {
+                lexer: Lexer {
+                    input,
+                    offset: 0,
+                    depth: 0,
+                },
+                tape: Vec::new(),
+                position: 0,
+            }
+        }
+        /// Constructs an error at the current position in the input.
+        pub fn error(&self, message: &'static str) -> Error {
+            match self.tape.get(self.position) {
+                Some(&(offset, _)) => Error { offset, message },
+                None => self.lexer.error(message),
+            }
+        }
+        /// Ensures nothing but whitespace remains in the input.
+        pub fn end(&mut self) -> Result<(), Error> {
+            self.lexer.end()
+        }
+        /// Consumes a null, if that is what comes next.
+        pub fn read_null(&mut self) -> Result<bool, Error> {
+            match self.tape.get(self.position) {
+                Some((_, Token::Null)) => {
+                    self.position += 1;
+                    Ok(true)
+                }
+                Some(_) => Ok(false),
+                None => self.lexer.read_null(),
+            }
+        }
+        pub fn read_bool(&mut self) -> Result<bool, Error> {
+            match self.tape.get(self.position) {
+                Some(&(_, Token::Bool(value))) => {
+                    self.position += 1;
+                    Ok(value)
+                }
+                Some(_) => Err(self.error("expected boolean")),
+                None => self.lexer.read_bool(),
+            }
+        }
+        /// Reads a number, returning its text as it appeared in the input.
+        pub fn read_number(&mut self) -> Result<&'de str, Error> {
+            match self.tape.get(self.position) {
+                Some(&(_, Token::Number(text))) => {
+                    self.position += 1;
+                    Ok(text)
+                }
+                Some(_) => Err(self.error("expected number")),
+                None => self.lexer.read_number(),
+            }
+        }
+        /// Reads an integer that fits in T.
+        ///
+        /// Like JSON Typedef itself, this accepts numbers with a zero fractional
+        /// part, such as 1.0, as integers.
+        pub fn read_integer<T: TryFrom<i64>>(&mut self) -> Result<T, Error> {
+            let start = self.start();
+            let text = self.read_number()?;
+            let value = match text.parse::<i64>() {
+                Ok(value) => Some(value),
+                Err(_) => {
+                    match text.parse::<f64>() {
+                        Ok(value) if value as i64 as f64 == value => Some(value as i64),
+                        _ => None,
+                    }
+                }
+            };
+            match value.and_then(|value| T::try_from(value).ok()) {
+                Some(value) => Ok(value),
+                None => {
+                    self.rewind(start);
+                    Err(self.error("expected integer in range"))
+                }
+            }
+        }
+        pub fn read_float(&mut self) -> Result<f64, Error> {
+            let start = self.start();
+            match self.read_number()?.parse::<f64>() {
+                Ok(value) if value.is_finite() => Ok(value),
+                _ => {
+                    self.rewind(start);
+                    Err(self.error("number out of range"))
+                }
+            }
+        }
+        /// Reads a string. The returned string borrows from the input, unless it
+        /// contained escape sequences.
+        pub fn read_str(&mut self) -> Result<Cow<'de, str>, Error> {
+            match self.tape.get_mut(self.position) {
+                Some((_, Token::Str(value))) => {
+                    let value = core::mem::replace(value, Cow::Borrowed(""));
+                    self.position += 1;
+                    Ok(value)
+                }
+                Some(_) => Err(self.error("expected string")),
+                None => self.lexer.read_str(),
+            }
+        }
+        /// Consumes the opening brace of an object.
+        pub fn begin_object(&mut self) -> Result<(), Error> {
+            match self.tape.get(self.position) {
+                Some((_, Token::BeginObject(_))) => {
+                    self.position += 1;
+                    Ok(())
+                }
+                Some(_) => Err(self.error("expected object")),
+                None => self.lexer.begin_object(),
+            }
+        }
+        /// Reads the key of the next property of an object, or consumes the
+        /// closing brace of the object and returns None.
+        ///
+        /// `first` tracks whether a comma is expected before the next property.
+        /// It should start out as true for each object.
+        pub fn next_key(
+            &mut self,
+            first: &mut bool,
+        ) -> Result<Option<Cow<'de, str>>, Error> {
+            match self.tape.get(self.position) {
+                Some((_, Token::EndObject)) => {
+                    self.position += 1;
+                    Ok(None)
+                }
+                Some(_) => {
+                    *first = false;
+                    self.read_str().map(Some)
+                }
+                None => self.lexer.next_key(first),
+            }
+        }
+        /// Consumes the opening bracket of an array.
+        pub fn begin_array(&mut self) -> Result<(), Error> {
+            match self.tape.get(self.position) {
+                Some((_, Token::BeginArray(_))) => {
+                    self.position += 1;
+                    Ok(())
+                }
+                Some(_) => Err(self.error("expected array")),
+                None => self.lexer.begin_array(),
+            }
+        }
+        /// Returns whether there is another element in an array, or consumes the
+        /// closing bracket of the array and returns false.
+        ///
+        /// `first` works the same way as with `next_key`.
+        pub fn next_element(&mut self, first: &mut bool) -> Result<bool, Error> {
+            match self.tape.get(self.position) {
+                Some((_, Token::EndArray)) => {
+                    self.position += 1;
+                    Ok(false)
+                }
+                Some(_) => {
+                    *first = false;
+                    Ok(true)
+                }
+                None => self.lexer.next_element(first),
+            }
+        }
+        /// Checks that a value is well-formed, and discards it.
+        pub fn skip_value(&mut self) -> Result<(), Error> {
+            match self.tape.get(self.position) {
+                Some(&(_, Token::BeginObject(end))) | Some(&(_, Token::BeginArray(end))) => {
+                    self.position = end;
+                }
+                Some(_) => self.position += 1,
+                None => return self.lexer.skip_value(),
+            }
+            Ok(())
+        }
+        /// Finds the value of the tag property of the object that comes next,
+        /// without consuming the object.
+        ///
+        /// When the tag is not the first property, the object is read ahead into
+        /// a buffer, which the object is then parsed from. Any discriminators
+        /// nested within it find their tags in that buffer, rather than reading
+        /// ahead again.
+        pub fn peek_tag(&mut self, tag: &str) -> Result<Cow<'de, str>, Error> {
+            if self.position == self.tape.len() {
+                let mut lookahead = self.lexer.clone();
+                lookahead.begin_object()?;
+                if let Some(key) = lookahead.next_key(&mut true)? {
+                    if key == tag {
+                        return lookahead.read_str();
+                    }
+                }
+                self.tape.clear();
+                self.position = 0;
+                self.buffer_value()?;
+            }
+            let end = match self.tape[self.position] {
+                (_, Token::BeginObject(end)) => end,
+                _ => return Err(self.error("expected object")),
+            };
+            let mut index = self.position + 1;
+            while index + 1 < end {
+                let (offset, ref value) = self.tape[index + 1];
+                if let Token::Str(key) = &self.tape[index].1 {
+                    if key == tag {
+                        return match value {
+                            Token::Str(value) => Ok(value.clone()),
+                            _ => {
+                                Err(Error {
+                                    offset,
+                                    message: "expected string",
+                                })
+                            }
+                        };
+                    }
+                }
+                index = match *value {
+                    Token::BeginObject(end) | Token::BeginArray(end) => end,
+                    _ => index + 2,
+                };
+            }
+            Err(self.error("missing discriminator tag"))
+        }
+        fn buffer_value(&mut self) -> Result<(), Error> {
+            let mut first = true;
+            let offset = match self.lexer.peek() {
+                Some(_) => self.lexer.offset,
+                None => return Err(self.lexer.error("expected value")),
+            };
+            let token = match self.lexer.peek() {
+                Some(b'{') => {
+                    let start = self.tape.len();
+                    self.tape.push((offset, Token::BeginObject(0)));
+                    self.lexer.begin_object()?;
+                    loop {
+                        self.lexer.peek();
+                        let offset = self.lexer.offset;
+                        match self.lexer.next_key(&mut first)? {
+                            Some(key) => {
+                                self.tape.push((offset, Token::Str(key)));
+                                self.buffer_value()?;
+                            }
+                            None => {
+                                self.tape.push((offset, Token::EndObject));
+                                break;
+                            }
+                        }
+                    }
+                    self.tape[start].1 = Token::BeginObject(self.tape.len());
+                    return Ok(());
+                }
+                Some(b'[') => {
+                    let start = self.tape.len();
+                    self.tape.push((offset, Token::BeginArray(0)));
+                    self.lexer.begin_array()?;
+                    loop {
+                        self.lexer.peek();
+                        let offset = self.lexer.offset;
+                        if self.lexer.next_element(&mut first)? {
+                            self.buffer_value()?;
+                        } else {
+                            self.tape.push((offset, Token::EndArray));
+                            break;
+                        }
+                    }
+                    self.tape[start].1 = Token::BeginArray(self.tape.len());
+                    return Ok(());
+                }
+                Some(b'"') => Token::Str(self.lexer.read_str()?),
+                Some(b't') | Some(b'f') => Token::Bool(self.lexer.read_bool()?),
+                Some(b'n') => {
+                    self.lexer.literal("null")?;
+                    Token::Null
+                }
+                _ => Token::Number(self.lexer.read_number()?),
+            };
+            self.tape.push((offset, token));
+            Ok(())
+        }
+        fn peek(&mut self) -> Option<u8> {
+            match self.tape.get(self.position) {
+                Some((_, token)) => {
+                    Some(
+                        match token {
+                            Token::BeginObject(_) => b'{',
+                            Token::EndObject => b'}',
+                            Token::BeginArray(_) => b'[',
+                            Token::EndArray => b']',
+                            Token::Str(_) => b'"',
+                            Token::Number(_) => b'0',
+                            Token::Bool(true) => b't',
+                            Token::Bool(false) => b'f',
+                            Token::Null => b'n',
+                        },
+                    )
+                }
+                None => self.lexer.peek(),
+            }
+        }
+        fn start(&mut self) -> (usize, usize) {
+            self.lexer.peek();
+            (self.position, self.lexer.offset)
+        }
+        fn rewind(&mut self, (position, offset): (usize, usize)) {
+            self.position = position;
+            self.lexer.offset = offset;
+        }
+    }
+
+    #[derive(Clone)]
+    struct Lexer<'de> {
+        input: &'de str,
+        offset: usize,
+        depth: usize,
+    }
+
+    impl<'de> Lexer<'de> {
+        fn error(&self, message: &'static str) -> Error {
+            Error {
+                offset: self.offset,
+                message,
+            }
+        }
+        fn end(&mut self) -> Result<(), Error> {
+            match self.peek() {
+                Some(_) => Err(self.error("trailing characters")),
+                None => Ok(()),
+            }
+        }
+        fn read_null(&mut self) -> Result<bool, Error> {
+            if self.peek() == Some(b'n') {
+                self.literal("null")?;
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+        fn read_bool(&mut self) -> Result<bool, Error> {
+            match self.peek() {
+                Some(b't') => self.literal("true").map(|_| true),
+                Some(b'f') => self.literal("false").map(|_| false),
+                _ => Err(self.error("expected boolean")),
+            }
+        }
+        fn read_number(&mut self) -> Result<&'de str, Error> {
+            self.peek();
+            let start = self.offset;
+            if self.byte() == Some(b'-') {
+                self.offset += 1;
+            }
+            match self.byte() {
+                Some(b'0') => self.offset += 1,
+                Some(b'1'..=b'9') => self.digits(),
+                _ => return Err(self.error("expected number")),
+            }
+            if self.byte() == Some(b'.') {
+                self.offset += 1;
+                if !matches!(self.byte(), Some(b'0'..= b'9')) {
+                    return Err(self.error("expected digit"));
+                }
+                self.digits();
+            }
+            if matches!(self.byte(), Some(b'e') | Some(b'E')) {
+                self.offset += 1;
+                if matches!(self.byte(), Some(b'+') | Some(b'-')) {
+                    self.offset += 1;
+                }
+                if !matches!(self.byte(), Some(b'0'..= b'9')) {
+                    return Err(self.error("expected digit"));
+                }
+                self.digits();
+            }
+            Ok(&self.input[start..self.offset])
+        }
+        fn read_str(&mut self) -> Result<Cow<'de, str>, Error> {
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected string"));
+            }
+            self.offset += 1;
+            let start = self.offset;
+            loop {
+                match self.byte() {
+                    Some(b'"') => {
+                        let value = &self.input[start..self.offset];
+                        self.offset += 1;
+                        return Ok(Cow::Borrowed(value));
+                    }
+                    Some(b'\\') => break,
+                    Some(0x00..=0x1f) => {
+                        return Err(self.error("control character in string"));
+                    }
+                    Some(_) => self.offset += 1,
+                    None => return Err(self.error("unterminated string")),
+                }
+            }
+            let mut value = String::from(&self.input[start..self.offset]);
+            loop {
+                match self.byte() {
+                    Some(b'"') => {
+                        self.offset += 1;
+                        return Ok(Cow::Owned(value));
+                    }
+                    Some(b'\\') => {
+                        self.offset += 1;
+                        let escaped = match self.byte() {
+                            Some(b'u') => {
+                                self.offset += 1;
+                                self.unicode_escape()?
+                            }
+                            Some(byte) => {
+                                let escaped = match byte {
+                                    b'"' => '"',
+                                    b'\\' => '\\',
+                                    b'/' => '/',
+                                    b'b' => '\u{8}',
+                                    b'f' => '\u{c}',
+                                    b'n' => '\n',
+                                    b'r' => '\r',
+                                    b't' => '\t',
+                                    _ => return Err(self.error("invalid escape")),
+                                };
+                                self.offset += 1;
+                                escaped
+                            }
+                            None => return Err(self.error("invalid escape")),
+                        };
+                        value.push(escaped);
+                    }
+                    Some(0x00..=0x1f) => {
+                        return Err(self.error("control character in string"));
+                    }
+                    Some(_) => {
+                        let run_start = self.offset;
+                        while !matches!(
+                            self.byte(), None | Some(b'"') | Some(b'\\') | Some(0x00..= 0x1f)
+                        ) {
+                            self.offset += 1;
+                        }
+                        value.push_str(&self.input[run_start..self.offset]);
+                    }
+                    None => return Err(self.error("unterminated string")),
+                }
+            }
+        }
+        fn begin_object(&mut self) -> Result<(), Error> {
+            if self.peek() != Some(b'{') {
+                return Err(self.error("expected object"));
+            }
+            self.offset += 1;
+            self.enter()
+        }
+        fn next_key(&mut self, first: &mut bool) -> Result<Option<Cow<'de, str>>, Error> {
+            if self.peek() == Some(b'}') {
+                self.offset += 1;
+                self.depth -= 1;
+                return Ok(None);
+            }
+            if !*first {
+                self.punctuation(b',', "expected ',' or '}'")?;
+            }
+            *first = false;
+            let key = self.read_str()?;
+            self.punctuation(b':', "expected ':'")?;
+            Ok(Some(key))
+        }
+        fn begin_array(&mut self) -> Result<(), Error> {
+            if self.peek() != Some(b'[') {
+                return Err(self.error("expected array"));
+            }
+            self.offset += 1;
+            self.enter()
+        }
+        fn next_element(&mut self, first: &mut bool) -> Result<bool, Error> {
+            if self.peek() == Some(b']') {
+                self.offset += 1;
+                self.depth -= 1;
+                return Ok(false);
+            }
+            if !*first {
+                self.punctuation(b',', "expected ',' or ']'")?;
+            }
+            *first = false;
+            Ok(true)
+        }
+        fn skip_value(&mut self) -> Result<(), Error> {
+            let mut first = true;
+            match self.peek() {
+                Some(b'{') => {
+                    self.begin_object()?;
+                    while self.next_key(&mut first)?.is_some() {
+                        self.skip_value()?;
+                    }
+                }
+                Some(b'[') => {
+                    self.begin_array()?;
+                    while self.next_element(&mut first)? {
+                        self.skip_value()?;
+                    }
+                }
+                Some(b'"') => {
+                    self.read_str()?;
+                }
+                Some(b't') | Some(b'f') => {
+                    self.read_bool()?;
+                }
+                Some(b'n') => self.literal("null")?,
+                _ => {
+                    self.read_number()?;
+                }
+            }
+            Ok(())
+        }
+        fn byte(&self) -> Option<u8> {
+            self.input.as_bytes().get(self.offset).copied()
+        }
+        fn peek(&mut self) -> Option<u8> {
+            while matches!(
+                self.byte(), Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r')
+            ) {
+                self.offset += 1;
+            }
+            self.byte()
+        }
+        fn digits(&mut self) {
+            while matches!(self.byte(), Some(b'0'..= b'9')) {
+                self.offset += 1;
+            }
+        }
+        fn literal(&mut self, literal: &'static str) -> Result<(), Error> {
+            if self.input.as_bytes()[self.offset..].starts_with(literal.as_bytes()) {
+                self.offset += literal.len();
+                Ok(())
+            } else {
+                Err(self.error("invalid literal"))
+            }
+        }
+        fn punctuation(&mut self, byte: u8, message: &'static str) -> Result<(), Error> {
+            if self.peek() == Some(byte) {
+                self.offset += 1;
+                Ok(())
+            } else {
+                Err(self.error(message))
+            }
+        }
+        fn enter(&mut self) -> Result<(), Error> {
+            self.depth += 1;
+            if self.depth > MAX_DEPTH {
+                Err(self.error("too deeply nested"))
+            } else {
+                Ok(())
+            }
+        }
+        fn unicode_escape(&mut self) -> Result<char, Error> {
+            let high = self.hex()?;
+            if !(0xd800..0xdc00).contains(&high) {
+                return core::char::from_u32(high)
+                    .ok_or_else(|| self.error("invalid escape"));
+            }
+            if !self.input.as_bytes()[self.offset..].starts_with(b"\\u") {
+                return Err(self.error("unpaired surrogate"));
+            }
+            self.offset += 2;
+            let low = self.hex()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(self.error("unpaired surrogate"));
+            }
+            core::char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+                .ok_or_else(|| self.error("invalid escape"))
+        }
+        fn hex(&mut self) -> Result<u32, Error> {
+            let mut value = 0;
+            for _ in 0..4 {
+                let digit = match self.byte() {
+                    Some(b @ b'0'..=b'9') => b - b'0',
+                    Some(b @ b'a'..=b'f') => b - b'a' + 10,
+                    Some(b @ b'A'..=b'F') => b - b'A' + 10,
+                    _ => return Err(self.error("invalid escape")),
+                };
+                value = value * 16 + u32::from(digit);
+                self.offset += 1;
+            }
+            Ok(value)
+        }
+    }
+
+    /// Writes JSON tokens out to a string.
+    pub struct Writer {
+        out: String,
+    }
+
+    impl Writer {
+        pub fn new() -> Self {
+            Self { out: String::new() }
+        }
+        pub fn into_string(self) -> String {
+            self.out
+        }
+        /// Writes out text that is already valid JSON.
+        pub fn write_raw(&mut self, json: &str) {
+            self.out.push_str(json);
+        }
+        /// Writes out the key of a property of an object, preceded by a comma
+        /// unless it is the first property. The key must already be a JSON string.
+        pub fn write_key(&mut self, first: &mut bool, key: &str) {
+            if !*first {
+                self.out.push(',');
+            }
+            *first = false;
+            self.out.push_str(key);
+            self.out.push(':');
+        }
+        /// Writes out a string, escaping it as needed.
+        pub fn write_str(&mut self, value: &str) {
+            self.out.push('"');
+            let mut start = 0;
+            for (index, byte) in value.bytes().enumerate() {
+                let escaped = match byte {
+                    b'"' => "\\\"",
+                    b'\\' => "\\\\",
+                    b'\n' => "\\n",
+                    b'\r' => "\\r",
+                    b'\t' => "\\t",
+                    0x08 => "\\b",
+                    0x0c => "\\f",
+                    0x00..=0x1f => "",
+                    _ => continue,
+                };
+                self.out.push_str(&value[start..index]);
+                if escaped.is_empty() {
+                    let _ = write!(self.out, "\\u{:04x}", byte);
+                } else {
+                    self.out.push_str(escaped);
+                }
+                start = index + 1;
+            }
+            self.out.push_str(&value[start..]);
+            self.out.push('"');
+        }
+        /// Writes out an integer.
+        pub fn write_integer(&mut self, value: i64) {
+            let mut digits = [0u8; 20];
+            let mut start = digits.len();
+            let mut rest = if value < 0 {
+                (value as u64).wrapping_neg()
+            } else {
+                value as u64
+            };
+            loop {
+                start -= 1;
+                digits[start] = b'0' + (rest % 10) as u8;
+                rest /= 10;
+                if rest == 0 {
+                    break;
+                }
+            }
+            if value < 0 {
+                start -= 1;
+                digits[start] = b'-';
+            }
+            if let Ok(digits) = core::str::from_utf8(&digits[start..]) {
+                self.out.push_str(digits);
+            }
+        }
+        /// Writes out formatted text, which must be valid JSON. This makes the
+        /// write! macro usable with a Writer.
+        pub fn write_fmt(&mut self, args: fmt::Arguments) {
+            let _ = self.out.write_fmt(args);
+        }
+    }
+
+    impl Default for Writer {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Reads the properties of an object into a map.
+    pub fn read_map<M: Default + Extend<(String, T)>, T: FromJson>(
+        reader: &mut Reader,
+    ) -> Result<M, Error> {
+        let mut map = M::default();
+        let mut first = true;
+        reader.begin_object()?;
+        while let Some(key) = reader.next_key(&mut first)? {
+            let value = T::read_json(reader)?;
+            map.extend(Some((key.into_owned(), value)));
+        }
+        Ok(map)
+    }
+
+    /// Writes out the entries of a map as an object.
+    pub fn write_map<'a, T: ToJson + 'a>(
+        writer: &mut Writer,
+        entries: impl Iterator<Item = (&'a String, &'a T)>,
+    ) {
+        let mut first = true;
+        writer.write_raw("{");
+        for (key, value) in entries {
+            if !first {
+                writer.write_raw(",");
+            }
+            first = false;
+            writer.write_str(key);
+            writer.write_raw(":");
+            value.write_json(writer);
+        }
+        writer.write_raw("}");
+    }
+
+    impl FromJson for bool {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_bool()
+        }
+    }
+
+    impl ToJson for bool {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_raw(if *self { "true" } else { "false" });
+        }
+    }
+
+    impl FromJson for i8 {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_integer()
+        }
+    }
+
+    impl ToJson for i8 {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_integer(i64::from(*self));
+        }
+    }
+
+    impl FromJson for u8 {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_integer()
+        }
+    }
+
+    impl ToJson for u8 {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_integer(i64::from(*self));
+        }
+    }
+
+    impl FromJson for i16 {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_integer()
+        }
+    }
+
+    impl ToJson for i16 {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_integer(i64::from(*self));
+        }
+    }
+
+    impl FromJson for u16 {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_integer()
+        }
+    }
+
+    impl ToJson for u16 {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_integer(i64::from(*self));
+        }
+    }
+
+    impl FromJson for i32 {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_integer()
+        }
+    }
+
+    impl ToJson for i32 {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_integer(i64::from(*self));
+        }
+    }
+
+    impl FromJson for u32 {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_integer()
+        }
+    }
+
+    impl ToJson for u32 {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_integer(i64::from(*self));
+        }
+    }
+
+    impl FromJson for f32 {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_float().map(|value| value as f32)
+        }
+    }
+
+    impl ToJson for f32 {
+        fn write_json(&self, writer: &mut Writer) {
+            if self.is_finite() {
+                writer.write_raw(ryu::Buffer::new().format_finite(*self));
+            } else {
+                writer.write_raw("null");
+            }
+        }
+    }
+
+    impl FromJson for f64 {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_float()
+        }
+    }
+
+    impl ToJson for f64 {
+        fn write_json(&self, writer: &mut Writer) {
+            if self.is_finite() {
+                writer.write_raw(ryu::Buffer::new().format_finite(*self));
+            } else {
+                writer.write_raw("null");
+            }
+        }
+    }
+
+    impl FromJson for String {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            reader.read_str().map(Cow::into_owned)
+        }
+    }
+
+    impl ToJson for String {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_str(self);
+        }
+    }
+
+    impl<T: FromJson> FromJson for Option<T> {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            if reader.read_null()? { Ok(None) } else { T::read_json(reader).map(Some) }
+        }
+    }
+
+    impl<T: ToJson> ToJson for Option<T> {
+        fn write_json(&self, writer: &mut Writer) {
+            match self {
+                Some(value) => value.write_json(writer),
+                None => writer.write_raw("null"),
+            }
+        }
+    }
+
+    impl<T: FromJson> FromJson for Box<T> {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            T::read_json(reader).map(Box::new)
+        }
+    }
+
+    impl<T: ToJson + ?Sized> ToJson for Box<T> {
+        fn write_json(&self, writer: &mut Writer) {
+            (**self).write_json(writer);
+        }
+    }
+
+    impl<T: FromJson> FromJson for Vec<T> {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            let mut values = Vec::new();
+            let mut first = true;
+            reader.begin_array()?;
+            while reader.next_element(&mut first)? {
+                values.push(T::read_json(reader)?);
+            }
+            Ok(values)
+        }
+    }
+
+    impl<T: ToJson> ToJson for Vec<T> {
+        fn write_json(&self, writer: &mut Writer) {
+            writer.write_raw("[");
+            for (index, value) in self.iter().enumerate() {
+                if index != 0 {
+                    writer.write_raw(",");
+                }
+                value.write_json(writer);
+            }
+            writer.write_raw("]");
+        }
+    }
+
+    impl<T: FromJson> FromJson for BTreeMap<String, T> {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            read_map(reader)
+        }
+    }
+
+    impl<T: ToJson> ToJson for BTreeMap<String, T> {
+        fn write_json(&self, writer: &mut Writer) {
+            write_map(writer, self.iter());
+        }
+    }
+
+    impl FromJson for Value {
+        fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+            Ok(
+                match reader.peek() {
+                    Some(b'n') => {
+                        reader.read_null()?;
+                        Value::Null
+                    }
+                    Some(b't') | Some(b'f') => Value::Bool(reader.read_bool()?),
+                    Some(b'"') => Value::String(reader.read_str()?.into_owned()),
+                    Some(b'[') => Value::Array(FromJson::read_json(reader)?),
+                    Some(b'{') => Value::Object(FromJson::read_json(reader)?),
+                    _ => Value::Number(String::from(reader.read_number()?)),
+                },
+            )
+        }
+    }
+
+    impl ToJson for Value {
+        fn write_json(&self, writer: &mut Writer) {
+            match self {
+                Value::Null => writer.write_raw("null"),
+                Value::Bool(value) => value.write_json(writer),
+                Value::Number(value) => writer.write_raw(value),
+                Value::String(value) => writer.write_str(value),
+                Value::Array(value) => value.write_json(writer),
+                Value::Object(value) => value.write_json(writer),
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+}
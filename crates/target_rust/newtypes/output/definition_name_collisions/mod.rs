@@ -0,0 +1,154 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Foo0);
+
+impl From<Foo0> for Root {
+    fn from(value: Foo0) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Foo0 {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Foo0;
+    fn deref(&self) -> &Foo0 {
+        &self.0
+    }
+}
+
+impl AsRef<Foo0> for Root {
+    fn as_ref(&self) -> &Foo0 {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Bar(pub Bar0);
+
+impl From<Bar0> for Bar {
+    fn from(value: Bar0) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bar> for Bar0 {
+    fn from(value: Bar) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Bar {
+    type Target = Bar0;
+    fn deref(&self) -> &Bar0 {
+        &self.0
+    }
+}
+
+impl AsRef<Bar0> for Bar {
+    fn as_ref(&self) -> &Bar0 {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Foo(pub Bar);
+
+impl From<Bar> for Foo {
+    fn from(value: Bar) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Foo> for Bar {
+    fn from(value: Foo) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Foo {
+    type Target = Bar;
+    fn deref(&self) -> &Bar {
+        &self.0
+    }
+}
+
+impl AsRef<Bar> for Foo {
+    fn as_ref(&self) -> &Bar {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Bar0(pub String);
+
+impl From<String> for Bar0 {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bar0> for String {
+    fn from(value: Bar0) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Bar0 {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Bar0 {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Bar0 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Foo0(pub Foo);
+
+impl From<Foo> for Foo0 {
+    fn from(value: Foo) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Foo0> for Foo {
+    fn from(value: Foo0) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Foo0 {
+    type Target = Foo;
+    fn deref(&self) -> &Foo {
+        &self.0
+    }
+}
+
+impl AsRef<Foo> for Foo0 {
+    fn as_ref(&self) -> &Foo {
+        &self.0
+    }
+}
@@ -0,0 +1,127 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+/// A description for discriminator
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "foo")]
+pub enum RootDiscriminatorWithDescription {
+    #[serde(rename = "bar")]
+    Bar(RootDiscriminatorWithDescriptionBar),
+}
+
+impl RootDiscriminatorWithDescription {
+    pub const TAGS: &'static [&'static str] = &["bar"];
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Self::Bar(_) => "bar",
+        }
+    }
+    pub fn as_bar(&self) -> Option<&RootDiscriminatorWithDescriptionBar> {
+        match self {
+            Self::Bar(value) => Some(value),
+        }
+    }
+}
+
+impl From<RootDiscriminatorWithDescriptionBar> for RootDiscriminatorWithDescription {
+    fn from(value: RootDiscriminatorWithDescriptionBar) -> Self {
+        Self::Bar(value)
+    }
+}
+
+impl std::convert::TryFrom<RootDiscriminatorWithDescription>
+for RootDiscriminatorWithDescriptionBar {
+    type Error = RootDiscriminatorWithDescription;
+    fn try_from(
+        value: RootDiscriminatorWithDescription,
+    ) -> std::result::Result<Self, RootDiscriminatorWithDescription> {
+        match value {
+            RootDiscriminatorWithDescription::Bar(value) => Ok(value),
+        }
+    }
+}
+
+/// A description for discriminator variant
+#[derive(Serialize, Deserialize)]
+pub struct RootDiscriminatorWithDescriptionBar {}
+
+/// A description for enum
+#[derive(Serialize, Deserialize)]
+pub enum RootEnumWithDescription {
+    /// A description for X
+    #[serde(rename = "X")]
+    X,
+    /// A description for Y
+    #[serde(rename = "Y")]
+    Y,
+    /// A description for Z
+    #[serde(rename = "Z")]
+    Z,
+}
+
+/// A description for properties
+#[derive(Serialize, Deserialize)]
+pub struct RootPropertiesWithDescription {}
+
+#[derive(Serialize, Deserialize)]
+pub struct Root {
+    /// A description for discriminator
+    #[serde(rename = "discriminator_with_description")]
+    pub discriminatorWithDescription: RootDiscriminatorWithDescription,
+    /// A description for enum
+    #[serde(rename = "enum_with_description")]
+    pub enumWithDescription: RootEnumWithDescription,
+    /// Whereas disregard and contempt for human rights have resulted in
+    /// barbarous acts which have outraged the conscience of mankind, and the
+    /// advent of a world in which human beings shall enjoy freedom of speech
+    /// and belief and freedom from fear and want has been proclaimed as the
+    /// highest aspiration of the common people,
+    #[serde(rename = "long_description")]
+    pub longDescription: String,
+    /// A description for properties
+    #[serde(rename = "properties_with_description")]
+    pub propertiesWithDescription: RootPropertiesWithDescription,
+    /// A description for ref
+    #[serde(rename = "ref_with_description")]
+    pub refWithDescription: Baz,
+    /// A description for string
+    #[serde(rename = "string_with_description")]
+    pub stringWithDescription: String,
+}
+
+/// A description for a definition
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baz(pub String);
+
+impl From<String> for Baz {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Baz> for String {
+    fn from(value: Baz) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Baz {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Baz {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Baz {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
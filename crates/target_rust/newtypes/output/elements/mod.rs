@@ -0,0 +1,32 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Vec<String>);
+
+impl From<Vec<String>> for Root {
+    fn from(value: Vec<String>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Vec<String> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Vec<String>;
+    fn deref(&self) -> &Vec<String> {
+        &self.0
+    }
+}
+
+impl AsRef<Vec<String>> for Root {
+    fn as_ref(&self) -> &Vec<String> {
+        &self.0
+    }
+}
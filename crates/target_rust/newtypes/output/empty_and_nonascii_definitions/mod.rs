@@ -0,0 +1,318 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub String);
+
+impl From<String> for Root {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for String {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Root {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Root {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DefaultName(pub String);
+
+impl From<String> for DefaultName {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DefaultName> for String {
+    fn from(value: DefaultName) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for DefaultName {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for DefaultName {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for DefaultName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Foo(pub String);
+
+impl From<String> for Foo {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Foo> for String {
+    fn from(value: Foo) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Foo {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Foo {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Foo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Foo0(pub String);
+
+impl From<String> for Foo0 {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Foo0> for String {
+    fn from(value: Foo0) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Foo0 {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Foo0 {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Foo0 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Foo1(pub String);
+
+impl From<String> for Foo1 {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Foo1> for String {
+    fn from(value: Foo1) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Foo1 {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Foo1 {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Foo1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FooBar(pub String);
+
+impl From<String> for FooBar {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<FooBar> for String {
+    fn from(value: FooBar) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for FooBar {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for FooBar {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for FooBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FooBar0(pub String);
+
+impl From<String> for FooBar0 {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<FooBar0> for String {
+    fn from(value: FooBar0) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for FooBar0 {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for FooBar0 {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for FooBar0 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Foo0bar(pub String);
+
+impl From<String> for Foo0bar {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Foo0bar> for String {
+    fn from(value: Foo0bar) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Foo0bar {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Foo0bar {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Foo0bar {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FooBar1(pub String);
+
+impl From<String> for FooBar1 {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<FooBar1> for String {
+    fn from(value: FooBar1) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for FooBar1 {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for FooBar1 {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for FooBar1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
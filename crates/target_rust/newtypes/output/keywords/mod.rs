@@ -0,0 +1,81 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Root {
+    #[serde(rename = "for")]
+    pub for_: For,
+    #[serde(rename = "object")]
+    pub object: Object,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct For(pub String);
+
+impl From<String> for For {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<For> for String {
+    fn from(value: For) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for For {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for For {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for For {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Object(pub String);
+
+impl From<String> for Object {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Object> for String {
+    fn from(value: Object) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Object {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Object {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,107 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "foo")]
+pub enum Root0 {
+    #[serde(rename = "bar")]
+    Bar(RootBar),
+    #[serde(rename = "quux")]
+    Quux(RootQuux),
+}
+
+impl Root0 {
+    pub const TAGS: &'static [&'static str] = &["bar", "quux"];
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Self::Bar(_) => "bar",
+            Self::Quux(_) => "quux",
+        }
+    }
+    pub fn as_bar(&self) -> Option<&RootBar> {
+        match self {
+            Self::Bar(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_quux(&self) -> Option<&RootQuux> {
+        match self {
+            Self::Quux(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<RootBar> for Root0 {
+    fn from(value: RootBar) -> Self {
+        Self::Bar(value)
+    }
+}
+
+impl std::convert::TryFrom<Root0> for RootBar {
+    type Error = Root0;
+    fn try_from(value: Root0) -> std::result::Result<Self, Root0> {
+        match value {
+            Root0::Bar(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<RootQuux> for Root0 {
+    fn from(value: RootQuux) -> Self {
+        Self::Quux(value)
+    }
+}
+
+impl std::convert::TryFrom<Root0> for RootQuux {
+    type Error = Root0;
+    fn try_from(value: Root0) -> std::result::Result<Self, Root0> {
+        match value {
+            Root0::Quux(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RootBar {
+    #[serde(rename = "baz")]
+    pub baz: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RootQuux {
+    #[serde(rename = "quuz")]
+    pub quuz: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Option<Box<Root0>>);
+
+impl From<Option<Box<Root0>>> for Root {
+    fn from(value: Option<Box<Root0>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Option<Box<Root0>> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Option<Box<Root0>>;
+    fn deref(&self) -> &Option<Box<Root0>> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Box<Root0>>> for Root {
+    fn as_ref(&self) -> &Option<Box<Root0>> {
+        &self.0
+    }
+}
@@ -0,0 +1,32 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Option<Box<Vec<String>>>);
+
+impl From<Option<Box<Vec<String>>>> for Root {
+    fn from(value: Option<Box<Vec<String>>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Option<Box<Vec<String>>> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Option<Box<Vec<String>>>;
+    fn deref(&self) -> &Option<Box<Vec<String>>> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Box<Vec<String>>>> for Root {
+    fn as_ref(&self) -> &Option<Box<Vec<String>>> {
+        &self.0
+    }
+}
@@ -0,0 +1,44 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Root0 {
+    #[serde(rename = "bar")]
+    pub bar: String,
+    #[serde(rename = "baz")]
+    pub baz: Vec<bool>,
+    #[serde(rename = "foo")]
+    pub foo: bool,
+    #[serde(rename = "quux")]
+    pub quux: Vec<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Option<Box<Root0>>);
+
+impl From<Option<Box<Root0>>> for Root {
+    fn from(value: Option<Box<Root0>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Option<Box<Root0>> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Option<Box<Root0>>;
+    fn deref(&self) -> &Option<Box<Root0>> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Box<Root0>>> for Root {
+    fn as_ref(&self) -> &Option<Box<Root0>> {
+        &self.0
+    }
+}
@@ -0,0 +1,199 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Root {
+    #[serde(rename = "notnull_ref_notnull_string")]
+    pub notnullRefNotnullString: NotnullRefNotnullString,
+    #[serde(rename = "notnull_ref_null_string")]
+    pub notnullRefNullString: NotnullRefNullString,
+    #[serde(rename = "notnull_string")]
+    pub notnullString: NotnullString,
+    #[serde(rename = "null_ref_notnull_string")]
+    pub nullRefNotnullString: NullRefNotnullString,
+    #[serde(rename = "null_ref_null_string")]
+    pub nullRefNullString: NullRefNullString,
+    #[serde(rename = "null_string")]
+    pub nullString: NullString,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NotnullRefNotnullString(pub NotnullString);
+
+impl From<NotnullString> for NotnullRefNotnullString {
+    fn from(value: NotnullString) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NotnullRefNotnullString> for NotnullString {
+    fn from(value: NotnullRefNotnullString) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for NotnullRefNotnullString {
+    type Target = NotnullString;
+    fn deref(&self) -> &NotnullString {
+        &self.0
+    }
+}
+
+impl AsRef<NotnullString> for NotnullRefNotnullString {
+    fn as_ref(&self) -> &NotnullString {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NotnullRefNullString(pub NullString);
+
+impl From<NullString> for NotnullRefNullString {
+    fn from(value: NullString) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NotnullRefNullString> for NullString {
+    fn from(value: NotnullRefNullString) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for NotnullRefNullString {
+    type Target = NullString;
+    fn deref(&self) -> &NullString {
+        &self.0
+    }
+}
+
+impl AsRef<NullString> for NotnullRefNullString {
+    fn as_ref(&self) -> &NullString {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NotnullString(pub String);
+
+impl From<String> for NotnullString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NotnullString> for String {
+    fn from(value: NotnullString) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for NotnullString {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for NotnullString {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for NotnullString {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NullRefNotnullString(pub Option<Box<NotnullString>>);
+
+impl From<Option<Box<NotnullString>>> for NullRefNotnullString {
+    fn from(value: Option<Box<NotnullString>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NullRefNotnullString> for Option<Box<NotnullString>> {
+    fn from(value: NullRefNotnullString) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for NullRefNotnullString {
+    type Target = Option<Box<NotnullString>>;
+    fn deref(&self) -> &Option<Box<NotnullString>> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Box<NotnullString>>> for NullRefNotnullString {
+    fn as_ref(&self) -> &Option<Box<NotnullString>> {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NullRefNullString(pub Option<Box<NullString>>);
+
+impl From<Option<Box<NullString>>> for NullRefNullString {
+    fn from(value: Option<Box<NullString>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NullRefNullString> for Option<Box<NullString>> {
+    fn from(value: NullRefNullString) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for NullRefNullString {
+    type Target = Option<Box<NullString>>;
+    fn deref(&self) -> &Option<Box<NullString>> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Box<NullString>>> for NullRefNullString {
+    fn as_ref(&self) -> &Option<Box<NullString>> {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NullString(pub Option<Box<String>>);
+
+impl From<Option<Box<String>>> for NullString {
+    fn from(value: Option<Box<String>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NullString> for Option<Box<String>> {
+    fn from(value: NullString) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for NullString {
+    type Target = Option<Box<String>>;
+    fn deref(&self) -> &Option<Box<String>> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Box<String>>> for NullString {
+    fn as_ref(&self) -> &Option<Box<String>> {
+        &self.0
+    }
+}
@@ -0,0 +1,125 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Foo);
+
+impl From<Foo> for Root {
+    fn from(value: Foo) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Foo {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Foo;
+    fn deref(&self) -> &Foo {
+        &self.0
+    }
+}
+
+impl AsRef<Foo> for Root {
+    fn as_ref(&self) -> &Foo {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Bar(pub Baz);
+
+impl From<Baz> for Bar {
+    fn from(value: Baz) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bar> for Baz {
+    fn from(value: Bar) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Bar {
+    type Target = Baz;
+    fn deref(&self) -> &Baz {
+        &self.0
+    }
+}
+
+impl AsRef<Baz> for Bar {
+    fn as_ref(&self) -> &Baz {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baz(pub String);
+
+impl From<String> for Baz {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Baz> for String {
+    fn from(value: Baz) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Baz {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<String> for Baz {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Baz {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Foo(pub Bar);
+
+impl From<Bar> for Foo {
+    fn from(value: Bar) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Foo> for Bar {
+    fn from(value: Foo) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Foo {
+    type Target = Bar;
+    fn deref(&self) -> &Bar {
+        &self.0
+    }
+}
+
+impl AsRef<Bar> for Foo {
+    fn as_ref(&self) -> &Bar {
+        &self.0
+    }
+}
@@ -0,0 +1,33 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Option<Value>);
+
+impl From<Option<Value>> for Root {
+    fn from(value: Option<Value>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Option<Value> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Option<Value>;
+    fn deref(&self) -> &Option<Value> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Value>> for Root {
+    fn as_ref(&self) -> &Option<Value> {
+        &self.0
+    }
+}
@@ -0,0 +1,38 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub i16);
+
+impl From<i16> for Root {
+    fn from(value: i16) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for i16 {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = i16;
+    fn deref(&self) -> &i16 {
+        &self.0
+    }
+}
+
+impl AsRef<i16> for Root {
+    fn as_ref(&self) -> &i16 {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Root {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,38 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub i8);
+
+impl From<i8> for Root {
+    fn from(value: i8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for i8 {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = i8;
+    fn deref(&self) -> &i8 {
+        &self.0
+    }
+}
+
+impl AsRef<i8> for Root {
+    fn as_ref(&self) -> &i8 {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Root {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,32 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Option<Box<String>>);
+
+impl From<Option<Box<String>>> for Root {
+    fn from(value: Option<Box<String>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Option<Box<String>> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Option<Box<String>>;
+    fn deref(&self) -> &Option<Box<String>> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Box<String>>> for Root {
+    fn as_ref(&self) -> &Option<Box<String>> {
+        &self.0
+    }
+}
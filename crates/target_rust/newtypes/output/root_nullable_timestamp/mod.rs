@@ -0,0 +1,33 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub Option<Box<DateTime<FixedOffset>>>);
+
+impl From<Option<Box<DateTime<FixedOffset>>>> for Root {
+    fn from(value: Option<Box<DateTime<FixedOffset>>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for Option<Box<DateTime<FixedOffset>>> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = Option<Box<DateTime<FixedOffset>>>;
+    fn deref(&self) -> &Option<Box<DateTime<FixedOffset>>> {
+        &self.0
+    }
+}
+
+impl AsRef<Option<Box<DateTime<FixedOffset>>>> for Root {
+    fn as_ref(&self) -> &Option<Box<DateTime<FixedOffset>>> {
+        &self.0
+    }
+}
@@ -0,0 +1,39 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub DateTime<FixedOffset>);
+
+impl From<DateTime<FixedOffset>> for Root {
+    fn from(value: DateTime<FixedOffset>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for DateTime<FixedOffset> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = DateTime<FixedOffset>;
+    fn deref(&self) -> &DateTime<FixedOffset> {
+        &self.0
+    }
+}
+
+impl AsRef<DateTime<FixedOffset>> for Root {
+    fn as_ref(&self) -> &DateTime<FixedOffset> {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Root {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,38 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub u16);
+
+impl From<u16> for Root {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for u16 {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = u16;
+    fn deref(&self) -> &u16 {
+        &self.0
+    }
+}
+
+impl AsRef<u16> for Root {
+    fn as_ref(&self) -> &u16 {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Root {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
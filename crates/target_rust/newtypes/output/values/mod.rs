@@ -0,0 +1,33 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Root(pub HashMap<String, String>);
+
+impl From<HashMap<String, String>> for Root {
+    fn from(value: HashMap<String, String>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Root> for HashMap<String, String> {
+    fn from(value: Root) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for Root {
+    type Target = HashMap<String, String>;
+    fn deref(&self) -> &HashMap<String, String> {
+        &self.0
+    }
+}
+
+impl AsRef<HashMap<String, String>> for Root {
+    fn as_ref(&self) -> &HashMap<String, String> {
+        &self.0
+    }
+}
@@ -0,0 +1,5 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod jtd_codegen_e2e;
@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RootOverrideTypeDiscriminatorBaz {}
+
+#[derive(Serialize, Deserialize)]
+pub struct Root {
+    #[serde(rename = "override_elements_container")]
+    pub overrideElementsContainer: Vec<String>,
+    #[serde(rename = "override_type_discriminator")]
+    pub overrideTypeDiscriminator: serde_json::Value,
+    #[serde(rename = "override_type_enum")]
+    pub overrideTypeEnum: serde_json::Value,
+    #[serde(rename = "override_type_expr")]
+    pub overrideTypeExpr: serde_json::Value,
+    #[serde(rename = "override_type_properties")]
+    pub overrideTypeProperties: serde_json::Value,
+    #[serde(rename = "override_values_container")]
+    pub overrideValuesContainer: BTreeMap<String, String>,
+}
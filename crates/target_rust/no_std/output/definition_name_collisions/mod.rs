@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::string::String;
+
+pub type Root = Foo0;
+
+pub type Bar = Bar0;
+
+pub type Foo = Bar;
+
+pub type Bar0 = String;
+
+pub type Foo0 = Foo;
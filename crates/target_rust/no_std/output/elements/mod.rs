@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub type Root = Vec<String>;
@@ -0,0 +1,16 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Root {
+    #[serde(rename = "for")]
+    pub for_: For,
+    #[serde(rename = "object")]
+    pub object: Object,
+}
+
+pub type For = String;
+
+pub type Object = String;
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub type Root = Option<Box<Vec<String>>>;
@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::string::String;
+
+pub type Root = Foo;
+
+pub type Bar = Baz;
+
+pub type Baz = String;
+
+pub type Foo = Bar;
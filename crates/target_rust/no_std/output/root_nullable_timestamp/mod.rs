@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::boxed::Box;
+use chrono::{DateTime, FixedOffset};
+
+pub type Root = Option<Box<DateTime<FixedOffset>>>;
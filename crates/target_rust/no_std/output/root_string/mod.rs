@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::string::String;
+
+pub type Root = String;
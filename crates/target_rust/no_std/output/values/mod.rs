@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for Rust v0.2.1
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+pub type Root = BTreeMap<String, String>;
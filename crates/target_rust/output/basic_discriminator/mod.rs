@@ -7,11 +7,64 @@ use serde::{Deserialize, Serialize};
 pub enum Root {
     #[serde(rename = "BAR_BAZ")]
     BarBaz(RootBarBaz),
-
     #[serde(rename = "QUUX")]
     Quux(RootQuux),
 }
 
+impl Root {
+    pub const TAGS: &'static [&'static str] = &["BAR_BAZ", "QUUX"];
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Self::BarBaz(_) => "BAR_BAZ",
+            Self::Quux(_) => "QUUX",
+        }
+    }
+    pub fn as_bar_baz(&self) -> Option<&RootBarBaz> {
+        match self {
+            Self::BarBaz(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_quux(&self) -> Option<&RootQuux> {
+        match self {
+            Self::Quux(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<RootBarBaz> for Root {
+    fn from(value: RootBarBaz) -> Self {
+        Self::BarBaz(value)
+    }
+}
+
+impl std::convert::TryFrom<Root> for RootBarBaz {
+    type Error = Root;
+    fn try_from(value: Root) -> std::result::Result<Self, Root> {
+        match value {
+            Root::BarBaz(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<RootQuux> for Root {
+    fn from(value: RootQuux) -> Self {
+        Self::Quux(value)
+    }
+}
+
+impl std::convert::TryFrom<Root> for RootQuux {
+    type Error = Root;
+    fn try_from(value: Root) -> std::result::Result<Self, Root> {
+        match value {
+            Root::Quux(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct RootBarBaz {
     #[serde(rename = "baz")]
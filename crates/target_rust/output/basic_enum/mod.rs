@@ -6,10 +6,8 @@ use serde::{Deserialize, Serialize};
 pub enum Root {
     #[serde(rename = "Bar")]
     Bar,
-
     #[serde(rename = "Baz")]
     Baz,
-
     #[serde(rename = "Foo")]
     Foo,
 }
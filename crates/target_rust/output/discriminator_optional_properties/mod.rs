@@ -9,12 +9,40 @@ pub enum Root {
     Bar(RootBar),
 }
 
+impl Root {
+    pub const TAGS: &'static [&'static str] = &["bar"];
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Self::Bar(_) => "bar",
+        }
+    }
+    pub fn as_bar(&self) -> Option<&RootBar> {
+        match self {
+            Self::Bar(value) => Some(value),
+        }
+    }
+}
+
+impl From<RootBar> for Root {
+    fn from(value: RootBar) -> Self {
+        Self::Bar(value)
+    }
+}
+
+impl std::convert::TryFrom<Root> for RootBar {
+    type Error = Root;
+    fn try_from(value: Root) -> std::result::Result<Self, Root> {
+        match value {
+            Root::Bar(value) => Ok(value),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct RootBar {
     #[serde(rename = "baz")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub baz: Option<Box<Vec<String>>>,
-
     #[serde(rename = "quux")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quux: Option<Box<bool>>,
@@ -6,25 +6,18 @@ use serde::{Deserialize, Serialize};
 pub enum Root {
     #[serde(rename = "")]
     DefaultName,
-
     #[serde(rename = "$foo")]
     Foo,
-
     #[serde(rename = "0foo")]
     Foo0,
-
     #[serde(rename = "_foo")]
     Foo1,
-
     #[serde(rename = "foo\nbar")]
     FooBar,
-
     #[serde(rename = "foo bar")]
     FooBar0,
-
     #[serde(rename = "foo0bar")]
     Foo0bar,
-
     #[serde(rename = "foo﷽bar")]
     FooBar1,
 }
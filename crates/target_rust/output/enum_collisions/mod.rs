@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 pub enum RootFooBar {
     #[serde(rename = "x")]
     X,
-
     #[serde(rename = "y")]
     Y,
 }
@@ -21,7 +20,6 @@ pub struct RootFoo {
 pub enum RootFooBar0 {
     #[serde(rename = "x")]
     X,
-
     #[serde(rename = "y")]
     Y,
 }
@@ -30,7 +28,6 @@ pub enum RootFooBar0 {
 pub struct Root {
     #[serde(rename = "foo")]
     pub foo: RootFoo,
-
     #[serde(rename = "foo_bar")]
     pub fooBar: RootFooBar0,
 }
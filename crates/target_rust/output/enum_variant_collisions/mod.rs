@@ -6,10 +6,8 @@ use serde::{Deserialize, Serialize};
 pub enum Root {
     #[serde(rename = "FOO")]
     Foo,
-
     #[serde(rename = "Foo")]
     Foo0,
-
     #[serde(rename = "foo")]
     Foo1,
 }
@@ -13,68 +13,68 @@ pub type Root = GeojsonObject;
 /// contained geometries, with all axes of the most southwesterly point
 /// followed by all axes of the more northeasterly point.  The axes order of
 /// a bbox follows the axes order of geometries.
-/// 
+///
 /// The "bbox" values define shapes with edges that follow lines of constant
 /// longitude, latitude, and elevation.
-/// 
+///
 /// The four lines of the bounding box are defined fully within the
 /// coordinate reference system; that is, for a box bounded by the values
 /// "west", "south", "east", and "north", every point on the northernmost
 /// line can be expressed as
-/// 
+///
 /// (lon, lat) = (west + (east - west) * t, north)
-/// 
+///
 /// with 0 <= t <= 1.
-/// 
+///
 /// Consider a set of point Features within the Fiji archipelago, straddling
 /// the antimeridian between 16 degrees S and 20 degrees S. The southwest
 /// corner of the box containing these Features is at 20 degrees S and 177
 /// degrees E, and the northwest corner is at 16 degrees S and 178 degrees
 /// W.  The antimeridian-spanning GeoJSON bounding box for this
 /// FeatureCollection is
-/// 
+///
 /// "bbox": [177.0, -20.0, -178.0, -16.0]
-/// 
+///
 /// and covers 5 degrees of longitude.
-/// 
+///
 /// The complementary bounding box for the same latitude band, not crossing
 /// the antimeridian, is
-/// 
+///
 /// "bbox": [-178.0, -20.0, 177.0, -16.0]
-/// 
+///
 /// and covers 355 degrees of longitude.
-/// 
+///
 /// The latitude of the northeast corner is always greater than the latitude
 /// of the southwest corner, but bounding boxes that cross the antimeridian
 /// have a northeast corner longitude that is less than the longitude of the
 /// southwest corner.
-/// 
+///
 /// A bounding box that contains the North Pole extends from a southwest
 /// corner of "minlat" degrees N, 180 degrees W to a northeast corner of 90
 /// degrees N, 180 degrees E.  Viewed on a globe, this bounding box
 /// approximates a spherical cap bounded by the "minlat" circle of latitude.
-/// 
+///
 /// "bbox": [-180.0, minlat, 180.0, 90.0]
-/// 
+///
 /// A bounding box that contains the South Pole extends from a southwest
 /// corner of 90 degrees S, 180 degrees W to a northeast corner of "maxlat"
 /// degrees S, 180 degrees E.
-/// 
+///
 /// "bbox": [-180.0, -90.0, 180.0, maxlat]
-/// 
+///
 /// A bounding box that just touches the North Pole and forms a slice of an
 /// approximate spherical cap when viewed on a globe extends from a
 /// southwest corner of "minlat" degrees N and "westlon" degrees E to a
 /// northeast corner of 90 degrees N and "eastlon" degrees E.
-/// 
+///
 /// "bbox": [westlon, minlat, eastlon, 90.0]
-/// 
+///
 /// Similarly, a bounding box that just touches the South Pole and forms a
 /// slice of an approximate spherical cap when viewed on a globe has the
 /// following representation in GeoJSON.
-/// 
+///
 /// "bbox": [westlon, -90.0, eastlon, maxlat]
-/// 
+///
 /// Implementers MUST NOT use latitude values greater than 90 or less than
 /// -90 to imply an extent that is not a spherical cap.
 pub type BoundingBox = Vec<f64>;
@@ -82,10 +82,10 @@ pub type BoundingBox = Vec<f64>;
 /// A Geometry object represents points, curves, and surfaces in coordinate
 /// space.  Every Geometry object is a GeoJSON object no matter where it
 /// occurs in a GeoJSON text.
-/// 
+///
 /// o  The value of a Geometry object's "type" member MUST be one of the
 ///     seven geometry types (see Section 1.4).
-/// 
+///
 /// o  A GeoJSON Geometry object of any type other than
 ///     "GeometryCollection" has a member with the name "coordinates". The
 ///     value of the "coordinates" member is an array.  The structure of the
@@ -97,43 +97,260 @@ pub type BoundingBox = Vec<f64>;
 pub enum GeojsonObject {
     #[serde(rename = "Feature")]
     Feature(GeojsonObjectFeature),
-
     #[serde(rename = "FeatureCollection")]
     FeatureCollection(GeojsonObjectFeatureCollection),
-
     #[serde(rename = "GeometryCollection")]
     GeometryCollection(GeojsonObjectGeometryCollection),
-
     #[serde(rename = "LineString")]
     LineString(GeojsonObjectLineString),
-
     #[serde(rename = "MultiLineString")]
     MultiLineString(GeojsonObjectMultiLineString),
-
     #[serde(rename = "MultiPoint")]
     MultiPoint(GeojsonObjectMultiPoint),
-
     #[serde(rename = "MultiPolygon")]
     MultiPolygon(GeojsonObjectMultiPolygon),
-
     #[serde(rename = "Point")]
     Point(GeojsonObjectPoint),
-
     #[serde(rename = "Polygon")]
     Polygon(GeojsonObjectPolygon),
 }
 
+impl GeojsonObject {
+    pub const TAGS: &'static [&'static str] = &[
+        "Feature",
+        "FeatureCollection",
+        "GeometryCollection",
+        "LineString",
+        "MultiLineString",
+        "MultiPoint",
+        "MultiPolygon",
+        "Point",
+        "Polygon",
+    ];
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Self::Feature(_) => "Feature",
+            Self::FeatureCollection(_) => "FeatureCollection",
+            Self::GeometryCollection(_) => "GeometryCollection",
+            Self::LineString(_) => "LineString",
+            Self::MultiLineString(_) => "MultiLineString",
+            Self::MultiPoint(_) => "MultiPoint",
+            Self::MultiPolygon(_) => "MultiPolygon",
+            Self::Point(_) => "Point",
+            Self::Polygon(_) => "Polygon",
+        }
+    }
+    pub fn as_feature(&self) -> Option<&GeojsonObjectFeature> {
+        match self {
+            Self::Feature(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_feature_collection(&self) -> Option<&GeojsonObjectFeatureCollection> {
+        match self {
+            Self::FeatureCollection(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_geometry_collection(&self) -> Option<&GeojsonObjectGeometryCollection> {
+        match self {
+            Self::GeometryCollection(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_line_string(&self) -> Option<&GeojsonObjectLineString> {
+        match self {
+            Self::LineString(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_multi_line_string(&self) -> Option<&GeojsonObjectMultiLineString> {
+        match self {
+            Self::MultiLineString(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_multi_point(&self) -> Option<&GeojsonObjectMultiPoint> {
+        match self {
+            Self::MultiPoint(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_multi_polygon(&self) -> Option<&GeojsonObjectMultiPolygon> {
+        match self {
+            Self::MultiPolygon(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_point(&self) -> Option<&GeojsonObjectPoint> {
+        match self {
+            Self::Point(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_polygon(&self) -> Option<&GeojsonObjectPolygon> {
+        match self {
+            Self::Polygon(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<GeojsonObjectFeature> for GeojsonObject {
+    fn from(value: GeojsonObjectFeature) -> Self {
+        Self::Feature(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectFeature {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::Feature(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<GeojsonObjectFeatureCollection> for GeojsonObject {
+    fn from(value: GeojsonObjectFeatureCollection) -> Self {
+        Self::FeatureCollection(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectFeatureCollection {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::FeatureCollection(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<GeojsonObjectGeometryCollection> for GeojsonObject {
+    fn from(value: GeojsonObjectGeometryCollection) -> Self {
+        Self::GeometryCollection(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectGeometryCollection {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::GeometryCollection(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<GeojsonObjectLineString> for GeojsonObject {
+    fn from(value: GeojsonObjectLineString) -> Self {
+        Self::LineString(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectLineString {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::LineString(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<GeojsonObjectMultiLineString> for GeojsonObject {
+    fn from(value: GeojsonObjectMultiLineString) -> Self {
+        Self::MultiLineString(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectMultiLineString {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::MultiLineString(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<GeojsonObjectMultiPoint> for GeojsonObject {
+    fn from(value: GeojsonObjectMultiPoint) -> Self {
+        Self::MultiPoint(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectMultiPoint {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::MultiPoint(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<GeojsonObjectMultiPolygon> for GeojsonObject {
+    fn from(value: GeojsonObjectMultiPolygon) -> Self {
+        Self::MultiPolygon(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectMultiPolygon {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::MultiPolygon(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<GeojsonObjectPoint> for GeojsonObject {
+    fn from(value: GeojsonObjectPoint) -> Self {
+        Self::Point(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectPoint {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::Point(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<GeojsonObjectPolygon> for GeojsonObject {
+    fn from(value: GeojsonObjectPolygon) -> Self {
+        Self::Polygon(value)
+    }
+}
+
+impl std::convert::TryFrom<GeojsonObject> for GeojsonObjectPolygon {
+    type Error = GeojsonObject;
+    fn try_from(value: GeojsonObject) -> std::result::Result<Self, GeojsonObject> {
+        match value {
+            GeojsonObject::Polygon(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
 /// A Feature object represents a spatially bounded thing.  Every
 /// Feature object is a GeoJSON object no matter where it occurs in a
 /// GeoJSON text.
-/// 
+///
 /// o  A Feature object has a "type" member with the value "Feature".
-/// 
+///
 /// o  A Feature object has a member with the name "geometry".  The
 ///     value of the geometry member SHALL be either a Geometry object
 ///     as defined above or, in the case that the Feature is unlocated,
 ///     a JSON null value.
-/// 
+///
 /// o  A Feature object has a member with the name "properties".  The
 ///     value of the properties member is an object (any JSON object or
 ///     a JSON null value).
@@ -142,16 +359,14 @@ pub struct GeojsonObjectFeature {
     /// The GeoJSON specification requires that these elements be
     /// GeoJSON geometry objects, but such a constraint can't be
     /// expressed in JSON Type Definition.
-    /// 
+    ///
     /// It is semantically invalid at the GeoJSON level for this
     /// member to be any GeoJSON object type other than one of the
     /// geometry types.
     #[serde(rename = "geometry")]
     pub geometry: Option<Box<GeojsonObject>>,
-
     #[serde(rename = "properties")]
     pub properties: HashMap<String, Option<Value>>,
-
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<Value>,
@@ -173,12 +388,12 @@ pub struct GeojsonObjectFeatureCollection {
 /// "geometries".  The value of "geometries" is an array.  Each element
 /// of this array is a GeoJSON Geometry object.  It is possible for this
 /// array to be empty.
-/// 
+///
 /// Unlike the other geometry types described above, a
 /// GeometryCollection can be a heterogeneous composition of smaller
 /// Geometry objects.  For example, a Geometry object in the shape of a
 /// lowercase roman "i" can be composed of one point and one LineString.
-/// 
+///
 /// GeometryCollections have a different syntax from single type
 /// Geometry objects (Point, LineString, and Polygon) and homogeneously
 /// typed multipart Geometry objects (MultiPoint, MultiLineString, and
@@ -188,7 +403,7 @@ pub struct GeojsonObjectFeatureCollection {
 /// collection.  The "geometries" member of a GeometryCollection
 /// describes the parts of this composition.  Implementations SHOULD NOT
 /// apply any additional semantics to the "geometries" array.
-/// 
+///
 /// To maximize interoperability, implementations SHOULD avoid nested
 /// GeometryCollections.  Furthermore, GeometryCollections composed of a
 /// single part or a number of parts of a single type SHOULD be avoided
@@ -199,7 +414,6 @@ pub struct GeojsonObjectFeatureCollection {
 pub struct GeojsonObjectGeometryCollection {
     #[serde(rename = "geometries")]
     pub geometries: Vec<GeojsonObject>,
-
     #[serde(rename = "bbox")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Box<BoundingBox>>,
@@ -211,7 +425,6 @@ pub struct GeojsonObjectGeometryCollection {
 pub struct GeojsonObjectLineString {
     #[serde(rename = "coordinates")]
     pub coordinates: Vec<Position>,
-
     #[serde(rename = "bbox")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Box<BoundingBox>>,
@@ -223,7 +436,6 @@ pub struct GeojsonObjectLineString {
 pub struct GeojsonObjectMultiLineString {
     #[serde(rename = "coordinates")]
     pub coordinates: Vec<Position>,
-
     #[serde(rename = "bbox")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Box<BoundingBox>>,
@@ -235,7 +447,6 @@ pub struct GeojsonObjectMultiLineString {
 pub struct GeojsonObjectMultiPoint {
     #[serde(rename = "coordinates")]
     pub coordinates: Vec<Position>,
-
     #[serde(rename = "bbox")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Box<BoundingBox>>,
@@ -247,7 +458,6 @@ pub struct GeojsonObjectMultiPoint {
 pub struct GeojsonObjectMultiPolygon {
     #[serde(rename = "coordinates")]
     pub coordinates: Vec<LinearRing>,
-
     #[serde(rename = "bbox")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Box<BoundingBox>>,
@@ -258,7 +468,6 @@ pub struct GeojsonObjectMultiPolygon {
 pub struct GeojsonObjectPoint {
     #[serde(rename = "coordinates")]
     pub coordinates: Position,
-
     #[serde(rename = "bbox")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Box<BoundingBox>>,
@@ -270,7 +479,6 @@ pub struct GeojsonObjectPoint {
 pub struct GeojsonObjectPolygon {
     #[serde(rename = "coordinates")]
     pub coordinates: Vec<LinearRing>,
-
     #[serde(rename = "bbox")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Box<BoundingBox>>,
@@ -278,27 +486,27 @@ pub struct GeojsonObjectPolygon {
 
 /// To specify a constraint specific to Polygons, it is useful to
 /// introduce the concept of a linear ring:
-/// 
+///
 /// o  A linear ring is a closed LineString with four or more positions.
-/// 
+///
 /// o  The first and last positions are equivalent, and they MUST contain
 ///     identical values; their representation SHOULD also be identical.
-/// 
+///
 /// o  A linear ring is the boundary of a surface or the boundary of a
 ///     hole in a surface.
-/// 
+///
 /// o  A linear ring MUST follow the right-hand rule with respect to the
 ///     area it bounds, i.e., exterior rings are counterclockwise, and holes
 ///     are clockwise.
-/// 
+///
 /// Note: the [GJ2008] specification did not discuss linear ring winding
 /// order.  For backwards compatibility, parsers SHOULD NOT reject Polygons
 /// that do not follow the right-hand rule.
-/// 
+///
 /// Though a linear ring is not explicitly represented as a GeoJSON geometry
 /// type, it leads to a canonical formulation of the Polygon geometry type
 /// definition as follows:
-/// 
+///
 /// For Polygons with more than one of these rings, the first MUST be the
 /// exterior ring, and any others MUST be interior rings.  The exterior ring
 /// bounds the surface, and the interior rings (if present) bound holes
@@ -306,12 +514,12 @@ pub struct GeojsonObjectPolygon {
 pub type LinearRing = Vec<Position>;
 
 /// A position is the fundamental geometry construct.
-/// 
+///
 /// A position is an array of numbers.  There MUST be two or more elements.
 /// The first two elements are longitude and latitude, or easting and
 /// northing, precisely in that order and using decimal numbers.  Altitude
 /// or elevation MAY be included as an optional third element.
-/// 
+///
 /// Implementations SHOULD NOT extend positions beyond three elements
 /// because the semantics of extra elements are unspecified and ambiguous.
 /// Historically, some implementations have used a fourth element to carry a
@@ -320,25 +528,25 @@ pub type LinearRing = Vec<Position>;
 /// interpret these values.  The interpretation and meaning of additional
 /// elements is beyond the scope of this specification, and additional
 /// elements MAY be ignored by parsers.
-/// 
+///
 /// A line between two positions is a straight Cartesian line, the shortest
 /// line between those two points in the coordinate reference system (see
 /// Section 4).
-/// 
+///
 /// In other words, every point on a line that does not cross the
 /// antimeridian between a point (lon0, lat0) and (lon1, lat1) can be
 /// calculated as
-/// 
+///
 /// F(lon, lat) = (lon0 + (lon1 - lon0) * t, lat0 + (lat1 - lat0) * t)
-/// 
+///
 /// with t being a real number greater than or equal to 0 and smaller than
 /// or equal to 1.  Note that this line may markedly differ from the
 /// geodesic path along the curved surface of the reference ellipsoid.
-/// 
+///
 /// The same applies to the optional height element with the proviso that
 /// the direction of the height is as specified in the coordinate reference
 /// system.
-/// 
+///
 /// Note that, again, this does not mean that a surface with equal height
 /// follows, for example, the curvature of a body of water.  Nor is a
 /// surface of equal height perpendicular to a plumb line.
@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 pub struct RootNestedIdInitialism {
     #[serde(rename = "json")]
     pub json: String,
-
     #[serde(rename = "normalword")]
     pub normalword: String,
 }
@@ -15,19 +14,14 @@ pub struct RootNestedIdInitialism {
 pub struct Root {
     #[serde(rename = "http")]
     pub http: String,
-
     #[serde(rename = "id")]
     pub id: String,
-
     #[serde(rename = "nested_id_initialism")]
     pub nestedIdInitialism: RootNestedIdInitialism,
-
     #[serde(rename = "utf8")]
     pub utf8: String,
-
     #[serde(rename = "word_with_embedded_id_initialism")]
     pub wordWithEmbeddedIdInitialism: String,
-
     #[serde(rename = "word_with_trailing_initialism_id")]
     pub wordWithTrailingInitialismId: String,
 }
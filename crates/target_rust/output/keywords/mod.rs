@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 pub struct Root {
     #[serde(rename = "for")]
     pub for_: For,
-
     #[serde(rename = "object")]
     pub object: Object,
 }
@@ -7,11 +7,64 @@ use serde::{Deserialize, Serialize};
 pub enum Root0 {
     #[serde(rename = "bar")]
     Bar(RootBar),
-
     #[serde(rename = "quux")]
     Quux(RootQuux),
 }
 
+impl Root0 {
+    pub const TAGS: &'static [&'static str] = &["bar", "quux"];
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Self::Bar(_) => "bar",
+            Self::Quux(_) => "quux",
+        }
+    }
+    pub fn as_bar(&self) -> Option<&RootBar> {
+        match self {
+            Self::Bar(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_quux(&self) -> Option<&RootQuux> {
+        match self {
+            Self::Quux(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<RootBar> for Root0 {
+    fn from(value: RootBar) -> Self {
+        Self::Bar(value)
+    }
+}
+
+impl std::convert::TryFrom<Root0> for RootBar {
+    type Error = Root0;
+    fn try_from(value: Root0) -> std::result::Result<Self, Root0> {
+        match value {
+            Root0::Bar(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
+impl From<RootQuux> for Root0 {
+    fn from(value: RootQuux) -> Self {
+        Self::Quux(value)
+    }
+}
+
+impl std::convert::TryFrom<Root0> for RootQuux {
+    type Error = Root0;
+    fn try_from(value: Root0) -> std::result::Result<Self, Root0> {
+        match value {
+            Root0::Quux(value) => Ok(value),
+            value => Err(value),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct RootBar {
     #[serde(rename = "baz")]
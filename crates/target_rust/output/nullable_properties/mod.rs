@@ -6,13 +6,10 @@ use serde::{Deserialize, Serialize};
 pub struct Root0 {
     #[serde(rename = "bar")]
     pub bar: String,
-
     #[serde(rename = "baz")]
     pub baz: Vec<bool>,
-
     #[serde(rename = "foo")]
     pub foo: bool,
-
     #[serde(rename = "quux")]
     pub quux: Vec<bool>,
 }
@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 pub struct Root {
     #[serde(rename = "Foo")]
     pub foo: String,
-
     #[serde(rename = "foo")]
     pub foo0: String,
 }
@@ -24,7 +24,6 @@ pub struct RootFooBar0 {
 pub struct Root {
     #[serde(rename = "foo")]
     pub foo: RootFoo,
-
     #[serde(rename = "foo_bar")]
     pub fooBar: RootFooBar0,
 }
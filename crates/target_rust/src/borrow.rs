@@ -0,0 +1,234 @@
+// Generates the code that borrowed mode deserializes nested strings with. See
+// Target::with_borrowed.
+//
+// serde only borrows a string for a field whose type is exactly Cow<'a, str>.
+// Every other field that borrows is deserialized through the trait in the
+// "borrow" module emitted here, which borrows strings in options, boxes,
+// arrays and maps too.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::collections::{BTreeMap, BTreeSet};
+
+// The path fields that borrow through the "borrow" module deserialize with.
+pub const DESERIALIZE_WITH: &str = "borrow::deserialize";
+
+// The "borrow" module, tailored to the imports the rest of the file uses.
+pub fn runtime(no_std: bool, imports: &BTreeMap<String, BTreeSet<String>>) -> TokenStream {
+    let imports_ident = |module: &str, name: &str| {
+        imports
+            .get(module)
+            .is_some_and(|idents| idents.contains(name))
+    };
+
+    let prelude = if no_std {
+        quote! {
+            use alloc::borrow::Cow;
+            use alloc::boxed::Box;
+            use alloc::string::String;
+            use alloc::vec::Vec;
+        }
+    } else {
+        quote! {
+            use std::borrow::Cow;
+        }
+    };
+
+    let raw_value_impls = if imports_ident("serde_json::value", "RawValue") {
+        quote! {
+            impl<'de: 'a, 'a> Deserialize<'de> for &'a serde_json::value::RawValue {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error> {
+                    serde::Deserialize::deserialize(deserializer)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let map = if no_std {
+        quote! { alloc::collections::BTreeMap }
+    } else {
+        quote! { std::collections::HashMap }
+    };
+
+    let map_impls = if imports_ident("alloc::collections", "BTreeMap")
+        || imports_ident("std::collections", "HashMap")
+    {
+        quote! {
+            impl<'de, T: Deserialize<'de>> Deserialize<'de> for #map<String, T> {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error> {
+                    struct Visitor<T>(core::marker::PhantomData<T>);
+
+                    impl<'de, T: Deserialize<'de>> serde::de::Visitor<'de> for Visitor<T> {
+                        type Value = #map<String, T>;
+
+                        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                            f.write_str("an object")
+                        }
+
+                        fn visit_map<A: serde::de::MapAccess<'de>>(
+                            self,
+                            mut map: A,
+                        ) -> core::result::Result<Self::Value, A::Error> {
+                            let mut values = #map::new();
+                            while let Some((key, Item(value))) = map.next_entry()? {
+                                values.insert(key, value);
+                            }
+
+                            Ok(values)
+                        }
+                    }
+
+                    deserializer.deserialize_map(Visitor(core::marker::PhantomData))
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        /// Deserializing values that borrow strings from their input, even
+        /// where serde's own impls would copy them out.
+        pub mod borrow {
+            #prelude
+
+            /// Like `serde::Deserialize`, except that strings are borrowed
+            /// wherever they are nested in options, boxes, arrays and maps.
+            pub trait Deserialize<'de>: Sized {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error>;
+            }
+
+            /// Deserializes a value with its impl of this module's
+            /// `Deserialize`. Fields that borrow are deserialized with this.
+            pub fn deserialize<'de, D: serde::Deserializer<'de>, T: Deserialize<'de>>(
+                deserializer: D,
+            ) -> core::result::Result<T, D::Error> {
+                T::deserialize(deserializer)
+            }
+
+            /// A value deserialized with this module's `Deserialize`, for
+            /// containers to deserialize their items as.
+            struct Item<T>(T);
+
+            impl<'de, T: Deserialize<'de>> serde::Deserialize<'de> for Item<T> {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error> {
+                    T::deserialize(deserializer).map(Item)
+                }
+            }
+
+            impl<'de: 'a, 'a> Deserialize<'de> for Cow<'a, str> {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error> {
+                    struct Visitor;
+
+                    impl<'de> serde::de::Visitor<'de> for Visitor {
+                        type Value = Cow<'de, str>;
+
+                        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                            f.write_str("a string")
+                        }
+
+                        fn visit_borrowed_str<E: serde::de::Error>(
+                            self,
+                            value: &'de str,
+                        ) -> core::result::Result<Self::Value, E> {
+                            Ok(Cow::Borrowed(value))
+                        }
+
+                        fn visit_str<E: serde::de::Error>(
+                            self,
+                            value: &str,
+                        ) -> core::result::Result<Self::Value, E> {
+                            Ok(Cow::Owned(String::from(value)))
+                        }
+
+                        fn visit_string<E: serde::de::Error>(
+                            self,
+                            value: String,
+                        ) -> core::result::Result<Self::Value, E> {
+                            Ok(Cow::Owned(value))
+                        }
+                    }
+
+                    deserializer.deserialize_str(Visitor)
+                }
+            }
+
+            #raw_value_impls
+
+            impl<'de, T: Deserialize<'de>> Deserialize<'de> for Option<T> {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error> {
+                    let value: Option<Item<T>> = serde::Deserialize::deserialize(deserializer)?;
+                    Ok(value.map(|Item(value)| value))
+                }
+            }
+
+            impl<'de, T: Deserialize<'de>> Deserialize<'de> for Box<T> {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error> {
+                    T::deserialize(deserializer).map(Box::new)
+                }
+            }
+
+            impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec<T> {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> core::result::Result<Self, D::Error> {
+                    struct Visitor<T>(core::marker::PhantomData<T>);
+
+                    impl<'de, T: Deserialize<'de>> serde::de::Visitor<'de> for Visitor<T> {
+                        type Value = Vec<T>;
+
+                        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                            f.write_str("an array")
+                        }
+
+                        fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                            self,
+                            mut seq: A,
+                        ) -> core::result::Result<Self::Value, A::Error> {
+                            let mut values = Vec::new();
+                            while let Some(Item(value)) = seq.next_element()? {
+                                values.push(value);
+                            }
+
+                            Ok(values)
+                        }
+                    }
+
+                    deserializer.deserialize_seq(Visitor(core::marker::PhantomData))
+                }
+            }
+
+            #map_impls
+        }
+    }
+}
+
+// The impl of the "borrow" module's Deserialize for a generated type that
+// borrows, which defers to the type's impl of serde's.
+pub fn impls(name: &syn::Type, lifetime: &syn::Lifetime, core: &Ident) -> TokenStream {
+    quote! {
+        impl<'de: #lifetime, #lifetime> borrow::Deserialize<'de> for #name {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> #core::result::Result<Self, D::Error> {
+                serde::Deserialize::deserialize(deserializer)
+            }
+        }
+    }
+}
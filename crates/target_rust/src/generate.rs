@@ -0,0 +1,136 @@
+use crate::Target;
+use jtd::{Schema, SerdeSchema};
+use jtd_codegen::codegen::CodegenInfo;
+use jtd_codegen::root_name::root_name_from_input_name;
+use std::convert::TryInto;
+use std::error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// Generates Rust code from a schema file into `out_dir`, for use from a
+/// `build.rs` script.
+///
+/// This produces the same `mod.rs` the CLI would, naming the root type after
+/// the schema file. It also tells Cargo to re-run the build script whenever
+/// the schema file changes. A typical build script looks like:
+///
+/// ```no_run
+/// // build.rs
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// jtd_codegen_target_rust::generate("schemas/user.jtd.json", out_dir).unwrap();
+/// ```
+///
+/// With the generated code then included using:
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/mod.rs"));
+/// ```
+///
+/// To generate code for several schemas, give each one its own out_dir.
+pub fn generate<P: AsRef<Path>, Q: AsRef<Path>>(
+    schema_path: P,
+    out_dir: Q,
+) -> Result<CodegenInfo, GenerateError> {
+    println!("cargo:rerun-if-changed={}", schema_path.as_ref().display());
+
+    Target::new().generate(schema_path, out_dir)
+}
+
+impl Target {
+    /// Generates Rust code from a schema file into `out_dir`, using this
+    /// target's configuration.
+    ///
+    /// Unlike [`generate`], this does not print any instructions for Cargo.
+    pub fn generate<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        schema_path: P,
+        out_dir: Q,
+    ) -> Result<CodegenInfo, GenerateError> {
+        let schema_path = schema_path.as_ref();
+        let root_name = root_name_from_input_name(&schema_path.to_string_lossy()).to_owned();
+
+        let schema: SerdeSchema = serde_json::from_reader(File::open(schema_path)?)?;
+        let schema: Schema = schema.try_into().map_err(GenerateError::InvalidSchema)?;
+        schema.validate().map_err(GenerateError::ValidateSchema)?;
+
+        Ok(jtd_codegen::codegen(
+            self,
+            root_name,
+            &schema,
+            out_dir.as_ref(),
+        )?)
+    }
+}
+
+#[derive(Debug)]
+pub enum GenerateError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    InvalidSchema(jtd::schema::SerdeConvertError),
+    ValidateSchema(jtd::schema::ValidateError),
+    Codegen(jtd_codegen::Error),
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenerateError::Io(err) => write!(f, "failed to read schema: {}", err),
+            GenerateError::Json(err) => write!(f, "failed to parse schema as JSON: {}", err),
+            GenerateError::InvalidSchema(err) => write!(f, "invalid schema: {:?}", err),
+            GenerateError::ValidateSchema(err) => write!(f, "invalid schema: {:?}", err),
+            GenerateError::Codegen(err) => write!(f, "failed to generate code: {}", err),
+        }
+    }
+}
+
+impl error::Error for GenerateError {}
+
+impl From<io::Error> for GenerateError {
+    fn from(err: io::Error) -> Self {
+        GenerateError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for GenerateError {
+    fn from(err: serde_json::Error) -> Self {
+        GenerateError::Json(err)
+    }
+}
+
+impl From<jtd_codegen::Error> for GenerateError {
+    fn from(err: jtd_codegen::Error) -> Self {
+        GenerateError::Codegen(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn test_generate_matches_std_output() {
+        let base_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        // The reference output names its root type "Root", so the schema
+        // needs to be in a file named accordingly.
+        let schema_dir = tempfile::tempdir().unwrap();
+        let schema_path = schema_dir.path().join("root.jtd.json");
+        fs::copy(
+            base_dir.join("../test/schemas/roundtrip_strict/basic_properties.jtd.json"),
+            &schema_path,
+        )
+        .unwrap();
+
+        let out_dir = tempfile::tempdir().unwrap();
+        let info = crate::generate(&schema_path, out_dir.path()).unwrap();
+        assert_eq!("Root", info.root_name);
+
+        assert_eq!(
+            fs::read_to_string(base_dir.join("output/basic_properties/mod.rs")).unwrap(),
+            fs::read_to_string(out_dir.path().join("mod.rs")).unwrap(),
+        );
+    }
+}
@@ -1,10 +1,18 @@
+mod borrow;
+mod generate;
+mod native_json;
+
 use jtd_codegen::target::{self, inflect, metadata};
-use jtd_codegen::Result;
+use jtd_codegen::{Error, Result};
 use lazy_static::lazy_static;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
 use serde_json::Value;
 use std::collections::{BTreeMap, BTreeSet};
 use std::io::Write;
 
+pub use generate::{generate, GenerateError};
+
 lazy_static! {
     static ref KEYWORDS: BTreeSet<String> = include_str!("keywords")
         .lines()
@@ -27,11 +35,234 @@ lazy_static! {
         ));
 }
 
-pub struct Target {}
+// The lifetime that types generated in borrowed mode are parameterized over.
+// Every type holding data borrowed from its input mentions this lifetime.
+const LIFETIME: &str = "'a";
+
+// Types which, when wrapped in a newtype, have a Display impl worth
+// forwarding to.
+const DISPLAY_TYPES: &[&str] = &[
+    "bool",
+    "i8",
+    "u8",
+    "i16",
+    "u16",
+    "i32",
+    "u32",
+    "f32",
+    "f64",
+    "String",
+    "Cow<'a, str>",
+    "DateTime<FixedOffset>",
+];
+
+pub struct Target {
+    borrowed: bool,
+    newtypes: bool,
+    no_std: bool,
+    native_json: bool,
+}
 
 impl Target {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            borrowed: false,
+            newtypes: false,
+            no_std: false,
+            native_json: false,
+        }
+    }
+
+    /// Generate types that borrow strings from the input they are deserialized
+    /// from, rather than copying them out.
+    ///
+    /// In this mode, strings are `Cow<'a, str>` and empty schemas are `&'a
+    /// RawValue`, and any type that transitively contains one of those takes a
+    /// lifetime parameter. Generated code depends on `serde_json` with its
+    /// `raw_value` feature enabled.
+    ///
+    /// serde itself only borrows strings for fields whose type is `Cow<'a,
+    /// str>`. So that strings in optional or nullable properties, in elements
+    /// and values, and behind type aliases are borrowed too, those fields are
+    /// deserialized through a generated `borrow` module. A type alias
+    /// deserialized on its own, rather than as a field, has to go through
+    /// `borrow::deserialize` to borrow its strings. Strings that have escapes
+    /// in them are always deserialized as `Cow::Owned`.
+    pub fn with_borrowed(mut self, borrowed: bool) -> Self {
+        self.borrowed = borrowed;
+        self
+    }
+
+    /// Generate aliases as distinct newtype structs, rather than as type
+    /// aliases.
+    ///
+    /// This way, two definitions of the same underlying type (say, a `UserId`
+    /// and an `OrderId` that are both strings) cannot be mixed up. Each newtype
+    /// comes with `From`, `Deref`, and `AsRef` impls for its underlying type,
+    /// and a `Display` impl if the underlying type is a primitive.
+    pub fn with_newtypes(mut self, newtypes: bool) -> Self {
+        self.newtypes = newtypes;
+        self
+    }
+
+    /// Generate code that uses only `core` and `alloc`, so that it can be used
+    /// from a `#![no_std]` crate.
+    ///
+    /// In this mode, dictionaries are `BTreeMap`s rather than `HashMap`s, and
+    /// types that are not in the `core` prelude are imported from `alloc`. The
+    /// crate the code is included in must declare `extern crate alloc`, and can
+    /// depend on `serde`, `serde_json` and `chrono` with their default features
+    /// turned off and their `alloc` features turned on.
+    pub fn with_no_std(mut self, no_std: bool) -> Self {
+        self.no_std = no_std;
+        self
+    }
+
+    /// Generate code that parses and writes JSON itself, rather than deriving
+    /// serde's traits.
+    ///
+    /// Each generated type gets parsing and writing code specialized to its
+    /// schema, which checks the input against the schema as it goes. The code
+    /// is built on a small runtime that is generated into a `json` module,
+    /// whose `from_json` and `to_json` functions are the entry points. Output
+    /// is the same JSON that serde would produce.
+    ///
+    /// Generated code depends on `ryu`, which writes out floats the same way
+    /// `serde_json` does, and on `chrono` if the schema uses timestamps. Empty
+    /// schemas are `json::Value`s. This mode cannot be combined with borrowed
+    /// mode, and `rustType` overrides must implement the `json` module's
+    /// traits.
+    pub fn with_native_json(mut self, native_json: bool) -> Self {
+        self.native_json = native_json;
+        self
+    }
+
+    // The attribute deriving serde's traits for an item, or nothing if the
+    // item gets native JSON impls instead.
+    fn derive(&self, state: &mut FileState, traits: &[&str]) -> TokenStream {
+        if self.native_json {
+            return quote! {};
+        }
+
+        state
+            .imports
+            .entry("serde".into())
+            .or_default()
+            .extend(traits.iter().map(|t| t.to_string()));
+
+        let traits = traits.iter().map(|t| ident(t));
+        quote! { #[derive(#(#traits),*)] }
+    }
+
+    // Likewise, a serde attribute.
+    fn serde(&self, args: TokenStream) -> TokenStream {
+        if self.native_json {
+            quote! {}
+        } else {
+            quote! { #[serde(#args)] }
+        }
+    }
+
+    fn struct_item(
+        &self,
+        state: &mut FileState,
+        metadata: &metadata::Metadata,
+        name: &str,
+        fields: &[target::Field],
+    ) -> TokenStream {
+        let derive = self.derive(state, &["Serialize", "Deserialize"]);
+        let fields: Vec<TokenStream> = fields
+            .iter()
+            .map(|field| {
+                let description = description(&field.metadata, 1);
+                let json_name = &field.json_name;
+                let rename = self.serde(quote! { rename = #json_name });
+                let borrow = self.borrow(state, &field.type_, field.optional);
+                let skip = if field.optional {
+                    self.serde(quote! { skip_serializing_if = "Option::is_none" })
+                } else {
+                    quote! {}
+                };
+                let name = ident(&field.name);
+                let type_ = parse_type(&field.type_);
+
+                quote! {
+                    #description
+                    #rename
+                    #borrow
+                    #skip
+                    pub #name: #type_
+                }
+            })
+            .collect();
+
+        let description = description(metadata, 0);
+        let impls = self.borrow_impls(state, name);
+        let (name, generics) = declared_name(name);
+        quote! {
+            #description
+            #derive
+            pub struct #name #generics {
+                #(#fields),*
+            }
+
+            #impls
+        }
+    }
+
+    // The serde attributes of a field or newtype of the given type, which
+    // borrows from the input if the type has a lifetime. serde borrows a
+    // Cow<'a, str> by itself, and anything else that borrows goes through
+    // the "borrow" module, which has to default optional fields itself.
+    fn borrow(&self, state: &mut FileState, type_: &str, optional: bool) -> TokenStream {
+        if !type_.contains(LIFETIME) {
+            return quote! {};
+        }
+
+        let cow = parse_type(&format!("Cow<{}, str>", LIFETIME));
+        let type_ = parse_type(type_);
+        if quote! { #cow }.to_string() == quote! { #type_ }.to_string() {
+            return self.serde(quote! { borrow });
+        }
+
+        state.borrows = true;
+
+        let borrow = self.serde(quote! { borrow });
+        let deserialize_with = borrow::DESERIALIZE_WITH;
+        let deserialize_with = self.serde(quote! { deserialize_with = #deserialize_with });
+        let default = if optional {
+            self.serde(quote! { default })
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            #borrow
+            #deserialize_with
+            #default
+        }
+    }
+
+    // The impl of the "borrow" module's Deserialize for a generated type, if
+    // it borrows.
+    fn borrow_impls(&self, state: &mut FileState, name: &str) -> TokenStream {
+        if !name.contains(LIFETIME) {
+            return quote! {};
+        }
+
+        state.borrows = true;
+        borrow::impls(&parse_type(name), &lifetime(), &self.core())
+    }
+
+    // The crate that fully-qualified paths to items in core are written
+    // against.
+    fn core(&self) -> Ident {
+        ident(if self.no_std { "core" } else { "std" })
+    }
+
+    // Likewise, for items in alloc.
+    fn alloc(&self) -> Ident {
+        ident(if self.no_std { "alloc" } else { "std" })
     }
 }
 
@@ -76,12 +307,23 @@ impl jtd_codegen::target::Target for Target {
         state: &mut FileState,
         metadata: metadata::Metadata,
         expr: target::Expr,
-    ) -> String {
-        if let Some(s) = metadata.get("rustType").and_then(|v| v.as_str()) {
-            return s.into();
+    ) -> Result<String> {
+        if let Some(type_) = type_override(&metadata)? {
+            return Ok(quote! { #type_ }.to_string());
         }
 
-        match expr {
+        let lifetime = lifetime();
+        let type_ = match expr {
+            target::Expr::Empty if self.borrowed => {
+                state
+                    .imports
+                    .entry("serde_json::value".into())
+                    .or_default()
+                    .insert("RawValue".to_owned());
+
+                quote! { Option<&#lifetime RawValue> }
+            }
+            target::Expr::Empty if self.native_json => quote! { Option<json::Value> },
             target::Expr::Empty => {
                 state
                     .imports
@@ -89,18 +331,44 @@ impl jtd_codegen::target::Target for Target {
                     .or_default()
                     .insert("Value".to_owned());
 
-                "Option<Value>".into()
+                quote! { Option<Value> }
+            }
+            target::Expr::Boolean => quote! { bool },
+            target::Expr::Int8 => quote! { i8 },
+            target::Expr::Uint8 => quote! { u8 },
+            target::Expr::Int16 => quote! { i16 },
+            target::Expr::Uint16 => quote! { u16 },
+            target::Expr::Int32 => quote! { i32 },
+            target::Expr::Uint32 => quote! { u32 },
+            target::Expr::Float32 => quote! { f32 },
+            target::Expr::Float64 => quote! { f64 },
+            target::Expr::String if self.borrowed => {
+                state
+                    .imports
+                    .entry(
+                        if self.no_std {
+                            "alloc::borrow"
+                        } else {
+                            "std::borrow"
+                        }
+                        .into(),
+                    )
+                    .or_default()
+                    .insert("Cow".to_owned());
+
+                quote! { Cow<#lifetime, str> }
+            }
+            target::Expr::String => {
+                if self.no_std {
+                    state
+                        .imports
+                        .entry("alloc::string".into())
+                        .or_default()
+                        .insert("String".to_owned());
+                }
+
+                quote! { String }
             }
-            target::Expr::Boolean => "bool".into(),
-            target::Expr::Int8 => "i8".into(),
-            target::Expr::Uint8 => "u8".into(),
-            target::Expr::Int16 => "i16".into(),
-            target::Expr::Uint16 => "u16".into(),
-            target::Expr::Int32 => "i32".into(),
-            target::Expr::Uint32 => "u32".into(),
-            target::Expr::Float32 => "f32".into(),
-            target::Expr::Float64 => "f64".into(),
-            target::Expr::String => "String".into(),
             target::Expr::Timestamp => {
                 state
                     .imports
@@ -108,9 +376,36 @@ impl jtd_codegen::target::Target for Target {
                     .or_default()
                     .extend(vec!["DateTime".to_owned(), "FixedOffset".to_owned()]);
 
-                "DateTime<FixedOffset>".into()
+                quote! { DateTime<FixedOffset> }
+            }
+            target::Expr::ArrayOf(sub_expr) => {
+                if self.no_std {
+                    state
+                        .imports
+                        .entry("alloc::vec".into())
+                        .or_default()
+                        .insert("Vec".to_owned());
+                }
+
+                let sub_expr = parse_type(&sub_expr);
+                quote! { Vec<#sub_expr> }
+            }
+            target::Expr::DictOf(sub_expr) if self.no_std => {
+                state
+                    .imports
+                    .entry("alloc::collections".into())
+                    .or_default()
+                    .insert("BTreeMap".to_owned());
+
+                state
+                    .imports
+                    .entry("alloc::string".into())
+                    .or_default()
+                    .insert("String".to_owned());
+
+                let sub_expr = parse_type(&sub_expr);
+                quote! { BTreeMap<String, #sub_expr> }
             }
-            target::Expr::ArrayOf(sub_expr) => format!("Vec<{}>", sub_expr),
             target::Expr::DictOf(sub_expr) => {
                 state
                     .imports
@@ -118,7 +413,8 @@ impl jtd_codegen::target::Target for Target {
                     .or_default()
                     .insert("HashMap".to_owned());
 
-                format!("HashMap<String, {}>", sub_expr)
+                let sub_expr = parse_type(&sub_expr);
+                quote! { HashMap<String, #sub_expr> }
             }
 
             // TODO: A Box here is necessary because otherwise a recursive data
@@ -128,8 +424,21 @@ impl jtd_codegen::target::Target for Target {
             // reference detector, and insert Box<T> only if it's necessary to
             // break a cyclic dependency. It's unclear how much of a problem
             // this is in the real world.
-            target::Expr::NullableOf(sub_expr) => format!("Option<Box<{}>>", sub_expr),
-        }
+            target::Expr::NullableOf(sub_expr) => {
+                if self.no_std {
+                    state
+                        .imports
+                        .entry("alloc::boxed".into())
+                        .or_default()
+                        .insert("Box".to_owned());
+                }
+
+                let sub_expr = parse_type(&sub_expr);
+                quote! { Option<Box<#sub_expr>> }
+            }
+        };
+
+        Ok(type_.to_string())
     }
 
     fn item(
@@ -145,42 +454,142 @@ impl jtd_codegen::target::Target for Target {
             }
 
             target::Item::Preamble => {
+                if self.borrowed && self.native_json {
+                    return Err(Error::UnsupportedFeature(
+                        "borrowed mode cannot be combined with native JSON mode".into(),
+                    ));
+                }
+
                 writeln!(
                     out,
                     "// Code generated by jtd-codegen for Rust v{}",
                     env!("CARGO_PKG_VERSION")
                 )?;
 
-                if !state.imports.is_empty() {
-                    writeln!(out)?;
-                }
+                None
+            }
 
-                for (module, idents) in &state.imports {
-                    write!(out, "use {}::", module)?;
+            target::Item::Postamble => {
+                // Everything other than the leading comment is written out
+                // here, all at once, because the imports a file needs are only
+                // known once all of its items have been generated.
+                let imports = state.imports.iter().map(|(module, idents)| {
+                    let module: syn::Path =
+                        syn::parse_str(module).expect("import module is not a valid path");
+                    let idents: Vec<Ident> = idents.iter().map(|i| ident(i)).collect();
 
-                    let idents: Vec<String> = idents.iter().cloned().collect();
                     if idents.len() == 1 {
-                        write!(out, "{}", &idents[0])?;
+                        quote! { use #module::#(#idents)*; }
                     } else {
-                        write!(out, "{{{}}}", idents.join(", "))?;
+                        quote! { use #module::{#(#idents),*}; }
                     }
+                });
 
-                    writeln!(out, ";")?;
-                }
+                let items = &state.items;
+                let runtime = if self.native_json {
+                    native_json::runtime(self.no_std, &state.imports)
+                } else if state.borrows {
+                    borrow::runtime(self.no_std, &state.imports)
+                } else {
+                    quote! {}
+                };
+
+                let file: syn::File = syn::parse2(quote! {
+                    #(#imports)*
+                    #(#items)*
+                    #runtime
+                })
+                .expect("generated code is not valid Rust");
+
+                write_items(out, file.items, "")?;
 
                 None
             }
 
-            target::Item::Postamble => None,
-
             target::Item::Alias {
                 metadata,
                 name,
                 type_,
             } => {
-                writeln!(out)?;
-                write!(out, "{}", description(&metadata, 0))?;
-                writeln!(out, "pub type {} = {};", name, type_)?;
+                let description = description(&metadata, 0);
+                let (ident, generics) = declared_name(&name);
+                let borrow = if self.newtypes {
+                    self.borrow(state, &type_, false)
+                } else {
+                    quote! {}
+                };
+                let impls = if self.newtypes {
+                    self.borrow_impls(state, &name)
+                } else {
+                    // Aliases that borrow can still be deserialized on their
+                    // own through the "borrow" module.
+                    state.borrows |= type_.contains(LIFETIME);
+                    quote! {}
+                };
+
+                let name = parse_type(&name);
+                let type_ = parse_type(&type_);
+                let core = self.core();
+
+                if !self.newtypes {
+                    state.items.push(quote! {
+                        #description
+                        pub type #ident #generics = #type_;
+                    });
+
+                    return Ok(None);
+                }
+
+                let derive = self.derive(state, &["Serialize", "Deserialize"]);
+                let transparent = self.serde(quote! { transparent });
+                state.items.push(quote! {
+                    #description
+                    #derive
+                    #transparent
+                    pub struct #ident #generics(#borrow pub #type_);
+
+                    impl #generics From<#type_> for #name {
+                        fn from(value: #type_) -> Self {
+                            Self(value)
+                        }
+                    }
+
+                    impl #generics From<#name> for #type_ {
+                        fn from(value: #name) -> Self {
+                            value.0
+                        }
+                    }
+
+                    impl #generics #core::ops::Deref for #name {
+                        type Target = #type_;
+
+                        fn deref(&self) -> &#type_ {
+                            &self.0
+                        }
+                    }
+
+                    impl #generics AsRef<#type_> for #name {
+                        fn as_ref(&self) -> &#type_ {
+                            &self.0
+                        }
+                    }
+
+                    #impls
+                });
+
+                if is_display_type(&type_) {
+                    state.items.push(quote! {
+                        impl #generics #core::fmt::Display for #name {
+                            fn fmt(&self, f: &mut #core::fmt::Formatter) -> #core::fmt::Result {
+                                #core::fmt::Display::fmt(&self.0, f)
+                            }
+                        }
+                    });
+                }
+
+                if self.native_json {
+                    state.items.push(native_json::newtype_impls(&name, &core));
+                }
 
                 None
             }
@@ -190,81 +599,64 @@ impl jtd_codegen::target::Target for Target {
                 name,
                 members,
             } => {
-                if let Some(s) = metadata.get("rustType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                if let Some(type_) = type_override(&metadata)? {
+                    return Ok(Some(quote! { #type_ }.to_string()));
                 }
 
-                state
-                    .imports
-                    .entry("serde".into())
-                    .or_default()
-                    .extend(vec!["Deserialize".to_owned(), "Serialize".to_owned()]);
+                let derive = self.derive(state, &["Serialize", "Deserialize"]);
+                let description = description(&metadata, 0);
+                let variants = members.iter().map(|member| {
+                    let description = enum_variant_description(&metadata, 1, &member.json_value);
+                    let json_value = &member.json_value;
+                    let rename = self.serde(quote! { rename = #json_value });
+                    let name = ident(&member.name);
 
-                writeln!(out)?;
-                write!(out, "{}", description(&metadata, 0))?;
-                writeln!(out, "#[derive(Serialize, Deserialize)]")?;
-                writeln!(out, "pub enum {} {{", name)?;
+                    quote! {
+                        #description
+                        #rename
+                        #name
+                    }
+                });
 
-                for (index, member) in members.into_iter().enumerate() {
-                    if index != 0 {
-                        writeln!(out)?;
+                let name = ident(&name);
+                state.items.push(quote! {
+                    #description
+                    #derive
+                    pub enum #name {
+                        #(#variants),*
                     }
+                });
 
-                    write!(
-                        out,
-                        "{}",
-                        enum_variant_description(&metadata, 1, &member.json_value)
-                    )?;
-                    writeln!(out, "    #[serde(rename = {:?})]", member.json_value)?;
-                    writeln!(out, "    {},", member.name)?;
+                if self.native_json {
+                    state
+                        .items
+                        .push(native_json::enum_impls(&name, &members, &self.core()));
                 }
 
-                writeln!(out, "}}")?;
-
                 None
             }
 
             target::Item::Struct {
                 metadata,
                 name,
-                has_additional: _,
+                has_additional,
                 fields,
             } => {
-                if let Some(s) = metadata.get("rustType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                if let Some(type_) = type_override(&metadata)? {
+                    return Ok(Some(quote! { #type_ }.to_string()));
                 }
 
-                state
-                    .imports
-                    .entry("serde".into())
-                    .or_default()
-                    .extend(vec!["Deserialize".to_owned(), "Serialize".to_owned()]);
-
-                writeln!(out)?;
-                write!(out, "{}", description(&metadata, 0))?;
-                writeln!(out, "#[derive(Serialize, Deserialize)]")?;
+                let item = self.struct_item(state, &metadata, &name, &fields);
+                state.items.push(item);
 
-                if fields.is_empty() {
-                    writeln!(out, "pub struct {} {{}}", name)?;
-                } else {
-                    writeln!(out, "pub struct {} {{", name)?;
-                    for (index, field) in fields.into_iter().enumerate() {
-                        if index != 0 {
-                            writeln!(out)?;
-                        }
-
-                        write!(out, "{}", description(&field.metadata, 1))?;
-                        writeln!(out, "    #[serde(rename = {:?})]", field.json_name)?;
-                        if field.optional {
-                            writeln!(
-                                out,
-                                "    #[serde(skip_serializing_if = \"Option::is_none\")]"
-                            )?;
-                        }
-                        writeln!(out, "    pub {}: {},", field.name, field.type_)?;
-                    }
-
-                    writeln!(out, "}}")?;
+                if self.native_json {
+                    state.items.push(native_json::struct_impls(
+                        &name,
+                        &fields,
+                        has_additional,
+                        None,
+                        &self.core(),
+                    ));
                 }
 
                 None
@@ -277,37 +669,177 @@ impl jtd_codegen::target::Target for Target {
                 tag_json_name,
                 ..
             } => {
-                if let Some(s) = metadata.get("rustType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                if let Some(type_) = type_override(&metadata)? {
+                    return Ok(Some(quote! { #type_ }.to_string()));
                 }
 
-                state
-                    .imports
-                    .entry("serde".into())
-                    .or_default()
-                    .extend(vec!["Deserialize".to_owned(), "Serialize".to_owned()]);
+                // serde's internally-tagged enums buffer their input before
+                // deserializing a variant, which loses track of what data can be
+                // borrowed from the input, and which RawValue does not support
+                // at all. So borrowing discriminators instead peek at the tag
+                // through a RawValue, and parse the variant from that.
+                let borrowing = name.contains(LIFETIME);
+                let core = self.core();
 
-                writeln!(out)?;
-                write!(out, "{}", description(&metadata, 0))?;
-                writeln!(out, "#[derive(Serialize, Deserialize)]")?;
-                writeln!(out, "#[serde(tag = {:?})]", tag_json_name)?;
-                writeln!(out, "pub enum {} {{", name)?;
+                let description = description(&metadata, 0);
+                let derive = if borrowing {
+                    self.derive(state, &["Serialize"])
+                } else {
+                    self.derive(state, &["Serialize", "Deserialize"])
+                };
+                let tag = self.serde(quote! { tag = #tag_json_name });
+
+                let tag_values: Vec<&String> = variants.iter().map(|v| &v.tag_value).collect();
+                let variant_names: Vec<Ident> = variants
+                    .iter()
+                    .map(|v| variant_name(&v.field_name))
+                    .collect();
+                let type_names: Vec<syn::Type> =
+                    variants.iter().map(|v| parse_type(&v.type_name)).collect();
 
-                for (index, variant) in variants.into_iter().enumerate() {
-                    if index != 0 {
-                        writeln!(out)?;
+                let renames = tag_values
+                    .iter()
+                    .map(|tag_value| self.serde(quote! { rename = #tag_value }));
+
+                let (declared_ident, generics) = declared_name(&name);
+                let name = parse_type(&name);
+                state.items.push(quote! {
+                    #description
+                    #derive
+                    #tag
+                    pub enum #declared_ident #generics {
+                        #(
+                            #renames
+                            #variant_names(#type_names)
+                        ),*
                     }
+                });
+
+                if self.native_json {
+                    state.items.push(native_json::discriminator_impls(
+                        &name,
+                        &tag_json_name,
+                        &tag_values,
+                        &variant_names,
+                        &core,
+                    ));
+                }
+
+                if borrowing {
+                    let alloc = self.alloc();
+                    let lifetime = lifetime();
+                    let impls = borrow::impls(&name, &lifetime, &core);
+                    state.borrows = true;
+
+                    state.items.push(quote! {
+                        impl<'de: #lifetime, #lifetime> serde::Deserialize<'de> for #name {
+                            fn deserialize<D: serde::Deserializer<'de>>(
+                                deserializer: D,
+                            ) -> #core::result::Result<Self, D::Error> {
+                                #[derive(serde::Deserialize)]
+                                struct Tag<#lifetime> {
+                                    #[serde(rename = #tag_json_name)]
+                                    #[serde(borrow)]
+                                    tag: #alloc::borrow::Cow<#lifetime, str>,
+                                }
 
-                    writeln!(out, "    #[serde(rename = {:?})]", variant.tag_value)?;
-                    writeln!(
-                        out,
-                        "    {}({}),",
-                        inflect::Case::pascal_case().inflect(&[variant.field_name]),
-                        variant.type_name
-                    )?;
+                                let raw: &'de serde_json::value::RawValue =
+                                    serde::Deserialize::deserialize(deserializer)?;
+                                let tag: Tag = serde_json::from_str(raw.get())
+                                    .map_err(serde::de::Error::custom)?;
+
+                                match tag.tag.as_ref() {
+                                    #(
+                                        #tag_values => serde_json::from_str(raw.get())
+                                            .map(Self::#variant_names)
+                                            .map_err(serde::de::Error::custom),
+                                    )*
+                                    tag => #core::result::Result::Err(
+                                        serde::de::Error::unknown_variant(tag, &[#(#tag_values),*]),
+                                    ),
+                                }
+                            }
+                        }
+
+                        #impls
+                    });
                 }
 
-                writeln!(out, "}}")?;
+                // With only one variant, the catch-all arms below would be
+                // unreachable.
+                let others = if variants.len() > 1 {
+                    quote! { _ => None, }
+                } else {
+                    quote! {}
+                };
+
+                let accessor_names = variants.iter().map(|v| {
+                    ident(&format!(
+                        "as_{}",
+                        inflect::Case::snake_case().inflect(std::slice::from_ref(&v.field_name))
+                    ))
+                });
+
+                state.items.push(quote! {
+                    impl #generics #name {
+                        pub const TAGS: &'static [&'static str] = &[#(#tag_values),*];
+
+                        pub fn tag(&self) -> &'static str {
+                            match *self {
+                                #(Self::#variant_names(_) => #tag_values,)*
+                            }
+                        }
+
+                        #(
+                            pub fn #accessor_names(&self) -> Option<&#type_names> {
+                                match self {
+                                    Self::#variant_names(value) => Some(value),
+                                    #others
+                                }
+                            }
+                        )*
+                    }
+                });
+
+                let others = if variants.len() > 1 {
+                    quote! { value => Err(value), }
+                } else {
+                    quote! {}
+                };
+
+                for (variant_name, type_name) in variant_names.iter().zip(&type_names) {
+                    // A rustType override can give several variants the same
+                    // type, in which case conversions from that type would be
+                    // ambiguous.
+                    let type_name_str = quote! { #type_name }.to_string();
+                    if type_names
+                        .iter()
+                        .filter(|t| quote! { #t }.to_string() == type_name_str)
+                        .count()
+                        > 1
+                    {
+                        continue;
+                    }
+
+                    state.items.push(quote! {
+                        impl #generics From<#type_name> for #name {
+                            fn from(value: #type_name) -> Self {
+                                Self::#variant_name(value)
+                            }
+                        }
+
+                        impl #generics #core::convert::TryFrom<#name> for #type_name {
+                            type Error = #name;
+
+                            fn try_from(value: #name) -> #core::result::Result<Self, #name> {
+                                match value {
+                                    #declared_ident::#variant_name(value) => Ok(value),
+                                    #others
+                                }
+                            }
+                        }
+                    });
+                }
 
                 None
             }
@@ -315,58 +847,173 @@ impl jtd_codegen::target::Target for Target {
             target::Item::DiscriminatorVariant {
                 metadata,
                 name,
+                tag_json_name,
+                has_additional,
                 fields,
                 ..
             } => {
-                if let Some(s) = metadata.get("rustType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                if let Some(type_) = type_override(&metadata)? {
+                    return Ok(Some(quote! { #type_ }.to_string()));
                 }
 
-                state
-                    .imports
-                    .entry("serde".into())
-                    .or_default()
-                    .extend(vec!["Deserialize".to_owned(), "Serialize".to_owned()]);
-
-                writeln!(out)?;
-                write!(out, "{}", description(&metadata, 0))?;
-                writeln!(out, "#[derive(Serialize, Deserialize)]")?;
+                let item = self.struct_item(state, &metadata, &name, &fields);
+                state.items.push(item);
 
-                if fields.is_empty() {
-                    writeln!(out, "pub struct {} {{}}", name)?;
-                } else {
-                    writeln!(out, "pub struct {} {{", name)?;
-                    for (index, field) in fields.into_iter().enumerate() {
-                        if index != 0 {
-                            writeln!(out)?;
-                        }
-
-                        write!(out, "{}", description(&field.metadata, 1))?;
-                        writeln!(out, "    #[serde(rename = {:?})]", field.json_name)?;
-                        if field.optional {
-                            writeln!(
-                                out,
-                                "    #[serde(skip_serializing_if = \"Option::is_none\")]"
-                            )?;
-                        }
-                        writeln!(out, "    pub {}: {},", field.name, field.type_)?;
-                    }
-
-                    writeln!(out, "}}")?;
+                if self.native_json {
+                    state.items.push(native_json::struct_impls(
+                        &name,
+                        &fields,
+                        has_additional,
+                        Some(&tag_json_name),
+                        &self.core(),
+                    ));
                 }
 
                 None
             }
         })
     }
+
+    // In borrowed mode, types that borrow from their input are marked, so that
+    // they're named with a lifetime.
+    fn marks(&self, metadata: &metadata::Metadata, expr: &target::Expr) -> bool {
+        if !self.borrowed || metadata.contains_key("rustType") {
+            return false;
+        }
+
+        matches!(expr, target::Expr::Empty | target::Expr::String)
+    }
+
+    fn marked_name(&self, name: String) -> String {
+        format!("{}<{}>", name, LIFETIME)
+    }
 }
 
 #[derive(Default)]
 pub struct FileState {
     imports: BTreeMap<String, BTreeSet<String>>,
+    items: Vec<TokenStream>,
+
+    // Whether any item deserializes through the "borrow" module.
+    borrows: bool,
+}
+
+// prettyplease prints items back-to-back. Imports are kept together, but
+// every other item is set apart by a blank line. The items of inline modules,
+// such as the one native JSON mode generates, are laid out the same way.
+fn write_items(out: &mut dyn Write, items: Vec<syn::Item>, indent: &str) -> Result<()> {
+    let mut after_import = None;
+    for item in items {
+        let is_import = matches!(item, syn::Item::Use(_));
+        let blank_line = match after_import {
+            Some(after_import) => !(is_import && after_import),
+            None => indent.is_empty(),
+        };
+
+        if blank_line {
+            writeln!(out)?;
+        }
+
+        after_import = Some(is_import);
+        match item {
+            syn::Item::Mod(mut module) if module.content.is_some() => {
+                let (brace, items) = module.content.take().unwrap();
+                module.content = Some((brace, Vec::new()));
+
+                // The module prints as "mod name {}", and its items are
+                // written out between the braces.
+                let header = unparse(syn::Item::Mod(module));
+                let header = header
+                    .trim_end()
+                    .strip_suffix("{}")
+                    .expect("module did not print with braces");
+
+                write_indented(out, header, indent)?;
+                writeln!(out, "{{")?;
+                write_items(out, items, &format!("{}    ", indent))?;
+                writeln!(out, "{}}}", indent)?;
+            }
+            item => write_indented(out, &unparse(item), indent)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn unparse(item: syn::Item) -> String {
+    prettyplease::unparse(&syn::File {
+        shebang: None,
+        attrs: Vec::new(),
+        items: vec![item],
+    })
 }
 
-fn description(metadata: &BTreeMap<String, Value>, indent: usize) -> String {
+// Writes out text line by line, indenting every line that isn't blank. The
+// last line is left unterminated if the text is.
+fn write_indented(out: &mut dyn Write, s: &str, indent: &str) -> Result<()> {
+    for (i, line) in s.split('\n').enumerate() {
+        if i != 0 {
+            writeln!(out)?;
+        }
+
+        if !line.is_empty() {
+            write!(out, "{}{}", indent, line)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Parses a rustType override, if one is present.
+fn type_override(metadata: &metadata::Metadata) -> Result<Option<syn::Type>> {
+    match metadata.get("rustType").and_then(|v| v.as_str()) {
+        Some(s) => syn::parse_str(s).map(Some).map_err(|err| {
+            Error::InvalidMetadata(format!(
+                "rustType {:?} is not a valid Rust type: {}",
+                s, err
+            ))
+        }),
+        None => Ok(None),
+    }
+}
+
+// Parses a type expression produced by this target. Such expressions are
+// always built from tokens, or from a rustType override that has already been
+// checked by type_override, so they always parse.
+fn parse_type(s: &str) -> syn::Type {
+    syn::parse_str(s).expect("type expression is not a valid Rust type")
+}
+
+// Splits a type name into the identifier and generics it is declared with.
+// Borrowing types are named with a lifetime parameter; see marked_name.
+fn declared_name(name: &str) -> (Ident, TokenStream) {
+    match name.strip_suffix(&format!("<{}>", LIFETIME)) {
+        Some(name) => {
+            let lifetime = lifetime();
+            (ident(name), quote! { <#lifetime> })
+        }
+        None => (ident(name), quote! {}),
+    }
+}
+
+fn is_display_type(type_: &syn::Type) -> bool {
+    let type_ = quote! { #type_ }.to_string();
+
+    DISPLAY_TYPES.iter().any(|display_type| {
+        let display_type = parse_type(display_type);
+        quote! { #display_type }.to_string() == type_
+    })
+}
+
+fn ident(name: &str) -> Ident {
+    Ident::new(name, Span::call_site())
+}
+
+fn lifetime() -> syn::Lifetime {
+    syn::Lifetime::new(LIFETIME, Span::call_site())
+}
+
+fn description(metadata: &BTreeMap<String, Value>, indent: usize) -> TokenStream {
     doc(indent, jtd_codegen::target::metadata::description(metadata))
 }
 
@@ -374,16 +1021,27 @@ fn enum_variant_description(
     metadata: &BTreeMap<String, Value>,
     indent: usize,
     value: &str,
-) -> String {
+) -> TokenStream {
     doc(
         indent,
         jtd_codegen::target::metadata::enum_variant_description(metadata, value),
     )
 }
 
-fn doc(ident: usize, s: &str) -> String {
-    let prefix = "    ".repeat(ident);
-    jtd_codegen::target::fmt::comment_block("", &format!("{}/// ", prefix), "", s)
+fn variant_name(field_name: &str) -> Ident {
+    ident(&inflect::Case::pascal_case().inflect(&[field_name.to_owned()]))
+}
+
+fn doc(indent: usize, s: &str) -> TokenStream {
+    // Descriptions are wrapped as though they were written out as "///"
+    // comments at the given indentation, which is how they are printed.
+    let prefix = format!("{}/// ", "    ".repeat(indent));
+    let lines = jtd_codegen::target::fmt::comment_block("", &prefix, "", s)
+        .lines()
+        .map(|line| format!(" {}", &line[prefix.len()..]))
+        .collect::<Vec<_>>();
+
+    quote! { #(#[doc = #lines])* }
 }
 
 #[cfg(test)]
@@ -392,6 +1050,309 @@ mod tests {
         jtd_codegen_test::std_test_cases!(&crate::Target::new());
     }
 
+    mod borrowed_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/borrowed"),
+            &crate::Target::new().with_borrowed(true)
+        );
+    }
+
+    mod newtypes_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/newtypes"),
+            &crate::Target::new().with_newtypes(true)
+        );
+    }
+
+    // Reference output of borrowed mode, to check what it borrows.
+    #[allow(dead_code)]
+    mod borrowed_basic_properties {
+        include!("../borrowed/output/basic_properties/mod.rs");
+    }
+
+    #[allow(clippy::box_collection)]
+    mod borrowed_optional_properties {
+        include!("../borrowed/output/optional_properties/mod.rs");
+    }
+
+    #[allow(non_snake_case)]
+    mod borrowed_nullable_references {
+        include!("../borrowed/output/nullable_references/mod.rs");
+    }
+
+    mod borrowed_values {
+        include!("../borrowed/output/values/mod.rs");
+    }
+
+    #[test]
+    fn test_borrowed_strings() {
+        use std::borrow::Cow;
+
+        let json = r#"{"foo":true,"bar":"x","baz":[],"quux":[]}"#;
+        let root: borrowed_basic_properties::Root = serde_json::from_str(json).unwrap();
+        assert!(matches!(root.bar, Cow::Borrowed("x")));
+
+        let json = r#"{"foo":true,"bar":"x\ny","baz":[],"quux":[]}"#;
+        let root: borrowed_basic_properties::Root = serde_json::from_str(json).unwrap();
+        assert!(matches!(root.bar, Cow::Owned(ref s) if s == "x\ny"));
+
+        // Strings in options and elements are borrowed too.
+        let json = r#"{"foo":"x","bar":["y","z\n"]}"#;
+        let root: borrowed_optional_properties::Root = serde_json::from_str(json).unwrap();
+        assert!(matches!(root.foo.as_deref(), Some(Cow::Borrowed("x"))));
+        let bar = root.bar.unwrap();
+        assert!(matches!(bar[0], Cow::Borrowed("y")));
+        assert!(matches!(bar[1], Cow::Owned(ref s) if s == "z\n"));
+
+        let json = r#"{}"#;
+        let root: borrowed_optional_properties::Root = serde_json::from_str(json).unwrap();
+        assert!(root.foo.is_none() && root.bar.is_none());
+
+        // And so are strings behind aliases, whether or not they're nullable.
+        let json = r#"{
+            "notnull_string": "a",
+            "null_string": "b",
+            "notnull_ref_notnull_string": "c",
+            "null_ref_notnull_string": null,
+            "notnull_ref_null_string": "e",
+            "null_ref_null_string": "f"
+        }"#;
+        let root: borrowed_nullable_references::Root = serde_json::from_str(json).unwrap();
+        assert!(matches!(root.notnullString, Cow::Borrowed("a")));
+        assert!(matches!(
+            root.nullString.as_deref(),
+            Some(Cow::Borrowed("b"))
+        ));
+        assert!(matches!(root.notnullRefNotnullString, Cow::Borrowed("c")));
+        assert!(root.nullRefNotnullString.is_none());
+        assert!(matches!(
+            root.notnullRefNullString.as_deref(),
+            Some(Cow::Borrowed("e"))
+        ));
+
+        // A type alias deserialized on its own borrows through the generated
+        // borrow module.
+        let json = r#"{"a":"b"}"#;
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let root: borrowed_values::Root =
+            borrowed_values::borrow::deserialize(&mut deserializer).unwrap();
+        assert!(matches!(root["a"], Cow::Borrowed("b")));
+    }
+
+    #[test]
+    fn test_invalid_type_override() {
+        use std::convert::TryInto;
+
+        let schema: jtd::SerdeSchema = serde_json::from_value(serde_json::json!({
+            "properties": {
+                "foo": { "type": "string", "metadata": { "rustType": "Vec<" }}
+            }
+        }))
+        .unwrap();
+
+        let out_dir = tempfile::tempdir().unwrap();
+        let err = jtd_codegen::codegen(
+            &crate::Target::new(),
+            "root".into(),
+            &schema.try_into().unwrap(),
+            out_dir.path(),
+        )
+        .err()
+        .unwrap();
+
+        assert!(matches!(err, jtd_codegen::Error::InvalidMetadata(_)));
+    }
+
+    #[test]
+    fn test_borrowed_native_json() {
+        use std::convert::TryInto;
+
+        let schema: jtd::SerdeSchema =
+            serde_json::from_value(serde_json::json!({ "type": "string" })).unwrap();
+
+        let out_dir = tempfile::tempdir().unwrap();
+        let err = jtd_codegen::codegen(
+            &crate::Target::new()
+                .with_borrowed(true)
+                .with_native_json(true),
+            "root".into(),
+            &schema.try_into().unwrap(),
+            out_dir.path(),
+        )
+        .err()
+        .unwrap();
+
+        assert!(matches!(err, jtd_codegen::Error::UnsupportedFeature(_)));
+    }
+
+    mod no_std_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/no_std"),
+            &crate::Target::new().with_no_std(true)
+        );
+    }
+
+    // Every standard case but custom_overrides, whose overrides are serde
+    // types that do not implement the json module's traits.
+    mod native_json_std_tests {
+        const BASE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/native_json");
+
+        fn target() -> crate::Target {
+            crate::Target::new().with_native_json(true)
+        }
+
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), basic_discriminator);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), basic_enum);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), basic_properties);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), definition_name_collisions);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), description);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), elements);
+        jtd_codegen_test::strict_std_test_case!(
+            BASE_DIR,
+            &target(),
+            empty_and_nonascii_definitions
+        );
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), enum_collisions);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), enum_variant_collisions);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), initialisms);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), keywords);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), nullable_discriminator);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), nullable_enum);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), nullable_elements);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), nullable_properties);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), nullable_references);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), property_name_collisions);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), reference);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_boolean);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_int8);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_int16);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_int32);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_nullable_string);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_string);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_uint8);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_uint16);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), root_uint32);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), type_collisions);
+        jtd_codegen_test::strict_std_test_case!(BASE_DIR, &target(), values);
+
+        jtd_codegen_test::loose_std_test_case!(
+            BASE_DIR,
+            &target(),
+            discriminator_optional_properties
+        );
+        jtd_codegen_test::loose_std_test_case!(BASE_DIR, &target(), geojson);
+        jtd_codegen_test::loose_std_test_case!(BASE_DIR, &target(), nullable_timestamp_property);
+        jtd_codegen_test::loose_std_test_case!(BASE_DIR, &target(), optional_properties);
+        jtd_codegen_test::loose_std_test_case!(BASE_DIR, &target(), root_empty);
+        jtd_codegen_test::loose_std_test_case!(BASE_DIR, &target(), root_float32);
+        jtd_codegen_test::loose_std_test_case!(BASE_DIR, &target(), root_float64);
+        jtd_codegen_test::loose_std_test_case!(BASE_DIR, &target(), root_nullable_timestamp);
+        jtd_codegen_test::loose_std_test_case!(BASE_DIR, &target(), root_timestamp);
+    }
+
+    // The runtime that native JSON mode generates, as it is included into
+    // code that uses std.
+    #[allow(dead_code)]
+    mod json {
+        use std::borrow::Cow;
+        use std::collections::BTreeMap;
+
+        include!("json_runtime.rs");
+    }
+
+    #[test]
+    fn test_json_runtime_read() {
+        use json::{from_json, Value};
+
+        assert_eq!(
+            Ok("a\u{e9}\u{1f600}\n/".to_owned()),
+            from_json(r#""a\u00e9\ud83d\ude00\n\/""#)
+        );
+
+        assert_eq!(Ok(1u8), from_json("1.0"));
+        assert_eq!(Ok(-100i8), from_json("-1e2"));
+        assert!(from_json::<u8>("256").is_err());
+        assert!(from_json::<u8>("1.5").is_err());
+        assert!(from_json::<f64>("1e999").is_err());
+
+        assert_eq!(
+            Ok(vec![None, Some(true)]),
+            from_json::<Vec<Option<bool>>>(" [ null , true ] ")
+        );
+
+        assert_eq!(
+            Ok(Value::Array(vec![
+                Value::Number("2.50".to_owned()),
+                Value::Object(Default::default()),
+            ])),
+            from_json(r#"[2.50, {}]"#)
+        );
+
+        let err = from_json::<Vec<bool>>("[true] false").unwrap_err();
+        assert_eq!((7, "trailing characters"), (err.offset, err.message));
+
+        for input in &[
+            "[true,]",
+            "[true false]",
+            r#""\ud83d""#,
+            "\"\u{1}\"",
+            "01",
+            "nul",
+        ] {
+            assert!(from_json::<Value>(input).is_err(), "{}", input);
+        }
+
+        let nested = format!("{}{}", "[".repeat(200), "]".repeat(200));
+        let err = from_json::<Value>(&nested).unwrap_err();
+        assert_eq!("too deeply nested", err.message);
+    }
+
+    #[test]
+    fn test_json_runtime_peek_tag() {
+        use json::{Reader, Value};
+
+        // The outer tag comes last, so the object is read ahead, and the inner
+        // tag is then found in what was read.
+        let mut reader = Reader::new(r#"{"a": {"b": [1, {"t": 2}], "t": "x"}, "t": "y"}"#);
+        assert_eq!(Ok("y".into()), reader.peek_tag("t"));
+
+        let mut first = true;
+        reader.begin_object().unwrap();
+        assert_eq!(Ok(Some("a".into())), reader.next_key(&mut first));
+        assert_eq!(Ok("x".into()), reader.peek_tag("t"));
+        reader.skip_value().unwrap();
+        assert_eq!(Ok(Some("t".into())), reader.next_key(&mut first));
+        assert_eq!(Ok("y".into()), reader.read_str());
+        assert_eq!(Ok(None), reader.next_key(&mut first));
+        reader.end().unwrap();
+
+        let mut reader = Reader::new(r#"{"t": "x", "a": 1}"#);
+        assert_eq!(Ok("x".into()), reader.peek_tag("t"));
+        assert!(json::FromJson::read_json(&mut reader)
+            .map(|_: Value| ())
+            .is_ok());
+
+        let mut reader = Reader::new(r#"{"a": {"t": "x"}} "#);
+        let err = reader.peek_tag("t").unwrap_err();
+        assert_eq!("missing discriminator tag", err.message);
+    }
+
+    #[test]
+    fn test_json_runtime_write() {
+        use json::{from_json, to_json, Value};
+
+        assert_eq!(
+            "\"\\\"\\\\\\n\\u0001\u{e9}\"",
+            to_json(&"\"\\\n\u{1}\u{e9}".to_owned())
+        );
+        assert_eq!("1.0", to_json(&1.0f64));
+        assert_eq!("null", to_json(&f32::NAN));
+        assert_eq!("[-3,null]", to_json(&vec![Some(-3i32), None]));
+
+        let input = r#"{"a":[1,2.50,null,true,"x\ty"],"b":{}}"#;
+        assert_eq!(input, to_json(&from_json::<Value>(input).unwrap()));
+    }
+
     mod optional_std_tests {
         jtd_codegen_test::strict_std_test_case!(
             &crate::Target::new(),
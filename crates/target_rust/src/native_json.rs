@@ -0,0 +1,312 @@
+// Generates the code that parses and writes JSON in native JSON mode. See
+// Target::with_native_json.
+//
+// Generated types implement the traits from the runtime in json_runtime.rs,
+// which is emitted alongside them as a "json" module.
+
+use crate::{ident, parse_type};
+use jtd_codegen::target;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::collections::{BTreeMap, BTreeSet};
+
+const RUNTIME: &str = include_str!("json_runtime.rs");
+
+// The "json" module that generated code is built on, tailored to the imports
+// the rest of the file uses.
+pub fn runtime(no_std: bool, imports: &BTreeMap<String, BTreeSet<String>>) -> TokenStream {
+    let runtime: syn::File = syn::parse_str(RUNTIME).expect("JSON runtime is not valid Rust");
+    let items = runtime.items;
+
+    let imports_ident = |module: &str, name: &str| {
+        imports
+            .get(module)
+            .is_some_and(|idents| idents.contains(name))
+    };
+
+    let prelude = if no_std {
+        quote! {
+            use alloc::borrow::Cow;
+            use alloc::boxed::Box;
+            use alloc::collections::BTreeMap;
+            use alloc::string::String;
+            use alloc::vec::Vec;
+        }
+    } else {
+        quote! {
+            use std::borrow::Cow;
+            use std::collections::BTreeMap;
+        }
+    };
+
+    let std_impls = if no_std {
+        quote! {}
+    } else {
+        quote! {
+            impl std::error::Error for Error {}
+        }
+    };
+
+    let hash_map_impls = if imports_ident("std::collections", "HashMap") {
+        quote! {
+            impl<T: FromJson> FromJson for std::collections::HashMap<String, T> {
+                fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+                    read_map(reader)
+                }
+            }
+
+            impl<T: ToJson> ToJson for std::collections::HashMap<String, T> {
+                fn write_json(&self, writer: &mut Writer) {
+                    write_map(writer, self.iter());
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Timestamps are written out the same way chrono's serde support writes
+    // them.
+    let timestamp_impls = if imports_ident("chrono", "DateTime") {
+        quote! {
+            impl FromJson for chrono::DateTime<chrono::FixedOffset> {
+                fn read_json(reader: &mut Reader) -> Result<Self, Error> {
+                    let value = reader.read_str()?;
+                    chrono::DateTime::parse_from_rfc3339(&value)
+                        .map_err(|_| reader.error("expected timestamp"))
+                }
+            }
+
+            impl ToJson for chrono::DateTime<chrono::FixedOffset> {
+                fn write_json(&self, writer: &mut Writer) {
+                    write!(writer, "\"{:?}\"", self);
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        /// Parsing and writing JSON for the types in this module, without
+        /// depending on serde.
+        pub mod json {
+            #prelude
+            #(#items)*
+            #std_impls
+            #hash_map_impls
+            #timestamp_impls
+        }
+    }
+}
+
+// The impls for a newtype, which is read and written the same way as the type
+// it wraps.
+pub fn newtype_impls(name: &syn::Type, core: &Ident) -> TokenStream {
+    quote! {
+        impl json::FromJson for #name {
+            fn read_json(reader: &mut json::Reader) -> #core::result::Result<Self, json::Error> {
+                json::FromJson::read_json(reader).map(Self)
+            }
+        }
+
+        impl json::ToJson for #name {
+            fn write_json(&self, writer: &mut json::Writer) {
+                json::ToJson::write_json(&self.0, writer);
+            }
+        }
+    }
+}
+
+pub fn enum_impls(name: &Ident, members: &[target::EnumMember], core: &Ident) -> TokenStream {
+    let json_values: Vec<&String> = members.iter().map(|m| &m.json_value).collect();
+    let json_strings: Vec<String> = json_values.iter().map(|v| json_string(v)).collect();
+    let member_names: Vec<Ident> = members.iter().map(|m| ident(&m.name)).collect();
+
+    quote! {
+        impl json::FromJson for #name {
+            fn read_json(reader: &mut json::Reader) -> #core::result::Result<Self, json::Error> {
+                match &*reader.read_str()? {
+                    #(#json_values => Ok(Self::#member_names),)*
+                    _ => Err(reader.error("unknown enum value")),
+                }
+            }
+        }
+
+        impl json::ToJson for #name {
+            fn write_json(&self, writer: &mut json::Writer) {
+                writer.write_raw(match self {
+                    #(Self::#member_names => #json_strings,)*
+                });
+            }
+        }
+    }
+}
+
+// The impls for a struct. Structs that are discriminator variants are given
+// the name of their tag property, which they ignore when parsing; writing
+// the tag is left to the discriminator.
+pub fn struct_impls(
+    name: &str,
+    fields: &[target::Field],
+    has_additional: bool,
+    tag_json_name: Option<&str>,
+    core: &Ident,
+) -> TokenStream {
+    let (declared_ident, _) = crate::declared_name(name);
+    let name = parse_type(name);
+
+    let locals: Vec<Ident> = (0..fields.len())
+        .map(|i| ident(&format!("field{}", i)))
+        .collect();
+    let field_names: Vec<Ident> = fields.iter().map(|f| ident(&f.name)).collect();
+    let json_names: Vec<&String> = fields.iter().map(|f| &f.json_name).collect();
+
+    let declare_locals = fields.iter().zip(&locals).map(|(field, local)| {
+        let type_ = parse_type(&field.type_);
+        if field.optional {
+            quote! { let mut #local: #type_ = None; }
+        } else {
+            quote! { let mut #local: Option<#type_> = None; }
+        }
+    });
+
+    let read_locals = fields.iter().zip(&locals).map(|(field, local)| {
+        if field.optional {
+            quote! { #local = json::FromJson::read_json(reader)? }
+        } else {
+            quote! { #local = Some(json::FromJson::read_json(reader)?) }
+        }
+    });
+
+    let finish_locals = fields.iter().zip(&locals).map(|(field, local)| {
+        if field.optional {
+            quote! { #local }
+        } else {
+            let message = format!("missing property {}", json_string(&field.json_name));
+            quote! { #local.ok_or_else(|| reader.error(#message))? }
+        }
+    });
+
+    let skip_tag = tag_json_name.map(|tag_json_name| {
+        quote! { #tag_json_name => reader.skip_value()?, }
+    });
+
+    let other_keys = if has_additional {
+        quote! { _ => reader.skip_value()?, }
+    } else {
+        quote! { _ => return Err(reader.error("unexpected property")), }
+    };
+
+    let write_fields = fields.iter().map(|field| {
+        let name = ident(&field.name);
+        let key = json_string(&field.json_name);
+
+        if field.optional {
+            quote! {
+                if let Some(value) = &self.#name {
+                    writer.write_key(first, #key);
+                    json::ToJson::write_json(value, writer);
+                }
+            }
+        } else {
+            quote! {
+                writer.write_key(first, #key);
+                json::ToJson::write_json(&self.#name, writer);
+            }
+        }
+    });
+
+    // Structs without any fields don't use these parameters.
+    let (writer, first) = if fields.is_empty() {
+        (ident("_writer"), ident("_first"))
+    } else {
+        (ident("writer"), ident("first"))
+    };
+
+    quote! {
+        impl json::FromJson for #name {
+            fn read_json(reader: &mut json::Reader) -> #core::result::Result<Self, json::Error> {
+                #(#declare_locals)*
+                let mut first = true;
+
+                reader.begin_object()?;
+                while let Some(key) = reader.next_key(&mut first)? {
+                    match &*key {
+                        #(#json_names => #read_locals,)*
+                        #skip_tag
+                        #other_keys
+                    }
+                }
+
+                Ok(#declared_ident {
+                    #(#field_names: #finish_locals,)*
+                })
+            }
+        }
+
+        impl json::ToJson for #name {
+            fn write_json(&self, writer: &mut json::Writer) {
+                writer.write_raw("{");
+                json::ToJsonProperties::write_json_properties(self, writer, &mut true);
+                writer.write_raw("}");
+            }
+        }
+
+        impl json::ToJsonProperties for #name {
+            fn write_json_properties(&self, #writer: &mut json::Writer, #first: &mut bool) {
+                #(#write_fields)*
+            }
+        }
+    }
+}
+
+// The impls for a discriminator, which picks a variant to parse by looking
+// ahead for its tag, and writes out its tag before the properties of its
+// variant.
+pub fn discriminator_impls(
+    name: &syn::Type,
+    tag_json_name: &str,
+    tag_values: &[&String],
+    variant_names: &[Ident],
+    core: &Ident,
+) -> TokenStream {
+    let tag_key = json_string(tag_json_name);
+    let tag_strings: Vec<String> = tag_values.iter().map(|v| json_string(v)).collect();
+
+    quote! {
+        impl json::FromJson for #name {
+            fn read_json(reader: &mut json::Reader) -> #core::result::Result<Self, json::Error> {
+                match &*reader.peek_tag(#tag_json_name)? {
+                    #(#tag_values => json::FromJson::read_json(reader).map(Self::#variant_names),)*
+                    _ => Err(reader.error("unknown discriminator tag")),
+                }
+            }
+        }
+
+        impl json::ToJson for #name {
+            fn write_json(&self, writer: &mut json::Writer) {
+                let mut first = true;
+
+                writer.write_raw("{");
+                writer.write_key(&mut first, #tag_key);
+                match self {
+                    #(
+                        Self::#variant_names(value) => {
+                            writer.write_raw(#tag_strings);
+                            json::ToJsonProperties::write_json_properties(value, writer, &mut first);
+                        }
+                    )*
+                }
+
+                writer.write_raw("}");
+            }
+        }
+    }
+}
+
+// Keys and enum values are escaped when code is generated, rather than every
+// time they are written.
+fn json_string(s: &str) -> String {
+    serde_json::to_string(s).expect("failed to encode string as JSON")
+}
@@ -0,0 +1,99 @@
+use jtd_codegen_target_rust::Target;
+use proc_macro::TokenStream;
+use std::env;
+use std::fs;
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Ident, LitStr, Token};
+
+/// Expands to the Rust code generated from a JSON Typedef schema file.
+///
+/// The path is relative to the root of the calling crate, and the root type is
+/// named after the schema file, just as with the `jtd-codegen` CLI. The
+/// generated code uses `serde`, `serde_json` and (for timestamps) `chrono`,
+/// so the calling crate must depend on those.
+///
+/// The path can be followed by options, which correspond to the `with_`
+/// methods of `jtd_codegen_target_rust::Target`: `borrowed`, `newtypes`,
+/// `no_std` and `native_json`.
+///
+/// ```ignore
+/// mod user {
+///     jtd_codegen_target_rust_macros::include_schema!("schemas/user.jtd.json");
+/// }
+///
+/// mod event {
+///     jtd_codegen_target_rust_macros::include_schema!("schemas/event.jtd.json", borrowed);
+/// }
+/// ```
+#[proc_macro]
+pub fn include_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+
+    match include_schema_code(&input.path.value(), &input.target) {
+        Ok(code) => code,
+        Err(err) => syn::Error::new(input.path.span(), err)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+// The arguments to include_schema: a path, then any options.
+struct Input {
+    path: LitStr,
+    target: Target,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let mut target = Target::new();
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let option: Ident = input.parse()?;
+            target = match option.to_string().as_str() {
+                "borrowed" => target.with_borrowed(true),
+                "newtypes" => target.with_newtypes(true),
+                "no_std" => target.with_no_std(true),
+                "native_json" => target.with_native_json(true),
+                _ => {
+                    return Err(syn::Error::new(
+                        option.span(),
+                        "expected one of borrowed, newtypes, no_std or native_json",
+                    ))
+                }
+            };
+        }
+
+        Ok(Input { path, target })
+    }
+}
+
+fn include_schema_code(path: &str, target: &Target) -> Result<TokenStream, String> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|err| err.to_string())?;
+    let schema_path = Path::new(&manifest_dir).join(path);
+
+    let out_dir = tempfile::tempdir().map_err(|err| err.to_string())?;
+    target
+        .generate(&schema_path, out_dir.path())
+        .map_err(|err| err.to_string())?;
+
+    let code = fs::read_to_string(out_dir.path().join("mod.rs")).map_err(|err| err.to_string())?;
+
+    // Proc macros can't emit rerun-if-changed. Instead, we have the generated
+    // code include the schema file, which makes Cargo rebuild the calling crate
+    // whenever the schema changes.
+    let tracking = format!(
+        "const _: &[u8] = include_bytes!({:?});",
+        schema_path.to_string_lossy()
+    );
+
+    format!("{}\n{}", code, tracking)
+        .parse()
+        .map_err(|err| format!("generated code is not valid Rust: {:?}", err))
+}
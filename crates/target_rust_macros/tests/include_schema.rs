@@ -0,0 +1,75 @@
+use serde_json::Value;
+
+mod basic_properties {
+    jtd_codegen_target_rust_macros::include_schema!(
+        "../test/schemas/roundtrip_strict/basic_properties.jtd.json"
+    );
+}
+
+#[test]
+fn test_include_schema() {
+    let input: Value =
+        serde_json::from_str(r#"{"foo": true, "bar": "bar", "baz": [true, false], "quux": []}"#)
+            .unwrap();
+
+    let data: basic_properties::BasicProperties = serde_json::from_value(input.clone()).unwrap();
+    assert_eq!("bar", data.bar);
+    assert_eq!(input, serde_json::to_value(&data).unwrap());
+}
+
+mod basic_discriminator {
+    jtd_codegen_target_rust_macros::include_schema!(
+        "../test/schemas/roundtrip_strict/basic_discriminator.jtd.json"
+    );
+}
+
+#[test]
+fn test_discriminator_helpers() {
+    use basic_discriminator::{
+        BasicDiscriminator, BasicDiscriminatorBarBaz, BasicDiscriminatorQuux,
+    };
+    use std::convert::TryFrom;
+
+    assert_eq!(&["BAR_BAZ", "QUUX"], BasicDiscriminator::TAGS);
+
+    let data: BasicDiscriminator = BasicDiscriminatorBarBaz { baz: "baz".into() }.into();
+    assert_eq!("BAR_BAZ", data.tag());
+    assert_eq!("baz", data.as_bar_baz().unwrap().baz);
+    assert!(data.as_quux().is_none());
+
+    let data = match BasicDiscriminatorQuux::try_from(data) {
+        Ok(_) => panic!("converted BAR_BAZ variant into QUUX"),
+        Err(data) => data,
+    };
+
+    match BasicDiscriminatorBarBaz::try_from(data) {
+        Ok(data) => assert_eq!("baz", data.baz),
+        Err(_) => panic!("failed to convert BAR_BAZ variant"),
+    }
+}
+
+mod borrowed_basic_properties {
+    jtd_codegen_target_rust_macros::include_schema!(
+        "../test/schemas/roundtrip_strict/basic_properties.jtd.json",
+        borrowed,
+    );
+}
+
+mod newtypes_reference {
+    jtd_codegen_target_rust_macros::include_schema!(
+        "../test/schemas/roundtrip_strict/reference.jtd.json",
+        newtypes
+    );
+}
+
+#[test]
+fn test_include_schema_options() {
+    use std::borrow::Cow;
+
+    let input = r#"{"foo": true, "bar": "bar", "baz": [], "quux": []}"#;
+    let data: borrowed_basic_properties::BasicProperties = serde_json::from_str(input).unwrap();
+    assert!(matches!(data.bar, Cow::Borrowed("bar")));
+
+    let data: newtypes_reference::Baz = serde_json::from_str(r#""baz""#).unwrap();
+    assert_eq!("baz", data.to_string());
+}
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export enum Root {
+  Bar = "Bar",
+  Baz = "Baz",
+  Foo = "Foo",
+}
@@ -0,0 +1,15 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = Foo0;
+
+export type Bar = Bar0;
+
+export type Foo = Bar;
+
+export type Bar0 = string & { readonly __brand: "Bar0" };
+
+export function Bar0(x: string): Bar0 {
+  return x as Bar0;
+}
+
+export type Foo0 = Foo;
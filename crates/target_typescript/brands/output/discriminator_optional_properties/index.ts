@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = RootBar;
+
+export interface RootBar {
+  foo: "bar";
+  baz?: string[];
+  quux?: boolean;
+}
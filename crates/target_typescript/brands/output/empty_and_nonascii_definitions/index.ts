@@ -0,0 +1,55 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = string & { readonly __brand: "Root" };
+
+export function Root(x: string): Root {
+  return x as Root;
+}
+
+export type DefaultName = string & { readonly __brand: "DefaultName" };
+
+export function DefaultName(x: string): DefaultName {
+  return x as DefaultName;
+}
+
+export type Foo = string & { readonly __brand: "Foo" };
+
+export function Foo(x: string): Foo {
+  return x as Foo;
+}
+
+export type Foo0 = string & { readonly __brand: "Foo0" };
+
+export function Foo0(x: string): Foo0 {
+  return x as Foo0;
+}
+
+export type Foo1 = string & { readonly __brand: "Foo1" };
+
+export function Foo1(x: string): Foo1 {
+  return x as Foo1;
+}
+
+export type FooBar = string & { readonly __brand: "FooBar" };
+
+export function FooBar(x: string): FooBar {
+  return x as FooBar;
+}
+
+export type FooBar0 = string & { readonly __brand: "FooBar0" };
+
+export function FooBar0(x: string): FooBar0 {
+  return x as FooBar0;
+}
+
+export type Foo0bar = string & { readonly __brand: "Foo0bar" };
+
+export function Foo0bar(x: string): Foo0bar {
+  return x as Foo0bar;
+}
+
+export type FooBar1 = string & { readonly __brand: "FooBar1" };
+
+export function FooBar1(x: string): FooBar1 {
+  return x as FooBar1;
+}
@@ -0,0 +1,18 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  for: For;
+  object: Object_;
+}
+
+export type For = string & { readonly __brand: "For" };
+
+export function For(x: string): For {
+  return x as For;
+}
+
+export type Object_ = string & { readonly __brand: "Object_" };
+
+export function Object_(x: string): Object_ {
+  return x as Object_;
+}
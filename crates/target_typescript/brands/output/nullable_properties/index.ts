@@ -0,0 +1,10 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root0 {
+  bar: string;
+  baz: boolean[];
+  foo: boolean;
+  quux: boolean[];
+}
+
+export type Root = (Root0 | null);
@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = Foo;
+
+export type Bar = Baz;
+
+export type Baz = string & { readonly __brand: "Baz" };
+
+export function Baz(x: string): Baz {
+  return x as Baz;
+}
+
+export type Foo = Bar;
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = boolean & { readonly __brand: "Root" };
+
+export function Root(x: boolean): Root {
+  return x as Root;
+}
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = string & { readonly __brand: "Root" };
+
+export function Root(x: string): Root {
+  return x as Root;
+}
@@ -0,0 +1,12 @@
+// With dates, values of generated types aren't their JSON representation, so
+// each line of input is converted into the generated type and back before
+// being written back out.
+import { fromJSONMAIN, toJSONMAIN } from "./jtd_codegen_e2e";
+
+declare const process: any;
+declare function require(name: string): any;
+
+const lines = require("readline").createInterface({ input: process.stdin });
+lines.on("line", (line: string) => {
+  console.log(JSON.stringify(toJSONMAIN(fromJSONMAIN(JSON.parse(line)))));
+});
@@ -0,0 +1,17 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  foo: (Date | null);
+}
+
+export function fromJSONRoot(x: any): Root {
+  const y: any = { ...x };
+  y.foo = x.foo === null ? null : new Date(x.foo);
+  return y;
+}
+
+export function toJSONRoot(x: Root): any {
+  const y: any = { ...x };
+  y.foo = x.foo === null ? null : x.foo.toISOString();
+  return y;
+}
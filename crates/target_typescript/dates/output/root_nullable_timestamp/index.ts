@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = (Date | null);
+
+export function fromJSONRoot(x: any): Root {
+  return x === null ? null : new Date(x);
+}
+
+export function toJSONRoot(x: Root): any {
+  return x === null ? null : x.toISOString();
+}
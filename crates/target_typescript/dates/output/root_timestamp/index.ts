@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = Date;
+
+export function fromJSONRoot(x: any): Root {
+  return new Date(x);
+}
+
+export function toJSONRoot(x: Root): any {
+  return x.toISOString();
+}
@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root";
+export type { RootBarBaz } from "./root-bar-baz";
+export type { RootQuux } from "./root-quux";
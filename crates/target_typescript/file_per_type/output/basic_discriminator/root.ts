@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootBarBaz } from "./root-bar-baz";
+import type { RootQuux } from "./root-quux";
+
+export type Root = RootBarBaz | RootQuux;
@@ -0,0 +1,4 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root";
+export type { RootOverrideTypeDiscriminatorBaz } from "./root-override-type-discriminator-baz";
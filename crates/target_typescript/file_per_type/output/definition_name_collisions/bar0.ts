@@ -0,0 +1,3 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Bar0 = string;
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Bar } from "./bar";
+export type { Bar0 } from "./bar0";
+export type { Foo } from "./foo";
+export type { Foo0 } from "./foo0";
+export type { Root } from "./root";
@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+/**
+ * A description for a definition
+ */
+export type Baz = string;
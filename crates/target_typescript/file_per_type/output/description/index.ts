@@ -0,0 +1,8 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Baz } from "./baz";
+export type { Root } from "./root";
+export type { RootDiscriminatorWithDescription } from "./root-discriminator-with-description";
+export type { RootDiscriminatorWithDescriptionBar } from "./root-discriminator-with-description-bar";
+export { RootEnumWithDescription } from "./root-enum-with-description";
+export type { RootPropertiesWithDescription } from "./root-properties-with-description";
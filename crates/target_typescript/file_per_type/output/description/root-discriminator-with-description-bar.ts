@@ -0,0 +1,8 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+/**
+ * A description for discriminator variant
+ */
+export interface RootDiscriminatorWithDescriptionBar {
+  foo: "bar";
+}
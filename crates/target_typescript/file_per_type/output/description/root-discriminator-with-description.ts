@@ -0,0 +1,8 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootDiscriminatorWithDescriptionBar } from "./root-discriminator-with-description-bar";
+
+/**
+ * A description for discriminator
+ */
+export type RootDiscriminatorWithDescription = RootDiscriminatorWithDescriptionBar;
@@ -0,0 +1,21 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+/**
+ * A description for enum
+ */
+export enum RootEnumWithDescription {
+  /**
+   * A description for X
+   */
+  X = "X",
+
+  /**
+   * A description for Y
+   */
+  Y = "Y",
+
+  /**
+   * A description for Z
+   */
+  Z = "Z",
+}
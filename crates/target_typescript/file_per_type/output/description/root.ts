@@ -0,0 +1,42 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Baz } from "./baz";
+import type { RootDiscriminatorWithDescription } from "./root-discriminator-with-description";
+import type { RootEnumWithDescription } from "./root-enum-with-description";
+import type { RootPropertiesWithDescription } from "./root-properties-with-description";
+
+export interface Root {
+  /**
+   * A description for discriminator
+   */
+  discriminator_with_description: RootDiscriminatorWithDescription;
+
+  /**
+   * A description for enum
+   */
+  enum_with_description: RootEnumWithDescription;
+
+  /**
+   * Whereas disregard and contempt for human rights have resulted in barbarous
+   * acts which have outraged the conscience of mankind, and the advent of a
+   * world in which human beings shall enjoy freedom of speech and belief and
+   * freedom from fear and want has been proclaimed as the highest aspiration of
+   * the common people,
+   */
+  long_description: string;
+
+  /**
+   * A description for properties
+   */
+  properties_with_description: RootPropertiesWithDescription;
+
+  /**
+   * A description for ref
+   */
+  ref_with_description: Baz;
+
+  /**
+   * A description for string
+   */
+  string_with_description: string;
+}
@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { DefaultName } from "./default-name";
+export type { Foo } from "./foo";
+export type { Foo0 } from "./foo0";
+export type { Foo0bar } from "./foo0bar";
+export type { Foo1 } from "./foo1";
+export type { FooBar } from "./foo-bar";
+export type { FooBar0 } from "./foo-bar0";
+export type { FooBar1 } from "./foo-bar1";
+export type { Root } from "./root";
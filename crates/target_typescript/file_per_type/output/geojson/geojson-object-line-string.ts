@@ -0,0 +1,14 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box";
+import type { Position } from "./position";
+
+/**
+ * For type "LineString", the "coordinates" member is an array of two
+ * or more positions.
+ */
+export interface GeojsonObjectLineString {
+  type: "LineString";
+  coordinates: Position[];
+  bbox?: BoundingBox;
+}
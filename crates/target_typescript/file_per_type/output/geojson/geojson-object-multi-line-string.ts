@@ -0,0 +1,14 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box";
+import type { Position } from "./position";
+
+/**
+ * For type "MultiLineString", the "coordinates" member is an array of
+ * LineString coordinate arrays.
+ */
+export interface GeojsonObjectMultiLineString {
+  type: "MultiLineString";
+  coordinates: Position[];
+  bbox?: BoundingBox;
+}
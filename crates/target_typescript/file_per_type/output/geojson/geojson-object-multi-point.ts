@@ -0,0 +1,14 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box";
+import type { Position } from "./position";
+
+/**
+ * For type "MultiPoint", the "coordinates" member is an array of
+ * positions.
+ */
+export interface GeojsonObjectMultiPoint {
+  type: "MultiPoint";
+  coordinates: Position[];
+  bbox?: BoundingBox;
+}
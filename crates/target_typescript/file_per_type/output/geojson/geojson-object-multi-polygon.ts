@@ -0,0 +1,14 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box";
+import type { LinearRing } from "./linear-ring";
+
+/**
+ * For type "MultiPolygon", the "coordinates" member is an array of
+ * Polygon coordinate arrays.
+ */
+export interface GeojsonObjectMultiPolygon {
+  type: "MultiPolygon";
+  coordinates: LinearRing[];
+  bbox?: BoundingBox;
+}
@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box";
+import type { Position } from "./position";
+
+/**
+ * For type "Point", the "coordinates" member is a single position.
+ */
+export interface GeojsonObjectPoint {
+  type: "Point";
+  coordinates: Position;
+  bbox?: BoundingBox;
+}
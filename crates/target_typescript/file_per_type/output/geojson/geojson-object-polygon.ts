@@ -0,0 +1,14 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box";
+import type { LinearRing } from "./linear-ring";
+
+/**
+ * For type "Polygon", the "coordinates" member MUST be an array of
+ * linear ring coordinate arrays.
+ */
+export interface GeojsonObjectPolygon {
+  type: "Polygon";
+  coordinates: LinearRing[];
+  bbox?: BoundingBox;
+}
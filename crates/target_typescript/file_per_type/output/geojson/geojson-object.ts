@@ -0,0 +1,28 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { GeojsonObjectFeature } from "./geojson-object-feature";
+import type { GeojsonObjectFeatureCollection } from "./geojson-object-feature-collection";
+import type { GeojsonObjectGeometryCollection } from "./geojson-object-geometry-collection";
+import type { GeojsonObjectLineString } from "./geojson-object-line-string";
+import type { GeojsonObjectMultiLineString } from "./geojson-object-multi-line-string";
+import type { GeojsonObjectMultiPoint } from "./geojson-object-multi-point";
+import type { GeojsonObjectMultiPolygon } from "./geojson-object-multi-polygon";
+import type { GeojsonObjectPoint } from "./geojson-object-point";
+import type { GeojsonObjectPolygon } from "./geojson-object-polygon";
+
+/**
+ * A Geometry object represents points, curves, and surfaces in coordinate
+ * space.  Every Geometry object is a GeoJSON object no matter where it
+ * occurs in a GeoJSON text.
+ * 
+ * o  The value of a Geometry object's "type" member MUST be one of the
+ *     seven geometry types (see Section 1.4).
+ * 
+ * o  A GeoJSON Geometry object of any type other than
+ *     "GeometryCollection" has a member with the name "coordinates". The
+ *     value of the "coordinates" member is an array.  The structure of the
+ *     elements in this array is determined by the type of geometry.
+ *     GeoJSON processors MAY interpret Geometry objects with empty
+ *     "coordinates" arrays as null objects.
+ */
+export type GeojsonObject = GeojsonObjectFeature | GeojsonObjectFeatureCollection | GeojsonObjectGeometryCollection | GeojsonObjectLineString | GeojsonObjectMultiLineString | GeojsonObjectMultiPoint | GeojsonObjectMultiPolygon | GeojsonObjectPoint | GeojsonObjectPolygon;
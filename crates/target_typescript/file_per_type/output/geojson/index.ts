@@ -0,0 +1,16 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { BoundingBox } from "./bounding-box";
+export type { GeojsonObject } from "./geojson-object";
+export type { GeojsonObjectFeature } from "./geojson-object-feature";
+export type { GeojsonObjectFeatureCollection } from "./geojson-object-feature-collection";
+export type { GeojsonObjectGeometryCollection } from "./geojson-object-geometry-collection";
+export type { GeojsonObjectLineString } from "./geojson-object-line-string";
+export type { GeojsonObjectMultiLineString } from "./geojson-object-multi-line-string";
+export type { GeojsonObjectMultiPoint } from "./geojson-object-multi-point";
+export type { GeojsonObjectMultiPolygon } from "./geojson-object-multi-polygon";
+export type { GeojsonObjectPoint } from "./geojson-object-point";
+export type { GeojsonObjectPolygon } from "./geojson-object-polygon";
+export type { LinearRing } from "./linear-ring";
+export type { Position } from "./position";
+export type { Root } from "./root";
@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface RootNestedIdInitialism {
+  json: string;
+  normalword: string;
+}
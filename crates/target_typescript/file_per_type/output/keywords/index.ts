@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { For } from "./for";
+export type { Object } from "./object";
+export type { Root } from "./root";
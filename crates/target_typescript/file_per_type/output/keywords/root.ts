@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { For } from "./for";
+import type { Object } from "./object";
+
+export interface Root {
+  for: For;
+  object: Object;
+}
@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root";
+export type { Root0 } from "./root0";
+export type { RootBar } from "./root-bar";
+export type { RootQuux } from "./root-quux";
@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootBar } from "./root-bar";
+import type { RootQuux } from "./root-quux";
+
+export type Root0 = RootBar | RootQuux;
@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Root0 } from "./root0";
+
+export type Root = (Root0 | null);
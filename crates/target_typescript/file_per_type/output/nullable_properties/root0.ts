@@ -0,0 +1,8 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root0 {
+  bar: string;
+  baz: boolean[];
+  foo: boolean;
+  quux: boolean[];
+}
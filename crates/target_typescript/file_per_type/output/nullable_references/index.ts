@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { NotnullRefNotnullString } from "./notnull-ref-notnull-string";
+export type { NotnullRefNullString } from "./notnull-ref-null-string";
+export type { NotnullString } from "./notnull-string";
+export type { NullRefNotnullString } from "./null-ref-notnull-string";
+export type { NullRefNullString } from "./null-ref-null-string";
+export type { NullString } from "./null-string";
+export type { Root } from "./root";
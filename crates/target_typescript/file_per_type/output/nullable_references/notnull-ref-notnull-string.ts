@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { NotnullString } from "./notnull-string";
+
+export type NotnullRefNotnullString = NotnullString;
@@ -0,0 +1,17 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { NotnullRefNotnullString } from "./notnull-ref-notnull-string";
+import type { NotnullRefNullString } from "./notnull-ref-null-string";
+import type { NotnullString } from "./notnull-string";
+import type { NullRefNotnullString } from "./null-ref-notnull-string";
+import type { NullRefNullString } from "./null-ref-null-string";
+import type { NullString } from "./null-string";
+
+export interface Root {
+  notnull_ref_notnull_string: NotnullRefNotnullString;
+  notnull_ref_null_string: NotnullRefNullString;
+  notnull_string: NotnullString;
+  null_ref_notnull_string: NullRefNotnullString;
+  null_ref_null_string: NullRefNullString;
+  null_string: NullString;
+}
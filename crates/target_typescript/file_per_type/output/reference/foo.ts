@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Bar } from "./bar";
+
+export type Foo = Bar;
@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Bar } from "./bar";
+export type { Baz } from "./baz";
+export type { Foo } from "./foo";
+export type { Root } from "./root";
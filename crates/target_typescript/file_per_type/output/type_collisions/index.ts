@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root";
+export type { RootFoo } from "./root-foo";
+export type { RootFooBar } from "./root-foo-bar";
+export type { RootFooBar0 } from "./root-foo-bar0";
@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface RootFooBar0 {
+  x: string;
+}
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootFooBar } from "./root-foo-bar";
+
+export interface RootFoo {
+  bar: RootFooBar;
+}
@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootFoo } from "./root-foo";
+import type { RootFooBar0 } from "./root-foo-bar0";
+
+export interface Root {
+  foo: RootFoo;
+  foo_bar: RootFooBar0;
+}
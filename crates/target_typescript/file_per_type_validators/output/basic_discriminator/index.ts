@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export type { RootBarBaz } from "./root-bar-baz.js";
+export { isRootBarBaz, parseRootBarBaz } from "./root-bar-baz.js";
+export type { RootQuux } from "./root-quux.js";
+export { isRootQuux, parseRootQuux } from "./root-quux.js";
+export { ValidationError } from "./validation.js";
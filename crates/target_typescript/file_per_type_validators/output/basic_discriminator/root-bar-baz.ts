@@ -0,0 +1,48 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface RootBarBaz {
+  foo: "BAR_BAZ";
+  baz: string;
+}
+
+export function validateRootBarBaz(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "BAR_BAZ") {
+    return [path + "/foo", "expected \"BAR_BAZ\""];
+  }
+
+  if (!hasProperty(x, "baz")) {
+    return [path, "missing property \"baz\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "baz":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkString(x["baz"], path + "/baz");
+}
+
+export function isRootBarBaz(x: unknown): x is RootBarBaz {
+  return validateRootBarBaz(x, "") === undefined;
+}
+
+export function parseRootBarBaz(x: unknown): RootBarBaz {
+  const violation = validateRootBarBaz(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootBarBaz;
+}
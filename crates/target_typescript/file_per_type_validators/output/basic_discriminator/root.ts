@@ -0,0 +1,40 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootBarBaz } from "./root-bar-baz.js";
+import { validateRootBarBaz } from "./root-bar-baz.js";
+import type { RootQuux } from "./root-quux.js";
+import { validateRootQuux } from "./root-quux.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, hasObjectType, hasProperty } from "./validation.js";
+
+export type Root = RootBarBaz | RootQuux;
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "foo") ? x["foo"] : undefined) {
+    case "BAR_BAZ":
+      return validateRootBarBaz(x, path);
+    case "QUUX":
+      return validateRootQuux(x, path);
+    case undefined:
+      return [path, "missing property \"foo\""];
+    default:
+      return [path + "/foo", "unknown discriminator tag"];
+  }
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
@@ -0,0 +1,34 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export enum Root {
+  Bar = "Bar",
+  Baz = "Baz",
+  Foo = "Foo",
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  switch (x) {
+    case "Bar":
+    case "Baz":
+    case "Foo":
+      return undefined;
+    default:
+      return [path, "unknown enum value"];
+  }
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
@@ -0,0 +1,65 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, checkBoolean, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  bar: string;
+  baz: boolean[];
+  foo: boolean;
+  quux: boolean[];
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "bar")) {
+    return [path, "missing property \"bar\""];
+  }
+
+  if (!hasProperty(x, "baz")) {
+    return [path, "missing property \"baz\""];
+  }
+
+  if (!hasProperty(x, "foo")) {
+    return [path, "missing property \"foo\""];
+  }
+
+  if (!hasProperty(x, "quux")) {
+    return [path, "missing property \"quux\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "bar":
+      case "baz":
+      case "foo":
+      case "quux":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkString(x["bar"], path + "/bar") ||
+    checkArray(x["baz"], path + "/baz", checkBoolean) ||
+    checkBoolean(x["foo"], path + "/foo") ||
+    checkArray(x["quux"], path + "/quux", checkBoolean)
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
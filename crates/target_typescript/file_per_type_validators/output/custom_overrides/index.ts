@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export type { RootOverrideTypeDiscriminatorBaz } from "./root-override-type-discriminator-baz.js";
+export { isRootOverrideTypeDiscriminatorBaz, parseRootOverrideTypeDiscriminatorBaz } from "./root-override-type-discriminator-baz.js";
+export { ValidationError } from "./validation.js";
@@ -0,0 +1,42 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface RootOverrideTypeDiscriminatorBaz {
+  foo: "baz";
+}
+
+export function validateRootOverrideTypeDiscriminatorBaz(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "baz") {
+    return [path + "/foo", "expected \"baz\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return undefined;
+}
+
+export function isRootOverrideTypeDiscriminatorBaz(x: unknown): x is RootOverrideTypeDiscriminatorBaz {
+  return validateRootOverrideTypeDiscriminatorBaz(x, "") === undefined;
+}
+
+export function parseRootOverrideTypeDiscriminatorBaz(x: unknown): RootOverrideTypeDiscriminatorBaz {
+  const violation = validateRootOverrideTypeDiscriminatorBaz(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootOverrideTypeDiscriminatorBaz;
+}
@@ -0,0 +1,75 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, checkDict, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  override_elements_container: string[];
+  override_type_discriminator: any;
+  override_type_enum: any;
+  override_type_expr: any;
+  override_type_properties: any;
+  override_values_container: { [key: string]: string };
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "override_elements_container")) {
+    return [path, "missing property \"override_elements_container\""];
+  }
+
+  if (!hasProperty(x, "override_type_discriminator")) {
+    return [path, "missing property \"override_type_discriminator\""];
+  }
+
+  if (!hasProperty(x, "override_type_enum")) {
+    return [path, "missing property \"override_type_enum\""];
+  }
+
+  if (!hasProperty(x, "override_type_expr")) {
+    return [path, "missing property \"override_type_expr\""];
+  }
+
+  if (!hasProperty(x, "override_type_properties")) {
+    return [path, "missing property \"override_type_properties\""];
+  }
+
+  if (!hasProperty(x, "override_values_container")) {
+    return [path, "missing property \"override_values_container\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "override_elements_container":
+      case "override_type_discriminator":
+      case "override_type_enum":
+      case "override_type_expr":
+      case "override_type_properties":
+      case "override_values_container":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkArray(x["override_elements_container"], path + "/override_elements_container", checkString) ||
+    checkDict(x["override_values_container"], path + "/override_values_container", checkString)
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
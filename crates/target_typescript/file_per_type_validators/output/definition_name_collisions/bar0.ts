@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type Bar0 = string;
+
+export function validateBar0(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isBar0(x: unknown): x is Bar0 {
+  return validateBar0(x, "") === undefined;
+}
+
+export function parseBar0(x: unknown): Bar0 {
+  const violation = validateBar0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Bar0;
+}
@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Foo } from "./foo.js";
+import { validateFoo } from "./foo.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type Foo0 = Foo;
+
+export function validateFoo0(x: unknown, path: string): Violation | undefined {
+  return validateFoo(x, path);
+}
+
+export function isFoo0(x: unknown): x is Foo0 {
+  return validateFoo0(x, "") === undefined;
+}
+
+export function parseFoo0(x: unknown): Foo0 {
+  const violation = validateFoo0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo0;
+}
@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Bar } from "./bar.js";
+export { isBar, parseBar } from "./bar.js";
+export type { Bar0 } from "./bar0.js";
+export { isBar0, parseBar0 } from "./bar0.js";
+export type { Foo } from "./foo.js";
+export { isFoo, parseFoo } from "./foo.js";
+export type { Foo0 } from "./foo0.js";
+export { isFoo0, parseFoo0 } from "./foo0.js";
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export { ValidationError } from "./validation.js";
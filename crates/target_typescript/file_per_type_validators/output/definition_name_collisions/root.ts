@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Foo0 } from "./foo0.js";
+import { validateFoo0 } from "./foo0.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type Root = Foo0;
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  return validateFoo0(x, path);
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
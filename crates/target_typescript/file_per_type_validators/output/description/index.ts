@@ -0,0 +1,14 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Baz } from "./baz.js";
+export { isBaz, parseBaz } from "./baz.js";
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export type { RootDiscriminatorWithDescription } from "./root-discriminator-with-description.js";
+export { isRootDiscriminatorWithDescription, parseRootDiscriminatorWithDescription } from "./root-discriminator-with-description.js";
+export type { RootDiscriminatorWithDescriptionBar } from "./root-discriminator-with-description-bar.js";
+export { isRootDiscriminatorWithDescriptionBar, parseRootDiscriminatorWithDescriptionBar } from "./root-discriminator-with-description-bar.js";
+export { RootEnumWithDescription, isRootEnumWithDescription, parseRootEnumWithDescription } from "./root-enum-with-description.js";
+export type { RootPropertiesWithDescription } from "./root-properties-with-description.js";
+export { isRootPropertiesWithDescription, parseRootPropertiesWithDescription } from "./root-properties-with-description.js";
+export { ValidationError } from "./validation.js";
@@ -0,0 +1,45 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * A description for discriminator variant
+ */
+export interface RootDiscriminatorWithDescriptionBar {
+  foo: "bar";
+}
+
+export function validateRootDiscriminatorWithDescriptionBar(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "bar") {
+    return [path + "/foo", "expected \"bar\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return undefined;
+}
+
+export function isRootDiscriminatorWithDescriptionBar(x: unknown): x is RootDiscriminatorWithDescriptionBar {
+  return validateRootDiscriminatorWithDescriptionBar(x, "") === undefined;
+}
+
+export function parseRootDiscriminatorWithDescriptionBar(x: unknown): RootDiscriminatorWithDescriptionBar {
+  const violation = validateRootDiscriminatorWithDescriptionBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootDiscriminatorWithDescriptionBar;
+}
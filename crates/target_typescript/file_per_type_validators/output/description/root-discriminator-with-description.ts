@@ -0,0 +1,39 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootDiscriminatorWithDescriptionBar } from "./root-discriminator-with-description-bar.js";
+import { validateRootDiscriminatorWithDescriptionBar } from "./root-discriminator-with-description-bar.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * A description for discriminator
+ */
+export type RootDiscriminatorWithDescription = RootDiscriminatorWithDescriptionBar;
+
+export function validateRootDiscriminatorWithDescription(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "foo") ? x["foo"] : undefined) {
+    case "bar":
+      return validateRootDiscriminatorWithDescriptionBar(x, path);
+    case undefined:
+      return [path, "missing property \"foo\""];
+    default:
+      return [path + "/foo", "unknown discriminator tag"];
+  }
+}
+
+export function isRootDiscriminatorWithDescription(x: unknown): x is RootDiscriminatorWithDescription {
+  return validateRootDiscriminatorWithDescription(x, "") === undefined;
+}
+
+export function parseRootDiscriminatorWithDescription(x: unknown): RootDiscriminatorWithDescription {
+  const violation = validateRootDiscriminatorWithDescription(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootDiscriminatorWithDescription;
+}
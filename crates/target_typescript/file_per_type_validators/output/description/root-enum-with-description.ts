@@ -0,0 +1,48 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+/**
+ * A description for enum
+ */
+export enum RootEnumWithDescription {
+  /**
+   * A description for X
+   */
+  X = "X",
+
+  /**
+   * A description for Y
+   */
+  Y = "Y",
+
+  /**
+   * A description for Z
+   */
+  Z = "Z",
+}
+
+export function validateRootEnumWithDescription(x: unknown, path: string): Violation | undefined {
+  switch (x) {
+    case "X":
+    case "Y":
+    case "Z":
+      return undefined;
+    default:
+      return [path, "unknown enum value"];
+  }
+}
+
+export function isRootEnumWithDescription(x: unknown): x is RootEnumWithDescription {
+  return validateRootEnumWithDescription(x, "") === undefined;
+}
+
+export function parseRootEnumWithDescription(x: unknown): RootEnumWithDescription {
+  const violation = validateRootEnumWithDescription(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootEnumWithDescription;
+}
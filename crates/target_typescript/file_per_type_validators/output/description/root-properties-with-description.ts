@@ -0,0 +1,38 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * A description for properties
+ */
+export interface RootPropertiesWithDescription {
+}
+
+export function validateRootPropertiesWithDescription(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return undefined;
+}
+
+export function isRootPropertiesWithDescription(x: unknown): x is RootPropertiesWithDescription {
+  return validateRootPropertiesWithDescription(x, "") === undefined;
+}
+
+export function parseRootPropertiesWithDescription(x: unknown): RootPropertiesWithDescription {
+  const violation = validateRootPropertiesWithDescription(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootPropertiesWithDescription;
+}
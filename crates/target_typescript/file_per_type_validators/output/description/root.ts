@@ -0,0 +1,114 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Baz } from "./baz.js";
+import { validateBaz } from "./baz.js";
+import type { RootDiscriminatorWithDescription } from "./root-discriminator-with-description.js";
+import { validateRootDiscriminatorWithDescription } from "./root-discriminator-with-description.js";
+import type { RootEnumWithDescription } from "./root-enum-with-description.js";
+import { validateRootEnumWithDescription } from "./root-enum-with-description.js";
+import type { RootPropertiesWithDescription } from "./root-properties-with-description.js";
+import { validateRootPropertiesWithDescription } from "./root-properties-with-description.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  /**
+   * A description for discriminator
+   */
+  discriminator_with_description: RootDiscriminatorWithDescription;
+
+  /**
+   * A description for enum
+   */
+  enum_with_description: RootEnumWithDescription;
+
+  /**
+   * Whereas disregard and contempt for human rights have resulted in barbarous
+   * acts which have outraged the conscience of mankind, and the advent of a
+   * world in which human beings shall enjoy freedom of speech and belief and
+   * freedom from fear and want has been proclaimed as the highest aspiration of
+   * the common people,
+   */
+  long_description: string;
+
+  /**
+   * A description for properties
+   */
+  properties_with_description: RootPropertiesWithDescription;
+
+  /**
+   * A description for ref
+   */
+  ref_with_description: Baz;
+
+  /**
+   * A description for string
+   */
+  string_with_description: string;
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "discriminator_with_description")) {
+    return [path, "missing property \"discriminator_with_description\""];
+  }
+
+  if (!hasProperty(x, "enum_with_description")) {
+    return [path, "missing property \"enum_with_description\""];
+  }
+
+  if (!hasProperty(x, "long_description")) {
+    return [path, "missing property \"long_description\""];
+  }
+
+  if (!hasProperty(x, "properties_with_description")) {
+    return [path, "missing property \"properties_with_description\""];
+  }
+
+  if (!hasProperty(x, "ref_with_description")) {
+    return [path, "missing property \"ref_with_description\""];
+  }
+
+  if (!hasProperty(x, "string_with_description")) {
+    return [path, "missing property \"string_with_description\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "discriminator_with_description":
+      case "enum_with_description":
+      case "long_description":
+      case "properties_with_description":
+      case "ref_with_description":
+      case "string_with_description":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    validateRootDiscriminatorWithDescription(x["discriminator_with_description"], path + "/discriminator_with_description") ||
+    validateRootEnumWithDescription(x["enum_with_description"], path + "/enum_with_description") ||
+    checkString(x["long_description"], path + "/long_description") ||
+    validateRootPropertiesWithDescription(x["properties_with_description"], path + "/properties_with_description") ||
+    validateBaz(x["ref_with_description"], path + "/ref_with_description") ||
+    checkString(x["string_with_description"], path + "/string_with_description")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
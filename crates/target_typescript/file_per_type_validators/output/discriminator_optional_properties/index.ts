@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export type { RootBar } from "./root-bar.js";
+export { isRootBar, parseRootBar } from "./root-bar.js";
+export { ValidationError } from "./validation.js";
@@ -0,0 +1,49 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, checkBoolean, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface RootBar {
+  foo: "bar";
+  baz?: string[];
+  quux?: boolean;
+}
+
+export function validateRootBar(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "bar") {
+    return [path + "/foo", "expected \"bar\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "baz":
+      case "quux":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    (hasProperty(x, "baz") ? checkArray(x["baz"], path + "/baz", checkString) : undefined) ||
+    (hasProperty(x, "quux") ? checkBoolean(x["quux"], path + "/quux") : undefined)
+  );
+}
+
+export function isRootBar(x: unknown): x is RootBar {
+  return validateRootBar(x, "") === undefined;
+}
+
+export function parseRootBar(x: unknown): RootBar {
+  const violation = validateRootBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootBar;
+}
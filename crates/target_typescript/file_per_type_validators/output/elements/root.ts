@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, checkString } from "./validation.js";
+
+export type Root = string[];
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  return checkArray(x, path, checkString);
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type DefaultName = string;
+
+export function validateDefaultName(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isDefaultName(x: unknown): x is DefaultName {
+  return validateDefaultName(x, "") === undefined;
+}
+
+export function parseDefaultName(x: unknown): DefaultName {
+  const violation = validateDefaultName(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as DefaultName;
+}
@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type FooBar = string;
+
+export function validateFooBar(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFooBar(x: unknown): x is FooBar {
+  return validateFooBar(x, "") === undefined;
+}
+
+export function parseFooBar(x: unknown): FooBar {
+  const violation = validateFooBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as FooBar;
+}
@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type FooBar0 = string;
+
+export function validateFooBar0(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFooBar0(x: unknown): x is FooBar0 {
+  return validateFooBar0(x, "") === undefined;
+}
+
+export function parseFooBar0(x: unknown): FooBar0 {
+  const violation = validateFooBar0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as FooBar0;
+}
@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type FooBar1 = string;
+
+export function validateFooBar1(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFooBar1(x: unknown): x is FooBar1 {
+  return validateFooBar1(x, "") === undefined;
+}
+
+export function parseFooBar1(x: unknown): FooBar1 {
+  const violation = validateFooBar1(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as FooBar1;
+}
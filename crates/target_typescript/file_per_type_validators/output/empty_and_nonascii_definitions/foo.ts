@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type Foo = string;
+
+export function validateFoo(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFoo(x: unknown): x is Foo {
+  return validateFoo(x, "") === undefined;
+}
+
+export function parseFoo(x: unknown): Foo {
+  const violation = validateFoo(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo;
+}
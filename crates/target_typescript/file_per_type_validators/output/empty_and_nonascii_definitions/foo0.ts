@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type Foo0 = string;
+
+export function validateFoo0(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFoo0(x: unknown): x is Foo0 {
+  return validateFoo0(x, "") === undefined;
+}
+
+export function parseFoo0(x: unknown): Foo0 {
+  const violation = validateFoo0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo0;
+}
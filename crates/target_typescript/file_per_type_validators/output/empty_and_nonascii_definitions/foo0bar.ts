@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type Foo0bar = string;
+
+export function validateFoo0bar(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFoo0bar(x: unknown): x is Foo0bar {
+  return validateFoo0bar(x, "") === undefined;
+}
+
+export function parseFoo0bar(x: unknown): Foo0bar {
+  const violation = validateFoo0bar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo0bar;
+}
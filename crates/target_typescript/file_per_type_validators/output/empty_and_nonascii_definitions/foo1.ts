@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type Foo1 = string;
+
+export function validateFoo1(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFoo1(x: unknown): x is Foo1 {
+  return validateFoo1(x, "") === undefined;
+}
+
+export function parseFoo1(x: unknown): Foo1 {
+  const violation = validateFoo1(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo1;
+}
@@ -0,0 +1,21 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { DefaultName } from "./default-name.js";
+export { isDefaultName, parseDefaultName } from "./default-name.js";
+export type { Foo } from "./foo.js";
+export { isFoo, parseFoo } from "./foo.js";
+export type { Foo0 } from "./foo0.js";
+export { isFoo0, parseFoo0 } from "./foo0.js";
+export type { Foo0bar } from "./foo0bar.js";
+export { isFoo0bar, parseFoo0bar } from "./foo0bar.js";
+export type { Foo1 } from "./foo1.js";
+export { isFoo1, parseFoo1 } from "./foo1.js";
+export type { FooBar } from "./foo-bar.js";
+export { isFooBar, parseFooBar } from "./foo-bar.js";
+export type { FooBar0 } from "./foo-bar0.js";
+export { isFooBar0, parseFooBar0 } from "./foo-bar0.js";
+export type { FooBar1 } from "./foo-bar1.js";
+export { isFooBar1, parseFooBar1 } from "./foo-bar1.js";
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export { ValidationError } from "./validation.js";
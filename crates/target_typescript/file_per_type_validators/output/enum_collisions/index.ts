@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export type { RootFoo } from "./root-foo.js";
+export { isRootFoo, parseRootFoo } from "./root-foo.js";
+export { RootFooBar, isRootFooBar, parseRootFooBar } from "./root-foo-bar.js";
+export { RootFooBar0, isRootFooBar0, parseRootFooBar0 } from "./root-foo-bar0.js";
+export { ValidationError } from "./validation.js";
@@ -0,0 +1,32 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export enum RootFooBar {
+  X = "x",
+  Y = "y",
+}
+
+export function validateRootFooBar(x: unknown, path: string): Violation | undefined {
+  switch (x) {
+    case "x":
+    case "y":
+      return undefined;
+    default:
+      return [path, "unknown enum value"];
+  }
+}
+
+export function isRootFooBar(x: unknown): x is RootFooBar {
+  return validateRootFooBar(x, "") === undefined;
+}
+
+export function parseRootFooBar(x: unknown): RootFooBar {
+  const violation = validateRootFooBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootFooBar;
+}
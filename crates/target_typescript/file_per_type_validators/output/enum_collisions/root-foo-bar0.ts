@@ -0,0 +1,32 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export enum RootFooBar0 {
+  X = "x",
+  Y = "y",
+}
+
+export function validateRootFooBar0(x: unknown, path: string): Violation | undefined {
+  switch (x) {
+    case "x":
+    case "y":
+      return undefined;
+    default:
+      return [path, "unknown enum value"];
+  }
+}
+
+export function isRootFooBar0(x: unknown): x is RootFooBar0 {
+  return validateRootFooBar0(x, "") === undefined;
+}
+
+export function parseRootFooBar0(x: unknown): RootFooBar0 {
+  const violation = validateRootFooBar0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootFooBar0;
+}
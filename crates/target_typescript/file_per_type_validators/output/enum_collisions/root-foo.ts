@@ -0,0 +1,44 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootFooBar } from "./root-foo-bar.js";
+import { validateRootFooBar } from "./root-foo-bar.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface RootFoo {
+  bar: RootFooBar;
+}
+
+export function validateRootFoo(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "bar")) {
+    return [path, "missing property \"bar\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "bar":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return validateRootFooBar(x["bar"], path + "/bar");
+}
+
+export function isRootFoo(x: unknown): x is RootFoo {
+  return validateRootFoo(x, "") === undefined;
+}
+
+export function parseRootFoo(x: unknown): RootFoo {
+  const violation = validateRootFoo(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootFoo;
+}
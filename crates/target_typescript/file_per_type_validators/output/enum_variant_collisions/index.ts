@@ -0,0 +1,4 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export { Root, isRoot, parseRoot } from "./root.js";
+export { ValidationError } from "./validation.js";
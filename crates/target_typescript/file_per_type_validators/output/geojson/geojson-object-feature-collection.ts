@@ -0,0 +1,57 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { GeojsonObject } from "./geojson-object.js";
+import { validateGeojsonObject } from "./geojson-object.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * A GeoJSON object with the type "FeatureCollection" is a
+ * FeatureCollection object.  A FeatureCollection object has a member
+ * with the name "features".  The value of "features" is a JSON array.
+ * Each element of the array is a Feature object as defined above.  It
+ * is possible for this array to be empty.
+ */
+export interface GeojsonObjectFeatureCollection {
+  type: "FeatureCollection";
+  features: GeojsonObject[];
+}
+
+export function validateGeojsonObjectFeatureCollection(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "FeatureCollection") {
+    return [path + "/type", "expected \"FeatureCollection\""];
+  }
+
+  if (!hasProperty(x, "features")) {
+    return [path, "missing property \"features\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "type":
+      case "features":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkArray(x["features"], path + "/features", validateGeojsonObject);
+}
+
+export function isGeojsonObjectFeatureCollection(x: unknown): x is GeojsonObjectFeatureCollection {
+  return validateGeojsonObjectFeatureCollection(x, "") === undefined;
+}
+
+export function parseGeojsonObjectFeatureCollection(x: unknown): GeojsonObjectFeatureCollection {
+  const violation = validateGeojsonObjectFeatureCollection(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectFeatureCollection;
+}
@@ -0,0 +1,75 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box.js";
+import { validateBoundingBox } from "./bounding-box.js";
+import type { GeojsonObject } from "./geojson-object.js";
+import { validateGeojsonObject } from "./geojson-object.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * A GeoJSON object with type "GeometryCollection" is a Geometry
+ * object. A GeometryCollection has a member with the name
+ * "geometries".  The value of "geometries" is an array.  Each element
+ * of this array is a GeoJSON Geometry object.  It is possible for this
+ * array to be empty.
+ * 
+ * Unlike the other geometry types described above, a
+ * GeometryCollection can be a heterogeneous composition of smaller
+ * Geometry objects.  For example, a Geometry object in the shape of a
+ * lowercase roman "i" can be composed of one point and one LineString.
+ * 
+ * GeometryCollections have a different syntax from single type
+ * Geometry objects (Point, LineString, and Polygon) and homogeneously
+ * typed multipart Geometry objects (MultiPoint, MultiLineString, and
+ * MultiPolygon) but have no different semantics.  Although a
+ * GeometryCollection object has no "coordinates" member, it does have
+ * coordinates: the coordinates of all its parts belong to the
+ * collection.  The "geometries" member of a GeometryCollection
+ * describes the parts of this composition.  Implementations SHOULD NOT
+ * apply any additional semantics to the "geometries" array.
+ * 
+ * To maximize interoperability, implementations SHOULD avoid nested
+ * GeometryCollections.  Furthermore, GeometryCollections composed of a
+ * single part or a number of parts of a single type SHOULD be avoided
+ * when that single part or a single object of multipart type
+ * (MultiPoint, MultiLineString, or MultiPolygon) could be used
+ * instead.
+ */
+export interface GeojsonObjectGeometryCollection {
+  type: "GeometryCollection";
+  geometries: GeojsonObject[];
+  bbox?: BoundingBox;
+}
+
+export function validateGeojsonObjectGeometryCollection(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "GeometryCollection") {
+    return [path + "/type", "expected \"GeometryCollection\""];
+  }
+
+  if (!hasProperty(x, "geometries")) {
+    return [path, "missing property \"geometries\""];
+  }
+
+  return (
+    checkArray(x["geometries"], path + "/geometries", validateGeojsonObject) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectGeometryCollection(x: unknown): x is GeojsonObjectGeometryCollection {
+  return validateGeojsonObjectGeometryCollection(x, "") === undefined;
+}
+
+export function parseGeojsonObjectGeometryCollection(x: unknown): GeojsonObjectGeometryCollection {
+  const violation = validateGeojsonObjectGeometryCollection(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectGeometryCollection;
+}
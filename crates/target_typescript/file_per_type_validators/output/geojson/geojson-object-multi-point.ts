@@ -0,0 +1,50 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box.js";
+import { validateBoundingBox } from "./bounding-box.js";
+import type { Position } from "./position.js";
+import { validatePosition } from "./position.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * For type "MultiPoint", the "coordinates" member is an array of
+ * positions.
+ */
+export interface GeojsonObjectMultiPoint {
+  type: "MultiPoint";
+  coordinates: Position[];
+  bbox?: BoundingBox;
+}
+
+export function validateGeojsonObjectMultiPoint(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "MultiPoint") {
+    return [path + "/type", "expected \"MultiPoint\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    checkArray(x["coordinates"], path + "/coordinates", validatePosition) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectMultiPoint(x: unknown): x is GeojsonObjectMultiPoint {
+  return validateGeojsonObjectMultiPoint(x, "") === undefined;
+}
+
+export function parseGeojsonObjectMultiPoint(x: unknown): GeojsonObjectMultiPoint {
+  const violation = validateGeojsonObjectMultiPoint(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectMultiPoint;
+}
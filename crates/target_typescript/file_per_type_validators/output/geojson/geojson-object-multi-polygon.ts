@@ -0,0 +1,50 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box.js";
+import { validateBoundingBox } from "./bounding-box.js";
+import type { LinearRing } from "./linear-ring.js";
+import { validateLinearRing } from "./linear-ring.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * For type "MultiPolygon", the "coordinates" member is an array of
+ * Polygon coordinate arrays.
+ */
+export interface GeojsonObjectMultiPolygon {
+  type: "MultiPolygon";
+  coordinates: LinearRing[];
+  bbox?: BoundingBox;
+}
+
+export function validateGeojsonObjectMultiPolygon(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "MultiPolygon") {
+    return [path + "/type", "expected \"MultiPolygon\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    checkArray(x["coordinates"], path + "/coordinates", validateLinearRing) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectMultiPolygon(x: unknown): x is GeojsonObjectMultiPolygon {
+  return validateGeojsonObjectMultiPolygon(x, "") === undefined;
+}
+
+export function parseGeojsonObjectMultiPolygon(x: unknown): GeojsonObjectMultiPolygon {
+  const violation = validateGeojsonObjectMultiPolygon(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectMultiPolygon;
+}
@@ -0,0 +1,49 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box.js";
+import { validateBoundingBox } from "./bounding-box.js";
+import type { Position } from "./position.js";
+import { validatePosition } from "./position.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * For type "Point", the "coordinates" member is a single position.
+ */
+export interface GeojsonObjectPoint {
+  type: "Point";
+  coordinates: Position;
+  bbox?: BoundingBox;
+}
+
+export function validateGeojsonObjectPoint(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "Point") {
+    return [path + "/type", "expected \"Point\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    validatePosition(x["coordinates"], path + "/coordinates") ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectPoint(x: unknown): x is GeojsonObjectPoint {
+  return validateGeojsonObjectPoint(x, "") === undefined;
+}
+
+export function parseGeojsonObjectPoint(x: unknown): GeojsonObjectPoint {
+  const violation = validateGeojsonObjectPoint(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectPoint;
+}
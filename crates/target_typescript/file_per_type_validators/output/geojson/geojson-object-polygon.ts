@@ -0,0 +1,50 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { BoundingBox } from "./bounding-box.js";
+import { validateBoundingBox } from "./bounding-box.js";
+import type { LinearRing } from "./linear-ring.js";
+import { validateLinearRing } from "./linear-ring.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * For type "Polygon", the "coordinates" member MUST be an array of
+ * linear ring coordinate arrays.
+ */
+export interface GeojsonObjectPolygon {
+  type: "Polygon";
+  coordinates: LinearRing[];
+  bbox?: BoundingBox;
+}
+
+export function validateGeojsonObjectPolygon(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "Polygon") {
+    return [path + "/type", "expected \"Polygon\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    checkArray(x["coordinates"], path + "/coordinates", validateLinearRing) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectPolygon(x: unknown): x is GeojsonObjectPolygon {
+  return validateGeojsonObjectPolygon(x, "") === undefined;
+}
+
+export function parseGeojsonObjectPolygon(x: unknown): GeojsonObjectPolygon {
+  const violation = validateGeojsonObjectPolygon(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectPolygon;
+}
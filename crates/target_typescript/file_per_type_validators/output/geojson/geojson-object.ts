@@ -0,0 +1,83 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { GeojsonObjectFeature } from "./geojson-object-feature.js";
+import { validateGeojsonObjectFeature } from "./geojson-object-feature.js";
+import type { GeojsonObjectFeatureCollection } from "./geojson-object-feature-collection.js";
+import { validateGeojsonObjectFeatureCollection } from "./geojson-object-feature-collection.js";
+import type { GeojsonObjectGeometryCollection } from "./geojson-object-geometry-collection.js";
+import { validateGeojsonObjectGeometryCollection } from "./geojson-object-geometry-collection.js";
+import type { GeojsonObjectLineString } from "./geojson-object-line-string.js";
+import { validateGeojsonObjectLineString } from "./geojson-object-line-string.js";
+import type { GeojsonObjectMultiLineString } from "./geojson-object-multi-line-string.js";
+import { validateGeojsonObjectMultiLineString } from "./geojson-object-multi-line-string.js";
+import type { GeojsonObjectMultiPoint } from "./geojson-object-multi-point.js";
+import { validateGeojsonObjectMultiPoint } from "./geojson-object-multi-point.js";
+import type { GeojsonObjectMultiPolygon } from "./geojson-object-multi-polygon.js";
+import { validateGeojsonObjectMultiPolygon } from "./geojson-object-multi-polygon.js";
+import type { GeojsonObjectPoint } from "./geojson-object-point.js";
+import { validateGeojsonObjectPoint } from "./geojson-object-point.js";
+import type { GeojsonObjectPolygon } from "./geojson-object-polygon.js";
+import { validateGeojsonObjectPolygon } from "./geojson-object-polygon.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, hasObjectType, hasProperty } from "./validation.js";
+
+/**
+ * A Geometry object represents points, curves, and surfaces in coordinate
+ * space.  Every Geometry object is a GeoJSON object no matter where it
+ * occurs in a GeoJSON text.
+ * 
+ * o  The value of a Geometry object's "type" member MUST be one of the
+ *     seven geometry types (see Section 1.4).
+ * 
+ * o  A GeoJSON Geometry object of any type other than
+ *     "GeometryCollection" has a member with the name "coordinates". The
+ *     value of the "coordinates" member is an array.  The structure of the
+ *     elements in this array is determined by the type of geometry.
+ *     GeoJSON processors MAY interpret Geometry objects with empty
+ *     "coordinates" arrays as null objects.
+ */
+export type GeojsonObject = GeojsonObjectFeature | GeojsonObjectFeatureCollection | GeojsonObjectGeometryCollection | GeojsonObjectLineString | GeojsonObjectMultiLineString | GeojsonObjectMultiPoint | GeojsonObjectMultiPolygon | GeojsonObjectPoint | GeojsonObjectPolygon;
+
+export function validateGeojsonObject(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "type") ? x["type"] : undefined) {
+    case "Feature":
+      return validateGeojsonObjectFeature(x, path);
+    case "FeatureCollection":
+      return validateGeojsonObjectFeatureCollection(x, path);
+    case "GeometryCollection":
+      return validateGeojsonObjectGeometryCollection(x, path);
+    case "LineString":
+      return validateGeojsonObjectLineString(x, path);
+    case "MultiLineString":
+      return validateGeojsonObjectMultiLineString(x, path);
+    case "MultiPoint":
+      return validateGeojsonObjectMultiPoint(x, path);
+    case "MultiPolygon":
+      return validateGeojsonObjectMultiPolygon(x, path);
+    case "Point":
+      return validateGeojsonObjectPoint(x, path);
+    case "Polygon":
+      return validateGeojsonObjectPolygon(x, path);
+    case undefined:
+      return [path, "missing property \"type\""];
+    default:
+      return [path + "/type", "unknown discriminator tag"];
+  }
+}
+
+export function isGeojsonObject(x: unknown): x is GeojsonObject {
+  return validateGeojsonObject(x, "") === undefined;
+}
+
+export function parseGeojsonObject(x: unknown): GeojsonObject {
+  const violation = validateGeojsonObject(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObject;
+}
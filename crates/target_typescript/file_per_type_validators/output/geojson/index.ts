@@ -0,0 +1,31 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { BoundingBox } from "./bounding-box.js";
+export { isBoundingBox, parseBoundingBox } from "./bounding-box.js";
+export type { GeojsonObject } from "./geojson-object.js";
+export { isGeojsonObject, parseGeojsonObject } from "./geojson-object.js";
+export type { GeojsonObjectFeature } from "./geojson-object-feature.js";
+export { isGeojsonObjectFeature, parseGeojsonObjectFeature } from "./geojson-object-feature.js";
+export type { GeojsonObjectFeatureCollection } from "./geojson-object-feature-collection.js";
+export { isGeojsonObjectFeatureCollection, parseGeojsonObjectFeatureCollection } from "./geojson-object-feature-collection.js";
+export type { GeojsonObjectGeometryCollection } from "./geojson-object-geometry-collection.js";
+export { isGeojsonObjectGeometryCollection, parseGeojsonObjectGeometryCollection } from "./geojson-object-geometry-collection.js";
+export type { GeojsonObjectLineString } from "./geojson-object-line-string.js";
+export { isGeojsonObjectLineString, parseGeojsonObjectLineString } from "./geojson-object-line-string.js";
+export type { GeojsonObjectMultiLineString } from "./geojson-object-multi-line-string.js";
+export { isGeojsonObjectMultiLineString, parseGeojsonObjectMultiLineString } from "./geojson-object-multi-line-string.js";
+export type { GeojsonObjectMultiPoint } from "./geojson-object-multi-point.js";
+export { isGeojsonObjectMultiPoint, parseGeojsonObjectMultiPoint } from "./geojson-object-multi-point.js";
+export type { GeojsonObjectMultiPolygon } from "./geojson-object-multi-polygon.js";
+export { isGeojsonObjectMultiPolygon, parseGeojsonObjectMultiPolygon } from "./geojson-object-multi-polygon.js";
+export type { GeojsonObjectPoint } from "./geojson-object-point.js";
+export { isGeojsonObjectPoint, parseGeojsonObjectPoint } from "./geojson-object-point.js";
+export type { GeojsonObjectPolygon } from "./geojson-object-polygon.js";
+export { isGeojsonObjectPolygon, parseGeojsonObjectPolygon } from "./geojson-object-polygon.js";
+export type { LinearRing } from "./linear-ring.js";
+export { isLinearRing, parseLinearRing } from "./linear-ring.js";
+export type { Position } from "./position.js";
+export { isPosition, parsePosition } from "./position.js";
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export { ValidationError } from "./validation.js";
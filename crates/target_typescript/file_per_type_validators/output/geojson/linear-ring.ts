@@ -0,0 +1,54 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Position } from "./position.js";
+import { validatePosition } from "./position.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray } from "./validation.js";
+
+/**
+ * To specify a constraint specific to Polygons, it is useful to
+ * introduce the concept of a linear ring:
+ * 
+ * o  A linear ring is a closed LineString with four or more positions.
+ * 
+ * o  The first and last positions are equivalent, and they MUST contain
+ *     identical values; their representation SHOULD also be identical.
+ * 
+ * o  A linear ring is the boundary of a surface or the boundary of a
+ *     hole in a surface.
+ * 
+ * o  A linear ring MUST follow the right-hand rule with respect to the
+ *     area it bounds, i.e., exterior rings are counterclockwise, and holes
+ *     are clockwise.
+ * 
+ * Note: the [GJ2008] specification did not discuss linear ring winding
+ * order.  For backwards compatibility, parsers SHOULD NOT reject Polygons
+ * that do not follow the right-hand rule.
+ * 
+ * Though a linear ring is not explicitly represented as a GeoJSON geometry
+ * type, it leads to a canonical formulation of the Polygon geometry type
+ * definition as follows:
+ * 
+ * For Polygons with more than one of these rings, the first MUST be the
+ * exterior ring, and any others MUST be interior rings.  The exterior ring
+ * bounds the surface, and the interior rings (if present) bound holes
+ * within the surface.
+ */
+export type LinearRing = Position[];
+
+export function validateLinearRing(x: unknown, path: string): Violation | undefined {
+  return checkArray(x, path, validatePosition);
+}
+
+export function isLinearRing(x: unknown): x is LinearRing {
+  return validateLinearRing(x, "") === undefined;
+}
+
+export function parseLinearRing(x: unknown): LinearRing {
+  const violation = validateLinearRing(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as LinearRing;
+}
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export type { RootNestedIdInitialism } from "./root-nested-id-initialism.js";
+export { isRootNestedIdInitialism, parseRootNestedIdInitialism } from "./root-nested-id-initialism.js";
+export { ValidationError } from "./validation.js";
@@ -0,0 +1,51 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface RootNestedIdInitialism {
+  json: string;
+  normalword: string;
+}
+
+export function validateRootNestedIdInitialism(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "json")) {
+    return [path, "missing property \"json\""];
+  }
+
+  if (!hasProperty(x, "normalword")) {
+    return [path, "missing property \"normalword\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "json":
+      case "normalword":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkString(x["json"], path + "/json") ||
+    checkString(x["normalword"], path + "/normalword")
+  );
+}
+
+export function isRootNestedIdInitialism(x: unknown): x is RootNestedIdInitialism {
+  return validateRootNestedIdInitialism(x, "") === undefined;
+}
+
+export function parseRootNestedIdInitialism(x: unknown): RootNestedIdInitialism {
+  const violation = validateRootNestedIdInitialism(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootNestedIdInitialism;
+}
@@ -0,0 +1,81 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootNestedIdInitialism } from "./root-nested-id-initialism.js";
+import { validateRootNestedIdInitialism } from "./root-nested-id-initialism.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  http: string;
+  id: string;
+  nested_id_initialism: RootNestedIdInitialism;
+  utf8: string;
+  word_with_embedded_id_initialism: string;
+  word_with_trailing_initialism_id: string;
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "http")) {
+    return [path, "missing property \"http\""];
+  }
+
+  if (!hasProperty(x, "id")) {
+    return [path, "missing property \"id\""];
+  }
+
+  if (!hasProperty(x, "nested_id_initialism")) {
+    return [path, "missing property \"nested_id_initialism\""];
+  }
+
+  if (!hasProperty(x, "utf8")) {
+    return [path, "missing property \"utf8\""];
+  }
+
+  if (!hasProperty(x, "word_with_embedded_id_initialism")) {
+    return [path, "missing property \"word_with_embedded_id_initialism\""];
+  }
+
+  if (!hasProperty(x, "word_with_trailing_initialism_id")) {
+    return [path, "missing property \"word_with_trailing_initialism_id\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "http":
+      case "id":
+      case "nested_id_initialism":
+      case "utf8":
+      case "word_with_embedded_id_initialism":
+      case "word_with_trailing_initialism_id":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkString(x["http"], path + "/http") ||
+    checkString(x["id"], path + "/id") ||
+    validateRootNestedIdInitialism(x["nested_id_initialism"], path + "/nested_id_initialism") ||
+    checkString(x["utf8"], path + "/utf8") ||
+    checkString(x["word_with_embedded_id_initialism"], path + "/word_with_embedded_id_initialism") ||
+    checkString(x["word_with_trailing_initialism_id"], path + "/word_with_trailing_initialism_id")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
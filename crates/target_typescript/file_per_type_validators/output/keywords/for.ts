@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type For = string;
+
+export function validateFor(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFor(x: unknown): x is For {
+  return validateFor(x, "") === undefined;
+}
+
+export function parseFor(x: unknown): For {
+  const violation = validateFor(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as For;
+}
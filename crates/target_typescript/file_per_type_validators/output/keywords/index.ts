@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { For } from "./for.js";
+export { isFor, parseFor } from "./for.js";
+export type { Object } from "./object.js";
+export { isObject, parseObject } from "./object.js";
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export { ValidationError } from "./validation.js";
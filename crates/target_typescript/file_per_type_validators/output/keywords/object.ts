@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type Object = string;
+
+export function validateObject(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isObject(x: unknown): x is Object {
+  return validateObject(x, "") === undefined;
+}
+
+export function parseObject(x: unknown): Object {
+  const violation = validateObject(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Object;
+}
@@ -0,0 +1,55 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { For } from "./for.js";
+import { validateFor } from "./for.js";
+import type { Object } from "./object.js";
+import { validateObject } from "./object.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  for: For;
+  object: Object;
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "for")) {
+    return [path, "missing property \"for\""];
+  }
+
+  if (!hasProperty(x, "object")) {
+    return [path, "missing property \"object\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "for":
+      case "object":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    validateFor(x["for"], path + "/for") ||
+    validateObject(x["object"], path + "/object")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
@@ -0,0 +1,48 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface RootQuux {
+  foo: "quux";
+  quuz: string;
+}
+
+export function validateRootQuux(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "quux") {
+    return [path + "/foo", "expected \"quux\""];
+  }
+
+  if (!hasProperty(x, "quuz")) {
+    return [path, "missing property \"quuz\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "quuz":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkString(x["quuz"], path + "/quuz");
+}
+
+export function isRootQuux(x: unknown): x is RootQuux {
+  return validateRootQuux(x, "") === undefined;
+}
+
+export function parseRootQuux(x: unknown): RootQuux {
+  const violation = validateRootQuux(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootQuux;
+}
@@ -0,0 +1,40 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootBar } from "./root-bar.js";
+import { validateRootBar } from "./root-bar.js";
+import type { RootQuux } from "./root-quux.js";
+import { validateRootQuux } from "./root-quux.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, hasObjectType, hasProperty } from "./validation.js";
+
+export type Root0 = RootBar | RootQuux;
+
+export function validateRoot0(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "foo") ? x["foo"] : undefined) {
+    case "bar":
+      return validateRootBar(x, path);
+    case "quux":
+      return validateRootQuux(x, path);
+    case undefined:
+      return [path, "missing property \"foo\""];
+    default:
+      return [path + "/foo", "unknown discriminator tag"];
+  }
+}
+
+export function isRoot0(x: unknown): x is Root0 {
+  return validateRoot0(x, "") === undefined;
+}
+
+export function parseRoot0(x: unknown): Root0 {
+  const violation = validateRoot0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root0;
+}
@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export { Root0, isRoot0, parseRoot0 } from "./root0.js";
+export { ValidationError } from "./validation.js";
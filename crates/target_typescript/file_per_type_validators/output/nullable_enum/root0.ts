@@ -0,0 +1,34 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export enum Root0 {
+  Bar = "Bar",
+  Baz = "Baz",
+  Foo = "Foo",
+}
+
+export function validateRoot0(x: unknown, path: string): Violation | undefined {
+  switch (x) {
+    case "Bar":
+    case "Baz":
+    case "Foo":
+      return undefined;
+    default:
+      return [path, "unknown enum value"];
+  }
+}
+
+export function isRoot0(x: unknown): x is Root0 {
+  return validateRoot0(x, "") === undefined;
+}
+
+export function parseRoot0(x: unknown): Root0 {
+  const violation = validateRoot0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root0;
+}
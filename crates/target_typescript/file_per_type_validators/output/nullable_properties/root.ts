@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Root0 } from "./root0.js";
+import { validateRoot0 } from "./root0.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type Root = (Root0 | null);
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  return (x === null ? undefined : validateRoot0(x, path));
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
@@ -0,0 +1,17 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { NotnullRefNotnullString } from "./notnull-ref-notnull-string.js";
+export { isNotnullRefNotnullString, parseNotnullRefNotnullString } from "./notnull-ref-notnull-string.js";
+export type { NotnullRefNullString } from "./notnull-ref-null-string.js";
+export { isNotnullRefNullString, parseNotnullRefNullString } from "./notnull-ref-null-string.js";
+export type { NotnullString } from "./notnull-string.js";
+export { isNotnullString, parseNotnullString } from "./notnull-string.js";
+export type { NullRefNotnullString } from "./null-ref-notnull-string.js";
+export { isNullRefNotnullString, parseNullRefNotnullString } from "./null-ref-notnull-string.js";
+export type { NullRefNullString } from "./null-ref-null-string.js";
+export { isNullRefNullString, parseNullRefNullString } from "./null-ref-null-string.js";
+export type { NullString } from "./null-string.js";
+export { isNullString, parseNullString } from "./null-string.js";
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export { ValidationError } from "./validation.js";
@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { NotnullString } from "./notnull-string.js";
+import { validateNotnullString } from "./notnull-string.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type NotnullRefNotnullString = NotnullString;
+
+export function validateNotnullRefNotnullString(x: unknown, path: string): Violation | undefined {
+  return validateNotnullString(x, path);
+}
+
+export function isNotnullRefNotnullString(x: unknown): x is NotnullRefNotnullString {
+  return validateNotnullRefNotnullString(x, "") === undefined;
+}
+
+export function parseNotnullRefNotnullString(x: unknown): NotnullRefNotnullString {
+  const violation = validateNotnullRefNotnullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NotnullRefNotnullString;
+}
@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { NullString } from "./null-string.js";
+import { validateNullString } from "./null-string.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type NotnullRefNullString = NullString;
+
+export function validateNotnullRefNullString(x: unknown, path: string): Violation | undefined {
+  return validateNullString(x, path);
+}
+
+export function isNotnullRefNullString(x: unknown): x is NotnullRefNullString {
+  return validateNotnullRefNullString(x, "") === undefined;
+}
+
+export function parseNotnullRefNullString(x: unknown): NotnullRefNullString {
+  const violation = validateNotnullRefNullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NotnullRefNullString;
+}
@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type NotnullString = string;
+
+export function validateNotnullString(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isNotnullString(x: unknown): x is NotnullString {
+  return validateNotnullString(x, "") === undefined;
+}
+
+export function parseNotnullString(x: unknown): NotnullString {
+  const violation = validateNotnullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NotnullString;
+}
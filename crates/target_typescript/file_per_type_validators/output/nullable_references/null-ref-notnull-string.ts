@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { NotnullString } from "./notnull-string.js";
+import { validateNotnullString } from "./notnull-string.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type NullRefNotnullString = (NotnullString | null);
+
+export function validateNullRefNotnullString(x: unknown, path: string): Violation | undefined {
+  return (x === null ? undefined : validateNotnullString(x, path));
+}
+
+export function isNullRefNotnullString(x: unknown): x is NullRefNotnullString {
+  return validateNullRefNotnullString(x, "") === undefined;
+}
+
+export function parseNullRefNotnullString(x: unknown): NullRefNotnullString {
+  const violation = validateNullRefNotnullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NullRefNotnullString;
+}
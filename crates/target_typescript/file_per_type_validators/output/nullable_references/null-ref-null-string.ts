@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { NullString } from "./null-string.js";
+import { validateNullString } from "./null-string.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type NullRefNullString = (NullString | null);
+
+export function validateNullRefNullString(x: unknown, path: string): Violation | undefined {
+  return (x === null ? undefined : validateNullString(x, path));
+}
+
+export function isNullRefNullString(x: unknown): x is NullRefNullString {
+  return validateNullRefNullString(x, "") === undefined;
+}
+
+export function parseNullRefNullString(x: unknown): NullRefNullString {
+  const violation = validateNullRefNullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NullRefNullString;
+}
@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type NullString = (string | null);
+
+export function validateNullString(x: unknown, path: string): Violation | undefined {
+  return (x === null ? undefined : checkString(x, path));
+}
+
+export function isNullString(x: unknown): x is NullString {
+  return validateNullString(x, "") === undefined;
+}
+
+export function parseNullString(x: unknown): NullString {
+  const violation = validateNullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NullString;
+}
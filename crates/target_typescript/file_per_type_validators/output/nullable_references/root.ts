@@ -0,0 +1,91 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { NotnullRefNotnullString } from "./notnull-ref-notnull-string.js";
+import { validateNotnullRefNotnullString } from "./notnull-ref-notnull-string.js";
+import type { NotnullRefNullString } from "./notnull-ref-null-string.js";
+import { validateNotnullRefNullString } from "./notnull-ref-null-string.js";
+import type { NotnullString } from "./notnull-string.js";
+import { validateNotnullString } from "./notnull-string.js";
+import type { NullRefNotnullString } from "./null-ref-notnull-string.js";
+import { validateNullRefNotnullString } from "./null-ref-notnull-string.js";
+import type { NullRefNullString } from "./null-ref-null-string.js";
+import { validateNullRefNullString } from "./null-ref-null-string.js";
+import type { NullString } from "./null-string.js";
+import { validateNullString } from "./null-string.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  notnull_ref_notnull_string: NotnullRefNotnullString;
+  notnull_ref_null_string: NotnullRefNullString;
+  notnull_string: NotnullString;
+  null_ref_notnull_string: NullRefNotnullString;
+  null_ref_null_string: NullRefNullString;
+  null_string: NullString;
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "notnull_ref_notnull_string")) {
+    return [path, "missing property \"notnull_ref_notnull_string\""];
+  }
+
+  if (!hasProperty(x, "notnull_ref_null_string")) {
+    return [path, "missing property \"notnull_ref_null_string\""];
+  }
+
+  if (!hasProperty(x, "notnull_string")) {
+    return [path, "missing property \"notnull_string\""];
+  }
+
+  if (!hasProperty(x, "null_ref_notnull_string")) {
+    return [path, "missing property \"null_ref_notnull_string\""];
+  }
+
+  if (!hasProperty(x, "null_ref_null_string")) {
+    return [path, "missing property \"null_ref_null_string\""];
+  }
+
+  if (!hasProperty(x, "null_string")) {
+    return [path, "missing property \"null_string\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "notnull_ref_notnull_string":
+      case "notnull_ref_null_string":
+      case "notnull_string":
+      case "null_ref_notnull_string":
+      case "null_ref_null_string":
+      case "null_string":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    validateNotnullRefNotnullString(x["notnull_ref_notnull_string"], path + "/notnull_ref_notnull_string") ||
+    validateNotnullRefNullString(x["notnull_ref_null_string"], path + "/notnull_ref_null_string") ||
+    validateNotnullString(x["notnull_string"], path + "/notnull_string") ||
+    validateNullRefNotnullString(x["null_ref_notnull_string"], path + "/null_ref_notnull_string") ||
+    validateNullRefNullString(x["null_ref_null_string"], path + "/null_ref_null_string") ||
+    validateNullString(x["null_string"], path + "/null_string")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
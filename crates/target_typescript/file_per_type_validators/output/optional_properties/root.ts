@@ -0,0 +1,46 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkArray, checkBoolean, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  bar?: string[];
+  baz?: boolean;
+  foo?: string;
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "bar":
+      case "baz":
+      case "foo":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    (hasProperty(x, "bar") ? checkArray(x["bar"], path + "/bar", checkString) : undefined) ||
+    (hasProperty(x, "baz") ? checkBoolean(x["baz"], path + "/baz") : undefined) ||
+    (hasProperty(x, "foo") ? checkString(x["foo"], path + "/foo") : undefined)
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
@@ -0,0 +1,51 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  Foo: string;
+  foo: string;
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "Foo")) {
+    return [path, "missing property \"Foo\""];
+  }
+
+  if (!hasProperty(x, "foo")) {
+    return [path, "missing property \"foo\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "Foo":
+      case "foo":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkString(x["Foo"], path + "/Foo") ||
+    checkString(x["foo"], path + "/foo")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
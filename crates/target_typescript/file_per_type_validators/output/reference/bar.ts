@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Baz } from "./baz.js";
+import { validateBaz } from "./baz.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type Bar = Baz;
+
+export function validateBar(x: unknown, path: string): Violation | undefined {
+  return validateBaz(x, path);
+}
+
+export function isBar(x: unknown): x is Bar {
+  return validateBar(x, "") === undefined;
+}
+
+export function parseBar(x: unknown): Bar {
+  const violation = validateBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Bar;
+}
@@ -0,0 +1,23 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString } from "./validation.js";
+
+export type Baz = string;
+
+export function validateBaz(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isBaz(x: unknown): x is Baz {
+  return validateBaz(x, "") === undefined;
+}
+
+export function parseBaz(x: unknown): Baz {
+  const violation = validateBaz(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Baz;
+}
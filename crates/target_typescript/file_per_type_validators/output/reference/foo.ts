@@ -0,0 +1,25 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Bar } from "./bar.js";
+import { validateBar } from "./bar.js";
+import type { Violation } from "./validation.js";
+import { ValidationError } from "./validation.js";
+
+export type Foo = Bar;
+
+export function validateFoo(x: unknown, path: string): Violation | undefined {
+  return validateBar(x, path);
+}
+
+export function isFoo(x: unknown): x is Foo {
+  return validateFoo(x, "") === undefined;
+}
+
+export function parseFoo(x: unknown): Foo {
+  const violation = validateFoo(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo;
+}
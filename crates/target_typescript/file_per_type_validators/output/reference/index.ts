@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type { Bar } from "./bar.js";
+export { isBar, parseBar } from "./bar.js";
+export type { Baz } from "./baz.js";
+export { isBaz, parseBaz } from "./baz.js";
+export type { Foo } from "./foo.js";
+export { isFoo, parseFoo } from "./foo.js";
+export type { Root } from "./root.js";
+export { isRoot, parseRoot } from "./root.js";
+export { ValidationError } from "./validation.js";
@@ -0,0 +1,123 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkBoolean(x: unknown, path: string): Violation | undefined {
+  return typeof x === "boolean" ? undefined : [path, "expected boolean"];
+}
+
+function checkInteger(x: unknown, path: string, min: number, max: number): Violation | undefined {
+  if (typeof x === "number" && Math.floor(x) === x && x >= min && x <= max) {
+    return undefined;
+  }
+
+  return [path, "expected integer between " + min + " and " + max];
+}
+
+function checkNumber(x: unknown, path: string): Violation | undefined {
+  return typeof x === "number" ? undefined : [path, "expected number"];
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
+
+function checkTimestamp(x: unknown, path: string): Violation | undefined {
+  const match = typeof x === "string" &&
+    /^(\d{4})-(\d{2})-(\d{2})[tT](\d{2}):(\d{2}):(\d{2})(\.\d+)?([zZ]|[+-](\d{2}):(\d{2}))$/.exec(x);
+
+  if (match) {
+    const [year, month, day, hour, minute, second] = match.slice(1, 7).map(Number);
+    const leap = year % 4 === 0 && (year % 100 !== 0 || year % 400 === 0);
+    const days = [31, leap ? 29 : 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31][month - 1];
+
+    // RFC 3339 permits leap seconds, so a second of 60 is allowed.
+    if (
+      day >= 1 && day <= days && hour <= 23 && minute <= 59 && second <= 60 &&
+      (match[9] === undefined || (Number(match[9]) <= 23 && Number(match[10]) <= 59))
+    ) {
+      return undefined;
+    }
+  }
+
+  return [path, "expected timestamp"];
+}
+
+function checkDate(x: unknown, path: string): Violation | undefined {
+  const violation = checkTimestamp(x, path);
+  if (violation === undefined && /:60(\.\d+)?([zZ]|[+-]\d{2}:\d{2})$/.test(x as string)) {
+    return [path, "leap seconds can't be represented as dates"];
+  }
+
+  return violation;
+}
+
+function checkArray(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!Array.isArray(x)) {
+    return [path, "expected array"];
+  }
+
+  for (let i = 0; i < x.length; i++) {
+    const violation = check(x[i], path + "/" + i);
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}
+
+function checkDict(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  for (const key of Object.keys(x)) {
+    const violation = check(x[key], path + "/" + escapePointer(key));
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}
+
+export type { Violation };
+export { hasObjectType, hasProperty, escapePointer, checkBoolean, checkInteger, checkNumber, checkString, checkTimestamp, checkDate, checkArray, checkDict };
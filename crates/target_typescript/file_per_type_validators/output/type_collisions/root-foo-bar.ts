@@ -0,0 +1,42 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkBoolean, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface RootFooBar {
+  x: boolean;
+}
+
+export function validateRootFooBar(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "x")) {
+    return [path, "missing property \"x\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "x":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkBoolean(x["x"], path + "/x");
+}
+
+export function isRootFooBar(x: unknown): x is RootFooBar {
+  return validateRootFooBar(x, "") === undefined;
+}
+
+export function parseRootFooBar(x: unknown): RootFooBar {
+  const violation = validateRootFooBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootFooBar;
+}
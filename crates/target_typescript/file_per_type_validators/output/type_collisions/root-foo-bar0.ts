@@ -0,0 +1,42 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { Violation } from "./validation.js";
+import { ValidationError, checkString, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface RootFooBar0 {
+  x: string;
+}
+
+export function validateRootFooBar0(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "x")) {
+    return [path, "missing property \"x\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "x":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkString(x["x"], path + "/x");
+}
+
+export function isRootFooBar0(x: unknown): x is RootFooBar0 {
+  return validateRootFooBar0(x, "") === undefined;
+}
+
+export function parseRootFooBar0(x: unknown): RootFooBar0 {
+  const violation = validateRootFooBar0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootFooBar0;
+}
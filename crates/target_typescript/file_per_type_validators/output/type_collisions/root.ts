@@ -0,0 +1,55 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+import type { RootFoo } from "./root-foo.js";
+import { validateRootFoo } from "./root-foo.js";
+import type { RootFooBar0 } from "./root-foo-bar0.js";
+import { validateRootFooBar0 } from "./root-foo-bar0.js";
+import type { Violation } from "./validation.js";
+import { ValidationError, escapePointer, hasObjectType, hasProperty } from "./validation.js";
+
+export interface Root {
+  foo: RootFoo;
+  foo_bar: RootFooBar0;
+}
+
+export function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo")) {
+    return [path, "missing property \"foo\""];
+  }
+
+  if (!hasProperty(x, "foo_bar")) {
+    return [path, "missing property \"foo_bar\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "foo_bar":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    validateRootFoo(x["foo"], path + "/foo") ||
+    validateRootFooBar0(x["foo_bar"], path + "/foo_bar")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = RootBarBaz | RootQuux;
+
+export interface RootBarBaz {
+  readonly foo: "BAR_BAZ";
+  readonly baz: string;
+}
+
+export interface RootQuux {
+  readonly foo: "QUUX";
+  readonly quuz: string;
+}
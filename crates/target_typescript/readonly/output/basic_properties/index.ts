@@ -0,0 +1,8 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  readonly bar: string;
+  readonly baz: ReadonlyArray<boolean>;
+  readonly foo: boolean;
+  readonly quux: ReadonlyArray<boolean>;
+}
@@ -0,0 +1,14 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface RootOverrideTypeDiscriminatorBaz {
+  readonly foo: "baz";
+}
+
+export interface Root {
+  readonly override_elements_container: ReadonlyArray<string>;
+  readonly override_type_discriminator: any;
+  readonly override_type_enum: any;
+  readonly override_type_expr: any;
+  readonly override_type_properties: any;
+  readonly override_values_container: Readonly<Record<string, string>>;
+}
@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = RootBar;
+
+export interface RootBar {
+  readonly foo: "bar";
+  readonly baz?: ReadonlyArray<string>;
+  readonly quux?: boolean;
+}
@@ -0,0 +1,20 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export enum RootFooBar {
+  X = "x",
+  Y = "y",
+}
+
+export interface RootFoo {
+  readonly bar: RootFooBar;
+}
+
+export enum RootFooBar0 {
+  X = "x",
+  Y = "y",
+}
+
+export interface Root {
+  readonly foo: RootFoo;
+  readonly foo_bar: RootFooBar0;
+}
@@ -0,0 +1,15 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface RootNestedIdInitialism {
+  readonly json: string;
+  readonly normalword: string;
+}
+
+export interface Root {
+  readonly http: string;
+  readonly id: string;
+  readonly nested_id_initialism: RootNestedIdInitialism;
+  readonly utf8: string;
+  readonly word_with_embedded_id_initialism: string;
+  readonly word_with_trailing_initialism_id: string;
+}
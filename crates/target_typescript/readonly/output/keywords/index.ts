@@ -0,0 +1,10 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  readonly for: For;
+  readonly object: Object;
+}
+
+export type For = string;
+
+export type Object = string;
@@ -0,0 +1,15 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root0 = RootBar | RootQuux;
+
+export interface RootBar {
+  readonly foo: "bar";
+  readonly baz: string;
+}
+
+export interface RootQuux {
+  readonly foo: "quux";
+  readonly quuz: string;
+}
+
+export type Root = (Root0 | null);
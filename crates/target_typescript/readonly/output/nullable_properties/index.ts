@@ -0,0 +1,10 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root0 {
+  readonly bar: string;
+  readonly baz: ReadonlyArray<boolean>;
+  readonly foo: boolean;
+  readonly quux: ReadonlyArray<boolean>;
+}
+
+export type Root = (Root0 | null);
@@ -0,0 +1,22 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  readonly notnull_ref_notnull_string: NotnullRefNotnullString;
+  readonly notnull_ref_null_string: NotnullRefNullString;
+  readonly notnull_string: NotnullString;
+  readonly null_ref_notnull_string: NullRefNotnullString;
+  readonly null_ref_null_string: NullRefNullString;
+  readonly null_string: NullString;
+}
+
+export type NotnullRefNotnullString = NotnullString;
+
+export type NotnullRefNullString = NullString;
+
+export type NotnullString = string;
+
+export type NullRefNotnullString = (NotnullString | null);
+
+export type NullRefNullString = (NullString | null);
+
+export type NullString = (string | null);
@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  readonly bar?: ReadonlyArray<string>;
+  readonly baz?: boolean;
+  readonly foo?: string;
+}
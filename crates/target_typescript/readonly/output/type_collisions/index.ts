@@ -0,0 +1,18 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface RootFooBar {
+  readonly x: boolean;
+}
+
+export interface RootFoo {
+  readonly bar: RootFooBar;
+}
+
+export interface RootFooBar0 {
+  readonly x: string;
+}
+
+export interface Root {
+  readonly foo: RootFoo;
+  readonly foo_bar: RootFooBar0;
+}
@@ -0,0 +1,188 @@
+// Generates the fromJSON and toJSON functions that Target::with_dates enables.
+//
+// In that mode, timestamps are Dates rather than strings, so values of
+// generated types no longer map directly to their JSON representation. For
+// each named type Foo that contains timestamps, fromJSONFoo converts Foo's
+// JSON representation into a Foo, and toJSONFoo does the opposite. Neither
+// modifies its input. Types without timestamps need no converting, so they
+// don't get these functions.
+//
+// These functions walk the same structure that validators check, so they're
+// generated from the same description of each type.
+
+use crate::validators::{Check, Property, Validator};
+use std::io::Write;
+
+#[derive(Clone, Copy)]
+enum Direction {
+    FromJSON,
+    ToJSON,
+}
+
+impl Direction {
+    fn prefix(self) -> &'static str {
+        match self {
+            Direction::FromJSON => "fromJSON",
+            Direction::ToJSON => "toJSON",
+        }
+    }
+}
+
+/// Writes out the fromJSON and toJSON functions for the type `name`. If the
+/// type is a brand, converted values are branded with its constructor.
+pub fn write(
+    out: &mut dyn Write,
+    name: &str,
+    validator: &Validator,
+    branded: bool,
+) -> std::io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "export function fromJSON{}(x: any): {} {{", name, name)?;
+    match validator {
+        Validator::Alias(check) if branded => {
+            let x = convert(check, Direction::FromJSON, "x", 1);
+            match x {
+                Some(x) => writeln!(out, "  return {}({});", name, x)?,
+                None => writeln!(out, "  return x;")?,
+            }
+        }
+        _ => write_body(out, validator, Direction::FromJSON)?,
+    }
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "export function toJSON{}(x: {}): any {{", name, name)?;
+    write_body(out, validator, Direction::ToJSON)?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+fn write_body(
+    out: &mut dyn Write,
+    validator: &Validator,
+    direction: Direction,
+) -> std::io::Result<()> {
+    match validator {
+        Validator::Alias(check) => {
+            let x = convert(check, direction, "x", 1);
+            writeln!(out, "  return {};", x.as_deref().unwrap_or("x"))?;
+        }
+
+        Validator::Enum(_) => {
+            writeln!(out, "  return x;")?;
+        }
+
+        Validator::Struct { properties, .. } => {
+            write_struct(out, properties, direction)?;
+        }
+
+        Validator::Discriminator {
+            tag_json_name,
+            variants,
+        } => {
+            writeln!(out, "  switch ({}) {{", property("x", tag_json_name))?;
+            for (tag_value, check) in variants {
+                let x = convert(check, direction, "x", 1);
+                writeln!(out, "    case {:?}:", tag_value)?;
+                writeln!(out, "      return {};", x.as_deref().unwrap_or("x"))?;
+            }
+            writeln!(out, "    default:")?;
+            writeln!(out, "      return x;")?;
+            writeln!(out, "  }}")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_struct(
+    out: &mut dyn Write,
+    properties: &[Property],
+    direction: Direction,
+) -> std::io::Result<()> {
+    let conversions: Vec<_> = properties
+        .iter()
+        .filter_map(|p| {
+            let x = property("x", &p.json_name);
+            convert(&p.check, direction, &x, 1).map(|converted| (p, x, converted))
+        })
+        .collect();
+
+    if conversions.is_empty() {
+        writeln!(out, "  return x;")?;
+        return Ok(());
+    }
+
+    // Copying x keeps any properties that don't need converting, including
+    // additional ones.
+    writeln!(out, "  const y: any = {{ ...x }};")?;
+    for (p, x, converted) in conversions {
+        let y = property("y", &p.json_name);
+        if p.optional {
+            writeln!(out, "  if ({} !== undefined) {{", x)?;
+            writeln!(out, "    {} = {};", y, converted)?;
+            writeln!(out, "  }}")?;
+        } else {
+            writeln!(out, "  {} = {};", y, converted)?;
+        }
+    }
+    writeln!(out, "  return y;")?;
+
+    Ok(())
+}
+
+// An expression converting the value of `x`, or None if it needs no
+// converting. Variables introduced by the expression are suffixed with
+// `depth`, so that they don't shadow those that `x` refers to.
+fn convert(check: &Check, direction: Direction, x: &str, depth: usize) -> Option<String> {
+    match check {
+        Check::Any
+        | Check::Boolean
+        | Check::Integer(..)
+        | Check::Number
+        | Check::String
+        | Check::Timestamp
+        | Check::Named(_, false) => None,
+
+        Check::Date => Some(match direction {
+            Direction::FromJSON => format!("new Date({})", x),
+            Direction::ToJSON => format!("{}.toISOString()", x),
+        }),
+
+        Check::Array(check) => {
+            let v = format!("v{}", depth);
+            let converted = convert(check, direction, &v, depth + 1)?;
+            Some(format!("{}.map(({}: any) => {})", x, v, converted))
+        }
+
+        Check::Dict(check) => {
+            let (k, y) = (format!("k{}", depth), format!("y{}", depth));
+            let converted = convert(check, direction, &format!("{}[{}]", x, k), depth + 1)?;
+            Some(format!(
+                "Object.keys({x}).reduce(({y}: any, {k}) => {{ {y}[{k}] = {c}; return {y}; }}, {{}})",
+                x = x,
+                y = y,
+                k = k,
+                c = converted
+            ))
+        }
+
+        Check::Nullable(check) => {
+            let converted = convert(check, direction, x, depth)?;
+            Some(format!("{} === null ? null : {}", x, converted))
+        }
+
+        Check::Named(name, true) => Some(format!("{}{}({})", direction.prefix(), name, x)),
+    }
+}
+
+// An expression for a property of the value of `x`.
+fn property(x: &str, json_name: &str) -> String {
+    let key = crate::format_property(json_name.to_owned());
+    if key.starts_with('"') {
+        format!("{}[{}]", x, key)
+    } else {
+        format!("{}.{}", x, key)
+    }
+}
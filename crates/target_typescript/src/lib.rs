@@ -1,10 +1,16 @@
+mod conversions;
+mod validators;
+
 use jtd_codegen::target::{self, inflect, metadata};
-use jtd_codegen::Result;
+use jtd_codegen::{Error, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde_json::Value;
+use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
 use std::io::Write;
+use validators::{Check, Property, Validator};
 
 lazy_static! {
     static ref KEYWORDS: BTreeSet<String> = include_str!("keywords")
@@ -23,20 +29,337 @@ lazy_static! {
         ));
 }
 
-pub struct Target {}
+// Marks a type name that an item's typescriptType overrode, as opposed to
+// the name of a generated type.
+const OVERRIDE: char = '\u{1}';
+
+// Prefixes the name of a type that contains timestamps in dates mode, and so
+// is converted with its fromJSON and toJSON functions. See Target::marks.
+const DATES: char = '\u{2}';
+
+pub struct Target {
+    validators: bool,
+    union_enums: bool,
+    file_per_type: bool,
+    js_extensions: bool,
+    dates: bool,
+    readonly: bool,
+    brands: bool,
+
+    // In file-per-type mode, the types and values each type's module exports
+    // for index.ts to re-export, by type name.
+    exports: RefCell<BTreeMap<String, Exports>>,
+}
 
 impl Target {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            validators: false,
+            union_enums: false,
+            file_per_type: false,
+            js_extensions: false,
+            dates: false,
+            readonly: false,
+            brands: false,
+            exports: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Whether to put each type in its own module, which imports the types it
+    /// refers to, instead of putting every type in `index.ts`. Modules are
+    /// named after their type in kebab case, like `root-bar-baz.ts` for
+    /// `RootBarBaz`.
+    ///
+    /// In this mode, `index.ts` re-exports each type, along with the functions
+    /// meant to be called on it: `isFoo` and `parseFoo`, and `fromJSONFoo` and
+    /// `toJSONFoo` if it has them.
+    pub fn with_file_per_type(mut self, file_per_type: bool) -> Self {
+        self.file_per_type = file_per_type;
+        self
+    }
+
+    /// Whether imports between modules in file-per-type mode end in `.js`, as
+    /// the `NodeNext` module resolution requires.
+    pub fn with_js_extensions(mut self, js_extensions: bool) -> Self {
+        self.js_extensions = js_extensions;
+        self
+    }
+
+    /// Whether to generate functions that check untrusted input against each
+    /// type. For a type `Foo`, this generates a type guard `isFoo` and a
+    /// `parseFoo` function, which throws a `ValidationError` pointing at the
+    /// first part of its input that isn't a valid `Foo`.
+    ///
+    /// These are plain functions, so bundlers can drop the ones that go
+    /// unused. Types overridden with `typescriptType` aren't checked.
+    pub fn with_validators(mut self, validators: bool) -> Self {
+        self.validators = validators;
+        self
+    }
+
+    /// Whether to represent timestamps as `Date`s rather than as strings. For
+    /// a type `Foo` that contains timestamps, this generates a `fromJSONFoo`
+    /// function, which converts the JSON representation of a `Foo` into a
+    /// `Foo`, and a `toJSONFoo` function, which does the opposite.
+    ///
+    /// In this mode, `parseFoo` converts its input with `fromJSONFoo`, and
+    /// `isFoo` checks that its input is the JSON representation of a `Foo`.
+    /// JavaScript dates can't represent leap seconds, so validators reject
+    /// timestamps with a leap second. `fromJSONFoo` doesn't check its input,
+    /// and turns them into invalid dates.
+    pub fn with_dates(mut self, dates: bool) -> Self {
+        self.dates = dates;
+        self
+    }
+
+    /// Whether to mark every property `readonly`, and to use `ReadonlyArray`
+    /// and `Readonly<Record<...>>` for arrays and dictionaries.
+    pub fn with_readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Whether to make aliases of primitive types nominal, so that a `UserId`
+    /// can't be mixed up with a `string` or with another alias of `string`.
+    /// For a branded type `UserId`, this generates a function `UserId` that
+    /// brands a `string` as a `UserId`.
+    pub fn with_brands(mut self, brands: bool) -> Self {
+        self.brands = brands;
+        self
+    }
+
+    /// Whether to render enums as a union of string literals, alongside a
+    /// constant array of their values, instead of as a TypeScript `enum`.
+    ///
+    /// Individual enums can override this with `typescriptEnumStyle`
+    /// metadata, which is either `"enum"` or `"union"`.
+    pub fn with_union_enums(mut self, union_enums: bool) -> Self {
+        self.union_enums = union_enums;
+        self
+    }
+
+    // Whether an enum is rendered as a union, per its typescriptEnumStyle
+    // metadata or otherwise the target's default.
+    fn union_enum(&self, metadata: &metadata::Metadata) -> Result<bool> {
+        match metadata.get("typescriptEnumStyle") {
+            None => Ok(self.union_enums),
+            Some(Value::String(s)) if s == "enum" => Ok(false),
+            Some(Value::String(s)) if s == "union" => Ok(true),
+            Some(v) => Err(Error::InvalidMetadata(format!(
+                "typescriptEnumStyle must be \"enum\" or \"union\", not {}",
+                v
+            ))),
+        }
+    }
+
+    // Whether generated types can't be given a name, because this target
+    // declares something else by that name.
+    fn reserves(&self, name: &str) -> bool {
+        // Validators declare these alongside generated types.
+        let validator_names = ["ValidationError", "Violation"];
+
+        // In file-per-type mode, types can't share a file name with the other
+        // modules this target generates.
+        let module_names = ["Index", "Validation"];
+
+        // Types can't shadow the Date that timestamps are represented with, if
+        // dates are enabled. Brands declare functions named after types, which
+        // can't shadow any of the globals that generated code uses.
+        let global_names = ["Array", "Date", "Error", "JSON", "Math", "Number", "Object"];
+
+        (self.validators && validator_names.contains(&name))
+            || (self.file_per_type && module_names.contains(&name))
+            || (self.dates && name == "Date")
+            || (self.brands && global_names.contains(&name))
+    }
+
+    // The modifier for properties of interfaces.
+    fn readonly(&self) -> &'static str {
+        if self.readonly {
+            "readonly "
+        } else {
+            ""
+        }
+    }
+
+    // The path other modules import a module from, given its file name.
+    fn module_path(&self, file_name: &str) -> String {
+        format!(
+            "./{}{}",
+            file_name,
+            if self.js_extensions { ".js" } else { "" }
+        )
+    }
+
+    // Records the functions generated alongside a type: its validate, is and
+    // parse functions if validators are enabled, and its fromJSON and toJSON
+    // functions if dates are and it contains timestamps. Values of branded
+    // types are made with their constructor.
+    fn functions(
+        &self,
+        state: &mut FileState,
+        name: &str,
+        dated: bool,
+        validator: Validator,
+        branded: bool,
+    ) -> Result<()> {
+        if self.validators {
+            state.exports.values.push(format!("is{}", name));
+            state.exports.values.push(format!("parse{}", name));
+            validator.write(
+                &mut state.functions,
+                &mut state.helpers,
+                name,
+                self.file_per_type,
+                dated,
+            )?;
+        }
+
+        if dated {
+            state.exports.values.push(format!("fromJSON{}", name));
+            state.exports.values.push(format!("toJSON{}", name));
+            conversions::write(&mut state.functions, name, &validator, branded)?;
+        }
+
+        Ok(())
+    }
+
+    // Writes out the imports a module in file-per-type mode needs.
+    fn write_imports(&self, out: &mut dyn Write, state: &FileState) -> Result<()> {
+        let names: Vec<&String> = state.referenced.difference(&state.declared).collect();
+        if names.is_empty() && state.helpers.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(out)?;
+        for name in names {
+            let path = self.module_path(&file_name(name));
+            writeln!(out, "import type {{ {} }} from {:?};", name, path)?;
+
+            let mut functions = Vec::new();
+            if state.dated.contains(name) {
+                functions.push(format!("fromJSON{}", name));
+                functions.push(format!("toJSON{}", name));
+            }
+            if self.validators {
+                functions.push(format!("validate{}", name));
+            }
+            if !functions.is_empty() {
+                writeln!(
+                    out,
+                    "import {{ {} }} from {:?};",
+                    functions.join(", "),
+                    path
+                )?;
+            }
+        }
+
+        if !state.helpers.is_empty() {
+            let path = self.module_path("validation");
+            let (types, values): (Vec<&str>, Vec<&str>) = state
+                .helpers
+                .iter()
+                .partition(|helper| validators::is_type(helper));
+
+            writeln!(
+                out,
+                "import type {{ {} }} from {:?};",
+                types.join(", "),
+                path
+            )?;
+            writeln!(out, "import {{ {} }} from {:?};", values.join(", "), path)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct FileState {
+    // The TypeScript types of expressions, and what to check their values
+    // against. Expressions are placeholders for an entry in this list.
+    exprs: Vec<(String, Check)>,
+
+    // The generated types this file declares, and those it refers to.
+    declared: BTreeSet<String>,
+    referenced: BTreeSet<String>,
+
+    // The referenced types that contain timestamps, in dates mode.
+    dated: BTreeSet<String>,
+
+    // The functions generated alongside the types in this file, which are
+    // written after the types themselves, and the helpers they use.
+    functions: Vec<u8>,
+    helpers: validators::Helpers,
+
+    // What index.ts re-exports from this file, in file-per-type mode.
+    exports: Exports,
+}
+
+// The names a module exports for index.ts to re-export. Types are re-exported
+// with `export type`, as isolatedModules requires. Functions that other
+// modules call, like validateFoo, aren't re-exported.
+#[derive(Default)]
+struct Exports {
+    types: Vec<String>,
+    values: Vec<String>,
+}
+
+impl FileState {
+    fn register(&mut self, type_: String, check: Check) -> String {
+        self.exprs.push((type_, check));
+        format!("\0{}", self.exprs.len() - 1)
+    }
+
+    // The TypeScript type for an expression or the name of a type.
+    fn type_(&mut self, s: &str) -> String {
+        if let Some((type_, _)) = self.expr(s) {
+            return type_.clone();
+        }
+
+        if let Some(type_) = s.strip_prefix(OVERRIDE) {
+            return type_.to_owned();
+        }
+
+        let (name, dated) = unmark(s);
+        if dated {
+            self.dated.insert(name.clone());
+        }
+
+        self.referenced.insert(name.clone());
+        name
+    }
+
+    // What to check values of an expression or named type against.
+    fn check(&self, s: &str) -> Check {
+        if let Some((_, check)) = self.expr(s) {
+            return check.clone();
+        }
+
+        if s.starts_with(OVERRIDE) {
+            return Check::Any;
+        }
+
+        let (name, dated) = unmark(s);
+        Check::Named(name, dated)
+    }
+
+    fn expr(&self, s: &str) -> Option<&(String, Check)> {
+        let index = s.strip_prefix('\0')?.parse::<usize>().ok()?;
+        self.exprs.get(index)
     }
 }
 
 impl jtd_codegen::target::Target for Target {
-    type FileState = ();
+    type FileState = FileState;
 
     fn strategy(&self) -> target::Strategy {
         target::Strategy {
-            file_partitioning: target::FilePartitioningStrategy::SingleFile("index.ts".into()),
+            file_partitioning: if self.file_per_type {
+                target::FilePartitioningStrategy::FilePerType("ts".into())
+            } else {
+                target::FilePartitioningStrategy::SingleFile("index.ts".into())
+            },
             enum_member_naming: target::EnumMemberNamingStrategy::Modularized,
             optional_property_handling: target::OptionalPropertyHandlingStrategy::NativeSupport,
             booleans_are_nullable: false,
@@ -61,7 +384,14 @@ impl jtd_codegen::target::Target for Target {
 
     fn name(&self, kind: target::NameableKind, parts: &[String]) -> String {
         match kind {
-            target::NameableKind::Type => TYPE_NAMING_CONVENTION.inflect(parts),
+            target::NameableKind::Type => {
+                let name = TYPE_NAMING_CONVENTION.inflect(parts);
+                if self.reserves(&name) {
+                    format!("{}_", name)
+                } else {
+                    name
+                }
+            }
             target::NameableKind::EnumMember => ENUM_MEMBER_NAMING_CONVENTION.inflect(parts),
 
             // Not used. TypeScript maps directly to the JSON data, so we don't
@@ -71,39 +401,130 @@ impl jtd_codegen::target::Target for Target {
         }
     }
 
-    fn expr(&self, _state: &mut (), metadata: metadata::Metadata, expr: target::Expr) -> String {
+    fn expr(
+        &self,
+        state: &mut FileState,
+        metadata: metadata::Metadata,
+        expr: target::Expr,
+    ) -> Result<String> {
         if let Some(s) = metadata.get("typescriptType").and_then(|v| v.as_str()) {
-            return s.into();
+            return Ok(state.register(s.into(), Check::Any));
         }
 
-        match expr {
-            target::Expr::Empty => "any".into(),
-            target::Expr::Boolean => "boolean".into(),
-            target::Expr::Int8 => "number".into(),
-            target::Expr::Uint8 => "number".into(),
-            target::Expr::Int16 => "number".into(),
-            target::Expr::Uint16 => "number".into(),
-            target::Expr::Int32 => "number".into(),
-            target::Expr::Uint32 => "number".into(),
-            target::Expr::Float32 => "number".into(),
-            target::Expr::Float64 => "number".into(),
-            target::Expr::String => "string".into(),
-            target::Expr::Timestamp => "string".into(),
-            target::Expr::ArrayOf(sub_expr) => format!("{}[]", sub_expr),
-            target::Expr::DictOf(sub_expr) => format!("{{ [key: string]: {} }}", sub_expr),
-            target::Expr::NullableOf(sub_expr) => format!("({} | null)", sub_expr),
-        }
+        let (type_, check) = match expr {
+            target::Expr::Empty => ("any".into(), Check::Any),
+            target::Expr::Boolean => ("boolean".into(), Check::Boolean),
+            target::Expr::Int8 => (
+                "number".into(),
+                Check::Integer(i8::MIN.into(), i8::MAX.into()),
+            ),
+            target::Expr::Uint8 => (
+                "number".into(),
+                Check::Integer(u8::MIN.into(), u8::MAX.into()),
+            ),
+            target::Expr::Int16 => (
+                "number".into(),
+                Check::Integer(i16::MIN.into(), i16::MAX.into()),
+            ),
+            target::Expr::Uint16 => (
+                "number".into(),
+                Check::Integer(u16::MIN.into(), u16::MAX.into()),
+            ),
+            target::Expr::Int32 => (
+                "number".into(),
+                Check::Integer(i32::MIN.into(), i32::MAX.into()),
+            ),
+            target::Expr::Uint32 => (
+                "number".into(),
+                Check::Integer(u32::MIN.into(), u32::MAX.into()),
+            ),
+            target::Expr::Float32 => ("number".into(), Check::Number),
+            target::Expr::Float64 => ("number".into(), Check::Number),
+            target::Expr::String => ("string".into(), Check::String),
+            target::Expr::Timestamp if self.dates => ("Date".into(), Check::Date),
+            target::Expr::Timestamp => ("string".into(), Check::Timestamp),
+            target::Expr::ArrayOf(sub_expr) => (
+                if self.readonly {
+                    format!("ReadonlyArray<{}>", state.type_(&sub_expr))
+                } else {
+                    format!("{}[]", state.type_(&sub_expr))
+                },
+                Check::Array(Box::new(state.check(&sub_expr))),
+            ),
+            target::Expr::DictOf(sub_expr) => (
+                if self.readonly {
+                    format!("Readonly<Record<string, {}>>", state.type_(&sub_expr))
+                } else {
+                    format!("{{ [key: string]: {} }}", state.type_(&sub_expr))
+                },
+                Check::Dict(Box::new(state.check(&sub_expr))),
+            ),
+            target::Expr::NullableOf(sub_expr) => (
+                format!("({} | null)", state.type_(&sub_expr)),
+                Check::Nullable(Box::new(state.check(&sub_expr))),
+            ),
+        };
+
+        Ok(state.register(type_, check))
     }
 
     fn item(
         &self,
         out: &mut dyn Write,
-        _state: &mut (),
+        state: &mut FileState,
         item: target::Item,
     ) -> Result<Option<String>> {
         Ok(match item {
-            target::Item::Auxiliary { .. } => {
-                // No auxiliary files needed.
+            target::Item::Auxiliary { out_dir, .. } => {
+                // In file-per-type mode, index.ts re-exports what every module
+                // exports, and validators share their helpers through another
+                // module.
+                if self.file_per_type {
+                    let mut out = File::create(out_dir.join("index.ts"))?;
+                    writeln!(
+                        out,
+                        "// Code generated by jtd-codegen for TypeScript v{}",
+                        env!("CARGO_PKG_VERSION")
+                    )?;
+
+                    writeln!(out)?;
+                    for (name, exports) in self.exports.borrow().iter() {
+                        let path = self.module_path(&file_name(name));
+                        if !exports.types.is_empty() {
+                            writeln!(
+                                out,
+                                "export type {{ {} }} from {:?};",
+                                exports.types.join(", "),
+                                path
+                            )?;
+                        }
+                        if !exports.values.is_empty() {
+                            writeln!(
+                                out,
+                                "export {{ {} }} from {:?};",
+                                exports.values.join(", "),
+                                path
+                            )?;
+                        }
+                    }
+
+                    if self.validators {
+                        writeln!(
+                            out,
+                            "export {{ ValidationError }} from {:?};",
+                            self.module_path("validation")
+                        )?;
+
+                        let mut out = File::create(out_dir.join("validation.ts"))?;
+                        writeln!(
+                            out,
+                            "// Code generated by jtd-codegen for TypeScript v{}",
+                            env!("CARGO_PKG_VERSION")
+                        )?;
+                        validators::write_helpers_module(&mut out)?;
+                    }
+                }
+
                 None
             }
 
@@ -114,19 +535,78 @@ impl jtd_codegen::target::Target for Target {
                     env!("CARGO_PKG_VERSION")
                 )?;
 
+                if self.file_per_type {
+                    self.write_imports(out, state)?;
+                }
+
                 None
             }
 
-            target::Item::Postamble => None,
+            target::Item::Postamble => {
+                out.write_all(&state.functions)?;
+                if self.file_per_type {
+                    // Each module declares at most one type, and exports
+                    // nothing if that type was overridden.
+                    if let Some(name) = state.declared.iter().next() {
+                        self.exports
+                            .borrow_mut()
+                            .insert(name.clone(), std::mem::take(&mut state.exports));
+                    }
+                } else {
+                    validators::write_helpers(out, &state.helpers)?;
+                }
+
+                None
+            }
 
             target::Item::Alias {
                 metadata,
                 name,
                 type_,
             } => {
+                let check = state.check(&type_);
+                let type_ = state.type_(&type_);
+
+                let (name, dated) = unmark(&name);
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
-                writeln!(out, "export type {} = {};", name, type_)?;
+
+                // Only primitives are branded. Other types are already
+                // distinct from one another, or are made up of types that can
+                // be branded themselves.
+                let primitive = matches!(
+                    check,
+                    Check::Boolean
+                        | Check::Integer(..)
+                        | Check::Number
+                        | Check::String
+                        | Check::Timestamp
+                        | Check::Date
+                );
+
+                let branded = self.brands && primitive;
+                if branded {
+                    writeln!(
+                        out,
+                        "export type {} = {} & {{ readonly __brand: {:?} }};",
+                        name, type_, name
+                    )?;
+                    writeln!(out)?;
+                    writeln!(out, "export function {}(x: {}): {} {{", name, type_, name)?;
+                    writeln!(out, "  return x as {};", name)?;
+                    writeln!(out, "}}")?;
+                } else {
+                    writeln!(out, "export type {} = {};", name, type_)?;
+                }
+
+                if branded {
+                    state.exports.values.push(name.clone());
+                } else {
+                    state.exports.types.push(name.clone());
+                }
+
+                state.declared.insert(name.clone());
+                self.functions(state, &name, dated, Validator::Alias(check), branded)?;
 
                 None
             }
@@ -137,23 +617,33 @@ impl jtd_codegen::target::Target for Target {
                 members,
             } => {
                 if let Some(s) = metadata.get("typescriptType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
                 }
 
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
-                writeln!(out, "export enum {} {{", name)?;
-                for (index, member) in members.iter().enumerate() {
-                    let description = enum_variant_description(&metadata, 1, &member.json_value);
+                if self.union_enum(&metadata)? {
+                    write_union_enum(out, &metadata, &name, &members)?;
+                } else {
+                    writeln!(out, "export enum {} {{", name)?;
+                    for (index, member) in members.iter().enumerate() {
+                        let description =
+                            enum_variant_description(&metadata, 1, &member.json_value);
 
-                    if index != 0 && !description.is_empty() {
-                        writeln!(out)?;
-                    }
+                        if index != 0 && !description.is_empty() {
+                            writeln!(out)?;
+                        }
 
-                    write!(out, "{}", &description)?;
-                    writeln!(out, "  {} = {:?},", member.name, member.json_value)?;
+                        write!(out, "{}", &description)?;
+                        writeln!(out, "  {} = {:?},", member.name, member.json_value)?;
+                    }
+                    writeln!(out, "}}")?;
                 }
-                writeln!(out, "}}")?;
+
+                let values = members.into_iter().map(|m| m.json_value).collect();
+                state.exports.values.push(name.clone());
+                state.declared.insert(name.clone());
+                self.functions(state, &name, false, Validator::Enum(values), false)?;
 
                 None
             }
@@ -161,13 +651,14 @@ impl jtd_codegen::target::Target for Target {
             target::Item::Struct {
                 metadata,
                 name,
-                has_additional: _,
+                has_additional,
                 fields,
             } => {
                 if let Some(s) = metadata.get("typescriptType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
                 }
 
+                let (name, dated) = unmark(&name);
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
                 writeln!(out, "export interface {} {{", name)?;
@@ -182,34 +673,48 @@ impl jtd_codegen::target::Target for Target {
                     if field.optional {
                         writeln!(
                             out,
-                            "  {}?: {};",
+                            "  {}{}?: {};",
+                            self.readonly(),
                             format_property(field.json_name.clone()),
-                            field.type_
+                            state.type_(&field.type_)
                         )?;
                     } else {
                         writeln!(
                             out,
-                            "  {}: {};",
+                            "  {}{}: {};",
+                            self.readonly(),
                             format_property(field.json_name.clone()),
-                            field.type_
+                            state.type_(&field.type_)
                         )?;
                     }
                 }
                 writeln!(out, "}}")?;
 
+                let validator = Validator::Struct {
+                    properties: properties(state, &fields),
+                    has_additional,
+                    tag: None,
+                };
+
+                state.exports.types.push(name.clone());
+                state.declared.insert(name.clone());
+                self.functions(state, &name, dated, validator, false)?;
+
                 None
             }
 
             target::Item::Discriminator {
                 metadata,
                 name,
+                tag_json_name,
                 variants,
                 ..
             } => {
                 if let Some(s) = metadata.get("typescriptType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
                 }
 
+                let (name, dated) = unmark(&name);
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
                 writeln!(
@@ -217,12 +722,24 @@ impl jtd_codegen::target::Target for Target {
                     "export type {} = {};",
                     name,
                     variants
-                        .into_iter()
-                        .map(|v| v.type_name)
+                        .iter()
+                        .map(|v| state.type_(&v.type_name))
                         .collect::<Vec<_>>()
                         .join(" | ")
                 )?;
 
+                let validator = Validator::Discriminator {
+                    tag_json_name,
+                    variants: variants
+                        .into_iter()
+                        .map(|v| (v.tag_value, state.check(&v.type_name)))
+                        .collect(),
+                };
+
+                state.exports.types.push(name.clone());
+                state.declared.insert(name.clone());
+                self.functions(state, &name, dated, validator, false)?;
+
                 None
             }
 
@@ -231,17 +748,25 @@ impl jtd_codegen::target::Target for Target {
                 name,
                 tag_json_name,
                 tag_value,
+                has_additional,
                 fields,
                 ..
             } => {
                 if let Some(s) = metadata.get("typescriptType").and_then(|v| v.as_str()) {
-                    return Ok(Some(s.into()));
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
                 }
 
+                let (name, dated) = unmark(&name);
                 writeln!(out)?;
                 write!(out, "{}", description(&metadata, 0))?;
                 writeln!(out, "export interface {} {{", name)?;
-                writeln!(out, "  {}: {:?};", tag_json_name, tag_value)?;
+                writeln!(
+                    out,
+                    "  {}{}: {:?};",
+                    self.readonly(),
+                    tag_json_name,
+                    tag_value
+                )?;
                 for field in &fields {
                     let description = description(&field.metadata, 1);
 
@@ -253,25 +778,134 @@ impl jtd_codegen::target::Target for Target {
                     if field.optional {
                         writeln!(
                             out,
-                            "  {}?: {};",
+                            "  {}{}?: {};",
+                            self.readonly(),
                             format_property(field.json_name.clone()),
-                            field.type_
+                            state.type_(&field.type_)
                         )?;
                     } else {
                         writeln!(
                             out,
-                            "  {}: {};",
+                            "  {}{}: {};",
+                            self.readonly(),
                             format_property(field.json_name.clone()),
-                            field.type_
+                            state.type_(&field.type_)
                         )?;
                     }
                 }
                 writeln!(out, "}}")?;
 
+                let validator = Validator::Struct {
+                    properties: properties(state, &fields),
+                    has_additional,
+                    tag: Some((tag_json_name, tag_value)),
+                };
+
+                state.exports.types.push(name.clone());
+                state.declared.insert(name.clone());
+                self.functions(state, &name, dated, validator, false)?;
+
                 None
             }
         })
     }
+
+    // In dates mode, types that contain timestamps are marked, so that only
+    // those types get fromJSON and toJSON functions, and only they are
+    // converted when they are referred to.
+    fn marks(&self, metadata: &metadata::Metadata, expr: &target::Expr) -> bool {
+        self.dates
+            && matches!(expr, target::Expr::Timestamp)
+            && !metadata.contains_key("typescriptType")
+    }
+
+    fn marked_name(&self, name: String) -> String {
+        format!("{}{}", DATES, name)
+    }
+
+    fn file_name(&self, type_name: &str) -> String {
+        file_name(type_name)
+    }
+}
+
+// Writes out an enum as a union of its values, and an array of those values
+// under the same name.
+fn write_union_enum(
+    out: &mut dyn Write,
+    metadata: &metadata::Metadata,
+    name: &str,
+    members: &[target::EnumMember],
+) -> Result<()> {
+    let values: Vec<String> = members
+        .iter()
+        .map(|member| format!("{:?}", member.json_value))
+        .collect();
+
+    writeln!(out, "export type {} = {};", name, values.join(" | "))?;
+    writeln!(out)?;
+
+    let descriptions: Vec<String> = members
+        .iter()
+        .map(|member| enum_variant_description(metadata, 1, &member.json_value))
+        .collect();
+
+    // Values are only given a line each if they need room for their
+    // descriptions.
+    if descriptions.iter().all(String::is_empty) {
+        writeln!(
+            out,
+            "export const {} = [{}] as const;",
+            name,
+            values.join(", ")
+        )?;
+    } else {
+        writeln!(out, "export const {} = [", name)?;
+        for (index, (value, description)) in values.iter().zip(&descriptions).enumerate() {
+            if index != 0 && !description.is_empty() {
+                writeln!(out)?;
+            }
+
+            write!(out, "{}", description)?;
+            writeln!(out, "  {},", value)?;
+        }
+        writeln!(out, "] as const;")?;
+    }
+
+    Ok(())
+}
+
+// The name of a type's module, which is its name in kebab case, like the rest
+// of a typical TypeScript project's files.
+fn file_name(type_name: &str) -> String {
+    let mut file_name = String::new();
+    for (index, c) in type_name.chars().enumerate() {
+        if index != 0 && c.is_ascii_uppercase() {
+            file_name.push('-');
+        }
+        file_name.push(c.to_ascii_lowercase());
+    }
+
+    file_name
+}
+
+// The name of a type, without the mark it has if it contains timestamps, and
+// whether it had that mark.
+fn unmark(name: &str) -> (String, bool) {
+    match name.strip_prefix(DATES) {
+        Some(name) => (name.to_owned(), true),
+        None => (name.to_owned(), false),
+    }
+}
+
+fn properties(state: &FileState, fields: &[target::Field]) -> Vec<Property> {
+    fields
+        .iter()
+        .map(|field| Property {
+            json_name: field.json_name.clone(),
+            optional: field.optional,
+            check: state.check(&field.type_),
+        })
+        .collect()
 }
 
 fn format_property(s: String) -> String {
@@ -324,6 +958,58 @@ mod tests {
         jtd_codegen_test::std_test_cases!(&crate::Target::new());
     }
 
+    mod validators_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/validators"),
+            &crate::Target::new().with_validators(true)
+        );
+    }
+
+    mod union_enums_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/union_enums"),
+            &crate::Target::new().with_union_enums(true)
+        );
+    }
+
+    mod file_per_type_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/file_per_type"),
+            &crate::Target::new().with_file_per_type(true)
+        );
+    }
+
+    mod file_per_type_validators_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/file_per_type_validators"),
+            &crate::Target::new()
+                .with_file_per_type(true)
+                .with_js_extensions(true)
+                .with_validators(true)
+        );
+    }
+
+    mod dates_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/dates"),
+            &crate::Target::new().with_dates(true)
+        );
+    }
+
+    mod readonly_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/readonly"),
+            &crate::Target::new().with_readonly(true)
+        );
+    }
+
+    mod brands_std_tests {
+        jtd_codegen_test::std_test_cases!(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/brands"),
+            &crate::Target::new().with_brands(true)
+        );
+    }
+
     mod optional_std_tests {
         jtd_codegen_test::strict_std_test_case!(
             &crate::Target::new(),
@@ -335,4 +1021,339 @@ mod tests {
             empty_and_nonascii_enum_values
         );
     }
+
+    fn codegen(target: &crate::Target, schema: serde_json::Value) -> jtd_codegen::Result<String> {
+        use std::convert::TryInto;
+
+        let schema: jtd::SerdeSchema = serde_json::from_value(schema).unwrap();
+        let out_dir = tempfile::tempdir().unwrap();
+        jtd_codegen::codegen(
+            target,
+            "root".into(),
+            &schema.try_into().unwrap(),
+            out_dir.path(),
+        )?;
+
+        Ok(std::fs::read_to_string(out_dir.path().join("index.ts")).unwrap())
+    }
+
+    #[test]
+    fn test_enum_style_metadata() {
+        let schema = serde_json::json!({
+            "properties": {
+                "foo": { "enum": ["A", "B"], "metadata": { "typescriptEnumStyle": "union" }},
+                "bar": { "enum": ["C", "D"], "metadata": { "typescriptEnumStyle": "enum" }},
+                "baz": { "enum": ["E", "F"] }
+            }
+        });
+
+        let out = codegen(&crate::Target::new(), schema.clone()).unwrap();
+        assert!(out.contains("export type RootFoo = \"A\" | \"B\";"));
+        assert!(out.contains("export const RootFoo = [\"A\", \"B\"] as const;"));
+        assert!(out.contains("export enum RootBar {"));
+        assert!(out.contains("export enum RootBaz {"));
+
+        let out = codegen(&crate::Target::new().with_union_enums(true), schema).unwrap();
+        assert!(out.contains("export type RootFoo = \"A\" | \"B\";"));
+        assert!(out.contains("export enum RootBar {"));
+        assert!(out.contains("export type RootBaz = \"E\" | \"F\";"));
+    }
+
+    #[test]
+    fn test_invalid_enum_style_metadata() {
+        let schema = serde_json::json!({
+            "enum": ["A", "B"],
+            "metadata": { "typescriptEnumStyle": "literal" }
+        });
+
+        let err = codegen(&crate::Target::new(), schema).err().unwrap();
+        assert!(matches!(err, jtd_codegen::Error::InvalidMetadata(_)));
+    }
+
+    #[test]
+    fn test_validator_name_collisions() {
+        let schema = serde_json::json!({
+            "definitions": {
+                "object": { "properties": {} },
+                "validation_error": { "type": "string" }
+            },
+            "properties": {
+                "foo": { "ref": "object" },
+                "bar": { "ref": "validation_error" }
+            }
+        });
+
+        let out = codegen(&crate::Target::new().with_validators(true), schema).unwrap();
+        assert!(out.contains("export function isObject(x: unknown): x is Object {"));
+        assert!(out.contains("export type ValidationError_ = string;"));
+        assert!(out.contains("export class ValidationError extends Error {"));
+    }
+
+    #[test]
+    fn test_validator_violations() {
+        use serde_json::json;
+
+        // Properties named after those of Object.prototype must not be
+        // mistaken for being present.
+        let schema = json!({
+            "definitions": {
+                "shape": {
+                    "discriminator": "kind",
+                    "mapping": {
+                        "circle": { "properties": { "radius": { "type": "uint8" }}}
+                    }
+                }
+            },
+            "properties": {
+                "count": { "type": "int8" },
+                "color": { "enum": ["red", "green"] },
+                "shape": { "ref": "shape" },
+                "at": { "type": "timestamp" },
+                "items": { "elements": { "properties": { "toString": { "type": "string" }}}}
+            },
+            "optionalProperties": {
+                "constructor": { "type": "string" }
+            }
+        });
+
+        let valid = json!({
+            "count": 1,
+            "color": "red",
+            "shape": { "kind": "circle", "radius": 3 },
+            "at": "2020-01-01T00:00:00Z",
+            "items": [{ "toString": "a" }]
+        });
+
+        let with = |pointer: &str, value: serde_json::Value| {
+            let mut input = valid.clone();
+            let (parent, key) = pointer.rsplit_once('/').unwrap();
+            let parent = input.pointer_mut(parent).unwrap();
+            if value.is_null() {
+                parent.as_object_mut().unwrap().remove(key);
+            } else {
+                parent[key] = value;
+            }
+
+            input
+        };
+
+        let violation =
+            |path: &str, message: &str| json!([path, format!("{} at {:?}", message, path)]);
+
+        let cases = vec![
+            (valid.clone(), json!(null)),
+            (with("/constructor", json!("x")), json!(null)),
+            (
+                with("/constructor", json!(1)),
+                violation("/constructor", "expected string"),
+            ),
+            (
+                with("/count", json!(128)),
+                violation("/count", "expected integer between -128 and 127"),
+            ),
+            (
+                with("/count", json!(1.5)),
+                violation("/count", "expected integer between -128 and 127"),
+            ),
+            (
+                with("/shape/radius", json!(256)),
+                violation("/shape/radius", "expected integer between 0 and 255"),
+            ),
+            (
+                with("/color", json!("blue")),
+                violation("/color", "unknown enum value"),
+            ),
+            (
+                with("/shape/kind", json!("square")),
+                violation("/shape/kind", "unknown discriminator tag"),
+            ),
+            (
+                with("/shape/kind", json!(null)),
+                violation("/shape", "missing property \"kind\""),
+            ),
+            (
+                with("/count", json!(null)),
+                violation("", "missing property \"count\""),
+            ),
+            (
+                with("/items/0/toString", json!(null)),
+                violation("/items/0", "missing property \"toString\""),
+            ),
+            (
+                with("/at", json!("2020-02-30T00:00:00Z")),
+                violation("/at", "expected timestamp"),
+            ),
+            (
+                with("/at", json!("2020-01-01T24:00:00Z")),
+                violation("/at", "expected timestamp"),
+            ),
+            (
+                with("/at", json!("2020-01-01 00:00:00Z")),
+                violation("/at", "expected timestamp"),
+            ),
+        ];
+
+        let input: String = cases
+            .iter()
+            .map(|(input, _)| format!("{}\n", input))
+            .collect();
+        let output = jtd_codegen_test::docker_output(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/validators/violations"),
+            &crate::Target::new().with_validators(true),
+            schema,
+            &input,
+        );
+
+        let output: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        for ((input, expected), actual) in cases.iter().zip(&output) {
+            assert_eq!(expected, actual, "input: {}", input);
+        }
+
+        assert_eq!(cases.len(), output.len());
+    }
+
+    #[test]
+    fn test_file_per_type_exports() {
+        let schema = serde_json::json!({
+            "definitions": {
+                "user_id": { "type": "string" },
+                "status": { "enum": ["A", "B"] }
+            },
+            "properties": {
+                "user_id": { "ref": "user_id" },
+                "status": { "ref": "status" },
+                "bar_baz": { "properties": { "at": { "type": "timestamp" }}}
+            }
+        });
+
+        let target = crate::Target::new()
+            .with_validators(true)
+            .with_dates(true)
+            .with_brands(true)
+            .with_file_per_type(true);
+        let out_dir = tempfile::tempdir().unwrap();
+        let schema: jtd::SerdeSchema = serde_json::from_value(schema).unwrap();
+        let schema = std::convert::TryInto::try_into(schema).unwrap();
+        jtd_codegen::codegen(&target, "root".into(), &schema, out_dir.path()).unwrap();
+
+        let out = std::fs::read_to_string(out_dir.path().join("root.ts")).unwrap();
+        assert!(out.contains("import type { RootBarBaz } from \"./root-bar-baz\";"));
+        assert!(out.contains("import type { UserId } from \"./user-id\";"));
+
+        let out = std::fs::read_to_string(out_dir.path().join("index.ts")).unwrap();
+        assert!(out.contains("export type { Root } from \"./root\";"));
+        assert!(
+            out.contains("export { isRoot, parseRoot, fromJSONRoot, toJSONRoot } from \"./root\";")
+        );
+        assert!(out.contains(
+            "export { isRootBarBaz, parseRootBarBaz, fromJSONRootBarBaz, toJSONRootBarBaz } from \"./root-bar-baz\";"
+        ));
+        assert!(out.contains("export { Status, isStatus, parseStatus } from \"./status\";"));
+        assert!(out.contains("export { UserId, isUserId, parseUserId } from \"./user-id\";"));
+        assert!(out.contains("export { ValidationError } from \"./validation\";"));
+        assert!(!out.contains("export *"));
+        assert!(!out.contains("validateRoot"));
+    }
+
+    #[test]
+    fn test_dates_name_collisions() {
+        let schema = serde_json::json!({
+            "definitions": {
+                "date": { "type": "string" }
+            },
+            "properties": {
+                "foo": { "ref": "date" },
+                "bar": { "type": "timestamp" }
+            }
+        });
+
+        let out = codegen(&crate::Target::new().with_dates(true), schema).unwrap();
+        assert!(out.contains("export type Date_ = string;"));
+        assert!(out.contains("  bar: Date;"));
+        assert!(out.contains("  foo: Date_;"));
+        assert!(out.contains("  y.bar = new Date(x.bar);"));
+    }
+
+    #[test]
+    fn test_dates_conversions() {
+        let schema = serde_json::json!({
+            "definitions": {
+                "event": { "properties": { "at": { "type": "timestamp" }}},
+                "events": { "elements": { "ref": "event" }},
+                "user": { "properties": { "name": { "type": "string" }}}
+            },
+            "properties": {
+                "events": { "ref": "events" },
+                "user": { "ref": "user" }
+            }
+        });
+
+        let out = codegen(&crate::Target::new().with_dates(true), schema.clone()).unwrap();
+        assert!(out.contains("export function fromJSONEvents(x: any): Events {"));
+        assert!(out.contains("  y.events = fromJSONEvents(x.events);"));
+        assert!(!out.contains("fromJSONUser"));
+        assert!(!out.contains("toJSONUser"));
+
+        let target = crate::Target::new()
+            .with_dates(true)
+            .with_validators(true)
+            .with_file_per_type(true);
+        let out_dir = tempfile::tempdir().unwrap();
+        let schema: jtd::SerdeSchema = serde_json::from_value(schema).unwrap();
+        let schema = std::convert::TryInto::try_into(schema).unwrap();
+        jtd_codegen::codegen(&target, "root".into(), &schema, out_dir.path()).unwrap();
+
+        let out = std::fs::read_to_string(out_dir.path().join("root.ts")).unwrap();
+        assert!(out.contains("import { fromJSONEvents, toJSONEvents, validateEvents } from"));
+        assert!(out.contains("import { validateUser } from"));
+
+        let out = std::fs::read_to_string(out_dir.path().join("user.ts")).unwrap();
+        assert!(out.contains("export function parseUser(x: unknown): User {"));
+        assert!(out.contains("  return x as User;"));
+    }
+
+    #[test]
+    fn test_dates_leap_seconds() {
+        let schema = serde_json::json!({
+            "properties": { "at": { "type": "timestamp" }}
+        });
+
+        let output = jtd_codegen_test::docker_output(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/validators/violations"),
+            &crate::Target::new().with_validators(true).with_dates(true),
+            schema,
+            "{\"at\":\"2016-12-31T23:59:59Z\"}\n{\"at\":\"2016-12-31T23:59:60Z\"}\n",
+        );
+
+        let expected =
+            "null\n[\"/at\",\"leap seconds can't be represented as dates at \\\"/at\\\"\"]\n";
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_brands() {
+        let schema = serde_json::json!({
+            "definitions": {
+                "created_at": { "type": "timestamp" },
+                "user_id": { "type": "string" },
+                "user_ids": { "elements": { "ref": "user_id" } }
+            },
+            "properties": {
+                "created_at": { "ref": "created_at" },
+                "user_ids": { "ref": "user_ids" }
+            }
+        });
+
+        let target = crate::Target::new().with_brands(true).with_dates(true);
+        let out = codegen(&target, schema).unwrap();
+        assert!(out.contains("export type UserId = string & { readonly __brand: \"UserId\" };"));
+        assert!(out.contains("export function UserId(x: string): UserId {"));
+        assert!(out.contains("export type UserIds = UserId[];"));
+        assert!(out.contains("export type CreatedAt = Date & { readonly __brand: \"CreatedAt\" };"));
+        assert!(out.contains("  return CreatedAt(new Date(x));"));
+    }
 }
@@ -0,0 +1,567 @@
+// Generates the type guards and parse functions that Target::with_validators
+// enables.
+//
+// For each named type Foo, this generates an internal validateFoo function,
+// which returns the first violation of Foo's schema in its input, if any. The
+// exported isFoo and parseFoo functions are thin wrappers around it. All of
+// these are plain function declarations, so that bundlers can drop the ones
+// that go unused.
+//
+// Properties are only read once hasProperty has found them on the object
+// itself, so that ones inherited from Object.prototype, such as constructor,
+// don't count as being present.
+
+use std::collections::BTreeSet;
+use std::io::Write;
+
+/// What a value needs to be checked against, according to its schema.
+#[derive(Debug, Clone)]
+pub enum Check {
+    /// Any value is acceptable. This is also used for overridden types, which
+    /// this target doesn't know how to check.
+    Any,
+    Boolean,
+    Integer(i64, i64),
+    Number,
+    String,
+    Timestamp,
+
+    /// A timestamp that is represented as a Date, which can't hold a leap
+    /// second.
+    Date,
+    Array(Box<Check>),
+    Dict(Box<Check>),
+    Nullable(Box<Check>),
+
+    /// A value of a generated type, checked by that type's validate function,
+    /// and whether the type contains dates.
+    Named(String, bool),
+}
+
+impl Check {
+    /// An expression evaluating to the first violation of this check by the
+    /// value of `x`, which is found at the JSON pointer that `path` evaluates
+    /// to. Returns None if there is nothing to check.
+    pub fn render(&self, helpers: &mut Helpers, x: &str, path: &str) -> Option<String> {
+        if let Some(helper) = self.helper() {
+            helpers.insert(helper);
+            return Some(format!("{}({}, {})", helper, x, path));
+        }
+
+        match self {
+            Check::Any => None,
+            Check::Integer(min, max) => {
+                helpers.insert("checkInteger");
+                Some(format!("checkInteger({}, {}, {}, {})", x, path, min, max))
+            }
+            Check::Array(sub_check) => {
+                helpers.insert("checkArray");
+                let sub_check = sub_check.function(helpers);
+                Some(format!("checkArray({}, {}, {})", x, path, sub_check))
+            }
+            Check::Dict(sub_check) => {
+                helpers.insert("checkDict");
+                let sub_check = sub_check.function(helpers);
+                Some(format!("checkDict({}, {}, {})", x, path, sub_check))
+            }
+            Check::Nullable(sub_check) => sub_check
+                .render(helpers, x, path)
+                .map(|check| format!("({} === null ? undefined : {})", x, check)),
+            Check::Named(name, _) => Some(format!("validate{}({}, {})", name, x, path)),
+            _ => unreachable!("check has a helper"),
+        }
+    }
+
+    // A function taking a value and its path, and performing this check.
+    fn function(&self, helpers: &mut Helpers) -> String {
+        if let Some(helper) = self.helper() {
+            helpers.insert(helper);
+            return helper.to_owned();
+        }
+
+        match self {
+            Check::Named(name, _) => format!("validate{}", name),
+            _ => match self.render(helpers, "x", "path") {
+                Some(check) => format!("(x, path) => {}", check),
+                None => "() => undefined".to_owned(),
+            },
+        }
+    }
+
+    // The helper that performs this check on its own, if there is one.
+    fn helper(&self) -> Option<&'static str> {
+        match self {
+            Check::Boolean => Some("checkBoolean"),
+            Check::Number => Some("checkNumber"),
+            Check::String => Some("checkString"),
+            Check::Timestamp => Some("checkTimestamp"),
+            Check::Date => Some("checkDate"),
+            _ => None,
+        }
+    }
+}
+
+/// A property of an object being validated.
+#[derive(Debug)]
+pub struct Property {
+    pub json_name: String,
+    pub optional: bool,
+    pub check: Check,
+}
+
+/// What the validate function for a generated type checks.
+#[derive(Debug)]
+pub enum Validator {
+    Alias(Check),
+    Enum(Vec<String>),
+
+    /// Discriminator variants also have the name and value of their tag.
+    Struct {
+        properties: Vec<Property>,
+        has_additional: bool,
+        tag: Option<(String, String)>,
+    },
+
+    /// The tag value and check for each variant.
+    Discriminator {
+        tag_json_name: String,
+        variants: Vec<(String, Check)>,
+    },
+}
+
+impl Validator {
+    /// Writes out the validate, is and parse functions for the type `name`.
+    /// The validate function is only exported if `export` is set, for when
+    /// other modules need to call it. If `dates` is set, the parse function
+    /// converts its input with the type's fromJSON function.
+    pub fn write(
+        &self,
+        out: &mut dyn Write,
+        helpers: &mut Helpers,
+        name: &str,
+        export: bool,
+        dates: bool,
+    ) -> std::io::Result<()> {
+        helpers.insert("Violation");
+        helpers.insert("ValidationError");
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "{}function validate{}(x: unknown, path: string): Violation | undefined {{",
+            if export { "export " } else { "" },
+            name
+        )?;
+        match self {
+            Validator::Alias(check) => {
+                let check = check.render(helpers, "x", "path");
+                writeln!(out, "  return {};", check.as_deref().unwrap_or("undefined"))?;
+            }
+            Validator::Enum(values) => {
+                writeln!(out, "  switch (x) {{")?;
+                for value in values {
+                    writeln!(out, "    case {:?}:", value)?;
+                }
+                writeln!(out, "      return undefined;")?;
+                writeln!(out, "    default:")?;
+                writeln!(out, "      return [path, \"unknown enum value\"];")?;
+                writeln!(out, "  }}")?;
+            }
+            Validator::Struct {
+                properties,
+                has_additional,
+                tag,
+            } => write_struct(out, helpers, properties, *has_additional, tag)?,
+            Validator::Discriminator {
+                tag_json_name,
+                variants,
+            } => {
+                helpers.insert("hasObjectType");
+                helpers.insert("hasProperty");
+
+                writeln!(out, "  if (!hasObjectType(x)) {{")?;
+                writeln!(out, "    return [path, \"expected object\"];")?;
+                writeln!(out, "  }}")?;
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "  switch (hasProperty(x, {:?}) ? x[{:?}] : undefined) {{",
+                    tag_json_name, tag_json_name
+                )?;
+                for (tag_value, check) in variants {
+                    let check = check.render(helpers, "x", "path");
+                    writeln!(out, "    case {:?}:", tag_value)?;
+                    writeln!(
+                        out,
+                        "      return {};",
+                        check.as_deref().unwrap_or("undefined")
+                    )?;
+                }
+                writeln!(out, "    case undefined:")?;
+                writeln!(
+                    out,
+                    "      return [path, {:?}];",
+                    format!("missing property {:?}", tag_json_name)
+                )?;
+                writeln!(out, "    default:")?;
+                writeln!(
+                    out,
+                    "      return [path + {:?}, \"unknown discriminator tag\"];",
+                    pointer(tag_json_name)
+                )?;
+                writeln!(out, "  }}")?;
+            }
+        }
+        writeln!(out, "}}")?;
+
+        writeln!(out)?;
+        // With dates, the input is only the JSON representation of the type,
+        // so it isn't narrowed to the type itself.
+        if dates {
+            writeln!(out, "export function is{}(x: unknown): boolean {{", name)?;
+        } else {
+            writeln!(
+                out,
+                "export function is{}(x: unknown): x is {} {{",
+                name, name
+            )?;
+        }
+        writeln!(out, "  return validate{}(x, \"\") === undefined;", name)?;
+        writeln!(out, "}}")?;
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "export function parse{}(x: unknown): {} {{",
+            name, name
+        )?;
+        writeln!(out, "  const violation = validate{}(x, \"\");", name)?;
+        writeln!(out, "  if (violation !== undefined) {{")?;
+        writeln!(
+            out,
+            "    throw new ValidationError(violation[0], violation[1]);"
+        )?;
+        writeln!(out, "  }}")?;
+        writeln!(out)?;
+        if dates {
+            writeln!(out, "  return fromJSON{}(x);", name)?;
+        } else {
+            writeln!(out, "  return x as {};", name)?;
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+}
+
+fn write_struct(
+    out: &mut dyn Write,
+    helpers: &mut Helpers,
+    properties: &[Property],
+    has_additional: bool,
+    tag: &Option<(String, String)>,
+) -> std::io::Result<()> {
+    helpers.insert("hasObjectType");
+    helpers.insert("hasProperty");
+
+    writeln!(out, "  if (!hasObjectType(x)) {{")?;
+    writeln!(out, "    return [path, \"expected object\"];")?;
+    writeln!(out, "  }}")?;
+
+    if let Some((tag_json_name, tag_value)) = tag {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "  if (!hasProperty(x, {:?}) || x[{:?}] !== {:?}) {{",
+            tag_json_name, tag_json_name, tag_value
+        )?;
+        writeln!(
+            out,
+            "    return [path + {:?}, {:?}];",
+            pointer(tag_json_name),
+            format!("expected {:?}", tag_value)
+        )?;
+        writeln!(out, "  }}")?;
+    }
+
+    for property in properties.iter().filter(|p| !p.optional) {
+        writeln!(out)?;
+        writeln!(out, "  if (!hasProperty(x, {:?})) {{", property.json_name)?;
+        writeln!(
+            out,
+            "    return [path, {:?}];",
+            format!("missing property {:?}", property.json_name)
+        )?;
+        writeln!(out, "  }}")?;
+    }
+
+    if !has_additional {
+        helpers.insert("escapePointer");
+
+        let json_names: Vec<&String> = tag
+            .iter()
+            .map(|(tag_json_name, _)| tag_json_name)
+            .chain(properties.iter().map(|p| &p.json_name))
+            .collect();
+
+        writeln!(out)?;
+        writeln!(out, "  for (const key of Object.keys(x)) {{")?;
+        writeln!(out, "    switch (key) {{")?;
+        for json_name in &json_names {
+            writeln!(out, "      case {:?}:", json_name)?;
+        }
+        if !json_names.is_empty() {
+            writeln!(out, "        break;")?;
+        }
+        writeln!(out, "      default:")?;
+        writeln!(
+            out,
+            "        return [path + \"/\" + escapePointer(key), \"unexpected property\"];"
+        )?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "  }}")?;
+    }
+
+    let checks: Vec<String> = properties
+        .iter()
+        .filter_map(|property| {
+            let x = format!("x[{:?}]", property.json_name);
+            let path = format!("path + {:?}", pointer(&property.json_name));
+            let check = property.check.render(helpers, &x, &path)?;
+
+            Some(if property.optional {
+                format!(
+                    "(hasProperty(x, {:?}) ? {} : undefined)",
+                    property.json_name, check
+                )
+            } else {
+                check
+            })
+        })
+        .collect();
+
+    writeln!(out)?;
+    match checks.len() {
+        0 => writeln!(out, "  return undefined;")?,
+        1 => writeln!(out, "  return {};", checks[0])?,
+        _ => {
+            writeln!(out, "  return (")?;
+            writeln!(out, "    {}", checks.join(" ||\n    "))?;
+            writeln!(out, "  );")?;
+        }
+    }
+
+    Ok(())
+}
+
+// The JSON pointer suffix for a property of an object.
+fn pointer(json_name: &str) -> String {
+    format!("/{}", json_name.replace('~', "~0").replace('/', "~1"))
+}
+
+/// The names of the helper functions that generated code has used so far.
+pub type Helpers = BTreeSet<&'static str>;
+
+// The helper functions generated code can use, in the order they are written
+// out. Helpers only used by other helpers are listed with them.
+const HELPERS: &[(&str, &[&str], &str)] = &[
+    (
+        "ValidationError",
+        &[],
+        r#"/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}"#,
+    ),
+    ("Violation", &[], r#"type Violation = [string, string];"#),
+    (
+        "hasObjectType",
+        &[],
+        r#"function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}"#,
+    ),
+    (
+        "hasProperty",
+        &[],
+        r#"function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}"#,
+    ),
+    (
+        "escapePointer",
+        &[],
+        r#"function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}"#,
+    ),
+    (
+        "checkBoolean",
+        &[],
+        r#"function checkBoolean(x: unknown, path: string): Violation | undefined {
+  return typeof x === "boolean" ? undefined : [path, "expected boolean"];
+}"#,
+    ),
+    (
+        "checkInteger",
+        &[],
+        r#"function checkInteger(x: unknown, path: string, min: number, max: number): Violation | undefined {
+  if (typeof x === "number" && Math.floor(x) === x && x >= min && x <= max) {
+    return undefined;
+  }
+
+  return [path, "expected integer between " + min + " and " + max];
+}"#,
+    ),
+    (
+        "checkNumber",
+        &[],
+        r#"function checkNumber(x: unknown, path: string): Violation | undefined {
+  return typeof x === "number" ? undefined : [path, "expected number"];
+}"#,
+    ),
+    (
+        "checkString",
+        &[],
+        r#"function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}"#,
+    ),
+    (
+        "checkTimestamp",
+        &[],
+        r#"function checkTimestamp(x: unknown, path: string): Violation | undefined {
+  const match = typeof x === "string" &&
+    /^(\d{4})-(\d{2})-(\d{2})[tT](\d{2}):(\d{2}):(\d{2})(\.\d+)?([zZ]|[+-](\d{2}):(\d{2}))$/.exec(x);
+
+  if (match) {
+    const [year, month, day, hour, minute, second] = match.slice(1, 7).map(Number);
+    const leap = year % 4 === 0 && (year % 100 !== 0 || year % 400 === 0);
+    const days = [31, leap ? 29 : 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31][month - 1];
+
+    // RFC 3339 permits leap seconds, so a second of 60 is allowed.
+    if (
+      day >= 1 && day <= days && hour <= 23 && minute <= 59 && second <= 60 &&
+      (match[9] === undefined || (Number(match[9]) <= 23 && Number(match[10]) <= 59))
+    ) {
+      return undefined;
+    }
+  }
+
+  return [path, "expected timestamp"];
+}"#,
+    ),
+    (
+        "checkDate",
+        &["checkTimestamp"],
+        r#"function checkDate(x: unknown, path: string): Violation | undefined {
+  const violation = checkTimestamp(x, path);
+  if (violation === undefined && /:60(\.\d+)?([zZ]|[+-]\d{2}:\d{2})$/.test(x as string)) {
+    return [path, "leap seconds can't be represented as dates"];
+  }
+
+  return violation;
+}"#,
+    ),
+    (
+        "checkArray",
+        &[],
+        r#"function checkArray(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!Array.isArray(x)) {
+    return [path, "expected array"];
+  }
+
+  for (let i = 0; i < x.length; i++) {
+    const violation = check(x[i], path + "/" + i);
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}"#,
+    ),
+    (
+        "checkDict",
+        &["hasObjectType", "escapePointer"],
+        r#"function checkDict(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  for (const key of Object.keys(x)) {
+    const violation = check(x[key], path + "/" + escapePointer(key));
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}"#,
+    ),
+];
+
+/// Writes out the helpers that generated code has used.
+pub fn write_helpers(out: &mut dyn Write, helpers: &Helpers) -> std::io::Result<()> {
+    let mut used = helpers.clone();
+    for (name, dependencies, _) in HELPERS {
+        if used.contains(name) {
+            used.extend(dependencies.iter());
+        }
+    }
+
+    for (name, _, code) in HELPERS {
+        if used.contains(name) {
+            writeln!(out)?;
+            writeln!(out, "{}", code)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes out every helper, for a module that the modules for each type
+/// import their helpers from.
+pub fn write_helpers_module(out: &mut dyn Write) -> std::io::Result<()> {
+    let helpers = HELPERS.iter().map(|(name, _, _)| *name).collect();
+    write_helpers(out, &helpers)?;
+
+    // ValidationError is already exported, being part of the public API.
+    let (types, values): (Vec<&str>, Vec<&str>) = HELPERS
+        .iter()
+        .map(|(name, _, _)| *name)
+        .filter(|name| *name != "ValidationError")
+        .partition(|name| is_type(name));
+
+    writeln!(out)?;
+    writeln!(out, "export type {{ {} }};", types.join(", "))?;
+    writeln!(out, "export {{ {} }};", values.join(", "))?;
+
+    Ok(())
+}
+
+/// Whether a helper is a type, rather than a value, and so needs to be
+/// imported with `import type`.
+pub fn is_type(helper: &str) -> bool {
+    helper == "Violation"
+}
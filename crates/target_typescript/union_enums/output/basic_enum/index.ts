@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = "Bar" | "Baz" | "Foo";
+
+export const Root = ["Bar", "Baz", "Foo"] as const;
@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = Foo0;
+
+export type Bar = Bar0;
+
+export type Foo = Bar;
+
+export type Bar0 = string;
+
+export type Foo0 = Foo;
@@ -0,0 +1,18 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type RootFooBar = "x" | "y";
+
+export const RootFooBar = ["x", "y"] as const;
+
+export interface RootFoo {
+  bar: RootFooBar;
+}
+
+export type RootFooBar0 = "x" | "y";
+
+export const RootFooBar0 = ["x", "y"] as const;
+
+export interface Root {
+  foo: RootFoo;
+  foo_bar: RootFooBar0;
+}
@@ -0,0 +1,5 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = "FOO" | "Foo" | "foo";
+
+export const Root = ["FOO", "Foo", "foo"] as const;
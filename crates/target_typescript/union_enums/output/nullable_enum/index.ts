@@ -0,0 +1,7 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root0 = "Bar" | "Baz" | "Foo";
+
+export const Root0 = ["Bar", "Baz", "Foo"] as const;
+
+export type Root = (Root0 | null);
@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = Foo;
+
+export type Bar = Baz;
+
+export type Baz = string;
+
+export type Foo = Bar;
@@ -0,0 +1,12 @@
+// Unlike the plain TypeScript output, validators do have a runtime aspect.
+// Each line of input is passed through the generated parse function, which
+// throws if the input isn't valid, and is then written back out.
+import { parseMAIN } from "./jtd_codegen_e2e";
+
+declare const process: any;
+declare function require(name: string): any;
+
+const lines = require("readline").createInterface({ input: process.stdin });
+lines.on("line", (line: string) => {
+  console.log(JSON.stringify(parseMAIN(JSON.parse(line))));
+});
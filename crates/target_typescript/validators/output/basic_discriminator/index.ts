@@ -0,0 +1,156 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = RootBarBaz | RootQuux;
+
+export interface RootBarBaz {
+  foo: "BAR_BAZ";
+  baz: string;
+}
+
+export interface RootQuux {
+  foo: "QUUX";
+  quuz: string;
+}
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "foo") ? x["foo"] : undefined) {
+    case "BAR_BAZ":
+      return validateRootBarBaz(x, path);
+    case "QUUX":
+      return validateRootQuux(x, path);
+    case undefined:
+      return [path, "missing property \"foo\""];
+    default:
+      return [path + "/foo", "unknown discriminator tag"];
+  }
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateRootBarBaz(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "BAR_BAZ") {
+    return [path + "/foo", "expected \"BAR_BAZ\""];
+  }
+
+  if (!hasProperty(x, "baz")) {
+    return [path, "missing property \"baz\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "baz":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkString(x["baz"], path + "/baz");
+}
+
+export function isRootBarBaz(x: unknown): x is RootBarBaz {
+  return validateRootBarBaz(x, "") === undefined;
+}
+
+export function parseRootBarBaz(x: unknown): RootBarBaz {
+  const violation = validateRootBarBaz(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootBarBaz;
+}
+
+function validateRootQuux(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "QUUX") {
+    return [path + "/foo", "expected \"QUUX\""];
+  }
+
+  if (!hasProperty(x, "quuz")) {
+    return [path, "missing property \"quuz\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "quuz":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkString(x["quuz"], path + "/quuz");
+}
+
+export function isRootQuux(x: unknown): x is RootQuux {
+  return validateRootQuux(x, "") === undefined;
+}
+
+export function parseRootQuux(x: unknown): RootQuux {
+  const violation = validateRootQuux(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootQuux;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
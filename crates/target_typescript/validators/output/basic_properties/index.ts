@@ -0,0 +1,120 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  bar: string;
+  baz: boolean[];
+  foo: boolean;
+  quux: boolean[];
+}
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "bar")) {
+    return [path, "missing property \"bar\""];
+  }
+
+  if (!hasProperty(x, "baz")) {
+    return [path, "missing property \"baz\""];
+  }
+
+  if (!hasProperty(x, "foo")) {
+    return [path, "missing property \"foo\""];
+  }
+
+  if (!hasProperty(x, "quux")) {
+    return [path, "missing property \"quux\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "bar":
+      case "baz":
+      case "foo":
+      case "quux":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkString(x["bar"], path + "/bar") ||
+    checkArray(x["baz"], path + "/baz", checkBoolean) ||
+    checkBoolean(x["foo"], path + "/foo") ||
+    checkArray(x["quux"], path + "/quux", checkBoolean)
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkBoolean(x: unknown, path: string): Violation | undefined {
+  return typeof x === "boolean" ? undefined : [path, "expected boolean"];
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
+
+function checkArray(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!Array.isArray(x)) {
+    return [path, "expected array"];
+  }
+
+  for (let i = 0; i < x.length; i++) {
+    const violation = check(x[i], path + "/" + i);
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}
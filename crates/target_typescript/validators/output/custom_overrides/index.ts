@@ -0,0 +1,183 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface RootOverrideTypeDiscriminatorBaz {
+  foo: "baz";
+}
+
+export interface Root {
+  override_elements_container: string[];
+  override_type_discriminator: any;
+  override_type_enum: any;
+  override_type_expr: any;
+  override_type_properties: any;
+  override_values_container: { [key: string]: string };
+}
+
+function validateRootOverrideTypeDiscriminatorBaz(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "baz") {
+    return [path + "/foo", "expected \"baz\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return undefined;
+}
+
+export function isRootOverrideTypeDiscriminatorBaz(x: unknown): x is RootOverrideTypeDiscriminatorBaz {
+  return validateRootOverrideTypeDiscriminatorBaz(x, "") === undefined;
+}
+
+export function parseRootOverrideTypeDiscriminatorBaz(x: unknown): RootOverrideTypeDiscriminatorBaz {
+  const violation = validateRootOverrideTypeDiscriminatorBaz(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootOverrideTypeDiscriminatorBaz;
+}
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "override_elements_container")) {
+    return [path, "missing property \"override_elements_container\""];
+  }
+
+  if (!hasProperty(x, "override_type_discriminator")) {
+    return [path, "missing property \"override_type_discriminator\""];
+  }
+
+  if (!hasProperty(x, "override_type_enum")) {
+    return [path, "missing property \"override_type_enum\""];
+  }
+
+  if (!hasProperty(x, "override_type_expr")) {
+    return [path, "missing property \"override_type_expr\""];
+  }
+
+  if (!hasProperty(x, "override_type_properties")) {
+    return [path, "missing property \"override_type_properties\""];
+  }
+
+  if (!hasProperty(x, "override_values_container")) {
+    return [path, "missing property \"override_values_container\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "override_elements_container":
+      case "override_type_discriminator":
+      case "override_type_enum":
+      case "override_type_expr":
+      case "override_type_properties":
+      case "override_values_container":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkArray(x["override_elements_container"], path + "/override_elements_container", checkString) ||
+    checkDict(x["override_values_container"], path + "/override_values_container", checkString)
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
+
+function checkArray(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!Array.isArray(x)) {
+    return [path, "expected array"];
+  }
+
+  for (let i = 0; i < x.length; i++) {
+    const violation = check(x[i], path + "/" + i);
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}
+
+function checkDict(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  for (const key of Object.keys(x)) {
+    const violation = check(x[key], path + "/" + escapePointer(key));
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}
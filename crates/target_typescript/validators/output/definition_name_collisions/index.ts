@@ -0,0 +1,119 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = Foo0;
+
+export type Bar = Bar0;
+
+export type Foo = Bar;
+
+export type Bar0 = string;
+
+export type Foo0 = Foo;
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  return validateFoo0(x, path);
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateBar(x: unknown, path: string): Violation | undefined {
+  return validateBar0(x, path);
+}
+
+export function isBar(x: unknown): x is Bar {
+  return validateBar(x, "") === undefined;
+}
+
+export function parseBar(x: unknown): Bar {
+  const violation = validateBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Bar;
+}
+
+function validateFoo(x: unknown, path: string): Violation | undefined {
+  return validateBar(x, path);
+}
+
+export function isFoo(x: unknown): x is Foo {
+  return validateFoo(x, "") === undefined;
+}
+
+export function parseFoo(x: unknown): Foo {
+  const violation = validateFoo(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo;
+}
+
+function validateBar0(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isBar0(x: unknown): x is Bar0 {
+  return validateBar0(x, "") === undefined;
+}
+
+export function parseBar0(x: unknown): Bar0 {
+  const violation = validateBar0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Bar0;
+}
+
+function validateFoo0(x: unknown, path: string): Violation | undefined {
+  return validateFoo(x, path);
+}
+
+export function isFoo0(x: unknown): x is Foo0 {
+  return validateFoo0(x, "") === undefined;
+}
+
+export function parseFoo0(x: unknown): Foo0 {
+  const violation = validateFoo0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo0;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
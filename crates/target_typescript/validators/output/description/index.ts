@@ -0,0 +1,312 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+/**
+ * A description for discriminator
+ */
+export type RootDiscriminatorWithDescription = RootDiscriminatorWithDescriptionBar;
+
+/**
+ * A description for discriminator variant
+ */
+export interface RootDiscriminatorWithDescriptionBar {
+  foo: "bar";
+}
+
+/**
+ * A description for enum
+ */
+export enum RootEnumWithDescription {
+  /**
+   * A description for X
+   */
+  X = "X",
+
+  /**
+   * A description for Y
+   */
+  Y = "Y",
+
+  /**
+   * A description for Z
+   */
+  Z = "Z",
+}
+
+/**
+ * A description for properties
+ */
+export interface RootPropertiesWithDescription {
+}
+
+export interface Root {
+  /**
+   * A description for discriminator
+   */
+  discriminator_with_description: RootDiscriminatorWithDescription;
+
+  /**
+   * A description for enum
+   */
+  enum_with_description: RootEnumWithDescription;
+
+  /**
+   * Whereas disregard and contempt for human rights have resulted in barbarous
+   * acts which have outraged the conscience of mankind, and the advent of a
+   * world in which human beings shall enjoy freedom of speech and belief and
+   * freedom from fear and want has been proclaimed as the highest aspiration of
+   * the common people,
+   */
+  long_description: string;
+
+  /**
+   * A description for properties
+   */
+  properties_with_description: RootPropertiesWithDescription;
+
+  /**
+   * A description for ref
+   */
+  ref_with_description: Baz;
+
+  /**
+   * A description for string
+   */
+  string_with_description: string;
+}
+
+/**
+ * A description for a definition
+ */
+export type Baz = string;
+
+function validateRootDiscriminatorWithDescription(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "foo") ? x["foo"] : undefined) {
+    case "bar":
+      return validateRootDiscriminatorWithDescriptionBar(x, path);
+    case undefined:
+      return [path, "missing property \"foo\""];
+    default:
+      return [path + "/foo", "unknown discriminator tag"];
+  }
+}
+
+export function isRootDiscriminatorWithDescription(x: unknown): x is RootDiscriminatorWithDescription {
+  return validateRootDiscriminatorWithDescription(x, "") === undefined;
+}
+
+export function parseRootDiscriminatorWithDescription(x: unknown): RootDiscriminatorWithDescription {
+  const violation = validateRootDiscriminatorWithDescription(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootDiscriminatorWithDescription;
+}
+
+function validateRootDiscriminatorWithDescriptionBar(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "bar") {
+    return [path + "/foo", "expected \"bar\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return undefined;
+}
+
+export function isRootDiscriminatorWithDescriptionBar(x: unknown): x is RootDiscriminatorWithDescriptionBar {
+  return validateRootDiscriminatorWithDescriptionBar(x, "") === undefined;
+}
+
+export function parseRootDiscriminatorWithDescriptionBar(x: unknown): RootDiscriminatorWithDescriptionBar {
+  const violation = validateRootDiscriminatorWithDescriptionBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootDiscriminatorWithDescriptionBar;
+}
+
+function validateRootEnumWithDescription(x: unknown, path: string): Violation | undefined {
+  switch (x) {
+    case "X":
+    case "Y":
+    case "Z":
+      return undefined;
+    default:
+      return [path, "unknown enum value"];
+  }
+}
+
+export function isRootEnumWithDescription(x: unknown): x is RootEnumWithDescription {
+  return validateRootEnumWithDescription(x, "") === undefined;
+}
+
+export function parseRootEnumWithDescription(x: unknown): RootEnumWithDescription {
+  const violation = validateRootEnumWithDescription(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootEnumWithDescription;
+}
+
+function validateRootPropertiesWithDescription(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return undefined;
+}
+
+export function isRootPropertiesWithDescription(x: unknown): x is RootPropertiesWithDescription {
+  return validateRootPropertiesWithDescription(x, "") === undefined;
+}
+
+export function parseRootPropertiesWithDescription(x: unknown): RootPropertiesWithDescription {
+  const violation = validateRootPropertiesWithDescription(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootPropertiesWithDescription;
+}
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "discriminator_with_description")) {
+    return [path, "missing property \"discriminator_with_description\""];
+  }
+
+  if (!hasProperty(x, "enum_with_description")) {
+    return [path, "missing property \"enum_with_description\""];
+  }
+
+  if (!hasProperty(x, "long_description")) {
+    return [path, "missing property \"long_description\""];
+  }
+
+  if (!hasProperty(x, "properties_with_description")) {
+    return [path, "missing property \"properties_with_description\""];
+  }
+
+  if (!hasProperty(x, "ref_with_description")) {
+    return [path, "missing property \"ref_with_description\""];
+  }
+
+  if (!hasProperty(x, "string_with_description")) {
+    return [path, "missing property \"string_with_description\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "discriminator_with_description":
+      case "enum_with_description":
+      case "long_description":
+      case "properties_with_description":
+      case "ref_with_description":
+      case "string_with_description":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    validateRootDiscriminatorWithDescription(x["discriminator_with_description"], path + "/discriminator_with_description") ||
+    validateRootEnumWithDescription(x["enum_with_description"], path + "/enum_with_description") ||
+    checkString(x["long_description"], path + "/long_description") ||
+    validateRootPropertiesWithDescription(x["properties_with_description"], path + "/properties_with_description") ||
+    validateBaz(x["ref_with_description"], path + "/ref_with_description") ||
+    checkString(x["string_with_description"], path + "/string_with_description")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateBaz(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isBaz(x: unknown): x is Baz {
+  return validateBaz(x, "") === undefined;
+}
+
+export function parseBaz(x: unknown): Baz {
+  const violation = validateBaz(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Baz;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
@@ -0,0 +1,134 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = RootBar;
+
+export interface RootBar {
+  foo: "bar";
+  baz?: string[];
+  quux?: boolean;
+}
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "foo") ? x["foo"] : undefined) {
+    case "bar":
+      return validateRootBar(x, path);
+    case undefined:
+      return [path, "missing property \"foo\""];
+    default:
+      return [path + "/foo", "unknown discriminator tag"];
+  }
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateRootBar(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "bar") {
+    return [path + "/foo", "expected \"bar\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "baz":
+      case "quux":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    (hasProperty(x, "baz") ? checkArray(x["baz"], path + "/baz", checkString) : undefined) ||
+    (hasProperty(x, "quux") ? checkBoolean(x["quux"], path + "/quux") : undefined)
+  );
+}
+
+export function isRootBar(x: unknown): x is RootBar {
+  return validateRootBar(x, "") === undefined;
+}
+
+export function parseRootBar(x: unknown): RootBar {
+  const violation = validateRootBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootBar;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkBoolean(x: unknown, path: string): Violation | undefined {
+  return typeof x === "boolean" ? undefined : [path, "expected boolean"];
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
+
+function checkArray(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!Array.isArray(x)) {
+    return [path, "expected array"];
+  }
+
+  for (let i = 0; i < x.length; i++) {
+    const violation = check(x[i], path + "/" + i);
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}
@@ -0,0 +1,195 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = string;
+
+export type DefaultName = string;
+
+export type Foo = string;
+
+export type Foo0 = string;
+
+export type Foo1 = string;
+
+export type FooBar = string;
+
+export type FooBar0 = string;
+
+export type Foo0bar = string;
+
+export type FooBar1 = string;
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateDefaultName(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isDefaultName(x: unknown): x is DefaultName {
+  return validateDefaultName(x, "") === undefined;
+}
+
+export function parseDefaultName(x: unknown): DefaultName {
+  const violation = validateDefaultName(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as DefaultName;
+}
+
+function validateFoo(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFoo(x: unknown): x is Foo {
+  return validateFoo(x, "") === undefined;
+}
+
+export function parseFoo(x: unknown): Foo {
+  const violation = validateFoo(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo;
+}
+
+function validateFoo0(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFoo0(x: unknown): x is Foo0 {
+  return validateFoo0(x, "") === undefined;
+}
+
+export function parseFoo0(x: unknown): Foo0 {
+  const violation = validateFoo0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo0;
+}
+
+function validateFoo1(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFoo1(x: unknown): x is Foo1 {
+  return validateFoo1(x, "") === undefined;
+}
+
+export function parseFoo1(x: unknown): Foo1 {
+  const violation = validateFoo1(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo1;
+}
+
+function validateFooBar(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFooBar(x: unknown): x is FooBar {
+  return validateFooBar(x, "") === undefined;
+}
+
+export function parseFooBar(x: unknown): FooBar {
+  const violation = validateFooBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as FooBar;
+}
+
+function validateFooBar0(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFooBar0(x: unknown): x is FooBar0 {
+  return validateFooBar0(x, "") === undefined;
+}
+
+export function parseFooBar0(x: unknown): FooBar0 {
+  const violation = validateFooBar0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as FooBar0;
+}
+
+function validateFoo0bar(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFoo0bar(x: unknown): x is Foo0bar {
+  return validateFoo0bar(x, "") === undefined;
+}
+
+export function parseFoo0bar(x: unknown): Foo0bar {
+  const violation = validateFoo0bar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo0bar;
+}
+
+function validateFooBar1(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFooBar1(x: unknown): x is FooBar1 {
+  return validateFooBar1(x, "") === undefined;
+}
+
+export function parseFooBar1(x: unknown): FooBar1 {
+  const violation = validateFooBar1(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as FooBar1;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
@@ -0,0 +1,802 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = GeojsonObject;
+
+/**
+ * A GeoJSON object MAY have a member named "bbox" to include information
+ * on the coordinate range for its Geometries, Features, or
+ * FeatureCollections.  The value of the bbox member MUST be an array of
+ * length 2*n where n is the number of dimensions represented in the
+ * contained geometries, with all axes of the most southwesterly point
+ * followed by all axes of the more northeasterly point.  The axes order of
+ * a bbox follows the axes order of geometries.
+ * 
+ * The "bbox" values define shapes with edges that follow lines of constant
+ * longitude, latitude, and elevation.
+ * 
+ * The four lines of the bounding box are defined fully within the
+ * coordinate reference system; that is, for a box bounded by the values
+ * "west", "south", "east", and "north", every point on the northernmost
+ * line can be expressed as
+ * 
+ * (lon, lat) = (west + (east - west) * t, north)
+ * 
+ * with 0 <= t <= 1.
+ * 
+ * Consider a set of point Features within the Fiji archipelago, straddling
+ * the antimeridian between 16 degrees S and 20 degrees S. The southwest
+ * corner of the box containing these Features is at 20 degrees S and 177
+ * degrees E, and the northwest corner is at 16 degrees S and 178 degrees
+ * W.  The antimeridian-spanning GeoJSON bounding box for this
+ * FeatureCollection is
+ * 
+ * "bbox": [177.0, -20.0, -178.0, -16.0]
+ * 
+ * and covers 5 degrees of longitude.
+ * 
+ * The complementary bounding box for the same latitude band, not crossing
+ * the antimeridian, is
+ * 
+ * "bbox": [-178.0, -20.0, 177.0, -16.0]
+ * 
+ * and covers 355 degrees of longitude.
+ * 
+ * The latitude of the northeast corner is always greater than the latitude
+ * of the southwest corner, but bounding boxes that cross the antimeridian
+ * have a northeast corner longitude that is less than the longitude of the
+ * southwest corner.
+ * 
+ * A bounding box that contains the North Pole extends from a southwest
+ * corner of "minlat" degrees N, 180 degrees W to a northeast corner of 90
+ * degrees N, 180 degrees E.  Viewed on a globe, this bounding box
+ * approximates a spherical cap bounded by the "minlat" circle of latitude.
+ * 
+ * "bbox": [-180.0, minlat, 180.0, 90.0]
+ * 
+ * A bounding box that contains the South Pole extends from a southwest
+ * corner of 90 degrees S, 180 degrees W to a northeast corner of "maxlat"
+ * degrees S, 180 degrees E.
+ * 
+ * "bbox": [-180.0, -90.0, 180.0, maxlat]
+ * 
+ * A bounding box that just touches the North Pole and forms a slice of an
+ * approximate spherical cap when viewed on a globe extends from a
+ * southwest corner of "minlat" degrees N and "westlon" degrees E to a
+ * northeast corner of 90 degrees N and "eastlon" degrees E.
+ * 
+ * "bbox": [westlon, minlat, eastlon, 90.0]
+ * 
+ * Similarly, a bounding box that just touches the South Pole and forms a
+ * slice of an approximate spherical cap when viewed on a globe has the
+ * following representation in GeoJSON.
+ * 
+ * "bbox": [westlon, -90.0, eastlon, maxlat]
+ * 
+ * Implementers MUST NOT use latitude values greater than 90 or less than
+ * -90 to imply an extent that is not a spherical cap.
+ */
+export type BoundingBox = number[];
+
+/**
+ * A Geometry object represents points, curves, and surfaces in coordinate
+ * space.  Every Geometry object is a GeoJSON object no matter where it
+ * occurs in a GeoJSON text.
+ * 
+ * o  The value of a Geometry object's "type" member MUST be one of the
+ *     seven geometry types (see Section 1.4).
+ * 
+ * o  A GeoJSON Geometry object of any type other than
+ *     "GeometryCollection" has a member with the name "coordinates". The
+ *     value of the "coordinates" member is an array.  The structure of the
+ *     elements in this array is determined by the type of geometry.
+ *     GeoJSON processors MAY interpret Geometry objects with empty
+ *     "coordinates" arrays as null objects.
+ */
+export type GeojsonObject = GeojsonObjectFeature | GeojsonObjectFeatureCollection | GeojsonObjectGeometryCollection | GeojsonObjectLineString | GeojsonObjectMultiLineString | GeojsonObjectMultiPoint | GeojsonObjectMultiPolygon | GeojsonObjectPoint | GeojsonObjectPolygon;
+
+/**
+ * A Feature object represents a spatially bounded thing.  Every
+ * Feature object is a GeoJSON object no matter where it occurs in a
+ * GeoJSON text.
+ * 
+ * o  A Feature object has a "type" member with the value "Feature".
+ * 
+ * o  A Feature object has a member with the name "geometry".  The
+ *     value of the geometry member SHALL be either a Geometry object
+ *     as defined above or, in the case that the Feature is unlocated,
+ *     a JSON null value.
+ * 
+ * o  A Feature object has a member with the name "properties".  The
+ *     value of the properties member is an object (any JSON object or
+ *     a JSON null value).
+ */
+export interface GeojsonObjectFeature {
+  type: "Feature";
+
+  /**
+   * The GeoJSON specification requires that these elements be
+   * GeoJSON geometry objects, but such a constraint can't be
+   * expressed in JSON Type Definition.
+   * 
+   * It is semantically invalid at the GeoJSON level for this
+   * member to be any GeoJSON object type other than one of the
+   * geometry types.
+   */
+  geometry: (GeojsonObject | null);
+  properties: { [key: string]: any };
+  id?: any;
+}
+
+/**
+ * A GeoJSON object with the type "FeatureCollection" is a
+ * FeatureCollection object.  A FeatureCollection object has a member
+ * with the name "features".  The value of "features" is a JSON array.
+ * Each element of the array is a Feature object as defined above.  It
+ * is possible for this array to be empty.
+ */
+export interface GeojsonObjectFeatureCollection {
+  type: "FeatureCollection";
+  features: GeojsonObject[];
+}
+
+/**
+ * A GeoJSON object with type "GeometryCollection" is a Geometry
+ * object. A GeometryCollection has a member with the name
+ * "geometries".  The value of "geometries" is an array.  Each element
+ * of this array is a GeoJSON Geometry object.  It is possible for this
+ * array to be empty.
+ * 
+ * Unlike the other geometry types described above, a
+ * GeometryCollection can be a heterogeneous composition of smaller
+ * Geometry objects.  For example, a Geometry object in the shape of a
+ * lowercase roman "i" can be composed of one point and one LineString.
+ * 
+ * GeometryCollections have a different syntax from single type
+ * Geometry objects (Point, LineString, and Polygon) and homogeneously
+ * typed multipart Geometry objects (MultiPoint, MultiLineString, and
+ * MultiPolygon) but have no different semantics.  Although a
+ * GeometryCollection object has no "coordinates" member, it does have
+ * coordinates: the coordinates of all its parts belong to the
+ * collection.  The "geometries" member of a GeometryCollection
+ * describes the parts of this composition.  Implementations SHOULD NOT
+ * apply any additional semantics to the "geometries" array.
+ * 
+ * To maximize interoperability, implementations SHOULD avoid nested
+ * GeometryCollections.  Furthermore, GeometryCollections composed of a
+ * single part or a number of parts of a single type SHOULD be avoided
+ * when that single part or a single object of multipart type
+ * (MultiPoint, MultiLineString, or MultiPolygon) could be used
+ * instead.
+ */
+export interface GeojsonObjectGeometryCollection {
+  type: "GeometryCollection";
+  geometries: GeojsonObject[];
+  bbox?: BoundingBox;
+}
+
+/**
+ * For type "LineString", the "coordinates" member is an array of two
+ * or more positions.
+ */
+export interface GeojsonObjectLineString {
+  type: "LineString";
+  coordinates: Position[];
+  bbox?: BoundingBox;
+}
+
+/**
+ * For type "MultiLineString", the "coordinates" member is an array of
+ * LineString coordinate arrays.
+ */
+export interface GeojsonObjectMultiLineString {
+  type: "MultiLineString";
+  coordinates: Position[];
+  bbox?: BoundingBox;
+}
+
+/**
+ * For type "MultiPoint", the "coordinates" member is an array of
+ * positions.
+ */
+export interface GeojsonObjectMultiPoint {
+  type: "MultiPoint";
+  coordinates: Position[];
+  bbox?: BoundingBox;
+}
+
+/**
+ * For type "MultiPolygon", the "coordinates" member is an array of
+ * Polygon coordinate arrays.
+ */
+export interface GeojsonObjectMultiPolygon {
+  type: "MultiPolygon";
+  coordinates: LinearRing[];
+  bbox?: BoundingBox;
+}
+
+/**
+ * For type "Point", the "coordinates" member is a single position.
+ */
+export interface GeojsonObjectPoint {
+  type: "Point";
+  coordinates: Position;
+  bbox?: BoundingBox;
+}
+
+/**
+ * For type "Polygon", the "coordinates" member MUST be an array of
+ * linear ring coordinate arrays.
+ */
+export interface GeojsonObjectPolygon {
+  type: "Polygon";
+  coordinates: LinearRing[];
+  bbox?: BoundingBox;
+}
+
+/**
+ * To specify a constraint specific to Polygons, it is useful to
+ * introduce the concept of a linear ring:
+ * 
+ * o  A linear ring is a closed LineString with four or more positions.
+ * 
+ * o  The first and last positions are equivalent, and they MUST contain
+ *     identical values; their representation SHOULD also be identical.
+ * 
+ * o  A linear ring is the boundary of a surface or the boundary of a
+ *     hole in a surface.
+ * 
+ * o  A linear ring MUST follow the right-hand rule with respect to the
+ *     area it bounds, i.e., exterior rings are counterclockwise, and holes
+ *     are clockwise.
+ * 
+ * Note: the [GJ2008] specification did not discuss linear ring winding
+ * order.  For backwards compatibility, parsers SHOULD NOT reject Polygons
+ * that do not follow the right-hand rule.
+ * 
+ * Though a linear ring is not explicitly represented as a GeoJSON geometry
+ * type, it leads to a canonical formulation of the Polygon geometry type
+ * definition as follows:
+ * 
+ * For Polygons with more than one of these rings, the first MUST be the
+ * exterior ring, and any others MUST be interior rings.  The exterior ring
+ * bounds the surface, and the interior rings (if present) bound holes
+ * within the surface.
+ */
+export type LinearRing = Position[];
+
+/**
+ * A position is the fundamental geometry construct.
+ * 
+ * A position is an array of numbers.  There MUST be two or more elements.
+ * The first two elements are longitude and latitude, or easting and
+ * northing, precisely in that order and using decimal numbers.  Altitude
+ * or elevation MAY be included as an optional third element.
+ * 
+ * Implementations SHOULD NOT extend positions beyond three elements
+ * because the semantics of extra elements are unspecified and ambiguous.
+ * Historically, some implementations have used a fourth element to carry a
+ * linear referencing measure (sometimes denoted as "M") or a numerical
+ * timestamp, but in most situations a parser will not be able to properly
+ * interpret these values.  The interpretation and meaning of additional
+ * elements is beyond the scope of this specification, and additional
+ * elements MAY be ignored by parsers.
+ * 
+ * A line between two positions is a straight Cartesian line, the shortest
+ * line between those two points in the coordinate reference system (see
+ * Section 4).
+ * 
+ * In other words, every point on a line that does not cross the
+ * antimeridian between a point (lon0, lat0) and (lon1, lat1) can be
+ * calculated as
+ * 
+ * F(lon, lat) = (lon0 + (lon1 - lon0) * t, lat0 + (lat1 - lat0) * t)
+ * 
+ * with t being a real number greater than or equal to 0 and smaller than
+ * or equal to 1.  Note that this line may markedly differ from the
+ * geodesic path along the curved surface of the reference ellipsoid.
+ * 
+ * The same applies to the optional height element with the proviso that
+ * the direction of the height is as specified in the coordinate reference
+ * system.
+ * 
+ * Note that, again, this does not mean that a surface with equal height
+ * follows, for example, the curvature of a body of water.  Nor is a
+ * surface of equal height perpendicular to a plumb line.
+ */
+export type Position = number[];
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  return validateGeojsonObject(x, path);
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateBoundingBox(x: unknown, path: string): Violation | undefined {
+  return checkArray(x, path, checkNumber);
+}
+
+export function isBoundingBox(x: unknown): x is BoundingBox {
+  return validateBoundingBox(x, "") === undefined;
+}
+
+export function parseBoundingBox(x: unknown): BoundingBox {
+  const violation = validateBoundingBox(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as BoundingBox;
+}
+
+function validateGeojsonObject(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "type") ? x["type"] : undefined) {
+    case "Feature":
+      return validateGeojsonObjectFeature(x, path);
+    case "FeatureCollection":
+      return validateGeojsonObjectFeatureCollection(x, path);
+    case "GeometryCollection":
+      return validateGeojsonObjectGeometryCollection(x, path);
+    case "LineString":
+      return validateGeojsonObjectLineString(x, path);
+    case "MultiLineString":
+      return validateGeojsonObjectMultiLineString(x, path);
+    case "MultiPoint":
+      return validateGeojsonObjectMultiPoint(x, path);
+    case "MultiPolygon":
+      return validateGeojsonObjectMultiPolygon(x, path);
+    case "Point":
+      return validateGeojsonObjectPoint(x, path);
+    case "Polygon":
+      return validateGeojsonObjectPolygon(x, path);
+    case undefined:
+      return [path, "missing property \"type\""];
+    default:
+      return [path + "/type", "unknown discriminator tag"];
+  }
+}
+
+export function isGeojsonObject(x: unknown): x is GeojsonObject {
+  return validateGeojsonObject(x, "") === undefined;
+}
+
+export function parseGeojsonObject(x: unknown): GeojsonObject {
+  const violation = validateGeojsonObject(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObject;
+}
+
+function validateGeojsonObjectFeature(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "Feature") {
+    return [path + "/type", "expected \"Feature\""];
+  }
+
+  if (!hasProperty(x, "geometry")) {
+    return [path, "missing property \"geometry\""];
+  }
+
+  if (!hasProperty(x, "properties")) {
+    return [path, "missing property \"properties\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "type":
+      case "geometry":
+      case "properties":
+      case "id":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    (x["geometry"] === null ? undefined : validateGeojsonObject(x["geometry"], path + "/geometry")) ||
+    checkDict(x["properties"], path + "/properties", () => undefined)
+  );
+}
+
+export function isGeojsonObjectFeature(x: unknown): x is GeojsonObjectFeature {
+  return validateGeojsonObjectFeature(x, "") === undefined;
+}
+
+export function parseGeojsonObjectFeature(x: unknown): GeojsonObjectFeature {
+  const violation = validateGeojsonObjectFeature(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectFeature;
+}
+
+function validateGeojsonObjectFeatureCollection(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "FeatureCollection") {
+    return [path + "/type", "expected \"FeatureCollection\""];
+  }
+
+  if (!hasProperty(x, "features")) {
+    return [path, "missing property \"features\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "type":
+      case "features":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkArray(x["features"], path + "/features", validateGeojsonObject);
+}
+
+export function isGeojsonObjectFeatureCollection(x: unknown): x is GeojsonObjectFeatureCollection {
+  return validateGeojsonObjectFeatureCollection(x, "") === undefined;
+}
+
+export function parseGeojsonObjectFeatureCollection(x: unknown): GeojsonObjectFeatureCollection {
+  const violation = validateGeojsonObjectFeatureCollection(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectFeatureCollection;
+}
+
+function validateGeojsonObjectGeometryCollection(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "GeometryCollection") {
+    return [path + "/type", "expected \"GeometryCollection\""];
+  }
+
+  if (!hasProperty(x, "geometries")) {
+    return [path, "missing property \"geometries\""];
+  }
+
+  return (
+    checkArray(x["geometries"], path + "/geometries", validateGeojsonObject) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectGeometryCollection(x: unknown): x is GeojsonObjectGeometryCollection {
+  return validateGeojsonObjectGeometryCollection(x, "") === undefined;
+}
+
+export function parseGeojsonObjectGeometryCollection(x: unknown): GeojsonObjectGeometryCollection {
+  const violation = validateGeojsonObjectGeometryCollection(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectGeometryCollection;
+}
+
+function validateGeojsonObjectLineString(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "LineString") {
+    return [path + "/type", "expected \"LineString\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    checkArray(x["coordinates"], path + "/coordinates", validatePosition) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectLineString(x: unknown): x is GeojsonObjectLineString {
+  return validateGeojsonObjectLineString(x, "") === undefined;
+}
+
+export function parseGeojsonObjectLineString(x: unknown): GeojsonObjectLineString {
+  const violation = validateGeojsonObjectLineString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectLineString;
+}
+
+function validateGeojsonObjectMultiLineString(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "MultiLineString") {
+    return [path + "/type", "expected \"MultiLineString\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    checkArray(x["coordinates"], path + "/coordinates", validatePosition) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectMultiLineString(x: unknown): x is GeojsonObjectMultiLineString {
+  return validateGeojsonObjectMultiLineString(x, "") === undefined;
+}
+
+export function parseGeojsonObjectMultiLineString(x: unknown): GeojsonObjectMultiLineString {
+  const violation = validateGeojsonObjectMultiLineString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectMultiLineString;
+}
+
+function validateGeojsonObjectMultiPoint(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "MultiPoint") {
+    return [path + "/type", "expected \"MultiPoint\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    checkArray(x["coordinates"], path + "/coordinates", validatePosition) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectMultiPoint(x: unknown): x is GeojsonObjectMultiPoint {
+  return validateGeojsonObjectMultiPoint(x, "") === undefined;
+}
+
+export function parseGeojsonObjectMultiPoint(x: unknown): GeojsonObjectMultiPoint {
+  const violation = validateGeojsonObjectMultiPoint(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectMultiPoint;
+}
+
+function validateGeojsonObjectMultiPolygon(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "MultiPolygon") {
+    return [path + "/type", "expected \"MultiPolygon\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    checkArray(x["coordinates"], path + "/coordinates", validateLinearRing) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectMultiPolygon(x: unknown): x is GeojsonObjectMultiPolygon {
+  return validateGeojsonObjectMultiPolygon(x, "") === undefined;
+}
+
+export function parseGeojsonObjectMultiPolygon(x: unknown): GeojsonObjectMultiPolygon {
+  const violation = validateGeojsonObjectMultiPolygon(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectMultiPolygon;
+}
+
+function validateGeojsonObjectPoint(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "Point") {
+    return [path + "/type", "expected \"Point\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    validatePosition(x["coordinates"], path + "/coordinates") ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectPoint(x: unknown): x is GeojsonObjectPoint {
+  return validateGeojsonObjectPoint(x, "") === undefined;
+}
+
+export function parseGeojsonObjectPoint(x: unknown): GeojsonObjectPoint {
+  const violation = validateGeojsonObjectPoint(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectPoint;
+}
+
+function validateGeojsonObjectPolygon(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "type") || x["type"] !== "Polygon") {
+    return [path + "/type", "expected \"Polygon\""];
+  }
+
+  if (!hasProperty(x, "coordinates")) {
+    return [path, "missing property \"coordinates\""];
+  }
+
+  return (
+    checkArray(x["coordinates"], path + "/coordinates", validateLinearRing) ||
+    (hasProperty(x, "bbox") ? validateBoundingBox(x["bbox"], path + "/bbox") : undefined)
+  );
+}
+
+export function isGeojsonObjectPolygon(x: unknown): x is GeojsonObjectPolygon {
+  return validateGeojsonObjectPolygon(x, "") === undefined;
+}
+
+export function parseGeojsonObjectPolygon(x: unknown): GeojsonObjectPolygon {
+  const violation = validateGeojsonObjectPolygon(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as GeojsonObjectPolygon;
+}
+
+function validateLinearRing(x: unknown, path: string): Violation | undefined {
+  return checkArray(x, path, validatePosition);
+}
+
+export function isLinearRing(x: unknown): x is LinearRing {
+  return validateLinearRing(x, "") === undefined;
+}
+
+export function parseLinearRing(x: unknown): LinearRing {
+  const violation = validateLinearRing(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as LinearRing;
+}
+
+function validatePosition(x: unknown, path: string): Violation | undefined {
+  return checkArray(x, path, checkNumber);
+}
+
+export function isPosition(x: unknown): x is Position {
+  return validatePosition(x, "") === undefined;
+}
+
+export function parsePosition(x: unknown): Position {
+  const violation = validatePosition(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Position;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkNumber(x: unknown, path: string): Violation | undefined {
+  return typeof x === "number" ? undefined : [path, "expected number"];
+}
+
+function checkArray(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!Array.isArray(x)) {
+    return [path, "expected array"];
+  }
+
+  for (let i = 0; i < x.length; i++) {
+    const violation = check(x[i], path + "/" + i);
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}
+
+function checkDict(
+  x: unknown,
+  path: string,
+  check: (x: unknown, path: string) => Violation | undefined,
+): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  for (const key of Object.keys(x)) {
+    const violation = check(x[key], path + "/" + escapePointer(key));
+    if (violation !== undefined) {
+      return violation;
+    }
+  }
+
+  return undefined;
+}
@@ -0,0 +1,158 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface RootNestedIdInitialism {
+  json: string;
+  normalword: string;
+}
+
+export interface Root {
+  http: string;
+  id: string;
+  nested_id_initialism: RootNestedIdInitialism;
+  utf8: string;
+  word_with_embedded_id_initialism: string;
+  word_with_trailing_initialism_id: string;
+}
+
+function validateRootNestedIdInitialism(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "json")) {
+    return [path, "missing property \"json\""];
+  }
+
+  if (!hasProperty(x, "normalword")) {
+    return [path, "missing property \"normalword\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "json":
+      case "normalword":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkString(x["json"], path + "/json") ||
+    checkString(x["normalword"], path + "/normalword")
+  );
+}
+
+export function isRootNestedIdInitialism(x: unknown): x is RootNestedIdInitialism {
+  return validateRootNestedIdInitialism(x, "") === undefined;
+}
+
+export function parseRootNestedIdInitialism(x: unknown): RootNestedIdInitialism {
+  const violation = validateRootNestedIdInitialism(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootNestedIdInitialism;
+}
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "http")) {
+    return [path, "missing property \"http\""];
+  }
+
+  if (!hasProperty(x, "id")) {
+    return [path, "missing property \"id\""];
+  }
+
+  if (!hasProperty(x, "nested_id_initialism")) {
+    return [path, "missing property \"nested_id_initialism\""];
+  }
+
+  if (!hasProperty(x, "utf8")) {
+    return [path, "missing property \"utf8\""];
+  }
+
+  if (!hasProperty(x, "word_with_embedded_id_initialism")) {
+    return [path, "missing property \"word_with_embedded_id_initialism\""];
+  }
+
+  if (!hasProperty(x, "word_with_trailing_initialism_id")) {
+    return [path, "missing property \"word_with_trailing_initialism_id\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "http":
+      case "id":
+      case "nested_id_initialism":
+      case "utf8":
+      case "word_with_embedded_id_initialism":
+      case "word_with_trailing_initialism_id":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    checkString(x["http"], path + "/http") ||
+    checkString(x["id"], path + "/id") ||
+    validateRootNestedIdInitialism(x["nested_id_initialism"], path + "/nested_id_initialism") ||
+    checkString(x["utf8"], path + "/utf8") ||
+    checkString(x["word_with_embedded_id_initialism"], path + "/word_with_embedded_id_initialism") ||
+    checkString(x["word_with_trailing_initialism_id"], path + "/word_with_trailing_initialism_id")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
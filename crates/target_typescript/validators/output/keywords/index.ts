@@ -0,0 +1,121 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  for: For;
+  object: Object;
+}
+
+export type For = string;
+
+export type Object = string;
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "for")) {
+    return [path, "missing property \"for\""];
+  }
+
+  if (!hasProperty(x, "object")) {
+    return [path, "missing property \"object\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "for":
+      case "object":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    validateFor(x["for"], path + "/for") ||
+    validateObject(x["object"], path + "/object")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateFor(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isFor(x: unknown): x is For {
+  return validateFor(x, "") === undefined;
+}
+
+export function parseFor(x: unknown): For {
+  const violation = validateFor(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as For;
+}
+
+function validateObject(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isObject(x: unknown): x is Object {
+  return validateObject(x, "") === undefined;
+}
+
+export function parseObject(x: unknown): Object {
+  const violation = validateObject(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Object;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
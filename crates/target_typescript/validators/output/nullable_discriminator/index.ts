@@ -0,0 +1,175 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root0 = RootBar | RootQuux;
+
+export interface RootBar {
+  foo: "bar";
+  baz: string;
+}
+
+export interface RootQuux {
+  foo: "quux";
+  quuz: string;
+}
+
+export type Root = (Root0 | null);
+
+function validateRoot0(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  switch (hasProperty(x, "foo") ? x["foo"] : undefined) {
+    case "bar":
+      return validateRootBar(x, path);
+    case "quux":
+      return validateRootQuux(x, path);
+    case undefined:
+      return [path, "missing property \"foo\""];
+    default:
+      return [path + "/foo", "unknown discriminator tag"];
+  }
+}
+
+export function isRoot0(x: unknown): x is Root0 {
+  return validateRoot0(x, "") === undefined;
+}
+
+export function parseRoot0(x: unknown): Root0 {
+  const violation = validateRoot0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root0;
+}
+
+function validateRootBar(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "bar") {
+    return [path + "/foo", "expected \"bar\""];
+  }
+
+  if (!hasProperty(x, "baz")) {
+    return [path, "missing property \"baz\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "baz":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkString(x["baz"], path + "/baz");
+}
+
+export function isRootBar(x: unknown): x is RootBar {
+  return validateRootBar(x, "") === undefined;
+}
+
+export function parseRootBar(x: unknown): RootBar {
+  const violation = validateRootBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootBar;
+}
+
+function validateRootQuux(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo") || x["foo"] !== "quux") {
+    return [path + "/foo", "expected \"quux\""];
+  }
+
+  if (!hasProperty(x, "quuz")) {
+    return [path, "missing property \"quuz\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "quuz":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkString(x["quuz"], path + "/quuz");
+}
+
+export function isRootQuux(x: unknown): x is RootQuux {
+  return validateRootQuux(x, "") === undefined;
+}
+
+export function parseRootQuux(x: unknown): RootQuux {
+  const violation = validateRootQuux(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootQuux;
+}
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  return (x === null ? undefined : validateRoot0(x, path));
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
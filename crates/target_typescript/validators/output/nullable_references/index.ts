@@ -0,0 +1,225 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface Root {
+  notnull_ref_notnull_string: NotnullRefNotnullString;
+  notnull_ref_null_string: NotnullRefNullString;
+  notnull_string: NotnullString;
+  null_ref_notnull_string: NullRefNotnullString;
+  null_ref_null_string: NullRefNullString;
+  null_string: NullString;
+}
+
+export type NotnullRefNotnullString = NotnullString;
+
+export type NotnullRefNullString = NullString;
+
+export type NotnullString = string;
+
+export type NullRefNotnullString = (NotnullString | null);
+
+export type NullRefNullString = (NullString | null);
+
+export type NullString = (string | null);
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "notnull_ref_notnull_string")) {
+    return [path, "missing property \"notnull_ref_notnull_string\""];
+  }
+
+  if (!hasProperty(x, "notnull_ref_null_string")) {
+    return [path, "missing property \"notnull_ref_null_string\""];
+  }
+
+  if (!hasProperty(x, "notnull_string")) {
+    return [path, "missing property \"notnull_string\""];
+  }
+
+  if (!hasProperty(x, "null_ref_notnull_string")) {
+    return [path, "missing property \"null_ref_notnull_string\""];
+  }
+
+  if (!hasProperty(x, "null_ref_null_string")) {
+    return [path, "missing property \"null_ref_null_string\""];
+  }
+
+  if (!hasProperty(x, "null_string")) {
+    return [path, "missing property \"null_string\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "notnull_ref_notnull_string":
+      case "notnull_ref_null_string":
+      case "notnull_string":
+      case "null_ref_notnull_string":
+      case "null_ref_null_string":
+      case "null_string":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    validateNotnullRefNotnullString(x["notnull_ref_notnull_string"], path + "/notnull_ref_notnull_string") ||
+    validateNotnullRefNullString(x["notnull_ref_null_string"], path + "/notnull_ref_null_string") ||
+    validateNotnullString(x["notnull_string"], path + "/notnull_string") ||
+    validateNullRefNotnullString(x["null_ref_notnull_string"], path + "/null_ref_notnull_string") ||
+    validateNullRefNullString(x["null_ref_null_string"], path + "/null_ref_null_string") ||
+    validateNullString(x["null_string"], path + "/null_string")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateNotnullRefNotnullString(x: unknown, path: string): Violation | undefined {
+  return validateNotnullString(x, path);
+}
+
+export function isNotnullRefNotnullString(x: unknown): x is NotnullRefNotnullString {
+  return validateNotnullRefNotnullString(x, "") === undefined;
+}
+
+export function parseNotnullRefNotnullString(x: unknown): NotnullRefNotnullString {
+  const violation = validateNotnullRefNotnullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NotnullRefNotnullString;
+}
+
+function validateNotnullRefNullString(x: unknown, path: string): Violation | undefined {
+  return validateNullString(x, path);
+}
+
+export function isNotnullRefNullString(x: unknown): x is NotnullRefNullString {
+  return validateNotnullRefNullString(x, "") === undefined;
+}
+
+export function parseNotnullRefNullString(x: unknown): NotnullRefNullString {
+  const violation = validateNotnullRefNullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NotnullRefNullString;
+}
+
+function validateNotnullString(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isNotnullString(x: unknown): x is NotnullString {
+  return validateNotnullString(x, "") === undefined;
+}
+
+export function parseNotnullString(x: unknown): NotnullString {
+  const violation = validateNotnullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NotnullString;
+}
+
+function validateNullRefNotnullString(x: unknown, path: string): Violation | undefined {
+  return (x === null ? undefined : validateNotnullString(x, path));
+}
+
+export function isNullRefNotnullString(x: unknown): x is NullRefNotnullString {
+  return validateNullRefNotnullString(x, "") === undefined;
+}
+
+export function parseNullRefNotnullString(x: unknown): NullRefNotnullString {
+  const violation = validateNullRefNotnullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NullRefNotnullString;
+}
+
+function validateNullRefNullString(x: unknown, path: string): Violation | undefined {
+  return (x === null ? undefined : validateNullString(x, path));
+}
+
+export function isNullRefNullString(x: unknown): x is NullRefNullString {
+  return validateNullRefNullString(x, "") === undefined;
+}
+
+export function parseNullRefNullString(x: unknown): NullRefNullString {
+  const violation = validateNullRefNullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NullRefNullString;
+}
+
+function validateNullString(x: unknown, path: string): Violation | undefined {
+  return (x === null ? undefined : checkString(x, path));
+}
+
+export function isNullString(x: unknown): x is NullString {
+  return validateNullString(x, "") === undefined;
+}
+
+export function parseNullString(x: unknown): NullString {
+  const violation = validateNullString(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as NullString;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
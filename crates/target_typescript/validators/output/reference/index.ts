@@ -0,0 +1,100 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = Foo;
+
+export type Bar = Baz;
+
+export type Baz = string;
+
+export type Foo = Bar;
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  return validateFoo(x, path);
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+function validateBar(x: unknown, path: string): Violation | undefined {
+  return validateBaz(x, path);
+}
+
+export function isBar(x: unknown): x is Bar {
+  return validateBar(x, "") === undefined;
+}
+
+export function parseBar(x: unknown): Bar {
+  const violation = validateBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Bar;
+}
+
+function validateBaz(x: unknown, path: string): Violation | undefined {
+  return checkString(x, path);
+}
+
+export function isBaz(x: unknown): x is Baz {
+  return validateBaz(x, "") === undefined;
+}
+
+export function parseBaz(x: unknown): Baz {
+  const violation = validateBaz(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Baz;
+}
+
+function validateFoo(x: unknown, path: string): Violation | undefined {
+  return validateBar(x, path);
+}
+
+export function isFoo(x: unknown): x is Foo {
+  return validateFoo(x, "") === undefined;
+}
+
+export function parseFoo(x: unknown): Foo {
+  const violation = validateFoo(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Foo;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
@@ -0,0 +1,47 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = number;
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  return checkInteger(x, path, -32768, 32767);
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function checkInteger(x: unknown, path: string, min: number, max: number): Violation | undefined {
+  if (typeof x === "number" && Math.floor(x) === x && x >= min && x <= max) {
+    return undefined;
+  }
+
+  return [path, "expected integer between " + min + " and " + max];
+}
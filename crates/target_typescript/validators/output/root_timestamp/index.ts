@@ -0,0 +1,60 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export type Root = string;
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  return checkTimestamp(x, path);
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function checkTimestamp(x: unknown, path: string): Violation | undefined {
+  const match = typeof x === "string" &&
+    /^(\d{4})-(\d{2})-(\d{2})[tT](\d{2}):(\d{2}):(\d{2})(\.\d+)?([zZ]|[+-](\d{2}):(\d{2}))$/.exec(x);
+
+  if (match) {
+    const [year, month, day, hour, minute, second] = match.slice(1, 7).map(Number);
+    const leap = year % 4 === 0 && (year % 100 !== 0 || year % 400 === 0);
+    const days = [31, leap ? 29 : 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31][month - 1];
+
+    // RFC 3339 permits leap seconds, so a second of 60 is allowed.
+    if (
+      day >= 1 && day <= days && hour <= 23 && minute <= 59 && second <= 60 &&
+      (match[9] === undefined || (Number(match[9]) <= 23 && Number(match[10]) <= 59))
+    ) {
+      return undefined;
+    }
+  }
+
+  return [path, "expected timestamp"];
+}
@@ -0,0 +1,201 @@
+// Code generated by jtd-codegen for TypeScript v0.2.1
+
+export interface RootFooBar {
+  x: boolean;
+}
+
+export interface RootFoo {
+  bar: RootFooBar;
+}
+
+export interface RootFooBar0 {
+  x: string;
+}
+
+export interface Root {
+  foo: RootFoo;
+  foo_bar: RootFooBar0;
+}
+
+function validateRootFooBar(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "x")) {
+    return [path, "missing property \"x\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "x":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkBoolean(x["x"], path + "/x");
+}
+
+export function isRootFooBar(x: unknown): x is RootFooBar {
+  return validateRootFooBar(x, "") === undefined;
+}
+
+export function parseRootFooBar(x: unknown): RootFooBar {
+  const violation = validateRootFooBar(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootFooBar;
+}
+
+function validateRootFoo(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "bar")) {
+    return [path, "missing property \"bar\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "bar":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return validateRootFooBar(x["bar"], path + "/bar");
+}
+
+export function isRootFoo(x: unknown): x is RootFoo {
+  return validateRootFoo(x, "") === undefined;
+}
+
+export function parseRootFoo(x: unknown): RootFoo {
+  const violation = validateRootFoo(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootFoo;
+}
+
+function validateRootFooBar0(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "x")) {
+    return [path, "missing property \"x\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "x":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return checkString(x["x"], path + "/x");
+}
+
+export function isRootFooBar0(x: unknown): x is RootFooBar0 {
+  return validateRootFooBar0(x, "") === undefined;
+}
+
+export function parseRootFooBar0(x: unknown): RootFooBar0 {
+  const violation = validateRootFooBar0(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as RootFooBar0;
+}
+
+function validateRoot(x: unknown, path: string): Violation | undefined {
+  if (!hasObjectType(x)) {
+    return [path, "expected object"];
+  }
+
+  if (!hasProperty(x, "foo")) {
+    return [path, "missing property \"foo\""];
+  }
+
+  if (!hasProperty(x, "foo_bar")) {
+    return [path, "missing property \"foo_bar\""];
+  }
+
+  for (const key of Object.keys(x)) {
+    switch (key) {
+      case "foo":
+      case "foo_bar":
+        break;
+      default:
+        return [path + "/" + escapePointer(key), "unexpected property"];
+    }
+  }
+
+  return (
+    validateRootFoo(x["foo"], path + "/foo") ||
+    validateRootFooBar0(x["foo_bar"], path + "/foo_bar")
+  );
+}
+
+export function isRoot(x: unknown): x is Root {
+  return validateRoot(x, "") === undefined;
+}
+
+export function parseRoot(x: unknown): Root {
+  const violation = validateRoot(x, "");
+  if (violation !== undefined) {
+    throw new ValidationError(violation[0], violation[1]);
+  }
+
+  return x as Root;
+}
+
+/**
+ * Thrown by parse functions when their input does not match the type they
+ * parse.
+ */
+export class ValidationError extends Error {
+  /**
+   * A JSON Pointer to the part of the input that was invalid.
+   */
+  readonly instancePath: string;
+
+  constructor(instancePath: string, message: string) {
+    super(message + " at " + JSON.stringify(instancePath));
+    this.name = "ValidationError";
+    this.instancePath = instancePath;
+  }
+}
+
+type Violation = [string, string];
+
+function hasObjectType(x: unknown): x is { [key: string]: unknown } {
+  return typeof x === "object" && x !== null && !Array.isArray(x);
+}
+
+function hasProperty(x: object, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(x, key);
+}
+
+function escapePointer(key: string): string {
+  return key.replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+function checkBoolean(x: unknown, path: string): Violation | undefined {
+  return typeof x === "boolean" ? undefined : [path, "expected boolean"];
+}
+
+function checkString(x: unknown, path: string): Violation | undefined {
+  return typeof x === "string" ? undefined : [path, "expected string"];
+}
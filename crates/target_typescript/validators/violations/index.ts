@@ -0,0 +1,22 @@
+// Passes each line of input through the generated parse function, and writes
+// out the path and message of the error it throws, or null if it throws none.
+import { parseMAIN } from "./jtd_codegen_e2e";
+
+declare const process: any;
+declare function require(name: string): any;
+
+const lines = require("readline").createInterface({ input: process.stdin });
+lines.on("line", (line: string) => {
+  try {
+    parseMAIN(JSON.parse(line));
+    console.log(JSON.stringify(null));
+  } catch (err) {
+    // Subclasses of Error aren't instanceof themselves once compiled to ES5,
+    // so errors are told apart by name.
+    if (err.name !== "ValidationError") {
+      throw err;
+    }
+
+    console.log(JSON.stringify([err.instancePath, err.message]));
+  }
+});
@@ -0,0 +1,11 @@
+// Each line of input is parsed with the generated schema, which throws if the
+// input isn't valid, and is then written back out.
+import { MAIN } from "./jtd_codegen_e2e";
+
+declare const process: any;
+declare function require(name: string): any;
+
+const lines = require("readline").createInterface({ input: process.stdin });
+lines.on("line", (line: string) => {
+  console.log(JSON.stringify(MAIN.parse(JSON.parse(line))));
+});
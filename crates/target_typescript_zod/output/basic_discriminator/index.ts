@@ -0,0 +1,18 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const RootBarBaz = z.object({
+  foo: z.literal("BAR_BAZ"),
+  baz: z.string(),
+}).strict();
+export type RootBarBaz = z.infer<typeof RootBarBaz>;
+
+export const RootQuux = z.object({
+  foo: z.literal("QUUX"),
+  quuz: z.string(),
+}).strict();
+export type RootQuux = z.infer<typeof RootQuux>;
+
+export const Root = z.discriminatedUnion("foo", [RootBarBaz, RootQuux]);
+export type Root = z.infer<typeof Root>;
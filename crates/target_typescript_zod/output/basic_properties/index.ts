@@ -0,0 +1,11 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.object({
+  bar: z.string(),
+  baz: z.array(z.boolean()),
+  foo: z.boolean(),
+  quux: z.array(z.boolean()),
+}).strict();
+export type Root = z.infer<typeof Root>;
@@ -0,0 +1,18 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.object({
+  override_elements_container: z.array(z.string()),
+  override_type_discriminator: z.any(),
+  override_type_enum: z.any(),
+  override_type_expr: z.any(),
+  override_type_properties: z.any(),
+  override_values_container: z.record(z.string(), z.string()),
+}).strict();
+export type Root = z.infer<typeof Root>;
+
+export const RootOverrideTypeDiscriminatorBaz = z.object({
+  foo: z.literal("baz"),
+}).strict();
+export type RootOverrideTypeDiscriminatorBaz = z.infer<typeof RootOverrideTypeDiscriminatorBaz>;
@@ -0,0 +1,18 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Bar0 = z.string();
+export type Bar0 = z.infer<typeof Bar0>;
+
+export const Bar = Bar0;
+export type Bar = z.infer<typeof Bar>;
+
+export const Foo = Bar;
+export type Foo = z.infer<typeof Foo>;
+
+export const Foo0 = Foo;
+export type Foo0 = z.infer<typeof Foo0>;
+
+export const Root = Foo0;
+export type Root = z.infer<typeof Root>;
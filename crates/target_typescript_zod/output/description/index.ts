@@ -0,0 +1,87 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+/**
+ * A description for discriminator variant
+ */
+export const RootDiscriminatorWithDescriptionBar = z.object({
+  foo: z.literal("bar"),
+}).strict();
+export type RootDiscriminatorWithDescriptionBar = z.infer<typeof RootDiscriminatorWithDescriptionBar>;
+
+/**
+ * A description for discriminator
+ */
+export const RootDiscriminatorWithDescription = z.discriminatedUnion("foo", [RootDiscriminatorWithDescriptionBar]);
+export type RootDiscriminatorWithDescription = z.infer<typeof RootDiscriminatorWithDescription>;
+
+/**
+ * A description for enum
+ */
+export const RootEnumWithDescription = z.enum([
+  /**
+   * A description for X
+   */
+  "X",
+
+  /**
+   * A description for Y
+   */
+  "Y",
+
+  /**
+   * A description for Z
+   */
+  "Z",
+]);
+export type RootEnumWithDescription = z.infer<typeof RootEnumWithDescription>;
+
+/**
+ * A description for properties
+ */
+export const RootPropertiesWithDescription = z.object({}).strict();
+export type RootPropertiesWithDescription = z.infer<typeof RootPropertiesWithDescription>;
+
+/**
+ * A description for a definition
+ */
+export const Baz = z.string();
+export type Baz = z.infer<typeof Baz>;
+
+export const Root = z.object({
+  /**
+   * A description for discriminator
+   */
+  discriminator_with_description: RootDiscriminatorWithDescription,
+
+  /**
+   * A description for enum
+   */
+  enum_with_description: RootEnumWithDescription,
+
+  /**
+   * Whereas disregard and contempt for human rights have resulted in barbarous
+   * acts which have outraged the conscience of mankind, and the advent of a
+   * world in which human beings shall enjoy freedom of speech and belief and
+   * freedom from fear and want has been proclaimed as the highest aspiration of
+   * the common people,
+   */
+  long_description: z.string(),
+
+  /**
+   * A description for properties
+   */
+  properties_with_description: RootPropertiesWithDescription,
+
+  /**
+   * A description for ref
+   */
+  ref_with_description: Baz,
+
+  /**
+   * A description for string
+   */
+  string_with_description: z.string(),
+}).strict();
+export type Root = z.infer<typeof Root>;
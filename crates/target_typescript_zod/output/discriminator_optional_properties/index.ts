@@ -0,0 +1,13 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const RootBar = z.object({
+  foo: z.literal("bar"),
+  baz: z.array(z.string()).optional(),
+  quux: z.boolean().optional(),
+}).strict();
+export type RootBar = z.infer<typeof RootBar>;
+
+export const Root = z.discriminatedUnion("foo", [RootBar]);
+export type Root = z.infer<typeof Root>;
@@ -0,0 +1,30 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.string();
+export type Root = z.infer<typeof Root>;
+
+export const DefaultName = z.string();
+export type DefaultName = z.infer<typeof DefaultName>;
+
+export const Foo = z.string();
+export type Foo = z.infer<typeof Foo>;
+
+export const Foo0 = z.string();
+export type Foo0 = z.infer<typeof Foo0>;
+
+export const Foo1 = z.string();
+export type Foo1 = z.infer<typeof Foo1>;
+
+export const FooBar = z.string();
+export type FooBar = z.infer<typeof FooBar>;
+
+export const FooBar0 = z.string();
+export type FooBar0 = z.infer<typeof FooBar0>;
+
+export const Foo0bar = z.string();
+export type Foo0bar = z.infer<typeof Foo0bar>;
+
+export const FooBar1 = z.string();
+export type FooBar1 = z.infer<typeof FooBar1>;
@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.enum(["", "$foo", "0foo", "_foo", "foo\nbar", "foo bar", "foo0bar", "foo﷽bar"]);
+export type Root = z.infer<typeof Root>;
@@ -0,0 +1,15 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.object({
+  "": z.string(),
+  $foo: z.string(),
+  "0foo": z.string(),
+  _foo: z.string(),
+  "foo\nbar": z.string(),
+  "foo bar": z.string(),
+  foo0bar: z.string(),
+  "foo﷽bar": z.string(),
+}).strict();
+export type Root = z.infer<typeof Root>;
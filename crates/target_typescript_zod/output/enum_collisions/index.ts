@@ -0,0 +1,20 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const RootFooBar = z.enum(["x", "y"]);
+export type RootFooBar = z.infer<typeof RootFooBar>;
+
+export const RootFoo = z.object({
+  bar: RootFooBar,
+}).strict();
+export type RootFoo = z.infer<typeof RootFoo>;
+
+export const RootFooBar0 = z.enum(["x", "y"]);
+export type RootFooBar0 = z.infer<typeof RootFooBar0>;
+
+export const Root = z.object({
+  foo: RootFoo,
+  foo_bar: RootFooBar0,
+}).strict();
+export type Root = z.infer<typeof Root>;
@@ -0,0 +1,19 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const RootNestedIdInitialism = z.object({
+  json: z.string(),
+  normalword: z.string(),
+}).strict();
+export type RootNestedIdInitialism = z.infer<typeof RootNestedIdInitialism>;
+
+export const Root = z.object({
+  http: z.string(),
+  id: z.string(),
+  nested_id_initialism: RootNestedIdInitialism,
+  utf8: z.string(),
+  word_with_embedded_id_initialism: z.string(),
+  word_with_trailing_initialism_id: z.string(),
+}).strict();
+export type Root = z.infer<typeof Root>;
@@ -0,0 +1,15 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const For = z.string();
+export type For = z.infer<typeof For>;
+
+export const Object = z.string();
+export type Object = z.infer<typeof Object>;
+
+export const Root = z.object({
+  for: For,
+  object: Object,
+}).strict();
+export type Root = z.infer<typeof Root>;
@@ -0,0 +1,21 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const RootBar = z.object({
+  foo: z.literal("bar"),
+  baz: z.string(),
+}).strict();
+export type RootBar = z.infer<typeof RootBar>;
+
+export const RootQuux = z.object({
+  foo: z.literal("quux"),
+  quuz: z.string(),
+}).strict();
+export type RootQuux = z.infer<typeof RootQuux>;
+
+export const Root0 = z.discriminatedUnion("foo", [RootBar, RootQuux]);
+export type Root0 = z.infer<typeof Root0>;
+
+export const Root = Root0.nullable();
+export type Root = z.infer<typeof Root>;
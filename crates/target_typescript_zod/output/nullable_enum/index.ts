@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root0 = z.enum(["Bar", "Baz", "Foo"]);
+export type Root0 = z.infer<typeof Root0>;
+
+export const Root = Root0.nullable();
+export type Root = z.infer<typeof Root>;
@@ -0,0 +1,31 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const NotnullString = z.string();
+export type NotnullString = z.infer<typeof NotnullString>;
+
+export const NotnullRefNotnullString = NotnullString;
+export type NotnullRefNotnullString = z.infer<typeof NotnullRefNotnullString>;
+
+export const NullString = z.string().nullable();
+export type NullString = z.infer<typeof NullString>;
+
+export const NotnullRefNullString = NullString;
+export type NotnullRefNullString = z.infer<typeof NotnullRefNullString>;
+
+export const NullRefNotnullString = NotnullString.nullable();
+export type NullRefNotnullString = z.infer<typeof NullRefNotnullString>;
+
+export const NullRefNullString = NullString.nullable();
+export type NullRefNullString = z.infer<typeof NullRefNullString>;
+
+export const Root = z.object({
+  notnull_ref_notnull_string: NotnullRefNotnullString,
+  notnull_ref_null_string: NotnullRefNullString,
+  notnull_string: NotnullString,
+  null_ref_notnull_string: NullRefNotnullString,
+  null_ref_null_string: NullRefNullString,
+  null_string: NullString,
+}).strict();
+export type Root = z.infer<typeof Root>;
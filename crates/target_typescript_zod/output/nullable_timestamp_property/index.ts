@@ -0,0 +1,8 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.object({
+  foo: z.string().datetime({ offset: true }).nullable(),
+}).strict();
+export type Root = z.infer<typeof Root>;
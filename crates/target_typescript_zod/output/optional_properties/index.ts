@@ -0,0 +1,10 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.object({
+  bar: z.array(z.string()).optional(),
+  baz: z.boolean().optional(),
+  foo: z.string().optional(),
+}).strict();
+export type Root = z.infer<typeof Root>;
@@ -0,0 +1,9 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.object({
+  Foo: z.string(),
+  foo: z.string(),
+}).strict();
+export type Root = z.infer<typeof Root>;
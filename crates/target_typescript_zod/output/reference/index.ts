@@ -0,0 +1,15 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Baz = z.string();
+export type Baz = z.infer<typeof Baz>;
+
+export const Bar = Baz;
+export type Bar = z.infer<typeof Bar>;
+
+export const Foo = Bar;
+export type Foo = z.infer<typeof Foo>;
+
+export const Root = Foo;
+export type Root = z.infer<typeof Root>;
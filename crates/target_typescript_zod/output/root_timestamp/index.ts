@@ -0,0 +1,6 @@
+// Code generated by jtd-codegen for TypeScript + Zod v0.1.0
+
+import { z } from "zod";
+
+export const Root = z.string().datetime({ offset: true });
+export type Root = z.infer<typeof Root>;
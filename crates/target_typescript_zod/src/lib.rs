@@ -0,0 +1,394 @@
+mod schemas;
+
+use jtd_codegen::target::{self, inflect, metadata};
+use jtd_codegen::Result;
+use lazy_static::lazy_static;
+use regex::Regex;
+use schemas::{Decl, Kind, Property, Schema};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+
+lazy_static! {
+    static ref KEYWORDS: BTreeSet<String> = include_str!("keywords")
+        .lines()
+        .map(str::to_owned)
+        .collect();
+    static ref TYPE_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
+        Box::new(inflect::KeywordAvoidingInflector::new(
+            KEYWORDS.clone(),
+            inflect::CombiningInflector::new(inflect::Case::pascal_case())
+        ));
+    static ref ENUM_MEMBER_NAMING_CONVENTION: Box<dyn inflect::Inflector + Send + Sync> =
+        Box::new(inflect::KeywordAvoidingInflector::new(
+            KEYWORDS.clone(),
+            inflect::TailInflector::new(inflect::Case::pascal_case())
+        ));
+}
+
+// Marks a schema that an item's typescriptZodType overrode, as opposed to the
+// name of a generated schema.
+const OVERRIDE: char = '\u{1}';
+
+pub struct Target {}
+
+impl Target {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Default)]
+pub struct FileState {
+    // The schemas of expressions. Expressions are placeholders for an entry in
+    // this list.
+    exprs: Vec<Schema>,
+
+    // Zod schemas are values, so unlike TypeScript types they can't be used
+    // before they're declared. Declarations are written out once they're all
+    // known, in an order that respects that.
+    decls: Vec<Decl>,
+}
+
+impl FileState {
+    fn register(&mut self, schema: Schema) -> String {
+        self.exprs.push(schema);
+        format!("\0{}", self.exprs.len() - 1)
+    }
+
+    // The schema for an expression or the name of a schema.
+    fn schema(&self, s: &str) -> Schema {
+        if let Some(schema) = s
+            .strip_prefix('\0')
+            .and_then(|index| index.parse::<usize>().ok())
+            .and_then(|index| self.exprs.get(index))
+        {
+            return schema.clone();
+        }
+
+        if let Some(schema) = s.strip_prefix(OVERRIDE) {
+            return Schema::Override(schema.to_owned());
+        }
+
+        Schema::Named(s.to_owned())
+    }
+
+    fn properties(&self, fields: &[target::Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| Property {
+                key: format_property(field.json_name.clone()),
+                description: description(&field.metadata, 1),
+                optional: field.optional,
+                schema: self.schema(&field.type_),
+            })
+            .collect()
+    }
+}
+
+impl jtd_codegen::target::Target for Target {
+    type FileState = FileState;
+
+    fn strategy(&self) -> target::Strategy {
+        target::Strategy {
+            file_partitioning: target::FilePartitioningStrategy::SingleFile("index.ts".into()),
+            enum_member_naming: target::EnumMemberNamingStrategy::Modularized,
+            optional_property_handling: target::OptionalPropertyHandlingStrategy::NativeSupport,
+            booleans_are_nullable: false,
+            int8s_are_nullable: false,
+            uint8s_are_nullable: false,
+            int16s_are_nullable: false,
+            uint16s_are_nullable: false,
+            int32s_are_nullable: false,
+            uint32s_are_nullable: false,
+            float32s_are_nullable: false,
+            float64s_are_nullable: false,
+            strings_are_nullable: false,
+            timestamps_are_nullable: false,
+            arrays_are_nullable: false,
+            dicts_are_nullable: false,
+            aliases_are_nullable: false,
+            enums_are_nullable: false,
+            structs_are_nullable: false,
+            discriminators_are_nullable: false,
+        }
+    }
+
+    fn name(&self, kind: target::NameableKind, parts: &[String]) -> String {
+        match kind {
+            target::NameableKind::Type => TYPE_NAMING_CONVENTION.inflect(parts),
+            target::NameableKind::EnumMember => ENUM_MEMBER_NAMING_CONVENTION.inflect(parts),
+
+            // Not used. Zod schemas map directly to the JSON data, so we don't
+            // have the option of distinguishing the JSON name from the
+            // TypeScript name
+            target::NameableKind::Field => "".into(),
+        }
+    }
+
+    fn expr(
+        &self,
+        state: &mut FileState,
+        metadata: metadata::Metadata,
+        expr: target::Expr,
+    ) -> Result<String> {
+        if let Some(s) = metadata.get("typescriptZodType").and_then(|v| v.as_str()) {
+            return Ok(state.register(Schema::Override(s.into())));
+        }
+
+        let schema = match expr {
+            target::Expr::Empty => Schema::Primitive("z.any()".into(), "any"),
+            target::Expr::Boolean => Schema::Primitive("z.boolean()".into(), "boolean"),
+            target::Expr::Int8 => integer(i8::MIN.into(), i8::MAX.into()),
+            target::Expr::Uint8 => integer(u8::MIN.into(), u8::MAX.into()),
+            target::Expr::Int16 => integer(i16::MIN.into(), i16::MAX.into()),
+            target::Expr::Uint16 => integer(u16::MIN.into(), u16::MAX.into()),
+            target::Expr::Int32 => integer(i32::MIN.into(), i32::MAX.into()),
+            target::Expr::Uint32 => integer(u32::MIN.into(), u32::MAX.into()),
+            target::Expr::Float32 => Schema::Primitive("z.number()".into(), "number"),
+            target::Expr::Float64 => Schema::Primitive("z.number()".into(), "number"),
+            target::Expr::String => Schema::Primitive("z.string()".into(), "string"),
+            target::Expr::Timestamp => {
+                Schema::Primitive("z.string().datetime({ offset: true })".into(), "string")
+            }
+            target::Expr::ArrayOf(sub_expr) => Schema::Array(Box::new(state.schema(&sub_expr))),
+            target::Expr::DictOf(sub_expr) => Schema::Record(Box::new(state.schema(&sub_expr))),
+            target::Expr::NullableOf(sub_expr) => {
+                Schema::Nullable(Box::new(state.schema(&sub_expr)))
+            }
+        };
+
+        Ok(state.register(schema))
+    }
+
+    fn item(
+        &self,
+        out: &mut dyn Write,
+        state: &mut FileState,
+        item: target::Item,
+    ) -> Result<Option<String>> {
+        Ok(match item {
+            target::Item::Auxiliary { .. } => {
+                // No auxiliary files needed.
+                None
+            }
+
+            target::Item::Preamble => {
+                writeln!(
+                    out,
+                    "// Code generated by jtd-codegen for TypeScript + Zod v{}",
+                    env!("CARGO_PKG_VERSION")
+                )?;
+                writeln!(out)?;
+                writeln!(out, "import {{ z }} from \"zod\";")?;
+
+                None
+            }
+
+            target::Item::Postamble => {
+                schemas::write_decls(out, &state.decls)?;
+
+                None
+            }
+
+            target::Item::Alias {
+                metadata,
+                name,
+                type_,
+            } => {
+                let kind = Kind::Alias(state.schema(&type_));
+                state.decls.push(Decl {
+                    name,
+                    description: description(&metadata, 0),
+                    kind,
+                });
+
+                None
+            }
+
+            target::Item::Enum {
+                metadata,
+                name,
+                members,
+            } => {
+                if let Some(s) = metadata.get("typescriptZodType").and_then(|v| v.as_str()) {
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
+                }
+
+                let values = members
+                    .into_iter()
+                    .map(|member| {
+                        let description =
+                            enum_variant_description(&metadata, 1, &member.json_value);
+                        (member.json_value, description)
+                    })
+                    .collect();
+
+                state.decls.push(Decl {
+                    name,
+                    description: description(&metadata, 0),
+                    kind: Kind::Enum(values),
+                });
+
+                None
+            }
+
+            target::Item::Struct {
+                metadata,
+                name,
+                has_additional,
+                fields,
+            } => {
+                if let Some(s) = metadata.get("typescriptZodType").and_then(|v| v.as_str()) {
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
+                }
+
+                let kind = Kind::Object {
+                    tag: None,
+                    properties: state.properties(&fields),
+                    has_additional,
+                };
+
+                state.decls.push(Decl {
+                    name,
+                    description: description(&metadata, 0),
+                    kind,
+                });
+
+                None
+            }
+
+            target::Item::Discriminator {
+                metadata,
+                name,
+                tag_json_name,
+                variants,
+                ..
+            } => {
+                if let Some(s) = metadata.get("typescriptZodType").and_then(|v| v.as_str()) {
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
+                }
+
+                let kind = Kind::Union {
+                    tag_json_name,
+                    variants: variants
+                        .iter()
+                        .map(|v| state.schema(&v.type_name))
+                        .collect(),
+                };
+
+                state.decls.push(Decl {
+                    name,
+                    description: description(&metadata, 0),
+                    kind,
+                });
+
+                None
+            }
+
+            target::Item::DiscriminatorVariant {
+                metadata,
+                name,
+                tag_json_name,
+                tag_value,
+                has_additional,
+                fields,
+                ..
+            } => {
+                if let Some(s) = metadata.get("typescriptZodType").and_then(|v| v.as_str()) {
+                    return Ok(Some(format!("{}{}", OVERRIDE, s)));
+                }
+
+                let kind = Kind::Object {
+                    tag: Some((format_property(tag_json_name), tag_value)),
+                    properties: state.properties(&fields),
+                    has_additional,
+                };
+
+                state.decls.push(Decl {
+                    name,
+                    description: description(&metadata, 0),
+                    kind,
+                });
+
+                None
+            }
+        })
+    }
+}
+
+fn integer(min: i64, max: i64) -> Schema {
+    Schema::Primitive(
+        format!("z.number().int().min({}).max({})", min, max),
+        "number",
+    )
+}
+
+fn format_property(s: String) -> String {
+    // This implements a conservative subset of the set of allowable identifiers
+    // in JavaScript:
+    //
+    // https://tc39.es/ecma262/#sec-names-and-keywords
+    //
+    // If a property isn't an allowable identifier by these rules, then we
+    // escape the property.
+    lazy_static! {
+        static ref IDENTIFIER: Regex = Regex::new("^[a-zA-Z_$][a-zA-Z0-9_$]*$").unwrap();
+    }
+
+    if IDENTIFIER.is_match(&s) {
+        s
+    } else {
+        format!("{:?}", s)
+    }
+}
+
+fn description(metadata: &BTreeMap<String, Value>, indent: usize) -> String {
+    doc(indent, jtd_codegen::target::metadata::description(metadata))
+}
+
+fn enum_variant_description(
+    metadata: &BTreeMap<String, Value>,
+    indent: usize,
+    value: &str,
+) -> String {
+    doc(
+        indent,
+        jtd_codegen::target::metadata::enum_variant_description(metadata, value),
+    )
+}
+
+fn doc(ident: usize, s: &str) -> String {
+    let prefix = "  ".repeat(ident);
+    jtd_codegen::target::fmt::comment_block(
+        &format!("{}/**", prefix),
+        &format!("{} * ", prefix),
+        &format!("{} */", prefix),
+        s,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    mod std_tests {
+        jtd_codegen_test::std_test_cases!(&crate::Target::new());
+    }
+
+    mod optional_std_tests {
+        jtd_codegen_test::strict_std_test_case!(
+            &crate::Target::new(),
+            empty_and_nonascii_properties
+        );
+
+        jtd_codegen_test::strict_std_test_case!(
+            &crate::Target::new(),
+            empty_and_nonascii_enum_values
+        );
+    }
+}
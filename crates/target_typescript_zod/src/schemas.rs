@@ -0,0 +1,383 @@
+use jtd_codegen::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+
+/// A Zod schema for an expression, which may refer to declared schemas by
+/// name.
+#[derive(Clone)]
+pub enum Schema {
+    /// A schema for a primitive type, and the TypeScript type it parses into.
+    Primitive(String, &'static str),
+
+    /// A schema given by `typescriptZodType` metadata.
+    Override(String),
+
+    Named(String),
+    Array(Box<Schema>),
+    Record(Box<Schema>),
+    Nullable(Box<Schema>),
+}
+
+impl Schema {
+    // Renders the schema. Declarations named in `lazy` are referred to
+    // lazily, because they haven't been declared yet.
+    fn zod(&self, lazy: &BTreeSet<&str>) -> String {
+        match self {
+            Schema::Primitive(zod, _) | Schema::Override(zod) => zod.clone(),
+            Schema::Named(name) if lazy.contains(name.as_str()) => {
+                format!("z.lazy(() => {})", name)
+            }
+            Schema::Named(name) => name.clone(),
+            Schema::Array(schema) => format!("z.array({})", schema.zod(lazy)),
+            Schema::Record(schema) => format!("z.record(z.string(), {})", schema.zod(lazy)),
+            Schema::Nullable(schema) => format!("{}.nullable()", schema.zod(lazy)),
+        }
+    }
+
+    // The TypeScript type that the schema parses into, for declarations whose
+    // type Zod can't infer.
+    fn ts(&self) -> String {
+        match self {
+            Schema::Primitive(_, ts) => ts.to_string(),
+            Schema::Override(_) => "any".into(),
+            Schema::Named(name) => name.clone(),
+            Schema::Array(schema) => format!("{}[]", schema.ts()),
+            Schema::Record(schema) => format!("{{ [key: string]: {} }}", schema.ts()),
+            Schema::Nullable(schema) => format!("({} | null)", schema.ts()),
+        }
+    }
+
+    fn refs<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Schema::Primitive(..) | Schema::Override(_) => {}
+            Schema::Named(name) => out.push(name),
+            Schema::Array(schema) | Schema::Record(schema) | Schema::Nullable(schema) => {
+                schema.refs(out)
+            }
+        }
+    }
+}
+
+pub struct Property {
+    /// The property's key, formatted for use in an object literal.
+    pub key: String,
+    pub description: String,
+    pub optional: bool,
+    pub schema: Schema,
+}
+
+pub enum Kind {
+    Alias(Schema),
+
+    /// The values of an enum, and their descriptions.
+    Enum(Vec<(String, String)>),
+
+    Object {
+        /// The tag property and value of a discriminator variant.
+        tag: Option<(String, String)>,
+        properties: Vec<Property>,
+        has_additional: bool,
+    },
+
+    Union {
+        tag_json_name: String,
+        variants: Vec<Schema>,
+    },
+}
+
+/// An exported schema, and the type it parses into.
+pub struct Decl {
+    pub name: String,
+    pub description: String,
+    pub kind: Kind,
+}
+
+impl Decl {
+    fn refs(&self) -> Vec<&str> {
+        let mut out = Vec::new();
+        match &self.kind {
+            Kind::Alias(schema) => schema.refs(&mut out),
+            Kind::Enum(_) => {}
+            Kind::Object { properties, .. } => {
+                for property in properties {
+                    property.schema.refs(&mut out);
+                }
+            }
+            Kind::Union { variants, .. } => {
+                for variant in variants {
+                    variant.refs(&mut out);
+                }
+            }
+        }
+
+        out
+    }
+
+    fn is_variant(&self) -> bool {
+        matches!(self.kind, Kind::Object { tag: Some(_), .. })
+    }
+
+    // Writes out the declaration. Declarations named in `lazy` haven't been
+    // written yet.
+    fn write(&self, out: &mut dyn Write, decls: &Decls, lazy: &BTreeSet<&str>) -> Result<()> {
+        writeln!(out)?;
+        write!(out, "{}", self.description)?;
+
+        // Zod can't infer the type of a schema that refers to itself, so such
+        // schemas are given their type explicitly.
+        if lazy.contains(self.name.as_str()) {
+            let ts = self.ts(decls, 0);
+            let separator = if ts.starts_with('\n') { "" } else { " " };
+            writeln!(out, "export type {} ={}{};", self.name, separator, ts)?;
+            writeln!(
+                out,
+                "export const {}: z.ZodType<{}> = {};",
+                self.name,
+                self.name,
+                self.zod(lazy)
+            )?;
+        } else {
+            writeln!(out, "export const {} = {};", self.name, self.zod(lazy))?;
+            writeln!(
+                out,
+                "export type {} = z.infer<typeof {}>;",
+                self.name, self.name
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn zod(&self, lazy: &BTreeSet<&str>) -> String {
+        match &self.kind {
+            Kind::Alias(schema) => schema.zod(lazy),
+
+            Kind::Enum(values) => {
+                // Values are only given a line each if they need room for
+                // their descriptions.
+                if values.iter().all(|(_, description)| description.is_empty()) {
+                    let values: Vec<String> = values
+                        .iter()
+                        .map(|(value, _)| format!("{:?}", value))
+                        .collect();
+                    return format!("z.enum([{}])", values.join(", "));
+                }
+
+                let mut out = "z.enum([\n".to_owned();
+                for (index, (value, description)) in values.iter().enumerate() {
+                    if index != 0 && !description.is_empty() {
+                        out.push('\n');
+                    }
+
+                    out.push_str(description);
+                    out.push_str(&format!("  {:?},\n", value));
+                }
+                out.push_str("])");
+                out
+            }
+
+            Kind::Object {
+                tag,
+                properties,
+                has_additional,
+            } => {
+                let unknown_keys = if *has_additional {
+                    "passthrough"
+                } else {
+                    "strict"
+                };
+
+                if tag.is_none() && properties.is_empty() {
+                    return format!("z.object({{}}).{}()", unknown_keys);
+                }
+
+                let mut out = "z.object({\n".to_owned();
+                if let Some((key, value)) = tag {
+                    out.push_str(&format!("  {}: z.literal({:?}),\n", key, value));
+                }
+
+                for (index, property) in properties.iter().enumerate() {
+                    if (index != 0 || tag.is_some()) && !property.description.is_empty() {
+                        out.push('\n');
+                    }
+
+                    out.push_str(&property.description);
+                    out.push_str(&format!(
+                        "  {}: {}{},\n",
+                        property.key,
+                        property.schema.zod(lazy),
+                        if property.optional { ".optional()" } else { "" }
+                    ));
+                }
+                out.push_str(&format!("}}).{}()", unknown_keys));
+                out
+            }
+
+            Kind::Union {
+                tag_json_name,
+                variants,
+            } => {
+                let schemas: Vec<String> = variants.iter().map(|v| v.zod(lazy)).collect();
+
+                // z.discriminatedUnion only accepts object schemas, which
+                // variants given by typescriptZodType may not be.
+                let overridden = variants.iter().any(|v| matches!(v, Schema::Override(_)));
+                match schemas.len() {
+                    0 => "z.never()".into(),
+                    1 if overridden => schemas[0].clone(),
+                    _ if overridden => format!("z.union([{}])", schemas.join(", ")),
+                    _ => format!(
+                        "z.discriminatedUnion({:?}, [{}])",
+                        tag_json_name,
+                        schemas.join(", ")
+                    ),
+                }
+            }
+        }
+    }
+
+    // The TypeScript type of the declaration, spelled out. Variants are
+    // spelled out in their discriminator's type too, which is how TypeScript
+    // can tell that the discriminator doesn't refer to itself circularly.
+    fn ts(&self, decls: &Decls, indent: usize) -> String {
+        let prefix = "  ".repeat(indent);
+        match &self.kind {
+            Kind::Alias(schema) => schema.ts(),
+
+            Kind::Enum(values) => {
+                let values: Vec<String> = values
+                    .iter()
+                    .map(|(value, _)| format!("{:?}", value))
+                    .collect();
+                values.join(" | ")
+            }
+
+            Kind::Object {
+                tag, properties, ..
+            } => {
+                if tag.is_none() && properties.is_empty() {
+                    return "{}".into();
+                }
+
+                let mut out = "{\n".to_owned();
+                if let Some((key, value)) = tag {
+                    out.push_str(&format!("{}  {}: {:?};\n", prefix, key, value));
+                }
+
+                for (index, property) in properties.iter().enumerate() {
+                    if (index != 0 || tag.is_some()) && !property.description.is_empty() {
+                        out.push('\n');
+                    }
+
+                    for line in property.description.lines() {
+                        out.push_str(&format!("{}{}\n", prefix, line));
+                    }
+
+                    out.push_str(&format!(
+                        "{}  {}{}: {};\n",
+                        prefix,
+                        property.key,
+                        if property.optional { "?" } else { "" },
+                        property.schema.ts()
+                    ));
+                }
+                out.push_str(&format!("{}}}", prefix));
+                out
+            }
+
+            Kind::Union { variants, .. } => {
+                if variants.is_empty() {
+                    return "never".into();
+                }
+
+                let mut out = String::new();
+                for variant in variants {
+                    let ts = match variant {
+                        Schema::Named(name) => match decls.get(name.as_str()) {
+                            Some(decl) => decl.ts(decls, indent + 2),
+                            None => variant.ts(),
+                        },
+                        _ => variant.ts(),
+                    };
+
+                    out.push_str(&format!("\n{}  | {}", prefix, ts));
+                }
+                out
+            }
+        }
+    }
+}
+
+type Decls<'a> = BTreeMap<&'a str, &'a Decl>;
+
+/// Writes out declarations, ordered so that each comes after the ones it
+/// refers to.
+///
+/// Declarations that refer to one another in a cycle can't all be ordered
+/// that way. References that would close a cycle are made lazily instead.
+pub fn write_decls(out: &mut dyn Write, decls: &[Decl]) -> Result<()> {
+    let by_name: Decls = decls
+        .iter()
+        .map(|decl| (decl.name.as_str(), decl))
+        .collect();
+
+    let mut order = Order {
+        by_name: &by_name,
+        visited: BTreeSet::new(),
+        active: BTreeSet::new(),
+        order: Vec::new(),
+        recursive: BTreeSet::new(),
+    };
+
+    // Variants are only visited through their discriminator, so that it's
+    // never a variant that gets referred to lazily. Zod can only build a
+    // discriminated union out of object schemas.
+    for decl in decls.iter().filter(|decl| !decl.is_variant()) {
+        order.visit(decl);
+    }
+
+    // This picks up variants whose discriminator was overridden.
+    for decl in decls {
+        order.visit(decl);
+    }
+
+    // A declaration is referred to lazily until it's been written. Only those
+    // that are referred to recursively are ever referred to before then.
+    let mut lazy = order.recursive;
+    for decl in &order.order {
+        decl.write(out, &by_name, &lazy)?;
+        lazy.remove(decl.name.as_str());
+    }
+
+    Ok(())
+}
+
+struct Order<'a> {
+    by_name: &'a Decls<'a>,
+    visited: BTreeSet<&'a str>,
+    active: BTreeSet<&'a str>,
+    order: Vec<&'a Decl>,
+    recursive: BTreeSet<&'a str>,
+}
+
+impl<'a> Order<'a> {
+    fn visit(&mut self, decl: &'a Decl) {
+        if !self.visited.insert(&decl.name) {
+            return;
+        }
+
+        self.active.insert(&decl.name);
+        for name in decl.refs() {
+            if let Some(referent) = self.by_name.get(name) {
+                if self.active.contains(name) {
+                    self.recursive.insert(&referent.name);
+                } else {
+                    self.visit(referent);
+                }
+            }
+        }
+        self.active.remove(decl.name.as_str());
+
+        self.order.push(decl);
+    }
+}
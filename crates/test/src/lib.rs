@@ -12,72 +12,87 @@ use std::io::{BufRead, BufReader, Read, Write};
 use std::path::Path;
 use std::process::{Command, Stdio};
 
+/// Generates a test for each of the standard test cases.
+///
+/// By default, reference output and docker data are taken from the `output`
+/// and `docker` directories of the calling crate. Targets with more than one
+/// mode of output can pass a different base directory for each mode.
 #[macro_export]
 macro_rules! std_test_cases {
+    ($base_dir: expr, $target: expr) => {
+        $crate::strict_std_test_case!($base_dir, $target, basic_discriminator);
+        $crate::strict_std_test_case!($base_dir, $target, basic_enum);
+        $crate::strict_std_test_case!($base_dir, $target, basic_properties);
+        $crate::strict_std_test_case!($base_dir, $target, custom_overrides);
+        $crate::strict_std_test_case!($base_dir, $target, definition_name_collisions);
+        $crate::strict_std_test_case!($base_dir, $target, description);
+        $crate::strict_std_test_case!($base_dir, $target, elements);
+        $crate::strict_std_test_case!($base_dir, $target, empty_and_nonascii_definitions);
+        $crate::strict_std_test_case!($base_dir, $target, enum_collisions);
+        $crate::strict_std_test_case!($base_dir, $target, enum_variant_collisions);
+        $crate::strict_std_test_case!($base_dir, $target, initialisms);
+        $crate::strict_std_test_case!($base_dir, $target, keywords);
+        $crate::strict_std_test_case!($base_dir, $target, nullable_discriminator);
+        $crate::strict_std_test_case!($base_dir, $target, nullable_enum);
+        $crate::strict_std_test_case!($base_dir, $target, nullable_elements);
+        $crate::strict_std_test_case!($base_dir, $target, nullable_properties);
+        $crate::strict_std_test_case!($base_dir, $target, nullable_references);
+        $crate::strict_std_test_case!($base_dir, $target, property_name_collisions);
+        $crate::strict_std_test_case!($base_dir, $target, reference);
+        $crate::strict_std_test_case!($base_dir, $target, root_boolean);
+        $crate::strict_std_test_case!($base_dir, $target, root_int8);
+        $crate::strict_std_test_case!($base_dir, $target, root_int16);
+        $crate::strict_std_test_case!($base_dir, $target, root_int32);
+        $crate::strict_std_test_case!($base_dir, $target, root_nullable_string);
+        $crate::strict_std_test_case!($base_dir, $target, root_string);
+        $crate::strict_std_test_case!($base_dir, $target, root_uint8);
+        $crate::strict_std_test_case!($base_dir, $target, root_uint16);
+        $crate::strict_std_test_case!($base_dir, $target, root_uint32);
+        $crate::strict_std_test_case!($base_dir, $target, type_collisions);
+        $crate::strict_std_test_case!($base_dir, $target, values);
+
+        $crate::loose_std_test_case!($base_dir, $target, discriminator_optional_properties);
+        $crate::loose_std_test_case!($base_dir, $target, geojson);
+        $crate::loose_std_test_case!($base_dir, $target, nullable_timestamp_property);
+        $crate::loose_std_test_case!($base_dir, $target, optional_properties);
+        $crate::loose_std_test_case!($base_dir, $target, root_empty);
+        $crate::loose_std_test_case!($base_dir, $target, root_float32);
+        $crate::loose_std_test_case!($base_dir, $target, root_float64);
+        $crate::loose_std_test_case!($base_dir, $target, root_nullable_timestamp);
+        $crate::loose_std_test_case!($base_dir, $target, root_timestamp);
+    };
+
     ($target: expr) => {
-        $crate::strict_std_test_case!($target, basic_discriminator);
-        $crate::strict_std_test_case!($target, basic_enum);
-        $crate::strict_std_test_case!($target, basic_properties);
-        $crate::strict_std_test_case!($target, custom_overrides);
-        $crate::strict_std_test_case!($target, definition_name_collisions);
-        $crate::strict_std_test_case!($target, description);
-        $crate::strict_std_test_case!($target, elements);
-        $crate::strict_std_test_case!($target, empty_and_nonascii_definitions);
-        $crate::strict_std_test_case!($target, enum_collisions);
-        $crate::strict_std_test_case!($target, enum_variant_collisions);
-        $crate::strict_std_test_case!($target, initialisms);
-        $crate::strict_std_test_case!($target, keywords);
-        $crate::strict_std_test_case!($target, nullable_discriminator);
-        $crate::strict_std_test_case!($target, nullable_enum);
-        $crate::strict_std_test_case!($target, nullable_elements);
-        $crate::strict_std_test_case!($target, nullable_properties);
-        $crate::strict_std_test_case!($target, nullable_references);
-        $crate::strict_std_test_case!($target, property_name_collisions);
-        $crate::strict_std_test_case!($target, reference);
-        $crate::strict_std_test_case!($target, root_boolean);
-        $crate::strict_std_test_case!($target, root_int8);
-        $crate::strict_std_test_case!($target, root_int16);
-        $crate::strict_std_test_case!($target, root_int32);
-        $crate::strict_std_test_case!($target, root_nullable_string);
-        $crate::strict_std_test_case!($target, root_string);
-        $crate::strict_std_test_case!($target, root_uint8);
-        $crate::strict_std_test_case!($target, root_uint16);
-        $crate::strict_std_test_case!($target, root_uint32);
-        $crate::strict_std_test_case!($target, type_collisions);
-        $crate::strict_std_test_case!($target, values);
-
-        $crate::loose_std_test_case!($target, discriminator_optional_properties);
-        $crate::loose_std_test_case!($target, geojson);
-        $crate::loose_std_test_case!($target, nullable_timestamp_property);
-        $crate::loose_std_test_case!($target, optional_properties);
-        $crate::loose_std_test_case!($target, root_empty);
-        $crate::loose_std_test_case!($target, root_float32);
-        $crate::loose_std_test_case!($target, root_float64);
-        $crate::loose_std_test_case!($target, root_nullable_timestamp);
-        $crate::loose_std_test_case!($target, root_timestamp);
+        $crate::std_test_cases!(env!("CARGO_MANIFEST_DIR"), $target);
     };
 }
 
 #[macro_export]
 macro_rules! strict_std_test_case {
-    ($target: expr, $name: ident) => {
+    ($base_dir: expr, $target: expr, $name: ident) => {
         #[test]
         fn $name() {
-            let base_dir = env!("CARGO_MANIFEST_DIR");
-            $crate::assert_std_test_case(base_dir, $target, stringify!($name), true);
+            $crate::assert_std_test_case($base_dir, $target, stringify!($name), true);
         }
     };
+
+    ($target: expr, $name: ident) => {
+        $crate::strict_std_test_case!(env!("CARGO_MANIFEST_DIR"), $target, $name);
+    };
 }
 
 #[macro_export]
 macro_rules! loose_std_test_case {
-    ($target: expr, $name: ident) => {
+    ($base_dir: expr, $target: expr, $name: ident) => {
         #[test]
         fn $name() {
-            let base_dir = env!("CARGO_MANIFEST_DIR");
-            $crate::assert_std_test_case(base_dir, $target, stringify!($name), false);
+            $crate::assert_std_test_case($base_dir, $target, stringify!($name), false);
         }
     };
+
+    ($target: expr, $name: ident) => {
+        $crate::loose_std_test_case!(env!("CARGO_MANIFEST_DIR"), $target, $name);
+    };
 }
 
 pub fn assert_std_test_case<T: Target>(
@@ -136,58 +151,14 @@ fn assert_roundtrip(
     seed: u64,
     strict: bool,
 ) {
-    // Copy over the target crate's docker data into the temp dir.
     let crate_docker_dir = Path::new(target_crate_base_dir).join("docker");
-    for entry in fs::read_dir(crate_docker_dir).expect("read crate docker dir") {
-        let entry = entry.expect("read crate docker dir entry");
-
-        fs::copy(
-            entry.path(),
-            temp_dir.join(
-                entry
-                    .path()
-                    .file_name()
-                    .expect("crate docker dir entry file name"),
-            ),
-        )
-        .expect("copy crate docker dir entry to temp dir");
-    }
-
-    // Build the docker container. We pipe stdout so we can get back the image
-    // docker created for a later invocation with docker run.
-    //
-    // We let docker build inherit out stderr, that way if the build fails
-    // docker will have outputted a log to stderr, which the Rust test runner
-    // outputs on test failures.
-    let mut docker_build = Command::new("docker")
-        .arg("build")
-        .arg("--quiet")
-        .arg("--build-arg")
-        .arg(format!("MAIN={}", root_name))
-        .arg(temp_dir)
-        .stdout(Stdio::piped())
-        .spawn()
-        .expect("spawn docker build");
-
-    // Ensure docker build succeeds. TODO: do this for docker run as well
-    assert!(
-        docker_build.wait().expect("wait docker build").success(),
-        "docker build failed"
-    );
-
-    // Read the outputted build image.
-    let mut image = String::new();
-    docker_build
-        .stdout
-        .unwrap()
-        .read_to_string(&mut image)
-        .expect("read docker build stdout");
+    let image = build_image(&crate_docker_dir, temp_dir, root_name);
 
     // Run the docker container, with the input piped in.
     let mut docker_run = Command::new("docker")
         .arg("run")
         .arg("--interactive")
-        .arg(image.trim_end())
+        .arg(&image)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .spawn()
@@ -244,6 +215,97 @@ fn assert_roundtrip(
     );
 }
 
+/// Generates code for a schema, and runs it in the docker image described by
+/// `docker_dir` with `input` on stdin, returning what it writes to stdout.
+///
+/// Unlike the standard test cases, this is for checking how generated code
+/// behaves on particular inputs, such as ones it should reject. As with them,
+/// the generated code is put in a `gen` directory next to the docker data,
+/// and the name of its root type is passed in as the `MAIN` build argument.
+pub fn docker_output<T: Target>(
+    docker_dir: &str,
+    target: &T,
+    schema: Value,
+    input: &str,
+) -> String {
+    let schema: SerdeSchema = serde_json::from_value(schema).expect("deserialize schema");
+    let schema: Schema = schema.try_into().expect("validate schema");
+
+    let (temp_dir, root_name) = generate_code(target, &schema);
+    let image = build_image(Path::new(docker_dir), temp_dir.path(), &root_name);
+
+    let mut docker_run = Command::new("docker")
+        .arg("run")
+        .arg("--interactive")
+        .arg(&image)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("spawn docker run");
+
+    docker_run
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .expect("write docker run stdin");
+
+    let output = docker_run.wait_with_output().expect("wait docker run");
+    String::from_utf8(output.stdout).expect("read docker run stdout")
+}
+
+// Builds the docker image for generated code in temp_dir, returning the ID of
+// the image.
+fn build_image(docker_dir: &Path, temp_dir: &Path, root_name: &str) -> String {
+    // Copy over the docker data into the temp dir.
+    for entry in fs::read_dir(docker_dir).expect("read crate docker dir") {
+        let entry = entry.expect("read crate docker dir entry");
+
+        fs::copy(
+            entry.path(),
+            temp_dir.join(
+                entry
+                    .path()
+                    .file_name()
+                    .expect("crate docker dir entry file name"),
+            ),
+        )
+        .expect("copy crate docker dir entry to temp dir");
+    }
+
+    // Build the docker container. We pipe stdout so we can get back the image
+    // docker created for a later invocation with docker run.
+    //
+    // We let docker build inherit out stderr, that way if the build fails
+    // docker will have outputted a log to stderr, which the Rust test runner
+    // outputs on test failures.
+    let mut docker_build = Command::new("docker")
+        .arg("build")
+        .arg("--quiet")
+        .arg("--build-arg")
+        .arg(format!("MAIN={}", root_name))
+        .arg(temp_dir)
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("spawn docker build");
+
+    // Ensure docker build succeeds. TODO: do this for docker run as well
+    assert!(
+        docker_build.wait().expect("wait docker build").success(),
+        "docker build failed"
+    );
+
+    // Read the outputted build image.
+    let mut image = String::new();
+    docker_build
+        .stdout
+        .unwrap()
+        .read_to_string(&mut image)
+        .expect("read docker build stdout");
+
+    image.trim_end().to_owned()
+}
+
 fn assert_stable(target_crate_base_dir: &str, test_case_name: &str, tempdir: &tempfile::TempDir) {
     let output_dir = tempdir.path().join("gen");
     let reference_dir = Path::new(target_crate_base_dir)